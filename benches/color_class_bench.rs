@@ -0,0 +1,67 @@
+//! Compares updating the uncolored vertices of myciel6 after building a large color class, as
+//! GRASP's `assign_color` does, with membership tested on a `Vec` and on a `ColorClass`, with and
+//! without the time to build the latter.
+//!
+//! Run with `cargo bench --bench color_class_bench`.
+
+use gcp_heuristics::algorithms::{
+    color_class::ColorClass, independent_set::maximal_independent_set,
+};
+use gcp_heuristics::input::read_graph_from_file;
+use rand::rngs::SmallRng;
+use rand::SeedableRng;
+use std::hint::black_box;
+use std::time::{Duration, Instant};
+
+const RUNS: u32 = 200;
+
+/// Average duration of `RUNS` calls to `update`, after a warm-up call.
+fn measure(mut update: impl FnMut() -> usize) -> Duration {
+    black_box(update());
+
+    let start = Instant::now();
+    for _ in 0..RUNS {
+        black_box(update());
+    }
+
+    start.elapsed() / RUNS
+}
+
+fn main() {
+    let graph = read_graph_from_file("data/myc/myciel6.col")
+        .unwrap()
+        .expect("The file containing the benchmark graph is missing");
+    let vertex_set: Vec<usize> = (0..graph.num_vertices()).collect();
+    let mut rng = SmallRng::seed_from_u64(0);
+    let class =
+        maximal_independent_set(&graph, &vertex_set, graph.num_vertices(), &mut rng).unwrap();
+
+    let vec = measure(|| {
+        let mut remaining = vertex_set.clone();
+        remaining.retain(|vertex| !class.contains(vertex));
+        remaining.len()
+    });
+    // As in `assign_color`, where each attempt gives a new class
+    let built = measure(|| {
+        let class = ColorClass::from(class.clone());
+        let mut remaining = vertex_set.clone();
+        remaining.retain(|vertex| !class.contains(*vertex));
+        remaining.len()
+    });
+    // As in `grasp_iteration`, where the class kept is already built
+    let class_set = ColorClass::from(class.clone());
+    let prebuilt = measure(|| {
+        let mut remaining = vertex_set.clone();
+        remaining.retain(|vertex| !class_set.contains(*vertex));
+        remaining.len()
+    });
+
+    println!("class of {} vertices", class.len());
+    for (name, duration) in [
+        ("Vec", vec),
+        ("ColorClass (built)", built),
+        ("ColorClass", prebuilt),
+    ] {
+        println!("{:<20} {:>10.3} us", name, duration.as_secs_f64() * 1e6);
+    }
+}
@@ -0,0 +1,53 @@
+//! Compares the degree counts of `AdjList::get_degree_in_list` and
+//! `AdjList::get_degree_in_sorted_list` on myciel7, towards a subset with most of its vertices.
+//!
+//! Run with `cargo bench --bench degree_bench`.
+
+use gcp_heuristics::input::read_graph_from_file;
+use std::hint::black_box;
+use std::time::{Duration, Instant};
+
+const RUNS: u32 = 200;
+
+/// Average duration of `RUNS` calls to `count`, after a warm-up call.
+fn measure(mut count: impl FnMut() -> usize) -> Duration {
+    black_box(count());
+
+    let start = Instant::now();
+    for _ in 0..RUNS {
+        black_box(count());
+    }
+
+    start.elapsed() / RUNS
+}
+
+fn main() {
+    let graph = read_graph_from_file("data/myc/myciel7.col")
+        .unwrap()
+        .expect("The file containing the benchmark graph is missing");
+    let n = graph.num_vertices();
+    // Every vertex but one in ten
+    let subset: Vec<usize> = (0..n).filter(|v| v % 10 != 0).collect();
+
+    let unsorted = measure(|| (0..n).map(|v| graph.get_degree_in_list(v, &subset)).sum());
+    let sorted = measure(|| {
+        (0..n)
+            .map(|v| graph.get_degree_in_sorted_list(v, &subset))
+            .sum()
+    });
+
+    println!(
+        "{:<28} {:>10.3} us",
+        "get_degree_in_list",
+        unsorted.as_secs_f64() * 1e6
+    );
+    println!(
+        "{:<28} {:>10.3} us",
+        "get_degree_in_sorted_list",
+        sorted.as_secs_f64() * 1e6
+    );
+    println!(
+        "speedup: {:.2}x",
+        unsorted.as_secs_f64() / sorted.as_secs_f64()
+    );
+}
@@ -0,0 +1,122 @@
+//! Measures GRASP on myciel3, myciel4 and myciel5 and its building blocks on their own: counting
+//! the edges left once a class is taken, for subsets of myciel7 of several sizes, building an
+//! independent set from candidate lists of several sizes, and the local search on random graphs
+//! of 10, 50 and 100 vertices.
+//!
+//! Run with `cargo bench --bench grasp_bench`, adding `-- --json` to print the results as a JSON
+//! array instead, with the average time of each benchmark in nanoseconds.
+
+use gcp_heuristics::algorithms::grasp::{
+    grasp_with_config, local_search_profiled, GraspConfig, LocalSearchConfig,
+};
+use gcp_heuristics::algorithms::independent_set::maximal_independent_set;
+use gcp_heuristics::graph::random::random_graph;
+use gcp_heuristics::graph::residual::ResidualGraph;
+use gcp_heuristics::input::read_graph_from_file;
+use rand::rngs::SmallRng;
+use rand::{Rng, SeedableRng};
+use std::env;
+use std::hint::black_box;
+use std::time::{Duration, Instant};
+
+const GRASP_RUNS: u32 = 5;
+
+const RUNS: u32 = 200;
+
+/// Average duration of `runs` calls to `f`, after a warm-up call.
+fn measure<T>(runs: u32, mut f: impl FnMut() -> T) -> Duration {
+    black_box(f());
+
+    let start = Instant::now();
+    for _ in 0..runs {
+        black_box(f());
+    }
+
+    start.elapsed() / runs
+}
+
+fn main() {
+    let json = env::args().any(|arg| arg == "--json");
+    let mut results: Vec<(String, Duration)> = Vec::new();
+
+    let config = GraspConfig::new(3, 20, 3).seed(0);
+    for name in ["myciel3", "myciel4", "myciel5"] {
+        let graph = read_graph_from_file(&format!("data/myc/{name}.col"))
+            .unwrap()
+            .expect("The file containing the benchmark graph is missing");
+
+        results.push((
+            format!("grasp/{name}"),
+            measure(GRASP_RUNS, || grasp_with_config(&graph, &config).unwrap()),
+        ));
+    }
+
+    let graph = read_graph_from_file("data/myc/myciel7.col")
+        .unwrap()
+        .expect("The file containing the benchmark graph is missing");
+    let n = graph.num_vertices();
+    for fraction in [10, 50, 90] {
+        let vertex_set: Vec<usize> = (0..n).filter(|v| v % 100 < fraction).collect();
+        let class =
+            maximal_independent_set(&graph, &vertex_set, 5, &mut SmallRng::seed_from_u64(0))
+                .unwrap();
+        let residual = ResidualGraph::new(&graph, &vertex_set);
+
+        results.push((
+            format!("num_edges_without/{fraction}%"),
+            measure(RUNS, || residual.num_edges_without(&class)),
+        ));
+    }
+
+    let vertex_set: Vec<usize> = (0..n).collect();
+    for list_size in [1, 5, 25] {
+        let mut rng = SmallRng::seed_from_u64(0);
+
+        results.push((
+            format!("maximal_independent_set/{list_size}"),
+            measure(RUNS, || {
+                maximal_independent_set(&graph, &vertex_set, list_size, &mut rng).unwrap()
+            }),
+        ));
+    }
+
+    let config = LocalSearchConfig {
+        max_iterations: 100,
+        ..LocalSearchConfig::default()
+    };
+    for n in [10, 50, 100] {
+        // Half of the edges, colored at random with a fifth as many colors as vertices
+        let graph = random_graph(n, n * (n - 1) / 4, 0).unwrap();
+        let mut rng = SmallRng::seed_from_u64(0);
+        let mut class_list = vec![Vec::new(); n / 5];
+        for vertex in 0..n {
+            class_list[rng.gen_range(0..n / 5)].push(vertex);
+        }
+
+        results.push((
+            format!("local_search/{n}"),
+            measure(RUNS, || {
+                let mut class_list = class_list.clone();
+                local_search_profiled(&graph, &mut class_list, &config, &mut rng)
+            }),
+        ));
+    }
+
+    if json {
+        let entries: Vec<String> = results
+            .iter()
+            .map(|(name, duration)| {
+                format!(
+                    "  {{\"name\": \"{name}\", \"average_ns\": {}}}",
+                    duration.as_nanos()
+                )
+            })
+            .collect();
+
+        println!("[\n{}\n]", entries.join(",\n"));
+    } else {
+        for (name, duration) in results.iter() {
+            println!("{:<32} {:>12.3} us", name, duration.as_secs_f64() * 1e6);
+        }
+    }
+}
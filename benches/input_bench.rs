@@ -0,0 +1,66 @@
+//! Measures the throughput of the DIMACS reader on a random graph with 100K edges.
+//!
+//! Run with `cargo bench --bench input_bench`.
+
+use gcp_heuristics::graph::random::random_graph;
+use gcp_heuristics::input::{
+    read_graph_from_file, read_graph_from_file_buffered, write_graph_to_file,
+};
+use std::env;
+use std::fs;
+use std::time::{Duration, Instant};
+
+const NUM_VERTICES: usize = 5000;
+const NUM_EDGES: usize = 100_000;
+const RUNS: u32 = 20;
+
+/// Average duration of `RUNS` calls to `read`, after a warm-up call.
+fn measure(mut read: impl FnMut()) -> Duration {
+    read();
+
+    let start = Instant::now();
+    for _ in 0..RUNS {
+        read();
+    }
+
+    start.elapsed() / RUNS
+}
+
+fn report(name: &str, elapsed: Duration, size: u64) {
+    let seconds = elapsed.as_secs_f64();
+
+    println!(
+        "{name:<24} {:>8.3} ms {:>8.1} MB/s {:>6.2} M edges/s",
+        seconds * 1000.0,
+        size as f64 / seconds / 1e6,
+        NUM_EDGES as f64 / seconds / 1e6
+    );
+}
+
+fn main() {
+    let graph = random_graph(NUM_VERTICES, NUM_EDGES, 0).unwrap();
+    let path = env::temp_dir().join("gcp_heuristics_input_bench.col");
+    let path = path.to_str().unwrap();
+    write_graph_to_file(&graph, path).unwrap();
+    let size = fs::metadata(path).unwrap().len();
+
+    report(
+        "read_graph_from_file",
+        measure(|| {
+            read_graph_from_file(path).unwrap();
+        }),
+        size,
+    );
+
+    for buffer_size in [8 * 1024, 64 * 1024, 1024 * 1024] {
+        report(
+            &format!("buffered ({} KiB)", buffer_size / 1024),
+            measure(|| {
+                read_graph_from_file_buffered(path, buffer_size).unwrap();
+            }),
+            size,
+        );
+    }
+
+    fs::remove_file(path).unwrap();
+}
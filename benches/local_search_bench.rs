@@ -0,0 +1,56 @@
+//! Measures the local search on a random graph colored with many colors, where evaluating the
+//! recolorings of each vertex dominates.
+//!
+//! Run with `cargo bench --bench local_search_bench`, then with `--features parallel` to
+//! compare against the parallel evaluation of the recolorings.
+
+use gcp_heuristics::algorithms::grasp::{
+    local_search_profiled, LocalSearchConfig, LocalSearchStrategy,
+};
+use gcp_heuristics::graph::random::random_graph;
+use rand::rngs::SmallRng;
+use rand::{Rng, SeedableRng};
+use std::hint::black_box;
+use std::time::{Duration, Instant};
+
+const RUNS: u32 = 5;
+
+/// Average duration of `RUNS` local searches with `k` colors, each from the same random class
+/// list.
+fn measure(k: usize) -> Duration {
+    let graph = random_graph(1000, 200_000, 0).unwrap();
+    let config = LocalSearchConfig {
+        strategy: LocalSearchStrategy::BestImprovement,
+        max_iterations: 20,
+        ..LocalSearchConfig::default()
+    };
+    let mut rng = SmallRng::seed_from_u64(0);
+    let mut class_list = vec![Vec::new(); k];
+    for vertex in 0..graph.num_vertices() {
+        class_list[rng.gen_range(0..k)].push(vertex);
+    }
+
+    let start = Instant::now();
+    for run in 0..RUNS {
+        let mut class_list = class_list.clone();
+        let mut rng = SmallRng::seed_from_u64(run as u64);
+        black_box(local_search_profiled(
+            &graph,
+            &mut class_list,
+            &config,
+            &mut rng,
+        ));
+    }
+
+    start.elapsed() / RUNS
+}
+
+fn main() {
+    for k in [10, 50, 100, 200] {
+        println!(
+            "{:<8} {:>10.3} ms",
+            format!("k = {k}"),
+            measure(k).as_secs_f64() * 1e3
+        );
+    }
+}
@@ -0,0 +1,76 @@
+//! Compares counting the edges left among the uncolored vertices of myciel7 once a color class is
+//! taken, from scratch over the edge list and with `ResidualGraph::num_edges_without`, and then
+//! measures GRASP, which counts them once per attempt at building a class.
+//!
+//! Run with `cargo bench --bench residual_bench`.
+
+use gcp_heuristics::algorithms::grasp::{grasp_with_config, GraspConfig};
+use gcp_heuristics::algorithms::independent_set::maximal_independent_set;
+use gcp_heuristics::graph::residual::ResidualGraph;
+use gcp_heuristics::input::read_graph_from_file;
+use rand::rngs::SmallRng;
+use rand::SeedableRng;
+use std::hint::black_box;
+use std::time::{Duration, Instant};
+
+const RUNS: u32 = 200;
+
+const GRASP_RUNS: u32 = 5;
+
+/// Average duration of `runs` calls to `f`, after a warm-up call.
+fn measure<T>(runs: u32, mut f: impl FnMut() -> T) -> Duration {
+    black_box(f());
+
+    let start = Instant::now();
+    for _ in 0..runs {
+        black_box(f());
+    }
+
+    start.elapsed() / runs
+}
+
+fn main() {
+    let graph = read_graph_from_file("data/myc/myciel7.col")
+        .unwrap()
+        .expect("The file containing the benchmark graph is missing");
+    let n = graph.num_vertices();
+    // Every vertex but one in ten is uncolored
+    let vertex_set: Vec<usize> = (0..n).filter(|v| v % 10 != 0).collect();
+    let class =
+        maximal_independent_set(&graph, &vertex_set, 5, &mut SmallRng::seed_from_u64(0)).unwrap();
+    let residual = ResidualGraph::new(&graph, &vertex_set);
+
+    let from_scratch = measure(RUNS, || {
+        let mut remaining = vec![false; n];
+        for vertex in vertex_set.iter().filter(|vertex| !class.contains(vertex)) {
+            remaining[*vertex] = true;
+        }
+
+        graph
+            .edge_list_as_slice()
+            .iter()
+            .filter(|(u, v)| remaining[*u] && remaining[*v])
+            .count()
+    });
+    let incremental = measure(RUNS, || residual.num_edges_without(&class));
+
+    println!(
+        "{:<28} {:>10.3} us",
+        "from scratch",
+        from_scratch.as_secs_f64() * 1e6
+    );
+    println!(
+        "{:<28} {:>10.3} us",
+        "num_edges_without",
+        incremental.as_secs_f64() * 1e6
+    );
+    println!(
+        "speedup: {:.2}x",
+        from_scratch.as_secs_f64() / incremental.as_secs_f64()
+    );
+
+    let config = GraspConfig::new(3, 50, 5).seed(0);
+    let grasp = measure(GRASP_RUNS, || grasp_with_config(&graph, &config).unwrap());
+
+    println!("{:<28} {:>10.3} ms", "grasp", grasp.as_secs_f64() * 1e3);
+}
@@ -0,0 +1,42 @@
+#![no_main]
+
+use gcp_heuristics::{algorithms::count_forbidden_edges_for_vertex, graph::adj_list::AdjList};
+use libfuzzer_sys::{arbitrary, fuzz_target};
+
+#[derive(Debug, arbitrary::Arbitrary)]
+struct Input {
+    num_vertices: u8,
+    edges: Vec<(u8, u8)>,
+    coloring: Vec<u8>,
+}
+
+// Run with `cargo fuzz run coloring_validity`. Counting the forbidden edges and validating the
+// coloring should agree on any graph and coloring.
+fuzz_target!(|input: Input| {
+    let n = input.num_vertices as usize;
+    let mut graph = AdjList::new(n);
+    for (u, v) in input.edges.iter().map(|(u, v)| (*u as usize, *v as usize)) {
+        if u < n && v < n && u != v {
+            graph.add_edge(u, v);
+        }
+    }
+
+    // Colors are 1-indexed, so 0 stands for an uncolored vertex
+    let coloring: Vec<usize> = input
+        .coloring
+        .iter()
+        .map(|color| *color as usize)
+        .chain(std::iter::repeat(1))
+        .take(n)
+        .collect();
+    let num_forbidden: usize = (0..n)
+        .map(|vertex| count_forbidden_edges_for_vertex(&graph, &coloring, vertex))
+        .sum();
+    let all_colored = coloring.iter().all(|color| *color > 0);
+
+    assert_eq!(
+        graph.is_valid_coloring(&coloring),
+        all_colored && num_forbidden == 0
+    );
+    assert_eq!(num_forbidden, 2 * graph.conflict_subgraph(&coloring).num_edges());
+});
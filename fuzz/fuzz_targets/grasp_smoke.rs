@@ -0,0 +1,30 @@
+#![no_main]
+
+use gcp_heuristics::{
+    algorithms::grasp::{grasp_with_config, GraspConfig},
+    graph::adj_list::AdjList,
+};
+use libfuzzer_sys::fuzz_target;
+
+// Run with `cargo fuzz run grasp_smoke`. GRASP should return a valid coloring of any small graph.
+// The first byte gives the number of vertices, up to 10, and each following pair of bytes an edge.
+fuzz_target!(|data: &[u8]| {
+    let Some((first, rest)) = data.split_first() else {
+        return;
+    };
+    let n = *first as usize % 10 + 1;
+    let mut graph = AdjList::new(n);
+    for pair in rest.chunks_exact(2) {
+        let (u, v) = (pair[0] as usize % n, pair[1] as usize % n);
+
+        if u != v {
+            graph.add_edge(u, v);
+        }
+    }
+
+    let config = GraspConfig::new(2, 2, 3).seed(0);
+    let (num_colors, coloring) = grasp_with_config(&graph, &config).unwrap();
+
+    assert!(graph.is_valid_coloring(&coloring));
+    assert!((1..=n).contains(&num_colors));
+});
@@ -0,0 +1,31 @@
+#![no_main]
+
+use gcp_heuristics::graph::adj_list::AdjList;
+use libfuzzer_sys::fuzz_target;
+
+// Run with `cargo fuzz run parse_dimacs`. The parser should reject malformed input with an error
+// instead of panicking, and any graph it returns should be well formed.
+fuzz_target!(|data: &[u8]| {
+    let Ok(s) = std::str::from_utf8(data) else {
+        return;
+    };
+    let Ok(Some(graph)) = AdjList::from_dimacs_string(s) else {
+        return;
+    };
+
+    // The header's edge count isn't checked against the `e` lines, as repeated edges are dropped
+    let n = graph.num_vertices();
+    let mut degree_sum = 0;
+
+    for (u, neighbors) in graph.adj_list().iter().enumerate() {
+        degree_sum += neighbors.len();
+
+        for v in neighbors.iter() {
+            assert!(*v < n && *v != u);
+            assert!(graph.adj_list()[*v].contains(&u));
+        }
+    }
+
+    assert_eq!(degree_sum, 2 * graph.num_edges());
+    assert_eq!(graph.edge_list_as_slice().len(), graph.num_edges());
+});
@@ -0,0 +1,422 @@
+use super::get_class_list_from_coloring;
+use super::greedy::welsh_powell;
+use crate::graph::adj_list::AdjList;
+
+/// An upper bound for the chromatic number, given by the number of colors of [`welsh_powell`].
+pub fn greedy_upper_bound(graph: &AdjList) -> usize {
+    welsh_powell(graph).0
+}
+
+/// Number of cliques of a partition of the vertices of `graph` into cliques, built by adding each
+/// vertex, in order, to the first clique it is adjacent to entirely, in `O(n^2)` time.
+fn greedy_clique_partition_size(graph: &AdjList) -> usize {
+    let mut cliques: Vec<Vec<usize>> = Vec::new();
+    let mut is_neighbor = vec![false; graph.num_vertices()];
+
+    for vertex in 0..graph.num_vertices() {
+        for neighbor in graph.adj_list()[vertex].iter() {
+            is_neighbor[*neighbor] = true;
+        }
+
+        match cliques
+            .iter_mut()
+            .find(|clique| clique.iter().all(|u| is_neighbor[*u]))
+        {
+            Some(clique) => clique.push(vertex),
+            None => cliques.push(vec![vertex]),
+        }
+
+        for neighbor in graph.adj_list()[vertex].iter() {
+            is_neighbor[*neighbor] = false;
+        }
+    }
+
+    cliques.len()
+}
+
+/// A lower bound for the fractional chromatic number of `graph`, and thus for its chromatic
+/// number, `n / θ`, where `θ` is the number of cliques of a greedy partition of the vertices into
+/// cliques. An independent set takes at most one vertex of each clique, so `θ` is at least the
+/// independence number `α(G)` and the bound is at most `n / α(G)`.
+///
+/// Equals 0 for the empty graph.
+pub fn fractional_chromatic_lower_bound(graph: &AdjList) -> f64 {
+    let num_vertices = graph.num_vertices();
+
+    if num_vertices == 0 {
+        return 0.0;
+    }
+
+    // There is a vertex, so there is a clique
+    num_vertices as f64 / greedy_clique_partition_size(graph) as f64
+}
+
+/// Hoffman's lower bound for the chromatic number of `graph`, `1 + λ_max / |λ_min|`, see
+/// [`AdjList::spectral_lower_bound`]. The eigenvalues are computed with an error bound that is
+/// applied on the safe side, so the result never exceeds the chromatic number, although it may be
+/// a hair below the exact value of the formula.
+pub fn hoffman_lower_bound(graph: &AdjList) -> f64 {
+    graph.spectral_lower_bound()
+}
+
+/// Sandwiches the chromatic number of `graph` between [`fractional_chromatic_lower_bound`] and
+/// [`greedy_upper_bound`], returned in that order.
+pub fn chromatic_bounds(graph: &AdjList) -> (f64, usize) {
+    (
+        fractional_chromatic_lower_bound(graph),
+        greedy_upper_bound(graph),
+    )
+}
+
+/// A bound for the chromatic number computed by [`compute_bounds`].
+#[derive(Debug, Clone, Copy, PartialEq, Eq)]
+pub enum BoundMethod {
+    /// Lower bound given by [`AdjList::clique_lower_bound`].
+    Clique,
+    /// Lower bound given by [`hoffman_lower_bound`].
+    Spectral,
+    /// Lower bound given by [`fractional_chromatic_lower_bound`].
+    Fractional,
+    /// Upper bound given by the degeneracy `d` of the graph, which first-fit in the
+    /// [`AdjList::degeneracy_ordering`] colors with at most `d + 1` colors.
+    DegeneracyPlus1,
+    /// Upper bound given by [`greedy_upper_bound`].
+    WelshPowell,
+    /// Upper bound of 4 for planar graphs (see [`AdjList::is_planar`]), by the four color
+    /// theorem. Doesn't bound other graphs.
+    Planar,
+}
+
+/// Bounds for the chromatic number aggregated by [`compute_bounds`].
+#[derive(Debug, Clone, PartialEq)]
+pub struct ColoringBounds {
+    /// Largest of the lower bounds, or 0 if none was computed.
+    pub lower: f64,
+    /// Smallest of the upper bounds, or the number of vertices if none was computed.
+    pub upper: usize,
+    /// The methods computed, each once, in the order they were given.
+    pub methods_used: Vec<BoundMethod>,
+}
+
+/// Computes the bounds of the given `methods` for the chromatic number of `graph`, keeping the
+/// largest lower bound and the smallest upper bound. Every method gives a proven bound, with the
+/// error of the eigenvalues of [`BoundMethod::Spectral`] applied on the safe side, so the
+/// chromatic number is always between them.
+pub fn compute_bounds(graph: &AdjList, methods: &[BoundMethod]) -> ColoringBounds {
+    let mut bounds = ColoringBounds {
+        lower: 0.0,
+        upper: graph.num_vertices(),
+        methods_used: Vec::new(),
+    };
+
+    for method in methods.iter().copied() {
+        if bounds.methods_used.contains(&method) {
+            continue;
+        }
+
+        match method {
+            BoundMethod::Clique => {
+                bounds.lower = bounds.lower.max(graph.clique_lower_bound() as f64);
+            }
+            BoundMethod::Spectral => {
+                bounds.lower = bounds.lower.max(hoffman_lower_bound(graph));
+            }
+            BoundMethod::Fractional => {
+                bounds.lower = bounds.lower.max(fractional_chromatic_lower_bound(graph));
+            }
+            BoundMethod::DegeneracyPlus1 => {
+                // The degeneracy is the largest coreness, and the empty graph needs no colors
+                let upper = graph
+                    .k_core_decomposition()
+                    .into_iter()
+                    .max()
+                    .map_or(0, |degeneracy| degeneracy + 1);
+                bounds.upper = bounds.upper.min(upper);
+            }
+            BoundMethod::WelshPowell => {
+                bounds.upper = bounds.upper.min(greedy_upper_bound(graph));
+            }
+            BoundMethod::Planar => {
+                if graph.is_planar() {
+                    bounds.upper = bounds.upper.min(4);
+                }
+            }
+        }
+
+        bounds.methods_used.push(method);
+    }
+
+    bounds
+}
+
+/// A certificate that a coloring is optimal: its classes along with a clique with as many
+/// vertices as there are (non-empty) classes, so that no coloring can use fewer colors.
+#[derive(Debug, Clone, PartialEq, Eq)]
+pub struct OptimalityProof {
+    pub coloring: Vec<Vec<usize>>,
+    pub clique: Vec<usize>,
+}
+
+impl OptimalityProof {
+    /// Checks the certificate: the coloring is a valid class list of `graph`, and the clique is
+    /// made of distinct, pairwise adjacent vertices of `graph`, as many as there are colors.
+    pub fn verify(&self, graph: &AdjList) -> bool {
+        let num_colors = self
+            .coloring
+            .iter()
+            .filter(|class| !class.is_empty())
+            .count();
+        let is_clique = self.clique.iter().enumerate().all(|(i, u)| {
+            *u < graph.num_vertices() && self.clique[i + 1..].iter().all(|v| graph.has_edge(*u, *v))
+        });
+
+        graph.is_valid_class_list(&self.coloring) && is_clique && self.clique.len() == num_colors
+    }
+}
+
+/// Looks for an [`OptimalityProof`] of the chromatic number of `graph`, which requires it to
+/// equal the clique number, as it does for perfect graphs. Only the coloring of
+/// [`greedy_upper_bound`] and the clique of [`AdjList::clique_lower_bound`] are tried, so the
+/// proof is found when both bounds meet.
+pub fn try_find_optimality_proof(graph: &AdjList) -> Option<OptimalityProof> {
+    let clique = graph.greedy_clique();
+    let (num_colors, coloring) = welsh_powell(graph);
+
+    (clique.len() == num_colors).then(|| OptimalityProof {
+        coloring: get_class_list_from_coloring(&coloring),
+        clique,
+    })
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+    use crate::{
+        algorithms::chromatic_polynomial::chromatic_number_exact_via_polynomial,
+        graph::{
+            generators::{cycle_graph, path_graph},
+            random::random_graph,
+        },
+        input,
+    };
+
+    #[test]
+    fn test_chromatic_bounds() {
+        assert_eq!(chromatic_bounds(&AdjList::complete(5)), (5.0, 5));
+        assert_eq!(chromatic_bounds(&cycle_graph(6)), (2.0, 2));
+        // Any partition of C_5 into cliques takes 3 of them, although α(C_5) is 2
+        assert_eq!(chromatic_bounds(&cycle_graph(5)), (5.0 / 3.0, 3));
+        assert_eq!(chromatic_bounds(&AdjList::new(0)), (0.0, 0));
+    }
+
+    /// 30 disjoint copies of C_6, on which greedy independent sets often miss one of the 90
+    /// vertices of a maximum independent set.
+    fn disjoint_hexagons() -> AdjList {
+        let mut graph = AdjList::new(180);
+        for start in (0..180).step_by(6) {
+            for i in 0..6 {
+                graph.add_edge(start + i, start + (i + 1) % 6);
+            }
+        }
+
+        graph
+    }
+
+    #[test]
+    fn test_fractional_chromatic_lower_bound() {
+        assert_eq!(fractional_chromatic_lower_bound(&disjoint_hexagons()), 2.0);
+        assert_eq!(fractional_chromatic_lower_bound(&AdjList::new(4)), 1.0);
+
+        if let Ok(Some(graph)) = input::read_graph_from_file("data/myc/myciel4.col") {
+            // myciel4 has chromatic number 5
+            assert!(fractional_chromatic_lower_bound(&graph) <= 5.0);
+        } else {
+            panic!("The file containing the test graph is missing")
+        }
+    }
+
+    #[test]
+    fn test_compute_bounds() {
+        let all = [
+            BoundMethod::Clique,
+            BoundMethod::Spectral,
+            BoundMethod::Fractional,
+            BoundMethod::DegeneracyPlus1,
+            BoundMethod::WelshPowell,
+            BoundMethod::Planar,
+        ];
+
+        // Every method is exact on K_5
+        let graph = AdjList::complete(5);
+        for method in &all[..3] {
+            assert!((compute_bounds(&graph, &[*method]).lower - 5.0).abs() < 1e-6);
+        }
+        for method in &all[3..] {
+            assert_eq!(compute_bounds(&graph, &[*method]).upper, 5);
+            assert_eq!(compute_bounds(&graph, &[*method]).lower, 0.0);
+        }
+
+        let bounds = compute_bounds(&graph, &all);
+
+        assert!((bounds.lower - 5.0).abs() < 1e-6);
+        assert_eq!(bounds.upper, 5);
+
+        // On C_5 the Hoffman bound, 1 + 2 / (2 cos(π / 5)), is the best lower bound, while the
+        // fractional one is below the clique one
+        let bounds = compute_bounds(&cycle_graph(5), &all);
+
+        assert!((bounds.lower - (1.0 + 1.0 / (std::f64::consts::PI / 5.0).cos())).abs() < 1e-6);
+        assert!(compute_bounds(&cycle_graph(5), &[BoundMethod::Fractional]).lower < 2.0);
+        assert_eq!(bounds.upper, 3);
+        assert_eq!(bounds.methods_used, all.to_vec());
+
+        // The greedy independent sets of the hexagons miss a maximum one, which must not push the
+        // lower bound above the upper one
+        let graph = disjoint_hexagons();
+        let bounds = compute_bounds(&graph, &all);
+
+        assert!(graph.independence_number_approx(100, 0) < 90);
+        assert!(bounds.lower <= bounds.upper as f64);
+        assert_eq!((bounds.lower, bounds.upper), (2.0, 2));
+
+        // The four color theorem only bounds planar graphs
+        assert_eq!(
+            compute_bounds(&cycle_graph(11), &[BoundMethod::Planar]).upper,
+            4
+        );
+        assert_eq!(
+            compute_bounds(&AdjList::complete(5), &[BoundMethod::Planar]).upper,
+            5
+        );
+
+        // Repeated methods are computed once, and the trivial bounds are used without methods
+        let bounds = compute_bounds(&cycle_graph(5), &[BoundMethod::Clique, BoundMethod::Clique]);
+
+        assert_eq!(bounds.lower, 2.0);
+        assert_eq!(bounds.upper, 5);
+        assert_eq!(bounds.methods_used, vec![BoundMethod::Clique]);
+        assert_eq!(
+            compute_bounds(&AdjList::new(0), &all),
+            ColoringBounds {
+                lower: 0.0,
+                upper: 0,
+                methods_used: all.to_vec(),
+            }
+        );
+    }
+
+    #[test]
+    fn test_compute_bounds_around_chromatic_number() {
+        let all = [
+            BoundMethod::Clique,
+            BoundMethod::Spectral,
+            BoundMethod::Fractional,
+            BoundMethod::DegeneracyPlus1,
+            BoundMethod::WelshPowell,
+            BoundMethod::Planar,
+        ];
+
+        // The Hoffman bound of a long path must not cross the greedy coloring with 2 colors
+        let bounds = compute_bounds(
+            &path_graph(50),
+            &[BoundMethod::Spectral, BoundMethod::WelshPowell],
+        );
+
+        assert!(bounds.lower <= 2.0);
+        assert_eq!(bounds.upper, 2);
+
+        for seed in 0..150 {
+            let n = 1 + (seed % 9) as usize;
+            let m = (seed as usize * 5) % (n * (n - 1) / 2 + 1);
+            let graph = random_graph(n, m, seed).unwrap();
+            let chromatic_number = chromatic_number_exact_via_polynomial(&graph).unwrap();
+            let bounds = compute_bounds(&graph, &all);
+
+            assert!(bounds.lower <= chromatic_number as f64);
+            assert!(chromatic_number <= bounds.upper);
+        }
+    }
+
+    #[test]
+    fn test_hoffman_lower_bound() {
+        // The eigenvalues of K_n are n - 1 and -1
+        assert!((hoffman_lower_bound(&AdjList::complete(5)) - 5.0).abs() < 1e-6);
+
+        // The eigenvalues of C_5 are 2 and 2cos(4π/5), so the bound is 1 + 2 / φ = √5
+        assert!((hoffman_lower_bound(&cycle_graph(5)) - 5.0f64.sqrt()).abs() < 1e-6);
+
+        // Bipartite graphs have symmetric spectra
+        assert!((hoffman_lower_bound(&cycle_graph(6)) - 2.0).abs() < 1e-6);
+
+        assert_eq!(hoffman_lower_bound(&AdjList::new(3)), 1.0);
+        assert_eq!(hoffman_lower_bound(&AdjList::new(0)), 0.0);
+
+        // Neither a long path nor an eigenvector missed by the eigensolver can push the bound
+        // above the chromatic number
+        assert!(hoffman_lower_bound(&path_graph(50)) <= 2.0);
+
+        for (n, m, seed, chromatic_number) in [(5, 6, 26, 3), (5, 6, 202, 3), (6, 13, 299, 4)] {
+            let graph = random_graph(n, m, seed).unwrap();
+
+            assert_eq!(
+                chromatic_number_exact_via_polynomial(&graph).unwrap(),
+                chromatic_number
+            );
+            assert!(hoffman_lower_bound(&graph) <= chromatic_number as f64);
+        }
+
+        if let Ok(Some(graph)) = input::read_graph_from_file("data/myc/myciel3.col") {
+            let bound = hoffman_lower_bound(&graph);
+
+            assert!(bound > 1.0 && bound <= 4.0);
+        } else {
+            panic!("The file containing the test graph is missing")
+        }
+    }
+
+    #[test]
+    fn test_chromatic_bounds_myciel() {
+        if let Ok(Some(graph)) = input::read_graph_from_file("data/myc/myciel3.col") {
+            let (lower, upper) = chromatic_bounds(&graph);
+
+            // The chromatic number of myciel3 is 4
+            assert!(lower <= 4.0);
+            assert!(upper >= 4);
+        } else {
+            panic!("The file containing the test graph is missing")
+        }
+    }
+    #[test]
+    fn test_try_find_optimality_proof() {
+        for graph in [AdjList::complete(5), cycle_graph(6), AdjList::new(0)] {
+            let proof = try_find_optimality_proof(&graph).unwrap();
+
+            assert!(proof.verify(&graph));
+            assert_eq!(proof.clique.len(), greedy_upper_bound(&graph));
+        }
+
+        let graph = cycle_graph(6);
+        let proof = try_find_optimality_proof(&graph).unwrap();
+
+        assert_eq!(proof.clique.len(), 2);
+        assert_eq!(proof.coloring.len(), 2);
+
+        // C_5 needs 3 colors, but has no triangle
+        assert_eq!(try_find_optimality_proof(&cycle_graph(5)), None);
+
+        // A smaller clique, a non-clique and an invalid coloring are all rejected
+        let mut wrong = proof.clone();
+        wrong.clique.pop();
+        assert!(!wrong.verify(&graph));
+
+        let mut wrong = proof.clone();
+        wrong.clique = vec![0, 2];
+        assert!(!wrong.verify(&graph));
+
+        let wrong = OptimalityProof {
+            coloring: vec![vec![0, 1, 2], vec![3, 4, 5]],
+            ..proof
+        };
+        assert!(!wrong.verify(&graph));
+    }
+}
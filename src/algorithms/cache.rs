@@ -0,0 +1,148 @@
+use super::{
+    get_class_list_from_coloring,
+    grasp::{grasp_with_config, GraspConfig},
+    ColoringError,
+};
+use crate::graph::adj_list::AdjList;
+use std::collections::HashMap;
+
+/// Identifies a GRASP execution: the [`AdjList::fingerprint`] of the graph, along with its size
+/// and edges to tell apart graphs with the same fingerprint, and the configuration.
+#[derive(Debug, Clone, PartialEq, Eq, Hash)]
+struct CacheKey {
+    fingerprint: u64,
+    num_vertices: usize,
+    edges: Vec<(usize, usize)>,
+    // GraspConfig holds trait objects, so configurations are compared through their description
+    config: String,
+}
+
+impl CacheKey {
+    fn new(graph: &AdjList, config: &GraspConfig) -> Self {
+        CacheKey {
+            fingerprint: graph.fingerprint(),
+            num_vertices: graph.num_vertices(),
+            edges: graph.edge_list(),
+            config: format!("{config:?}"),
+        }
+    }
+}
+
+/// Number of colors and class list of a solution.
+type ClassListSolution = (usize, Vec<Vec<usize>>);
+
+/// Runs GRASP through a cache of the last `cache_size` solutions, so that solving the same graph
+/// with the same configuration again returns the stored solution.
+///
+/// When the cache is full, the least recently used solution is evicted. Only seeded
+/// configurations are reproducible, so a cached solution is just one of the possible outcomes for
+/// the unseeded ones.
+#[derive(Debug, Clone)]
+pub struct ColoringSolver {
+    cache_size: usize,
+    // Solution of each key and the time of its last use
+    cache: HashMap<CacheKey, (ClassListSolution, usize)>,
+    clock: usize,
+    num_runs: usize,
+}
+
+impl ColoringSolver {
+    /// Builds a solver keeping up to `cache_size` solutions. A size of 0 disables the cache.
+    pub fn new(cache_size: usize) -> Self {
+        ColoringSolver {
+            cache_size,
+            cache: HashMap::with_capacity(cache_size),
+            clock: 0,
+            num_runs: 0,
+        }
+    }
+
+    /// Returns the number of colors and the class list of the solution of `graph` with `config`,
+    /// running [`grasp_with_config`] only if it isn't cached.
+    pub fn solve(
+        &mut self,
+        graph: &AdjList,
+        config: &GraspConfig,
+    ) -> Result<ClassListSolution, ColoringError> {
+        let key = CacheKey::new(graph, config);
+        self.clock += 1;
+
+        if let Some((solution, last_use)) = self.cache.get_mut(&key) {
+            *last_use = self.clock;
+            return Ok(solution.clone());
+        }
+
+        let (num_colors, coloring) = grasp_with_config(graph, config)?;
+        let solution = (num_colors, get_class_list_from_coloring(&coloring));
+        self.num_runs += 1;
+
+        if self.cache_size == 0 {
+            return Ok(solution);
+        }
+
+        if self.cache.len() == self.cache_size {
+            // The cache isn't empty, so we can unwrap
+            let least_recent = self
+                .cache
+                .iter()
+                .min_by_key(|(_, (_, last_use))| *last_use)
+                .map(|(key, _)| key.clone())
+                .unwrap();
+            self.cache.remove(&least_recent);
+        }
+
+        self.cache.insert(key, (solution.clone(), self.clock));
+
+        Ok(solution)
+    }
+
+    /// Number of GRASP executions run, i.e., of calls to [`ColoringSolver::solve`] that missed
+    /// the cache.
+    pub fn num_runs(&self) -> usize {
+        self.num_runs
+    }
+
+    /// Number of solutions currently cached.
+    pub fn len(&self) -> usize {
+        self.cache.len()
+    }
+
+    pub fn is_empty(&self) -> bool {
+        self.cache.is_empty()
+    }
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+    use crate::{graph::generators::cycle_graph, input};
+
+    #[test]
+    fn test_coloring_solver() {
+        if let Ok(Some(graph)) = input::read_graph_from_file("data/myc/myciel3.col") {
+            let config = GraspConfig::new(5, 5, 3).seed(7);
+            let mut solver = ColoringSolver::new(2);
+
+            let first = solver.solve(&graph, &config).unwrap();
+            let second = solver.solve(&graph, &config).unwrap();
+
+            assert_eq!(first, second);
+            assert_eq!(solver.num_runs(), 1);
+            assert!(graph.is_valid_class_list(&first.1));
+
+            // A different configuration is another execution
+            solver.solve(&graph, &config.clone().seed(8)).unwrap();
+
+            assert_eq!(solver.num_runs(), 2);
+
+            // The cycle evicts the least recently used entry, the one with seed 7
+            solver.solve(&cycle_graph(5), &config).unwrap();
+            solver.solve(&graph, &config).unwrap();
+
+            assert_eq!(solver.num_runs(), 4);
+            assert_eq!(solver.len(), 2);
+        } else {
+            panic!("The file containing the test graph is missing")
+        }
+    }
+}
@@ -0,0 +1,334 @@
+use crate::graph::adj_list::AdjList;
+use std::error::Error;
+use std::fmt;
+
+/// Largest number of vertices accepted by [`chromatic_polynomial`].
+pub const MAX_VERTICES: usize = 20;
+
+/// Primes used to evaluate the chromatic polynomial. Their product exceeds `k^n` for any
+/// `k, n <= MAX_VERTICES`, so a value is zero iff it is zero modulo both of them.
+const MODULI: [i128; 2] = [(1 << 61) - 1, 1_000_000_007];
+
+/// The graph has more than [`MAX_VERTICES`] vertices.
+#[derive(Debug, Clone, PartialEq, Eq)]
+pub struct TooLargeError {
+    pub num_vertices: usize,
+}
+
+impl fmt::Display for TooLargeError {
+    fn fmt(&self, f: &mut fmt::Formatter<'_>) -> fmt::Result {
+        write!(
+            f,
+            "the graph has {} vertices, but at most {MAX_VERTICES} are supported",
+            self.num_vertices
+        )
+    }
+}
+
+impl Error for TooLargeError {}
+
+/// Coefficients of `k^n`.
+fn power(n: usize) -> Vec<i64> {
+    let mut coefficients = vec![0; n + 1];
+    coefficients[n] = 1;
+    coefficients
+}
+
+/// Coefficients of `k (k - 1) ... (k - n + 1)`, the chromatic polynomial of `K_n`.
+fn falling_factorial(n: usize) -> Vec<i64> {
+    let mut coefficients = vec![1];
+
+    for i in 0..n as i64 {
+        // Multiplying by (k - i)
+        let mut next = vec![0; coefficients.len() + 1];
+        for (j, coefficient) in coefficients.iter().enumerate() {
+            next[j + 1] += coefficient;
+            next[j] -= i * coefficient;
+        }
+        coefficients = next;
+    }
+
+    coefficients
+}
+
+/// Applies the deletion-contraction recurrence `P(G) = P(G - e) - P(G / e)` on the graph given
+/// by the adjacency bitmasks `adj`, restricted to the vertices in `present`.
+fn deletion_contraction(adj: [u32; MAX_VERTICES], present: u32) -> Vec<i64> {
+    let n = present.count_ones() as usize;
+    let num_edges: usize = adj
+        .iter()
+        .map(|mask| mask.count_ones() as usize)
+        .sum::<usize>()
+        / 2;
+
+    if num_edges == n * n.saturating_sub(1) / 2 {
+        // Also covers the graphs with less than two vertices
+        return falling_factorial(n);
+    }
+
+    let Some(u) = (0..MAX_VERTICES).find(|u| adj[*u] != 0) else {
+        return power(n);
+    };
+    let v = adj[u].trailing_zeros() as usize;
+
+    let mut deleted = adj;
+    deleted[u] &= !(1 << v);
+    deleted[v] &= !(1 << u);
+
+    // Merging v into u
+    let mut contracted = deleted;
+    contracted[u] |= deleted[v];
+    contracted[v] = 0;
+    for w in (0..MAX_VERTICES).filter(|w| deleted[v] & (1 << w) != 0) {
+        contracted[w] = (contracted[w] & !(1 << v)) | (1 << u);
+    }
+
+    let mut coefficients = deletion_contraction(deleted, present);
+    let subtracted = deletion_contraction(contracted, present & !(1 << v));
+
+    for (i, coefficient) in subtracted.iter().enumerate() {
+        coefficients[i] -= coefficient;
+    }
+
+    coefficients
+}
+
+/// Computes the chromatic polynomial `P(G, k)` of `graph`, which counts its colorings with (at
+/// most) `k` colors, with the deletion-contraction recurrence.
+///
+/// The `i`th element is the coefficient of `k^i`. Takes exponential time, so graphs with more
+/// than [`MAX_VERTICES`] vertices are rejected.
+pub fn chromatic_polynomial(graph: &AdjList) -> Result<Vec<i64>, TooLargeError> {
+    let num_vertices = graph.num_vertices();
+
+    if num_vertices > MAX_VERTICES {
+        return Err(TooLargeError { num_vertices });
+    }
+
+    let mut adj = [0; MAX_VERTICES];
+    for (vertex, neighbors) in graph.adj_list().iter().enumerate() {
+        for neighbor in neighbors.iter() {
+            adj[vertex] |= 1 << neighbor;
+        }
+    }
+
+    let present = ((1u64 << num_vertices) - 1) as u32;
+
+    Ok(deletion_contraction(adj, present))
+}
+
+/// Evaluates the polynomial with `coefficients` at `k`, modulo `modulus`.
+fn evaluate_mod(coefficients: &[i64], k: usize, modulus: i128) -> i128 {
+    coefficients.iter().rev().fold(0, |value, coefficient| {
+        (value * k as i128 + *coefficient as i128).rem_euclid(modulus)
+    })
+}
+
+/// Computes the chromatic number of `graph` as the smallest `k` such that `P(G, k) > 0`.
+pub fn chromatic_number_exact_via_polynomial(graph: &AdjList) -> Result<usize, TooLargeError> {
+    let coefficients = chromatic_polynomial(graph)?;
+
+    // Every graph is n-colorable, so the search ends
+    Ok((0..=graph.num_vertices())
+        .find(|k| {
+            MODULI
+                .iter()
+                .any(|modulus| evaluate_mod(&coefficients, *k, *modulus) != 0)
+        })
+        .unwrap())
+}
+
+/// Coefficients of `p^k` up to the one of `x^degree`, in wrapping arithmetic.
+fn truncated_power(p: &[u64], mut k: usize, degree: usize) -> Vec<u64> {
+    let multiply = |a: &[u64], b: &[u64]| {
+        let mut product = vec![0u64; degree + 1];
+        for (i, x) in a.iter().enumerate().filter(|(_, x)| **x != 0) {
+            for (j, y) in b.iter().enumerate().take(degree + 1 - i) {
+                product[i + j] = product[i + j].wrapping_add(x.wrapping_mul(*y));
+            }
+        }
+        product
+    };
+
+    let mut result = vec![0; degree + 1];
+    result[0] = 1;
+    let mut base = p.to_vec();
+    base.resize(degree + 1, 0);
+
+    while k > 0 {
+        if k & 1 == 1 {
+            result = multiply(&result, &base);
+        }
+        k >>= 1;
+        if k > 0 {
+            base = multiply(&base, &base);
+        }
+    }
+
+    result
+}
+
+/// Counts the proper colorings of `graph` with (at most) `k` colors, i.e., `P(G, k)`, without
+/// going through [`chromatic_polynomial`], so that each can be checked against the other.
+///
+/// A coloring is a sequence of `k` disjoint independent sets covering the graph. By
+/// inclusion-exclusion, their number is the sum over the vertex subsets `S` of
+/// `(-1)^(n - |S|)` times the number of sequences of `k` independent sets within `S` whose sizes
+/// add up to `n`, which are disjoint and cover `S` exactly when `S` is the whole graph. The
+/// independent sets within every subset are counted by size with a zeta transform, in
+/// `O(n^2 2^n)` time and `O(n 2^n)` memory, plus `O(n^2 log k)` time per subset.
+///
+/// The count wraps around if it exceeds [`u64::MAX`]. Graphs with more than [`MAX_VERTICES`]
+/// vertices are rejected.
+pub fn k_coloring_count(graph: &AdjList, k: usize) -> Result<u64, TooLargeError> {
+    let n = graph.num_vertices();
+
+    if n > MAX_VERTICES {
+        return Err(TooLargeError { num_vertices: n });
+    }
+
+    let mut adj = [0u32; MAX_VERTICES];
+    for (vertex, neighbors) in graph.adj_list().iter().enumerate() {
+        for neighbor in neighbors.iter() {
+            adj[vertex] |= 1 << neighbor;
+        }
+    }
+
+    let num_subsets = 1usize << n;
+
+    // counts[j * num_subsets + s] is the number of independent sets of size j within s, which
+    // fits in a u32 since it is at most C(20, 10)
+    let mut counts = vec![0u32; (n + 1) * num_subsets];
+    let mut independent = vec![true; num_subsets];
+    counts[0] = 1;
+    for s in 1..num_subsets {
+        let v = s.trailing_zeros() as usize;
+        let rest = s & (s - 1);
+        independent[s] = independent[rest] && adj[v] & rest as u32 == 0;
+        if independent[s] {
+            counts[s.count_ones() as usize * num_subsets + s] = 1;
+        }
+    }
+
+    for j in 0..=n {
+        let rank = &mut counts[j * num_subsets..(j + 1) * num_subsets];
+        for i in 0..n {
+            for s in (0..num_subsets).filter(|s| s & (1 << i) != 0) {
+                rank[s] += rank[s ^ (1 << i)];
+            }
+        }
+    }
+
+    let mut total = 0u64;
+    for s in 0..num_subsets {
+        let size = s.count_ones() as usize;
+
+        // The independent sets within s are too small to reach n vertices
+        if size * k < n {
+            continue;
+        }
+
+        let p: Vec<u64> = (0..=size)
+            .map(|j| counts[j * num_subsets + s] as u64)
+            .collect();
+        let sequences = truncated_power(&p, k, n)[n];
+
+        total = if (n - size).is_multiple_of(2) {
+            total.wrapping_add(sequences)
+        } else {
+            total.wrapping_sub(sequences)
+        };
+    }
+
+    Ok(total)
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+    use crate::{graph::generators::cycle_graph, input};
+
+    #[test]
+    fn test_chromatic_polynomial() {
+        // k (k - 1) (k - 2)
+        assert_eq!(
+            chromatic_polynomial(&AdjList::complete(3)),
+            Ok(vec![0, 2, -3, 1])
+        );
+
+        // (k - 1)^4 + (k - 1)
+        assert_eq!(
+            chromatic_polynomial(&cycle_graph(4)),
+            Ok(vec![0, -3, 6, -4, 1])
+        );
+
+        // k^3
+        assert_eq!(chromatic_polynomial(&AdjList::new(3)), Ok(vec![0, 0, 0, 1]));
+    }
+
+    #[test]
+    fn test_chromatic_number_exact_via_polynomial() {
+        assert_eq!(
+            chromatic_number_exact_via_polynomial(&cycle_graph(5)),
+            Ok(3)
+        );
+        assert_eq!(
+            chromatic_number_exact_via_polynomial(&AdjList::new(0)),
+            Ok(0)
+        );
+
+        if let Ok(Some(graph)) = input::read_graph_from_file("data/myc/myciel3.col") {
+            assert_eq!(chromatic_number_exact_via_polynomial(&graph), Ok(4));
+        } else {
+            panic!("The file containing the test graph is missing")
+        }
+
+        if let Ok(Some(graph)) = input::read_graph_from_file("data/myc/myciel4.col") {
+            assert_eq!(
+                chromatic_polynomial(&graph),
+                Err(TooLargeError { num_vertices: 23 })
+            );
+        } else {
+            panic!("The file containing the test graph is missing")
+        }
+    }
+
+    #[test]
+    fn test_k_coloring_count() {
+        assert_eq!(k_coloring_count(&AdjList::complete(3), 3), Ok(6));
+        assert_eq!(k_coloring_count(&AdjList::complete(3), 2), Ok(0));
+        assert_eq!(k_coloring_count(&cycle_graph(4), 2), Ok(2));
+        assert_eq!(k_coloring_count(&AdjList::new(0), 0), Ok(1));
+        assert_eq!(k_coloring_count(&AdjList::new(3), 4), Ok(64));
+
+        let evaluate = |coefficients: &[i64], k: usize| {
+            coefficients.iter().rev().fold(0i128, |value, coefficient| {
+                value * k as i128 + *coefficient as i128
+            })
+        };
+
+        if let Ok(Some(graph)) = input::read_graph_from_file("data/myc/myciel3.col") {
+            for graph in [graph, cycle_graph(5), AdjList::complete(4)] {
+                let coefficients = chromatic_polynomial(&graph).unwrap();
+
+                for k in 0..=6 {
+                    assert_eq!(
+                        k_coloring_count(&graph, k).unwrap() as i128,
+                        evaluate(&coefficients, k)
+                    );
+                }
+            }
+        } else {
+            panic!("The file containing the test graph is missing")
+        }
+
+        if let Ok(Some(graph)) = input::read_graph_from_file("data/myc/myciel4.col") {
+            assert_eq!(
+                k_coloring_count(&graph, 5),
+                Err(TooLargeError { num_vertices: 23 })
+            );
+        } else {
+            panic!("The file containing the test graph is missing")
+        }
+    }
+}
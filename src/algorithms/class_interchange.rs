@@ -0,0 +1,182 @@
+use super::get_coloring_from_class_list;
+use crate::graph::adj_list::AdjList;
+use std::collections::VecDeque;
+
+/// Best repartition of the vertices of the classes `class_a` and `class_b` (with 1-indexed colors
+/// `color_a` and `color_b` in `coloring`) between the two of them.
+///
+/// Each connected component of the subgraph induced by both classes is split along the levels
+/// of a BFS, which removes all its conflicts if it is bipartite, and the split is kept when it
+/// has fewer conflicts than the current one. Returns the number of conflicts eliminated and the
+/// vertices that change class.
+fn best_repartition(
+    graph: &AdjList,
+    coloring: &[usize],
+    color_a: usize,
+    color_b: usize,
+) -> (usize, Vec<usize>) {
+    let in_pair = |v: usize| coloring[v] == color_a || coloring[v] == color_b;
+    // Side of each visited vertex in the BFS split
+    let mut side: Vec<Option<usize>> = vec![None; graph.num_vertices()];
+    let mut total_gain = 0;
+    let mut changed = Vec::new();
+
+    for start in (0..graph.num_vertices()).filter(|v| in_pair(*v)) {
+        if side[start].is_some() {
+            continue;
+        }
+
+        let mut component = vec![start];
+        let mut queue = VecDeque::from([start]);
+        side[start] = Some(color_a);
+
+        while let Some(vertex) = queue.pop_front() {
+            let other = if side[vertex] == Some(color_a) {
+                color_b
+            } else {
+                color_a
+            };
+
+            for neighbor in graph.adj_list()[vertex].iter().copied() {
+                if in_pair(neighbor) && side[neighbor].is_none() {
+                    side[neighbor] = Some(other);
+                    component.push(neighbor);
+                    queue.push_back(neighbor);
+                }
+            }
+        }
+
+        // Every edge is seen from both endpoints
+        let count_conflicts = |color_of: &dyn Fn(usize) -> usize| {
+            component
+                .iter()
+                .map(|u| {
+                    graph.adj_list()[*u]
+                        .iter()
+                        .filter(|v| in_pair(**v) && color_of(**v) == color_of(*u))
+                        .count()
+                })
+                .sum::<usize>()
+                / 2
+        };
+        let current = count_conflicts(&|v| coloring[v]);
+        let split = count_conflicts(&|v| side[v].unwrap());
+
+        if split < current {
+            total_gain += current - split;
+
+            // Either orientation of the split works, so the one moving fewer vertices is kept
+            let flipped: Vec<usize> = component
+                .iter()
+                .copied()
+                .filter(|v| side[*v] != Some(coloring[*v]))
+                .collect();
+
+            if 2 * flipped.len() <= component.len() {
+                changed.extend(flipped);
+            } else {
+                changed.extend(component.iter().filter(|v| !flipped.contains(v)));
+            }
+        }
+    }
+
+    (total_gain, changed)
+}
+
+/// Looks for the pair of classes of `class_list` whose vertices can be redistributed between
+/// the two of them to eliminate the most conflicts, and applies that redistribution.
+///
+/// Unlike recoloring single vertices, a redistribution can move many vertices at once in both
+/// directions, so it can escape the local optima of [`super::grasp::local_search`]. See
+/// [`best_repartition`] for how each pair is redistributed. Takes `O(k (n + m))` time for `k`
+/// classes.
+///
+/// Returns the number of conflicts eliminated, leaving `class_list` untouched if it is 0.
+pub fn color_class_interchange(graph: &AdjList, class_list: &mut [Vec<usize>]) -> usize {
+    let coloring = get_coloring_from_class_list(graph.num_vertices(), class_list);
+    let mut best: Option<(usize, usize, usize, Vec<usize>)> = None;
+
+    for i in 0..class_list.len() {
+        for j in i + 1..class_list.len() {
+            // Colors are 1-indexed
+            let (gain, changed) = best_repartition(graph, &coloring, i + 1, j + 1);
+
+            if gain > best.as_ref().map_or(0, |(best_gain, _, _, _)| *best_gain) {
+                best = Some((gain, i, j, changed));
+            }
+        }
+    }
+
+    let Some((gain, i, j, changed)) = best else {
+        return 0;
+    };
+
+    class_list[i].retain(|v| !changed.contains(v));
+    class_list[j].retain(|v| !changed.contains(v));
+
+    for vertex in changed {
+        if coloring[vertex] == i + 1 {
+            class_list[j].push(vertex);
+        } else {
+            class_list[i].push(vertex);
+        }
+    }
+
+    gain
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+    use crate::{
+        algorithms::grasp::{local_search_with_objective, LocalSearchConfig},
+        graph::generators::cycle_graph,
+    };
+    use rand::rngs::SmallRng;
+    use rand::SeedableRng;
+
+    #[test]
+    fn test_color_class_interchange() {
+        // Two adjacent pairs of the 4-cycle 0---1---2---3---0 share a class. Recoloring any
+        // single vertex keeps 2 conflicts, so the local search (without ejection chains) is
+        // frozen
+        let graph = cycle_graph(4);
+        let mut class_list = vec![vec![0, 1], vec![2, 3]];
+
+        assert_eq!(
+            local_search_with_objective(
+                &graph,
+                &mut class_list,
+                true,
+                &LocalSearchConfig::default(),
+                &mut SmallRng::seed_from_u64(0)
+            ),
+            2
+        );
+
+        assert_eq!(color_class_interchange(&graph, &mut class_list), 2);
+        assert!(graph.is_valid_class_list(&class_list));
+        assert_eq!(class_list.iter().map(Vec::len).collect::<Vec<_>>(), [2, 2]);
+    }
+
+    #[test]
+    fn test_color_class_interchange_best_pair() {
+        // A triangle with two vertices in the same class, and a path 3---4---5 within that class
+        let mut graph = AdjList::complete(3);
+        for _ in 0..3 {
+            graph.add_vertex();
+        }
+        graph.add_edge(3, 4);
+        graph.add_edge(4, 5);
+        let mut class_list = vec![vec![0, 1, 3, 4, 5], vec![2], vec![]];
+
+        // Splitting the first class with the empty one eliminates the conflicts of the path and
+        // the one of the triangle, while the first two classes could only fix the path
+        assert_eq!(color_class_interchange(&graph, &mut class_list), 3);
+        assert!(graph.is_valid_class_list(&class_list));
+        assert_eq!(class_list[1], vec![2]);
+
+        // Nothing is left to improve
+        assert_eq!(color_class_interchange(&graph, &mut class_list), 0);
+    }
+}
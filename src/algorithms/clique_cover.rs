@@ -0,0 +1,73 @@
+use super::{
+    get_class_list_from_coloring,
+    grasp::{grasp_with_config, GraspConfig},
+};
+use crate::graph::adj_list::AdjList;
+
+/// Partitions the vertices of `graph` into few cliques, for instance to group compatible tasks.
+///
+/// The cliques of `graph` are the independent sets of its complement, so the partition is given
+/// by the color classes of a GRASP coloring of the complement (see [`AdjList::complement`]).
+///
+/// Returns the number of cliques and the cliques themselves.
+pub fn clique_cover(graph: &AdjList) -> (usize, Vec<Vec<usize>>) {
+    if graph.num_vertices() == 0 {
+        return (0, Vec::new());
+    }
+
+    // The default GRASP parameters are valid, so we can unwrap
+    let (num_cliques, coloring) =
+        grasp_with_config(&graph.complement(), &GraspConfig::default()).unwrap();
+
+    (num_cliques, get_class_list_from_coloring(&coloring))
+}
+
+/// Approximates the clique cover number of `graph`, the fewest cliques partitioning its vertices,
+/// which is the chromatic number of its complement, by the size of [`clique_cover`].
+pub fn clique_cover_number_approx(graph: &AdjList) -> usize {
+    clique_cover(graph).0
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+    use crate::input;
+
+    #[test]
+    fn test_clique_cover() {
+        let (num_cliques, cover) = clique_cover(&AdjList::complete(5));
+
+        assert_eq!(num_cliques, 1);
+        assert_eq!(cover, vec![vec![0, 1, 2, 3, 4]]);
+
+        let (num_cliques, cover) = clique_cover(&AdjList::empty_graph(5));
+
+        assert_eq!(num_cliques, 5);
+        assert!(cover.iter().all(|clique| clique.len() == 1));
+
+        assert_eq!(clique_cover(&AdjList::new(0)), (0, Vec::new()));
+
+        // Two disjoint triangles
+        let mut graph = AdjList::new(6);
+        for i in 0..3 {
+            graph.add_edge(i, (i + 1) % 3);
+            graph.add_edge(3 + i, 3 + (i + 1) % 3);
+        }
+
+        assert_eq!(clique_cover_number_approx(&graph), 2);
+    }
+
+    #[test]
+    fn test_clique_cover_myciel3() {
+        if let Ok(Some(graph)) = input::read_graph_from_file("data/myc/myciel3.col") {
+            let (num_cliques, cover) = clique_cover(&graph);
+
+            // The graph is triangle-free, so the cliques are edges or single vertices
+            assert!(num_cliques >= 6);
+            assert_eq!(cover.len(), num_cliques);
+            assert!(graph.is_clique_cover_valid(&cover));
+        } else {
+            panic!("The file containing the test graph is missing")
+        }
+    }
+}
@@ -0,0 +1,181 @@
+use crate::graph::adj_list::AdjList;
+use std::collections::HashSet;
+
+/// A (partial) coloring kept both as a color per vertex and as color classes, along with its
+/// number of forbidden edges, all updated incrementally.
+///
+/// Colors are 1-indexed, from 1 to the number of colors `k`.
+#[derive(Debug, Clone)]
+pub struct ColorAssignment {
+    // 0 for unassigned vertices
+    assignment: Vec<usize>,
+    classes: Vec<HashSet<usize>>,
+    conflict_count: usize,
+}
+
+impl ColorAssignment {
+    /// Builds an assignment of `n` uncolored vertices with `k` colors available.
+    pub fn new(n: usize, k: usize) -> Self {
+        ColorAssignment {
+            assignment: vec![0; n],
+            classes: vec![HashSet::new(); k],
+            conflict_count: 0,
+        }
+    }
+
+    /// Builds the assignment of `graph` given by `class_list`, with as many colors as classes.
+    /// Vertices outside every class are left uncolored.
+    pub fn from_class_list(graph: &AdjList, class_list: &[Vec<usize>]) -> Self {
+        let mut assignment = ColorAssignment::new(graph.num_vertices(), class_list.len());
+
+        for (i, class) in class_list.iter().enumerate() {
+            for vertex in class.iter() {
+                assignment.assign(graph, *vertex, i + 1);
+            }
+        }
+
+        assignment
+    }
+
+    /// Gives `color` to `vertex`, replacing its previous color.
+    pub fn assign(&mut self, graph: &AdjList, vertex: usize, color: usize) {
+        assert!((1..=self.classes.len()).contains(&color));
+
+        self.unassign(graph, vertex);
+
+        self.assignment[vertex] = color;
+        self.classes[color - 1].insert(vertex);
+        self.conflict_count += self.conflicts_for(vertex, graph);
+    }
+
+    /// Removes the color of `vertex`, if any.
+    pub fn unassign(&mut self, graph: &AdjList, vertex: usize) {
+        if let Some(color) = self.color_of(vertex) {
+            self.conflict_count -= self.conflicts_for(vertex, graph);
+            self.classes[color - 1].remove(&vertex);
+            self.assignment[vertex] = 0;
+        }
+    }
+
+    /// The color of every vertex, 0 for the uncolored ones.
+    pub fn coloring(&self) -> &[usize] {
+        &self.assignment
+    }
+
+    pub fn color_of(&self, vertex: usize) -> Option<usize> {
+        match self.assignment[vertex] {
+            0 => None,
+            color => Some(color),
+        }
+    }
+
+    /// Number of neighbors of `vertex` sharing its color (0 if it is uncolored).
+    pub fn conflicts_for(&self, vertex: usize, graph: &AdjList) -> usize {
+        self.color_of(vertex)
+            .map_or(0, |color| self.conflicts_with_color(vertex, color, graph))
+    }
+
+    /// Number of neighbors of `vertex` colored with `color`.
+    pub fn conflicts_with_color(&self, vertex: usize, color: usize, graph: &AdjList) -> usize {
+        graph.adj_list()[vertex]
+            .iter()
+            .filter(|u| self.assignment[**u] == color)
+            .count()
+    }
+
+    /// Total weight of the edges between `vertex` and its neighbors colored with `color`.
+    pub fn weighted_conflicts_with_color(
+        &self,
+        vertex: usize,
+        color: usize,
+        graph: &AdjList,
+    ) -> f64 {
+        graph.adj_list()[vertex]
+            .iter()
+            .filter(|u| self.assignment[**u] == color)
+            .map(|u| graph.edge_weight(vertex, *u).unwrap())
+            .sum()
+    }
+
+    /// Number of forbidden edges, i.e., edges whose endpoints share a color.
+    pub fn conflict_count(&self) -> usize {
+        self.conflict_count
+    }
+
+    pub fn num_colors(&self) -> usize {
+        self.classes.len()
+    }
+
+    pub fn classes(&self) -> &[HashSet<usize>] {
+        &self.classes
+    }
+
+    /// Vertices with at least one conflict, in increasing order.
+    pub fn forbidden_vertices(&self, graph: &AdjList) -> Vec<usize> {
+        (0..self.assignment.len())
+            .filter(|vertex| self.conflicts_for(*vertex, graph) > 0)
+            .collect()
+    }
+
+    /// The color classes as a class list, each class sorted.
+    pub fn to_class_list(&self) -> Vec<Vec<usize>> {
+        self.classes
+            .iter()
+            .map(|class| {
+                let mut class: Vec<usize> = class.iter().copied().collect();
+                class.sort();
+                class
+            })
+            .collect()
+    }
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+
+    #[test]
+    fn test_color_assignment() {
+        // A path 0---1---2---3
+        let mut graph = AdjList::new(4);
+        graph.add_edge(0, 1);
+        graph.add_edge(1, 2);
+        graph.add_edge(2, 3);
+        let mut assignment = ColorAssignment::new(4, 2);
+
+        for vertex in 0..4 {
+            assignment.assign(&graph, vertex, 1);
+        }
+
+        assert_eq!(assignment.conflict_count(), 3);
+        assert_eq!(assignment.conflicts_for(1, &graph), 2);
+
+        assignment.assign(&graph, 1, 2);
+
+        assert_eq!(assignment.conflict_count(), 1);
+        assert_eq!(assignment.color_of(1), Some(2));
+        assert_eq!(assignment.forbidden_vertices(&graph), vec![2, 3]);
+
+        assignment.unassign(&graph, 3);
+
+        assert_eq!(assignment.conflict_count(), 0);
+        assert_eq!(assignment.color_of(3), None);
+        assert_eq!(assignment.conflicts_for(3, &graph), 0);
+        assert_eq!(assignment.to_class_list(), vec![vec![0, 2], vec![1]]);
+
+        // Unassigning twice has no effect
+        assignment.unassign(&graph, 3);
+
+        assert_eq!(assignment.conflict_count(), 0);
+    }
+
+    #[test]
+    fn test_from_class_list() {
+        let graph = AdjList::complete(4);
+        let assignment = ColorAssignment::from_class_list(&graph, &[vec![0, 1, 2], vec![3]]);
+
+        assert_eq!(assignment.num_colors(), 2);
+        assert_eq!(assignment.conflict_count(), 3);
+        assert_eq!(assignment.to_class_list(), vec![vec![0, 1, 2], vec![3]]);
+    }
+}
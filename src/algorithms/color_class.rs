@@ -0,0 +1,97 @@
+use std::collections::HashSet;
+
+/// A color class kept both as the list of its vertices, in insertion order, and as a set, so that
+/// membership is tested in constant time instead of scanning the list.
+#[derive(Debug, Clone, Default, PartialEq, Eq)]
+pub struct ColorClass {
+    members: Vec<usize>,
+    member_set: HashSet<usize>,
+}
+
+impl ColorClass {
+    pub fn new() -> Self {
+        ColorClass::default()
+    }
+
+    /// Checks whether `vertex` is in the class, in `O(1)` time.
+    pub fn contains(&self, vertex: usize) -> bool {
+        self.member_set.contains(&vertex)
+    }
+
+    /// Adds `vertex` to the end of the class, unless it is already in it.
+    pub fn push(&mut self, vertex: usize) {
+        if self.member_set.insert(vertex) {
+            self.members.push(vertex);
+        }
+    }
+
+    /// Removes `vertex` from the class, keeping the order of the others. Returns whether it was
+    /// in the class.
+    pub fn remove(&mut self, vertex: usize) -> bool {
+        if !self.member_set.remove(&vertex) {
+            return false;
+        }
+
+        self.members.retain(|member| *member != vertex);
+
+        true
+    }
+
+    pub fn iter(&self) -> impl Iterator<Item = &usize> {
+        self.members.iter()
+    }
+
+    pub fn len(&self) -> usize {
+        self.members.len()
+    }
+
+    pub fn is_empty(&self) -> bool {
+        self.members.is_empty()
+    }
+
+    /// The vertices of the class, in insertion order.
+    pub fn into_members(self) -> Vec<usize> {
+        self.members
+    }
+}
+
+impl From<Vec<usize>> for ColorClass {
+    /// Builds the class of the vertices of `members`, dropping the repeated ones.
+    fn from(members: Vec<usize>) -> Self {
+        let mut class = ColorClass::new();
+        for vertex in members {
+            class.push(vertex);
+        }
+
+        class
+    }
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+
+    #[test]
+    fn test_color_class() {
+        let mut class = ColorClass::from(vec![3, 1, 4, 1, 5]);
+
+        assert_eq!(class.len(), 4);
+        assert!(class.contains(1) && class.contains(5));
+        assert!(!class.contains(2));
+
+        class.push(2);
+        class.push(3);
+
+        assert_eq!(
+            class.iter().copied().collect::<Vec<usize>>(),
+            [3, 1, 4, 5, 2]
+        );
+
+        assert!(class.remove(4));
+        assert!(!class.remove(4));
+        assert!(!class.contains(4));
+        assert_eq!(class.clone().into_members(), vec![3, 1, 5, 2]);
+
+        assert!(ColorClass::new().is_empty());
+    }
+}
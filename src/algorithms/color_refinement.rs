@@ -0,0 +1,138 @@
+use crate::graph::adj_list::AdjList;
+use std::collections::hash_map::DefaultHasher;
+use std::collections::{HashMap, HashSet};
+use std::hash::{Hash, Hasher};
+
+fn hash_of(value: impl Hash) -> u64 {
+    let mut hasher = DefaultHasher::new();
+    value.hash(&mut hasher);
+    hasher.finish()
+}
+
+/// Runs the 1-dimensional Weisfeiler-Leman refinement from `initial_coloring` and returns the
+/// stable colors as hashes.
+///
+/// Each round replaces the color of every vertex with the hash of its color and the sorted colors
+/// of its neighbors. Hashes don't depend on the numbering of the vertices, so isomorphic graphs
+/// get the same colors.
+fn refine(graph: &AdjList, initial_coloring: &[usize]) -> Vec<u64> {
+    assert_eq!(initial_coloring.len(), graph.num_vertices());
+
+    let mut colors: Vec<u64> = initial_coloring.iter().map(hash_of).collect();
+    let mut num_colors = colors.iter().collect::<HashSet<_>>().len();
+
+    loop {
+        let refined: Vec<u64> = (0..graph.num_vertices())
+            .map(|vertex| {
+                let mut neighbor_colors: Vec<u64> = graph.adj_list()[vertex]
+                    .iter()
+                    .map(|u| colors[*u])
+                    .collect();
+                neighbor_colors.sort();
+
+                hash_of((colors[vertex], neighbor_colors))
+            })
+            .collect();
+        let num_refined = refined.iter().collect::<HashSet<_>>().len();
+        colors = refined;
+
+        // Classes are only ever split, so the refinement is stable once none was
+        if num_refined == num_colors {
+            return colors;
+        }
+
+        num_colors = num_refined;
+    }
+}
+
+/// Refines `initial_coloring` with the 1-dimensional Weisfeiler-Leman algorithm until it is
+/// stable: two vertices end up with the same color iff they had the same color, and the same
+/// number of neighbors of each color, in every round.
+///
+/// `initial_coloring` holds any label per vertex. The stable colors are numbered from 0 in order
+/// of first appearance, so they only describe the partition of this graph (see
+/// [`wl_canonical_form`] to compare graphs).
+pub fn wl_color_refinement(graph: &AdjList, initial_coloring: &[usize]) -> Vec<usize> {
+    let mut names: HashMap<u64, usize> = HashMap::new();
+
+    refine(graph, initial_coloring)
+        .into_iter()
+        .map(|color| {
+            let next = names.len();
+            *names.entry(color).or_insert(next)
+        })
+        .collect()
+}
+
+/// Certificate of `graph` given by the Weisfeiler-Leman refinement from a single color: the
+/// sorted stable colors, which don't depend on the numbering of the vertices.
+///
+/// Isomorphic graphs have the same form, so graphs with different forms aren't isomorphic. The
+/// converse doesn't hold: for instance, every `d`-regular graph on `n` vertices gets the same form.
+pub fn wl_canonical_form(graph: &AdjList) -> Vec<usize> {
+    let mut form: Vec<usize> = refine(graph, &vec![0; graph.num_vertices()])
+        .into_iter()
+        .map(|color| color as usize)
+        .collect();
+    form.sort();
+
+    form
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+    use crate::graph::generators::{cycle_graph, path_graph};
+
+    #[test]
+    fn test_wl_color_refinement() {
+        // The ends of a path split from the inner vertices, and then by their distance to the ends
+        assert_eq!(
+            wl_color_refinement(&path_graph(5), &[0; 5]),
+            vec![0, 1, 2, 1, 0]
+        );
+
+        // Vertex-transitive graphs are never split
+        assert_eq!(wl_color_refinement(&cycle_graph(6), &[0; 6]), vec![0; 6]);
+
+        // Unless the initial coloring breaks the symmetry
+        assert_eq!(
+            wl_color_refinement(&cycle_graph(6), &[1, 0, 0, 0, 0, 0]),
+            vec![0, 1, 2, 3, 2, 1]
+        );
+    }
+
+    #[test]
+    fn test_wl_canonical_form() {
+        let triangle = AdjList::complete(3);
+        // A path 1---0---2, a relabeling of P_3
+        let mut relabeled_path = AdjList::new(3);
+        relabeled_path.add_edge(0, 1);
+        relabeled_path.add_edge(0, 2);
+
+        assert_ne!(
+            wl_canonical_form(&triangle),
+            wl_canonical_form(&path_graph(3))
+        );
+        assert_eq!(
+            wl_canonical_form(&triangle),
+            wl_canonical_form(&AdjList::complete(3))
+        );
+        assert_eq!(
+            wl_canonical_form(&path_graph(3)),
+            wl_canonical_form(&relabeled_path)
+        );
+
+        // C_6 and two disjoint triangles are both 2-regular, which the refinement can't tell apart
+        let mut two_triangles = AdjList::new(6);
+        for i in 0..3 {
+            two_triangles.add_edge(i, (i + 1) % 3);
+            two_triangles.add_edge(3 + i, 3 + (i + 1) % 3);
+        }
+
+        assert_eq!(
+            wl_canonical_form(&cycle_graph(6)),
+            wl_canonical_form(&two_triangles)
+        );
+    }
+}
@@ -0,0 +1,329 @@
+use crate::graph::adj_list::AdjList;
+
+/// Graphs with at most this many vertices are priced exactly by
+/// [`fractional_chromatic_number`], enumerating their maximal independent sets.
+pub const EXACT_PRICING_VERTICES: usize = 30;
+
+/// Tolerance of the comparisons of the simplex method and of the pricing.
+const EPSILON: f64 = 1e-9;
+
+/// Solves `max Σ y_v` subject to `Σ_{v ∈ S} y_v ≤ 1` for each set `S` of `sets` and `y ≥ 0`,
+/// where `y` has a variable for each of the `n` vertices, with the tableau simplex method.
+///
+/// The origin is feasible, so no first phase is needed, and Bland's rule avoids cycling on the
+/// many degenerate pivots of this problem. Every vertex must be in some set, which bounds the
+/// problem. Returns the optimal value and solution.
+fn solve_packing_lp(sets: &[Vec<usize>], n: usize) -> (f64, Vec<f64>) {
+    let m = sets.len();
+    let width = n + m + 1;
+    // Row i is the constraint of sets[i] with its slack variable n + i, the last row is the
+    // objective and the last column the right-hand side
+    let mut tableau = vec![vec![0.0; width]; m + 1];
+    for (i, set) in sets.iter().enumerate() {
+        for vertex in set.iter() {
+            tableau[i][*vertex] = 1.0;
+        }
+        tableau[i][n + i] = 1.0;
+        tableau[i][width - 1] = 1.0;
+    }
+    for entry in tableau[m][..n].iter_mut() {
+        *entry = -1.0;
+    }
+    let mut basis: Vec<usize> = (n..n + m).collect();
+
+    // Bland's rule: the entering variable is the first one that improves the objective
+    while let Some(entering) = (0..width - 1).find(|j| tableau[m][*j] < -EPSILON) {
+        // And the leaving one, among the rows of the minimum ratio, the basic variable of
+        // smallest index. Every vertex is in some set, so there is such a row and we can unwrap
+        let leaving = (0..m)
+            .filter(|i| tableau[*i][entering] > EPSILON)
+            .min_by(|a, b| {
+                let ratio_a = tableau[*a][width - 1] / tableau[*a][entering];
+                let ratio_b = tableau[*b][width - 1] / tableau[*b][entering];
+
+                if (ratio_a - ratio_b).abs() <= EPSILON {
+                    basis[*a].cmp(&basis[*b])
+                } else {
+                    ratio_a.total_cmp(&ratio_b)
+                }
+            })
+            .unwrap();
+
+        let pivot = tableau[leaving][entering];
+        tableau[leaving]
+            .iter_mut()
+            .for_each(|entry| *entry /= pivot);
+        let pivot_row = tableau[leaving].clone();
+
+        for (i, row) in tableau.iter_mut().enumerate() {
+            let factor = row[entering];
+
+            if i != leaving && factor != 0.0 {
+                for (entry, pivot_entry) in row.iter_mut().zip(pivot_row.iter()) {
+                    *entry -= factor * pivot_entry;
+                }
+            }
+        }
+
+        basis[leaving] = entering;
+    }
+
+    let mut solution = vec![0.0; n];
+    for (i, variable) in basis.iter().enumerate() {
+        if *variable < n {
+            solution[*variable] = tableau[i][width - 1];
+        }
+    }
+
+    (tableau[m][width - 1], solution)
+}
+
+/// Finds an independent set of `graph` whose total `weight` exceeds 1, which is a column that
+/// improves the master problem of [`fractional_chromatic_number`], if there is one.
+///
+/// Tries the greedy set that takes the heaviest vertices first and, on graphs with at most
+/// [`EXACT_PRICING_VERTICES`] vertices, the heaviest maximal independent set.
+fn price_independent_set(graph: &AdjList, weight: &[f64]) -> Option<Vec<usize>> {
+    let total = |set: &[usize]| set.iter().map(|v| weight[*v]).sum::<f64>();
+
+    let mut order: Vec<usize> = (0..graph.num_vertices()).collect();
+    order.sort_by(|a, b| weight[*b].total_cmp(&weight[*a]));
+
+    let mut greedy: Vec<usize> = Vec::new();
+    for vertex in order {
+        if graph.adj_list()[vertex].iter().all(|u| !greedy.contains(u)) {
+            greedy.push(vertex);
+        }
+    }
+
+    if total(&greedy) > 1.0 + EPSILON {
+        greedy.sort();
+        return Some(greedy);
+    }
+
+    if graph.num_vertices() > EXACT_PRICING_VERTICES {
+        return None;
+    }
+
+    graph
+        .maximal_independent_sets()
+        .max_by(|a, b| total(a).total_cmp(&total(b)))
+        .filter(|set| total(set) > 1.0 + EPSILON)
+}
+
+/// Upper bound for the total `weight` of an independent set of `graph`, which is exact on graphs
+/// with at most [`EXACT_PRICING_VERTICES`] vertices, enumerating their maximal independent sets.
+///
+/// On larger graphs, the vertices are greedily partitioned into cliques, heaviest vertices first,
+/// and an independent set holds at most one vertex of each clique, so it weighs at most the sum of
+/// the heaviest vertex of each.
+fn max_independent_set_weight_bound(graph: &AdjList, weight: &[f64]) -> f64 {
+    if graph.num_vertices() <= EXACT_PRICING_VERTICES {
+        return graph
+            .maximal_independent_sets()
+            .map(|set| set.iter().map(|v| weight[*v]).sum::<f64>())
+            .fold(0.0, f64::max);
+    }
+
+    let mut order: Vec<usize> = (0..graph.num_vertices()).collect();
+    order.sort_by(|a, b| weight[*b].total_cmp(&weight[*a]));
+
+    // The first vertex of each clique is its heaviest one
+    let mut cliques: Vec<Vec<usize>> = Vec::new();
+    for vertex in order {
+        let neighbors = &graph.adj_list()[vertex];
+
+        match cliques
+            .iter_mut()
+            .find(|clique| clique.iter().all(|u| neighbors.contains(u)))
+        {
+            Some(clique) => clique.push(vertex),
+            None => cliques.push(vec![vertex]),
+        }
+    }
+
+    cliques.iter().map(|clique| weight[clique[0]]).sum()
+}
+
+/// Computes a lower bound for the fractional chromatic number of `graph`, the optimum of the
+/// relaxation of the coloring problem that gives each independent set `S` a weight `x_S ≥ 0`,
+/// minimizing `Σ x_S` while the sets holding each vertex weigh at least 1 in total, and so a lower
+/// bound for the chromatic number.
+///
+/// The relaxation is solved by column generation, starting from the singletons: the dual of the
+/// restricted problem gives a weight to each vertex, and any independent set heavier than 1 is
+/// added as a column (see [`price_independent_set`]), until there is none or `max_columns`
+/// columns were added.
+///
+/// The optimum `z` of the restricted problem is at least the fractional chromatic number, so it
+/// is only returned when no independent set is heavier than 1. Otherwise, the result is Farley's
+/// bound `z / max_S w(S)`, for which the heaviest independent set is found exactly on graphs with
+/// at most [`EXACT_PRICING_VERTICES`] vertices and overestimated on larger ones (see
+/// [`max_independent_set_weight_bound`]). The result is then exact when the pricing is exact,
+/// i.e., on graphs with at most [`EXACT_PRICING_VERTICES`] vertices when `max_columns` isn't
+/// reached.
+pub fn fractional_chromatic_number(graph: &AdjList, max_columns: usize) -> f64 {
+    let n = graph.num_vertices();
+    let mut columns: Vec<Vec<usize>> = (0..n).map(|v| vec![v]).collect();
+
+    loop {
+        let (value, weight) = solve_packing_lp(&columns, n);
+
+        let set = if columns.len() - n >= max_columns {
+            None
+        } else {
+            price_independent_set(graph, &weight)
+        };
+
+        match set {
+            // A column that is already in the problem can't be heavier than 1
+            Some(set) if !columns.contains(&set) => columns.push(set),
+            // The weights divided by those of the heaviest independent set are a feasible dual
+            // solution of the whole relaxation
+            _ => return value / max_independent_set_weight_bound(graph, &weight).max(1.0),
+        }
+    }
+}
+
+/// Computes the fractional independence number of `graph`, the optimum of the relaxation of the
+/// maximum independent set problem `max Σ x_v` subject to `x_u + x_v ≤ 1` for each edge and
+/// `0 ≤ x_v ≤ 1`, with the simplex method. It is an upper bound for the independence number
+/// `α`, so `n` over it is a (weaker) lower bound for the chromatic number.
+///
+/// The relaxation only looks at edges, so it is at least `n / 2`, even on complete graphs. On
+/// bipartite graphs, it is exactly `α`.
+pub fn fractional_independence_number(graph: &AdjList) -> f64 {
+    let n = graph.num_vertices();
+    // The bound of 1 is only needed for isolated vertices, the edges already imply it
+    let sets: Vec<Vec<usize>> = graph
+        .edge_list_as_slice()
+        .iter()
+        .map(|(u, v)| vec![*u, *v])
+        .chain(
+            (0..n)
+                .filter(|vertex| graph.get_degree(*vertex) == 0)
+                .map(|vertex| vec![vertex]),
+        )
+        .collect();
+
+    solve_packing_lp(&sets, n).0
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+    use crate::{
+        algorithms::{
+            chromatic_polynomial::chromatic_number_exact_via_polynomial,
+            independent_set::maximal_independent_set,
+        },
+        graph::{generators::cycle_graph, random::random_graph},
+        input,
+    };
+    use rand::rngs::SmallRng;
+    use rand::SeedableRng;
+
+    #[test]
+    fn test_solve_packing_lp() {
+        // The edges of a triangle: the weights are at most 1/2 on each vertex
+        let (value, solution) = solve_packing_lp(&[vec![0, 1], vec![1, 2], vec![0, 2]], 3);
+
+        assert!((value - 1.5).abs() < 1e-9);
+        assert!(solution.iter().all(|y| (y - 0.5).abs() < 1e-9));
+    }
+
+    #[test]
+    fn test_fractional_chromatic_number() {
+        assert!((fractional_chromatic_number(&AdjList::complete(5), 100) - 5.0).abs() < 1e-6);
+        assert!((fractional_chromatic_number(&cycle_graph(5), 100) - 2.5).abs() < 1e-6);
+        assert!((fractional_chromatic_number(&cycle_graph(7), 100) - 3.5 / 1.5).abs() < 1e-6);
+
+        // The complete bipartite graph K_{3,4}
+        let mut graph = AdjList::new(7);
+        for u in 0..3 {
+            for v in 3..7 {
+                graph.add_edge(u, v);
+            }
+        }
+
+        assert!((fractional_chromatic_number(&graph, 100) - 2.0).abs() < 1e-6);
+        assert_eq!(fractional_chromatic_number(&AdjList::new(0), 100), 0.0);
+
+        // Without columns, the singletons give every vertex of C_5 a weight of 1, and the
+        // heaviest independent sets weigh 2
+        assert!((fractional_chromatic_number(&cycle_graph(5), 0) - 2.5).abs() < 1e-6);
+
+        if let Ok(Some(graph)) = input::read_graph_from_file("data/myc/myciel3.col") {
+            // The fractional chromatic number of the Grötzsch graph is 29/10
+            assert!((fractional_chromatic_number(&graph, 1000) - 2.9).abs() < 1e-6);
+        } else {
+            panic!("The file containing the test graph is missing")
+        }
+    }
+
+    #[test]
+    fn test_fractional_chromatic_number_with_few_columns() {
+        for seed in 0..100 {
+            let n = 1 + (seed % 9) as usize;
+            let m = (seed as usize * 5) % (n * (n - 1) / 2 + 1);
+            let graph = random_graph(n, m, seed).unwrap();
+            let chromatic_number = chromatic_number_exact_via_polynomial(&graph).unwrap();
+
+            for max_columns in 0..3 {
+                assert!(
+                    fractional_chromatic_number(&graph, max_columns) <= chromatic_number as f64
+                );
+            }
+        }
+
+        // Larger graphs overestimate the heaviest independent set instead
+        if let Ok(Some(graph)) = input::read_graph_from_file("data/myc/myciel5.col") {
+            // The chromatic number of myciel5 is 6
+            for max_columns in [0, 5, 50] {
+                let bound = fractional_chromatic_number(&graph, max_columns);
+
+                assert!(bound > 1.0 && bound <= 6.0);
+            }
+        } else {
+            panic!("The file containing the test graph is missing")
+        }
+    }
+
+    #[test]
+    fn test_fractional_independence_number() {
+        assert!((fractional_independence_number(&cycle_graph(5)) - 2.5).abs() < 1e-6);
+        assert!((fractional_independence_number(&AdjList::complete(4)) - 2.0).abs() < 1e-6);
+        assert!((fractional_independence_number(&AdjList::new(3)) - 3.0).abs() < 1e-6);
+        assert_eq!(fractional_independence_number(&AdjList::new(0)), 0.0);
+
+        // Bipartite graphs have an integral optimum, the 4 vertices of a side of K_{3,4}
+        let mut graph = AdjList::new(7);
+        for u in 0..3 {
+            for v in 3..7 {
+                graph.add_edge(u, v);
+            }
+        }
+
+        assert!((fractional_independence_number(&graph) - 4.0).abs() < 1e-6);
+
+        // Triangle-free graphs, between a greedy independent set and n minus a maximum matching
+        // (whose edges take at most one vertex each)
+        for path in ["data/myc/myciel3.col", "data/myc/myciel4.col"] {
+            if let Ok(Some(graph)) = input::read_graph_from_file(path) {
+                let n = graph.num_vertices();
+                let all: Vec<usize> = (0..n).collect();
+                let bound = fractional_independence_number(&graph);
+                let greedy =
+                    maximal_independent_set(&graph, &all, 1, &mut SmallRng::seed_from_u64(0))
+                        .unwrap();
+
+                assert!(greedy.len() as f64 <= bound + 1e-6);
+                assert!(bound >= n as f64 / 2.0 - 1e-6);
+                assert!(bound <= (n - graph.maximum_matching().len()) as f64 + 1e-6);
+                assert!(n as f64 / bound <= fractional_chromatic_number(&graph, 1000) + 1e-6);
+            } else {
+                panic!("The file containing the test graph is missing")
+            }
+        }
+    }
+}
@@ -0,0 +1,115 @@
+use super::{
+    count_forbidden_edges_for_vertex, get_coloring_from_class_list, grasp::get_class_list,
+};
+use crate::graph::adj_list::AdjList;
+
+/// Best color for `vertex` other than its current one, according to the number of forbidden
+/// edges it would have. Ties are broken by the smallest color.
+fn best_other_color(
+    graph: &AdjList,
+    coloring: &mut [usize],
+    vertex: usize,
+    num_classes: usize,
+) -> usize {
+    let original_color = coloring[vertex];
+    let mut best_color = original_color;
+    let mut best_count = usize::MAX;
+
+    // Colors are 1-indexed
+    for color in (1..num_classes + 1).filter(|color| *color != original_color) {
+        coloring[vertex] = color;
+        let count = count_forbidden_edges_for_vertex(graph, coloring, vertex);
+
+        if count < best_count {
+            best_count = count;
+            best_color = color;
+        }
+    }
+
+    coloring[vertex] = original_color;
+
+    best_color
+}
+
+/// Applies an ejection chain to `class_list` starting from `start_vertex`.
+///
+/// The first vertex is forced to its best other color. If that creates a conflict with one of its
+/// neighbors, the neighbor is ejected to its best other color as well, and so on. The chain stops
+/// after `max_chain_length` moves, when no neighbor was displaced or as soon as the number of
+/// forbidden edges decreases. Each vertex is moved at most once.
+///
+/// Returns `true` (and updates `class_list`) if the chain reduced the number of forbidden edges;
+/// otherwise `class_list` is left untouched.
+pub fn ejection_chain_move(
+    graph: &AdjList,
+    class_list: &mut [Vec<usize>],
+    start_vertex: usize,
+    max_chain_length: usize,
+) -> bool {
+    let num_classes = class_list.len();
+
+    // There is no other color to move to
+    if num_classes < 2 {
+        return false;
+    }
+
+    let mut coloring = get_coloring_from_class_list(graph.num_vertices(), class_list);
+    let mut moved = vec![false; graph.num_vertices()];
+    // Change in the number of forbidden edges since the start of the chain
+    let mut delta: isize = 0;
+    let mut current = Some(start_vertex);
+
+    for _ in 0..max_chain_length {
+        let Some(vertex) = current else {
+            break;
+        };
+
+        let before = count_forbidden_edges_for_vertex(graph, &coloring, vertex) as isize;
+        coloring[vertex] = best_other_color(graph, &mut coloring, vertex, num_classes);
+        let after = count_forbidden_edges_for_vertex(graph, &coloring, vertex) as isize;
+
+        moved[vertex] = true;
+        delta += after - before;
+
+        if delta < 0 {
+            class_list.clone_from_slice(&get_class_list(&coloring, num_classes));
+
+            return true;
+        }
+
+        // The displaced vertex is a neighbor that now shares the color of the moved one
+        current = graph.adj_list()[vertex]
+            .iter()
+            .find(|u| !moved[**u] && coloring[**u] == coloring[vertex])
+            .copied();
+    }
+
+    false
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+    use crate::algorithms::grasp::get_forbidden_vertices;
+
+    #[test]
+    fn test_ejection_chain_move() {
+        // A path colored as 1---2---2---1
+        let mut graph = AdjList::new(4);
+        graph.add_edge(0, 1);
+        graph.add_edge(1, 2);
+        graph.add_edge(2, 3);
+        let mut class_list = vec![vec![0, 3], vec![1, 2]];
+
+        // Moving 1 alone only displaces the conflict towards 0
+        assert!(!ejection_chain_move(&graph, &mut class_list, 1, 1));
+        assert_eq!(class_list, vec![vec![0, 3], vec![1, 2]]);
+
+        // Ejecting 0 afterwards resolves it
+        assert!(ejection_chain_move(&graph, &mut class_list, 1, 2));
+        assert_eq!(get_forbidden_vertices(&graph, &class_list).0, 0);
+
+        let coloring = get_coloring_from_class_list(graph.num_vertices(), &class_list);
+        assert!(graph.is_valid_coloring(&coloring));
+    }
+}
@@ -10,17 +10,7 @@ use rand::Rng;
 ///
 /// This upper bound is calculated based on the `Brook's theorem` (i.e., the chromatic number is less or equal than the maximum vertex degree of the graph plus one).
 fn coloring_upper_bound(graph: &AdjList) -> usize {
-    let mut colors = 0;
-
-    for i in 0..graph.num_vertices() {
-        let neighborhood_size = graph.adj_list()[i].len();
-
-        if neighborhood_size + 1 > colors {
-            colors = neighborhood_size + 1;
-        }
-    }
-
-    colors
+    graph.max_degree() + 1
 }
 
 /// Generates a randomized solution for the GCP problem.
@@ -175,7 +165,7 @@ pub fn genetic(
 #[cfg(test)]
 mod tests {
     use super::*;
-    use crate::{algorithms::is_coloring_valid, input};
+    use crate::input;
 
     #[test]
     fn test_coloring_upper_bound() {
@@ -203,7 +193,7 @@ mod tests {
 
             let individual = generate_individual(&graph, upper_bound);
 
-            assert!(is_coloring_valid(&graph, &individual));
+            assert!(graph.is_valid_coloring(&individual));
         } else {
             panic!("The file containing the test graph is missing")
         }
@@ -218,12 +208,12 @@ mod tests {
 
             let mut individual = generate_individual(&graph, upper_bound);
 
-            assert!(is_coloring_valid(&graph, &individual));
+            assert!(graph.is_valid_coloring(&individual));
 
             // A little higher mutation probability just to ensure that some vertices actually change
             mutate(&graph, &mut individual, upper_bound, 0.2);
 
-            assert!(is_coloring_valid(&graph, &individual));
+            assert!(graph.is_valid_coloring(&individual));
         } else {
             panic!("The file containing the test graph is missing")
         }
@@ -270,7 +260,7 @@ mod tests {
 
             let offspring = crossover(&graph, p1, p2);
 
-            assert!(is_coloring_valid(&graph, &offspring));
+            assert!(graph.is_valid_coloring(&offspring));
         } else {
             panic!("The file containing the test graph is missing")
         }
@@ -313,7 +303,7 @@ mod tests {
 
             assert!(best <= coloring_upper_bound(&graph));
 
-            assert!(is_coloring_valid(&graph, &colors));
+            assert!(graph.is_valid_coloring(&colors));
         } else {
             panic!("The file containing the test graph is missing")
         }
@@ -1,458 +1,3063 @@
-use super::Solution;
+use super::{
+    bounds::fractional_chromatic_lower_bound,
+    class_interchange::color_class_interchange,
+    color_assignment::ColorAssignment,
+    compact_class_list, compact_class_list_owned, conflicting_neighbors,
+    ejection::ejection_chain_move,
+    get_class_list_from_coloring, get_coloring_from_class_list,
+    independent_set::{maximal_independent_set, maximal_independent_set_with_scorer},
+    neighborhood_swap::neighborhood_swap,
+    sat_coloring::exact_chromatic_number,
+    scoring::VertexScorer,
+    ColoringAlgorithm, ColoringError, Solution,
+};
 use crate::graph::adj_list::AdjList;
-use rand::seq::SliceRandom;
+use crate::graph::residual::ResidualGraph;
+use rand::rngs::SmallRng;
+use rand::seq::{IteratorRandom, SliceRandom};
+use rand::{Rng, SeedableRng};
 use rayon::prelude::*;
-use std::collections::{BinaryHeap, HashSet};
-
-/// Given a `graph`, gets (at most) `n` indexes of the higher degree vertices in the subgraph induced by
-/// `subset`. If `list` is provided, don't use the induced subgraph.
-/// Instead, from the vertices in `subset` count the *overall* degrees only within the `list`.
-fn get_n_largest_degree(
+use std::cmp::Reverse;
+use std::collections::{BinaryHeap, HashMap, HashSet, VecDeque};
+use std::error::Error;
+use std::fmt;
+use std::fs::{self, File};
+use std::io::{self, BufWriter, Write};
+use std::sync::{Arc, Mutex};
+use std::time::{Duration, Instant};
+
+/// Gets (at most) `n` vertices of `vertices` with the highest `score_fn`, in decreasing order of
+/// score. Ties are broken by the smallest vertex.
+pub(super) fn get_n_best(
     n: usize,
-    graph: &AdjList,
-    subset: &[usize],
-    list: Option<&[usize]>,
+    vertices: &[usize],
+    score_fn: impl Fn(usize) -> i64,
 ) -> Vec<usize> {
-    let list = if let Some(list) = list { list } else { subset };
-    let vertex_set: Vec<usize> = (0..graph.num_vertices()).collect();
-    let mut degrees: Vec<(usize, usize)> = vertex_set
+    let mut scores: Vec<(usize, i64)> = vertices
         .iter()
-        .map(|vertex| (*vertex, graph.get_degree_in_list(*vertex, list)))
+        .map(|vertex| (*vertex, score_fn(*vertex)))
         .collect();
 
-    degrees.retain(|x| subset.contains(&x.0));
+    scores.sort_by_key(|(vertex, score)| (Reverse(*score), *vertex));
 
-    degrees.sort_by(|lhs, rhs| rhs.1.cmp(&lhs.1));
+    scores.iter().take(n).map(|(vertex, _)| *vertex).collect()
+}
 
-    degrees.iter().take(n).map(|(index, _)| *index).collect()
+/// Number of iterations between two diversity checks of the elite solutions.
+const DIVERSITY_CHECK_INTERVAL: i32 = 10;
+
+/// Fraction of the vertices that are randomly reassigned when the elite solutions lack diversity.
+const PERTURBATION_RATIO: f64 = 0.2;
+
+/// Maximum number of moves of the ejection chains tried by [`local_search`].
+const EJECTION_CHAIN_LENGTH: usize = 3;
+
+/// Largest [`GraspConfig::exact_threshold`], which keeps the exact colorings of the remaining
+/// vertices fast.
+pub const MAX_EXACT_THRESHOLD: usize = 30;
+
+/// Maximum number of perturbations applied by a single [`local_search`].
+const MAX_LOCAL_PERTURBATIONS: usize = 100;
+
+/// How [`local_search`] picks the move of each iteration.
+#[derive(Debug, Clone, Copy, PartialEq, Eq, Default)]
+pub enum LocalSearchStrategy {
+    /// Evaluates the recolorings of a random forbidden vertex and of the neighbors it conflicts
+    /// with, in O(k) per vertex for `k` colors.
+    #[default]
+    RandomVertex,
+    /// Evaluates the recolorings of every forbidden vertex and applies the best one overall, in
+    /// O(nk) per iteration.
+    BestImprovement,
+    /// Same as [`LocalSearchStrategy::BestImprovement`], but also evaluates swapping the colors
+    /// of every forbidden vertex and every vertex of another class at once, in O(n²) per
+    /// iteration. Swaps can get out of plateaus where every single move is neutral.
+    DoubleSwap,
 }
 
-/// Counts the number of edges in subgraph induced by `graph` and `list`.
-fn count_remaining_edges(graph: &AdjList, list: &[usize]) -> usize {
-    let mut count = 0;
+/// Parameters of [`local_search`].
+#[derive(Debug, Clone, Copy, PartialEq)]
+pub struct LocalSearchConfig {
+    /// How the move of each iteration is picked.
+    pub strategy: LocalSearchStrategy,
+    /// Maximum number of iterations, unlimited if 0.
+    pub max_iterations: usize,
+    /// Number of consecutive iterations without a successful move after which the search stops.
+    /// If 0, twice the initial number of forbidden edges is used.
+    pub no_improvement_ceil: usize,
+    /// Number of consecutive iterations in which the number of forbidden edges doesn't decrease
+    /// after which the assignment is perturbed.
+    pub stagnation_window: usize,
+    /// Fraction of the vertices randomly reassigned by a perturbation.
+    pub perturbation_rate: f64,
+    /// Number of sideways moves, recolorings that keep the number of forbidden edges, allowed
+    /// when no move improves. The budget is restored whenever the number of forbidden edges
+    /// decreases. Disabled if 0.
+    pub max_sideways_moves: usize,
+    /// Number of recent recolorings, including those of the swaps and joint moves, that are tabu:
+    /// no move gives a vertex a color it was given in any of them again, unless that removes
+    /// every forbidden edge. Disabled if 0.
+    pub tabu_size: usize,
+    /// Number of vertices recolored at once by a joint move: the random forbidden vertex and
+    /// random neighbors of it, trying every combination of colors, in O(k^m) for `m` vertices.
+    /// The joint move is applied if it improves more than any single move or swap. Disabled if at
+    /// most 1.
+    pub move_size: usize,
+}
 
-    for i in 0..list.len() {
-        for j in i + 1..list.len() {
-            if graph.adj_list()[i].contains(&j) {
-                count += 1;
-            }
-        }
+impl LocalSearchConfig {
+    /// Sets [`LocalSearchConfig::tabu_size`].
+    pub fn tabu_size(mut self, tabu_size: usize) -> Self {
+        self.tabu_size = tabu_size;
+        self
     }
 
-    count
+    /// Sets [`LocalSearchConfig::move_size`].
+    pub fn move_size(mut self, move_size: usize) -> Self {
+        self.move_size = move_size;
+        self
+    }
 }
 
-/// Runs a single GRASP execution with the given parameters.
-pub fn grasp_wrapper(
-    graph: &AdjList,
-    grasp_iterations: i32,
-    color_iterations: i32,
-    color_list_size: usize,
-) -> Solution {
-    let mut solutions = grasp(
-        graph,
-        grasp_iterations,
-        color_iterations,
-        color_list_size,
-        1,
-    );
-    let (num_colors, coloring) = solutions.pop().unwrap();
-    (num_colors, coloring)
+/// The last (at most) `capacity` items pushed, dropping the oldest ones.
+#[derive(Debug, Clone)]
+struct RingBuffer<T> {
+    items: VecDeque<T>,
+    capacity: usize,
 }
 
-pub fn grasp(
-    graph: &AdjList,
-    grasp_iterations: i32,
-    color_iterations: i32,
-    color_list_size: usize,
-    num_solutions: usize,
-) -> BinaryHeap<Solution> {
-    let max_colors = graph.num_vertices();
-    let mut solutions = BinaryHeap::with_capacity(num_solutions);
+impl<T: PartialEq> RingBuffer<T> {
+    fn new(capacity: usize) -> Self {
+        RingBuffer {
+            items: VecDeque::with_capacity(capacity),
+            capacity,
+        }
+    }
 
-    let all: Vec<Solution> = (0..grasp_iterations)
-        .into_par_iter()
-        .map(|_| {
-            let mut num_color_classes = 0;
-            let mut vertex_set: Vec<usize> = (0..max_colors).collect();
-            let mut class_list: Vec<Vec<usize>> = Vec::new();
+    fn push(&mut self, item: T) {
+        if self.capacity == 0 {
+            return;
+        }
 
-            class_list.resize(max_colors, Vec::new());
+        if self.items.len() == self.capacity {
+            self.items.pop_front();
+        }
+        self.items.push_back(item);
+    }
 
-            while !vertex_set.is_empty() {
-                let mut min_num_edges_remaining = usize::MAX;
+    fn contains(&self, item: &T) -> bool {
+        self.items.contains(item)
+    }
+}
 
-                num_color_classes += 1;
+impl Default for LocalSearchConfig {
+    fn default() -> Self {
+        LocalSearchConfig {
+            strategy: LocalSearchStrategy::RandomVertex,
+            max_iterations: 0,
+            no_improvement_ceil: 0,
+            stagnation_window: 50,
+            perturbation_rate: 0.1,
+            max_sideways_moves: 0,
+            tabu_size: 0,
+            move_size: 1,
+        }
+    }
+}
 
-                for _ in 0..color_iterations {
-                    assign_color(
-                        &vertex_set,
-                        color_list_size,
-                        graph,
-                        &mut min_num_edges_remaining,
-                        &mut class_list,
-                        num_color_classes,
-                    );
-                }
+/// Function called by [`run_grasp`] after each iteration, see [`GraspConfig::on_iteration`].
+type IterationFn = dyn Fn(usize, usize, &[Vec<usize>]) + Send;
 
-                vertex_set.retain(|vertex| !class_list[num_color_classes - 1].contains(vertex));
-            }
+/// Shared handle to an [`IterationFn`]. The mutex serializes the calls and makes the
+/// configuration shareable between the threads running the iterations.
+#[derive(Clone)]
+struct IterationCallback(Arc<Mutex<Box<IterationFn>>>);
 
-            improve_phase(graph, &mut num_color_classes, &mut class_list);
+impl fmt::Debug for IterationCallback {
+    fn fmt(&self, f: &mut fmt::Formatter<'_>) -> fmt::Result {
+        f.write_str("IterationCallback")
+    }
+}
 
-            let coloring = get_coloring_from_class_list(max_colors, &class_list);
-            (num_color_classes, coloring)
-        })
-        .collect();
+/// Parameters of a GRASP execution.
+#[derive(Debug, Clone)]
+pub struct GraspConfig {
+    // The plain parameters are read by the experiment archive (see `output::experiment`)
+    pub(crate) grasp_iterations: i32,
+    pub(crate) color_iterations: i32,
+    pub(crate) color_list_size: usize,
+    pub(crate) elite_size: usize,
+    pub(crate) diversity_threshold: f64,
+    pub(crate) time_budget: Option<Duration>,
+    pub(crate) weighted_objective: bool,
+    pub(crate) upper_bound_pruning: bool,
+    pub(crate) color_upper_bound: Option<usize>,
+    pub(crate) merge_group_size: usize,
+    pub(crate) plateau_moves: usize,
+    pub(crate) improve_min_reduction: usize,
+    pub(crate) merge_oracle_threshold: f64,
+    pub(crate) exact_threshold: usize,
+    pub(crate) adaptive_csize: Option<(usize, usize)>,
+    initial_solution: Option<Vec<Vec<usize>>>,
+    vertex_scorer: Option<Arc<dyn VertexScorer>>,
+    pub(crate) seed: Option<u64>,
+    on_iteration: Option<IterationCallback>,
+}
 
-    for solution in all {
-        if solutions.len() < num_solutions {
-            solutions.push(solution);
-        } else if solution.0 < solutions.peek().unwrap().0 {
-            solutions.pop();
-            solutions.push(solution);
+impl GraspConfig {
+    pub fn new(grasp_iterations: i32, color_iterations: i32, color_list_size: usize) -> Self {
+        GraspConfig {
+            grasp_iterations,
+            color_iterations,
+            color_list_size,
+            elite_size: 1,
+            diversity_threshold: 0.0,
+            time_budget: None,
+            weighted_objective: false,
+            upper_bound_pruning: true,
+            color_upper_bound: None,
+            merge_group_size: 2,
+            plateau_moves: 0,
+            improve_min_reduction: 1,
+            merge_oracle_threshold: 0.0,
+            exact_threshold: 0,
+            adaptive_csize: None,
+            initial_solution: None,
+            vertex_scorer: None,
+            seed: None,
+            on_iteration: None,
         }
     }
 
-    solutions
-}
+    /// Size of the archive of elite solutions kept by the execution.
+    pub fn elite_size(mut self, elite_size: usize) -> Self {
+        self.elite_size = elite_size;
+        self
+    }
 
-/// Tries to assign a color class `num_color_classes` to `class_list`
-/// following the greedy heuristic.
-///
-/// The greedy heuristic chooses an available vertex: a vertex such that none of its neighbors have
-/// been colored. It tries to cover the remaining graph entirely (or until no candidates remain).
-///
-/// Refer to the article for more information about the heuristic.
-fn assign_color(
-    vertex_set: &[usize],
-    color_list_size: usize,
-    graph: &AdjList,
-    min_num_edges_remaining: &mut usize,
-    class_list: &mut [Vec<usize>],
-    num_color_classes: usize,
-) {
-    let mut admissible_uncolored: Vec<usize> = vertex_set.to_vec();
-    let mut inadmissible_uncolored: Vec<usize> = Vec::new();
-    let mut current_color_class: Vec<usize> = Vec::new();
+    /// Minimum diversity (see [`solution_diversity`]) of the kept solutions. Whenever it drops
+    /// below the threshold, the next iteration starts from a perturbed solution instead of a new
+    /// construction. A threshold of 0 disables the perturbation.
+    pub fn diversity_threshold(mut self, diversity_threshold: f64) -> Self {
+        self.diversity_threshold = diversity_threshold;
+        self
+    }
 
-    while !admissible_uncolored.is_empty() {
-        let candidate_list = if inadmissible_uncolored.is_empty() {
-            get_n_largest_degree(color_list_size, graph, &admissible_uncolored, None)
-        } else {
-            get_n_largest_degree(
-                color_list_size,
-                graph,
-                &admissible_uncolored,
-                Some(&inadmissible_uncolored),
-            )
-        };
-        let vertex = candidate_list.choose(&mut rand::thread_rng());
+    /// Maximum duration of the execution, in addition to the number of iterations. Iterations
+    /// that haven't started when the budget runs out are skipped, but at least one is always run.
+    pub fn time_budget(mut self, time_budget: Duration) -> Self {
+        self.time_budget = Some(time_budget);
+        self
+    }
 
-        if let Some(vertex) = vertex {
-            current_color_class.push(*vertex);
-            let neighbors = graph.adj_list()[*vertex].clone();
-            admissible_uncolored.retain(|node| node != vertex && !neighbors.contains(node));
-            inadmissible_uncolored = [inadmissible_uncolored, neighbors].concat();
-        } else {
-            panic!("CSize must be at least 1")
-        }
+    /// Whether the local search minimizes the total weight of the forbidden edges instead of
+    /// their number. Disabled by default.
+    pub fn use_weighted_objective(mut self, weighted_objective: bool) -> Self {
+        self.weighted_objective = weighted_objective;
+        self
     }
-    let mut remaining_vertices = vertex_set.to_vec();
-    remaining_vertices.retain(|vertex| !current_color_class.contains(vertex));
-    let remaining_edges = count_remaining_edges(graph, &remaining_vertices);
 
-    if remaining_edges < *min_num_edges_remaining {
-        class_list[num_color_classes - 1] = current_color_class;
-        *min_num_edges_remaining = remaining_edges;
+    /// Whether the improve phase is skipped for the constructions that use at least as many
+    /// colors as the best solution found before their batch. Enabled by default.
+    pub fn upper_bound_pruning(mut self, upper_bound_pruning: bool) -> Self {
+        self.upper_bound_pruning = upper_bound_pruning;
+        self
     }
-}
 
-/// Tries to improve the coloring from `class_list` by
-///
-/// 1. Merging the smallest class colors
-/// 2. Applying a local search for the resulting class list
-///
-/// The process repeats until a forbidden coloring is found
-fn improve_phase(graph: &AdjList, num_classes: &mut usize, class_list: &mut Vec<Vec<usize>>) {
-    let mut num_forbidden = 0;
+    /// Largest number of colors accepted for the result, e.g., 4 for a planar graph. If the best
+    /// solution found uses more, the execution fails with
+    /// [`ColoringError::NoBoundedSolutionFound`]. There is no bound by default.
+    pub fn color_upper_bound(mut self, k: usize) -> Self {
+        self.color_upper_bound = Some(k);
+        self
+    }
 
-    while num_forbidden == 0 {
-        let mut lenghts: Vec<(usize, usize)> = class_list
-            .iter()
-            .enumerate()
-            .map(|(index, class)| (index, class.len()))
-            .take(*num_classes)
-            .collect();
+    /// Number of smallest classes, 2 or 3, that the improve phase tries to merge at once. With 3,
+    /// a failed merge of three classes falls back to merging two. Defaults to 2.
+    pub fn merge_group_size(mut self, k: usize) -> Self {
+        self.merge_group_size = k;
+        self
+    }
 
-        lenghts.sort_by(|lhs, rhs| rhs.1.cmp(&lhs.1));
+    /// Number of sideways moves allowed to the local searches of the improve phase when no move
+    /// improves (see [`LocalSearchConfig::max_sideways_moves`]). Disabled by default.
+    pub fn plateau_moves(mut self, max_sideways: usize) -> Self {
+        self.plateau_moves = max_sideways;
+        self
+    }
 
-        let smallest_lengths: Vec<usize> = lenghts
-            .iter()
-            .rev()
-            .take(2)
-            .map(|(index, _)| *index)
-            .collect();
+    /// Stops the improve phase after a merge that removes fewer than `k` classes, keeping that
+    /// merge, which trades quality for speed. Merges remove at most
+    /// [`GraspConfig::merge_group_size`] minus one classes, so with the default of 1 the phase
+    /// only stops when a merge fails.
+    pub fn improve_min_reduction(mut self, k: usize) -> Self {
+        self.improve_min_reduction = k;
+        self
+    }
 
-        let mut combined_class: Vec<usize> = vec![];
+    /// Minimum [`class_merge_feasibility`] of the classes merged by the improve phase for it to
+    /// try a local search when the merge leaves conflicts; merges below it fail right away,
+    /// saving the search. Only merges without conflicts pass a threshold of 1, while the default
+    /// of 0 always searches.
+    pub fn merge_oracle_threshold(mut self, t: f64) -> Self {
+        self.merge_oracle_threshold = t;
+        self
+    }
 
-        for index in smallest_lengths.iter() {
-            combined_class.append(&mut class_list[*index].clone());
-        }
+    /// Colors the last fewer than `n` uncolored vertices of each construction exactly, with
+    /// [`exact_chromatic_number`] on the subgraph they induce, instead of building more classes
+    /// greedily. Disabled with the default of 0, and `n` can be at most [`MAX_EXACT_THRESHOLD`].
+    pub fn exact_threshold(mut self, n: usize) -> Self {
+        self.exact_threshold = n;
+        self
+    }
 
-        let mut new_classes: Vec<Vec<usize>> = vec![];
+    /// Adapts the size of the candidate lists between `min` and `max` along the execution,
+    /// overriding the fixed `color_list_size`. It starts at `max`, shrinks by one after each
+    /// iteration that improves on the best solution, to exploit it more greedily, and grows by one
+    /// after each one that doesn't, to explore more randomly. Iterations run in parallel batches,
+    /// each using the size reached when it starts.
+    pub fn adaptive_csize(mut self, min: usize, max: usize) -> Self {
+        self.adaptive_csize = Some((min, max));
+        self
+    }
 
-        new_classes.push(combined_class);
+    /// Known solution to warm-start the execution with: its first iteration applies the improve
+    /// phase to `class_list` instead of building a new coloring. The class list must be a valid
+    /// coloring of the graph, which is checked when the execution starts since the configuration
+    /// isn't tied to a graph.
+    pub fn initial_solution(mut self, class_list: Vec<Vec<usize>>) -> Self {
+        self.initial_solution = Some(class_list);
+        self
+    }
 
-        for (index, class) in class_list.iter().enumerate() {
-            if index == smallest_lengths[0] || index == smallest_lengths[1] || class.is_empty() {
-                continue;
-            }
-            new_classes.push(class.clone());
-        }
+    /// Criterion of the candidate lists of the construction. By default, the candidates are the
+    /// vertices with the most neighbors among the ones that can no longer join the class (see
+    /// [`maximal_independent_set`]).
+    pub fn vertex_scorer(mut self, scorer: impl VertexScorer + 'static) -> Self {
+        self.vertex_scorer = Some(Arc::new(scorer));
+        self
+    }
 
-        num_forbidden = local_search(graph, &mut new_classes);
+    /// Seed of the random number generator, which makes the execution reproducible as long as
+    /// there is no time budget. A random seed is used by default.
+    pub fn seed(mut self, seed: u64) -> Self {
+        self.seed = Some(seed);
+        self
+    }
 
-        if num_forbidden == 0 {
-            *num_classes = new_classes.len();
-            *class_list = new_classes;
-        }
+    /// Sets a `callback`, invoked after each completed iteration with the index of the iteration,
+    /// the number of colors of the best solution so far and its class list.
+    pub fn on_iteration(mut self, callback: Box<IterationFn>) -> Self {
+        self.on_iteration = Some(IterationCallback(Arc::new(Mutex::new(callback))));
+        self
     }
 
-    let num_vertices = graph.num_vertices();
-    class_list.resize(num_vertices, Vec::new());
-}
+    /// Validates the parameters, returning [`ColoringError::InvalidConfig`] if the color list or
+    /// the elite archive are empty, if the merge group size isn't 2 or 3, if the merge oracle
+    /// threshold isn't between 0 and 1, or if the initial solution has a repeated vertex.
+    pub fn build(self) -> Result<Self, ColoringError> {
+        self.validate()?;
 
-/// Counts the number of forbidden edges in `graph` according to `class_list`.
-///
-/// Saves the corresponding vertices in a set.
-fn get_forbidden_vertices(graph: &AdjList, class_list: &[Vec<usize>]) -> (usize, HashSet<usize>) {
-    let num_vertices = graph.num_vertices();
-    let adj_list = graph.adj_list();
-    let coloring = get_coloring_from_class_list(num_vertices, class_list);
-    let mut count = 0;
-    let mut forbidden = HashSet::new();
-    for (i, v) in adj_list.iter().enumerate() {
-        for j in v.iter() {
-            if coloring[i] == coloring[*j] {
-                count += 1;
-                forbidden.insert(i);
-                forbidden.insert(*j);
-            }
+        Ok(self)
+    }
+
+    /// Parameters of the local searches of the improve phase.
+    fn local_search_config(&self) -> LocalSearchConfig {
+        LocalSearchConfig {
+            max_sideways_moves: self.plateau_moves,
+            ..LocalSearchConfig::default()
         }
     }
-    (count / 2, forbidden)
-}
 
-/// Applies a local search for `class_list` according to `graph`.
-///
-/// The local search works by selecting an illegal vertex and trying every possible color swap for
-/// said vertex to reduce the number of forbidden vertices in the graph.
-/// If we can improve, we update the `class_list`.
-///
-/// Repeats the process while they are forbidden vertices
-/// or the number of iterations that haven't improved `class_list` reaches a threshold.
-///
-/// Returns the number of edges that are still forbidden.
-fn local_search(graph: &AdjList, class_list: &mut Vec<Vec<usize>>) -> usize {
-    let (mut forbidden_count, mut forbidden_set) = get_forbidden_vertices(graph, class_list);
-    let no_improvement_ceil = 2 * forbidden_count;
-    let mut forbidden_vertices: Vec<usize> = forbidden_set.into_iter().collect();
-    // We use this variable to control how many iterations we can go by without improvement
-    let mut no_improvement = 0;
+    pub(crate) fn validate(&self) -> Result<(), ColoringError> {
+        if self.grasp_iterations <= 0 {
+            return Err(ColoringError::InvalidConfig(
+                "grasp_iterations must be at least 1".to_string(),
+            ));
+        }
 
-    while forbidden_count > 0 && no_improvement < no_improvement_ceil {
-        // Randomly choose an illegal vertex (i.e., one that is colored with the same color as an adjacent vertex).
+        if self.color_iterations <= 0 {
+            return Err(ColoringError::InvalidConfig(
+                "color_iterations must be at least 1".to_string(),
+            ));
+        }
+
+        if self.color_list_size == 0 {
+            return Err(ColoringError::InvalidConfig(
+                "color_list_size must be at least 1".to_string(),
+            ));
+        }
 
-        // Since forbidden_count > 0 we can unwrap
-        let vertex = forbidden_vertices.choose(&mut rand::thread_rng()).unwrap();
-        let mut coloring = get_coloring_from_class_list(graph.num_vertices(), class_list);
-        let mut best_count = count_forbidden_per_vertex(graph, &coloring, *vertex);
-        let original_count = best_count;
-        let mut best_color = coloring[*vertex];
-        let original_color = best_color;
+        if self.elite_size == 0 {
+            return Err(ColoringError::InvalidConfig(
+                "elite_size must be at least 1".to_string(),
+            ));
+        }
 
-        // Make all possible attempts to switch v to a different color to improve the current value of f(s).
+        if !(2..=3).contains(&self.merge_group_size) {
+            return Err(ColoringError::InvalidConfig(
+                "merge_group_size must be 2 or 3".to_string(),
+            ));
+        }
 
-        // Colors are 1-indexed
-        for i in 1..class_list.len() + 1 {
-            coloring[*vertex] = i;
-            let new_count = count_forbidden_per_vertex(graph, &coloring, *vertex);
-            if new_count < best_count {
-                best_count = new_count;
-                best_color = i;
-            }
+        if !(0.0..=1.0).contains(&self.merge_oracle_threshold) {
+            return Err(ColoringError::InvalidConfig(
+                "merge_oracle_threshold must be between 0 and 1".to_string(),
+            ));
         }
 
-        if best_count < original_count {
-            no_improvement = 0;
+        if self.exact_threshold > MAX_EXACT_THRESHOLD {
+            return Err(ColoringError::InvalidConfig(format!(
+                "exact_threshold must be at most {MAX_EXACT_THRESHOLD}"
+            )));
+        }
 
-            // Updating class_list
-            let original_index_in_class_list = class_list[original_color - 1]
-                .iter()
-                .position(|x| *x == *vertex)
-                .unwrap();
-            class_list[original_color - 1].remove(original_index_in_class_list);
-            class_list[best_color - 1].push(*vertex);
+        if self
+            .adaptive_csize
+            .is_some_and(|(min, max)| min == 0 || min > max)
+        {
+            return Err(ColoringError::InvalidConfig(
+                "adaptive_csize must have 1 <= min <= max".to_string(),
+            ));
+        }
 
-            (forbidden_count, forbidden_set) = get_forbidden_vertices(graph, class_list);
-            forbidden_vertices = forbidden_set.into_iter().collect();
-        } else {
-            no_improvement += 1;
+        if let Some(class_list) = &self.initial_solution {
+            let mut seen = HashSet::new();
+
+            if !class_list
+                .iter()
+                .flatten()
+                .all(|vertex| seen.insert(*vertex))
+            {
+                return Err(ColoringError::InvalidConfig(
+                    "initial_solution must hold each vertex once".to_string(),
+                ));
+            }
         }
-    }
 
-    forbidden_count
+        Ok(())
+    }
 }
 
-/// Turn a "Class List" into a traditional coloring. A class list assigns each index in a vector to
-/// a vector of vertices, which represent a given color.
-fn get_coloring_from_class_list(num_vertices: usize, class_list: &[Vec<usize>]) -> Vec<usize> {
-    let mut coloring: Vec<usize> = vec![0; num_vertices];
-
-    for (i, class) in class_list.iter().enumerate() {
-        for vertex in class {
-            assert_eq!(coloring[*vertex], 0);
+/// Size of the candidate lists of [`GraspConfig::adaptive_csize`], between `min` and `max`.
+#[derive(Debug, Clone, Copy, PartialEq, Eq)]
+struct AdaptiveCSize {
+    min: usize,
+    max: usize,
+    current: usize,
+}
 
-            coloring[*vertex] = i + 1;
+impl AdaptiveCSize {
+    fn new(min: usize, max: usize) -> Self {
+        AdaptiveCSize {
+            min,
+            max,
+            current: max,
         }
     }
 
-    coloring
+    /// Shrinks the size after an iteration that `improved` on the best solution, and grows it
+    /// otherwise.
+    fn update(&mut self, improved: bool) {
+        self.current = if improved {
+            self.current.saturating_sub(1).max(self.min)
+        } else {
+            (self.current + 1).min(self.max)
+        };
+    }
 }
 
-/// Counts the number of forbidden edges from `vertex` in `graph` according to `coloring`.
-fn count_forbidden_per_vertex(graph: &AdjList, coloring: &[usize], vertex: usize) -> usize {
-    graph.adj_list()[vertex]
-        .iter()
-        .filter(|x| coloring[**x] == coloring[vertex])
-        .count()
+/// Statistics collected during a GRASP execution.
+#[derive(Debug, Clone, Default, PartialEq)]
+pub struct GraspStats {
+    /// Number of colors of the best solution after each completed iteration.
+    pub best_colors: Vec<usize>,
+    /// Number of perturbations applied due to the lack of diversity.
+    pub num_perturbations: usize,
+    /// Total number of successful class merges of the improve phases.
+    pub num_merges: usize,
+    /// Number of improve phases run, which excludes the ones skipped by the upper bound pruning.
+    pub num_improve_phases: usize,
 }
 
-#[cfg(test)]
-mod tests {
-    use super::*;
-    use crate::{algorithms::is_coloring_valid, input};
-
-    #[test]
-    fn test_get_n_largest_degree() {
-        if let Ok(Some(graph)) = input::read_graph_from_file("data/myc/myciel3.col") {
-            // Use a subset to filter,
-            // i.e., use an induced subgraph
-            let set_subset = vec![10, 3, 4, 5];
-            let largest_degrees = get_n_largest_degree(3, &graph, &set_subset, None);
+/// Header of the CSV files written by [`GraspStats::to_csv`].
+const STATS_CSV_HEADER: &str = "iteration,best_colors,improvement_occurred";
 
-            assert_eq!(largest_degrees, vec![3, 5, 4]);
+impl GraspStats {
+    /// Writes the history of the best number of colors to a CSV file at `path`, one row per
+    /// iteration. The other counters aren't written.
+    pub fn to_csv(&self, path: &str) -> Result<(), io::Error> {
+        let mut writer = BufWriter::new(File::create(path)?);
 
-            // "Don't" use the subset to filter
-            // Since the parameter isn't optional, this effect is emulated by setting the subset to
-            // all vertices
-            let set_entire_graph: Vec<usize> = (0..graph.num_vertices()).collect();
-            let largest_degrees = get_n_largest_degree(5, &graph, &set_entire_graph, None);
+        writeln!(writer, "{STATS_CSV_HEADER}")?;
 
-            assert_eq!(largest_degrees, vec![10, 0, 1, 2, 3]);
+        for (iteration, best_colors) in self.best_colors.iter().enumerate() {
+            let improvement_occurred =
+                iteration == 0 || *best_colors < self.best_colors[iteration - 1];
 
-            // We don't care if the number of elements we're actually taking is smaller than the
-            // number we requested, due to a limitation in the subset length
-            let n_larger_than_subset = set_subset.len() + 1;
-            let largest_degrees =
-                get_n_largest_degree(n_larger_than_subset, &graph, &set_subset, None);
+            writeln!(writer, "{iteration},{best_colors},{improvement_occurred}")?;
+        }
 
-            assert_eq!(largest_degrees.len(), set_subset.len());
+        writer.flush()
+    }
 
-            // We also don't care if we request too many elements overall
-            // i.e., more elements than the number of vertices in the graph
-            let too_many_elements = set_entire_graph.len() + 1;
-            let largest_degrees =
-                get_n_largest_degree(too_many_elements, &graph, &set_entire_graph, None);
+    /// Reads the history of the best number of colors from a CSV file written by
+    /// [`GraspStats::to_csv`]. The other counters are 0.
+    pub fn from_csv(path: &str) -> Result<GraspStats, Box<dyn Error>> {
+        let content = fs::read_to_string(path)?;
+        let mut lines = content.lines();
+        let mut stats = GraspStats::default();
 
-            assert_eq!(largest_degrees.len(), set_entire_graph.len());
-        } else {
-            panic!("The file containing the test graph is missing")
+        if lines.next() != Some(STATS_CSV_HEADER) {
+            return Err(format!("Expected the header \"{STATS_CSV_HEADER}\"").into());
         }
 
-        let mut graph = AdjList::complete(4);
-
-        // Given the subgraph induce by &[0,1,3] (K3) and the list &[1]
-        // The vertices with largest_degree ought to be [0,3] since they share an edge with [1]
-        let largest_degrees = get_n_largest_degree(2, &graph, &[0, 1, 3], Some(&[1]));
+        for line in lines.filter(|line| !line.is_empty()) {
+            let fields: Vec<&str> = line.split(',').collect();
 
-        assert_eq!(largest_degrees, vec![0, 3]);
+            if fields.len() != 3 {
+                return Err(format!("Expected 3 fields, found \"{line}\"").into());
+            }
 
-        // There are no edges between the vertex 2 in the subgraph induced by [0,1,3]
-        // But we don't count the edges within the induced subgraph: instead, we use the subset
-        // parameter as a `filter`
-        //
-        // As in, to only consider these vertices, but the overall edges.
-        //
-        // Hence, when we remove an edge outside the induced subgraph,
-        // the return value should be updated accordingly
-        graph.sub_edge(0, 2);
-        let largest_degrees = get_n_largest_degree(2, &graph, &[0, 1, 3], Some(&[2]));
+            stats.best_colors.push(fields[1].parse()?);
+        }
 
-        assert_eq!(largest_degrees, vec![1, 3]);
+        Ok(stats)
     }
 
-    #[test]
-    fn test_count_remaining_edges() {
-        if let Ok(Some(graph)) = input::read_graph_from_file("data/myc/myciel3.col") {
-            let list = vec![0, 1, 2];
-            let num_edges = count_remaining_edges(&graph, &list);
-
-            assert_eq!(num_edges, 2);
-        } else {
-            panic!("The file containing the test graph is missing")
+    /// Combines the statistics of two executions (e.g., run in parallel), appending the history
+    /// of `other` to the one of `self`.
+    pub fn merge(&self, other: &GraspStats) -> GraspStats {
+        GraspStats {
+            best_colors: [self.best_colors.as_slice(), other.best_colors.as_slice()].concat(),
+            num_perturbations: self.num_perturbations + other.num_perturbations,
+            num_merges: self.num_merges + other.num_merges,
+            num_improve_phases: self.num_improve_phases + other.num_improve_phases,
         }
     }
+}
 
-    #[test]
-    fn test_grasp_wrapper() {
-        // Asserts GRASP provides a solution
-        if let Ok(Some(graph)) = input::read_graph_from_file("data/myc/myciel5.col") {
-            let (_, coloring) = grasp_wrapper(&graph, 10, 5, 5);
+impl Default for GraspConfig {
+    fn default() -> Self {
+        GraspConfig::new(25, 25, 3)
+    }
+}
 
-            assert!(is_coloring_valid(&graph, &coloring));
-        } else {
-            panic!("The file containing the test graph is missing")
+/// [`ColoringAlgorithm`] running [`grasp_with_config`].
+#[derive(Debug, Clone, Default)]
+pub struct GraspSolver {
+    config: GraspConfig,
+}
+
+impl GraspSolver {
+    /// Returns [`ColoringError::InvalidConfig`] if `config` is invalid.
+    pub fn new(config: GraspConfig) -> Result<Self, ColoringError> {
+        Ok(GraspSolver {
+            config: config.build()?,
+        })
+    }
+}
+
+impl ColoringAlgorithm for GraspSolver {
+    fn solve(&self, graph: &AdjList) -> Solution {
+        // The configuration was validated, so the only possible error is the missed bound
+        match grasp_with_config(graph, &self.config) {
+            Ok(solution) | Err(ColoringError::NoBoundedSolutionFound { best: solution, .. }) => {
+                solution
+            }
+            Err(error) => panic!("{error}"),
         }
     }
 
-    #[test]
-    fn test_improve_phase() {
-        let mut graph = AdjList::new(6);
-        graph.add_edge(0, 1);
-        graph.add_edge(1, 2);
-        graph.add_edge(2, 3);
-        graph.add_edge(2, 4);
-        graph.add_edge(2, 5);
+    fn time_budget(&self) -> Option<Duration> {
+        self.config.time_budget
+    }
+}
 
-        let mut num_classes = 4;
-        let mut class_list = vec![vec![1], vec![2], vec![4, 5], vec![0, 3]];
+/// Runs a single GRASP execution with the given parameters.
+pub fn grasp_wrapper(
+    graph: &AdjList,
+    grasp_iterations: i32,
+    color_iterations: i32,
+    color_list_size: usize,
+) -> Result<Solution, ColoringError> {
+    grasp_with_config(
+        graph,
+        &GraspConfig::new(grasp_iterations, color_iterations, color_list_size),
+    )
+}
 
-        improve_phase(&graph, &mut num_classes, &mut class_list);
+/// Runs a GRASP execution described by `config`, returning the best solution found.
+///
+/// Returns [`ColoringError::InvalidConfig`] if `config` is invalid, and
+/// [`ColoringError::NoBoundedSolutionFound`] if the best solution exceeds
+/// [`GraspConfig::color_upper_bound`].
+pub fn grasp_with_config(graph: &AdjList, config: &GraspConfig) -> Result<Solution, ColoringError> {
+    let (solution, _) = grasp_with_stats(graph, config)?;
 
-        assert!(num_classes <= 4);
+    Ok(solution)
+}
 
-        // Since the algorithm is randomized, we can't compare to an expected result
-        // But it should still be valid nonetheless
-        let coloring = get_coloring_from_class_list(6, &class_list);
+/// Same as [`grasp_with_config`], but also returns the statistics of the execution.
+pub fn grasp_with_stats(
+    graph: &AdjList,
+    config: &GraspConfig,
+) -> Result<(Solution, GraspStats), ColoringError> {
+    let (solutions, stats) = run_grasp(graph, config)?;
 
-        assert!(is_coloring_valid(&graph, &coloring));
-    }
+    // At least one iteration is run, so we can unwrap
+    Ok((solutions.into_iter().min().unwrap(), stats))
+}
 
-    #[test]
-    fn test_get_forbidden_vertices() {
-        // The complete graph
-        let graph = AdjList::complete(5);
-        let color_classes = vec![vec![0], vec![1], vec![2, 3, 4]];
+pub fn grasp(
+    graph: &AdjList,
+    grasp_iterations: i32,
+    color_iterations: i32,
+    color_list_size: usize,
+    num_solutions: usize,
+) -> Result<BinaryHeap<Solution>, ColoringError> {
+    let config = GraspConfig::new(grasp_iterations, color_iterations, color_list_size)
+        .elite_size(num_solutions);
+    let (solutions, _) = run_grasp(graph, &config)?;
 
-        let (count, forbidden) = get_forbidden_vertices(&graph, &color_classes);
+    Ok(solutions)
+}
 
-        assert_eq!(forbidden, HashSet::from([2, 3, 4]));
-        assert_eq!(count, 3)
-    }
+/// Runs a GRASP execution described by `config`, returning its archive of elite solutions,
+/// sorted from best to worst.
+pub fn grasp_archive(
+    graph: &AdjList,
+    config: &GraspConfig,
+) -> Result<Vec<Solution>, ColoringError> {
+    let (solutions, _) = run_grasp(graph, config)?;
 
-    #[test]
+    Ok(solutions.into_sorted_vec())
+}
+
+/// Inserts `solution` into the `archive` if it isn't full or if the solution uses fewer colors
+/// than the worst one, which is then discarded. Repeated colorings aren't inserted.
+fn archive_insert(archive: &mut BinaryHeap<Solution>, solution: Solution, elite_size: usize) {
+    if archive.iter().any(|(_, coloring)| *coloring == solution.1) {
+        return;
+    }
+
+    if archive.len() < elite_size {
+        archive.push(solution);
+    } else if archive.peek().is_some_and(|worst| solution.0 < worst.0) {
+        archive.pop();
+        archive.push(solution);
+    }
+}
+
+/// Runs the GRASP iterations in batches, keeping an archive of the `elite_size` best solutions.
+///
+/// After each batch, if the diversity of the kept solutions is below the threshold, the first
+/// iteration of the next batch perturbs one of them. The execution stops early once every kept
+/// solution matches the clique lower bound or the rounded up [`fractional_chromatic_lower_bound`],
+/// since they can't be improved, or when the time budget runs out.
+///
+/// A single [`SmallRng`], seeded from the configuration, drives the execution. Each iteration of a
+/// batch gets its own generator seeded from it, so the parallel iterations don't depend on the
+/// scheduling. Compared to querying `thread_rng` in the inner loops, the time of 100 iterations
+/// on `myciel6.col` stays around 6s (within the noise), as it is dominated by the local search.
+///
+/// With [`GraspConfig::upper_bound_pruning`], the iterations of a batch skip the improve phase of
+/// the constructions that are already no better than the best solution of the previous batches.
+///
+/// With [`GraspConfig::initial_solution`], the first iteration improves the given solution
+/// instead of building one.
+///
+/// Returns the kept solutions and the statistics of the execution,
+/// [`ColoringError::InvalidConfig`] if the initial solution isn't a valid coloring of `graph`, or
+/// [`ColoringError::NoBoundedSolutionFound`] if even the best one exceeds
+/// [`GraspConfig::color_upper_bound`].
+fn run_grasp(
+    graph: &AdjList,
+    config: &GraspConfig,
+) -> Result<(BinaryHeap<Solution>, GraspStats), ColoringError> {
+    config.validate()?;
+
+    if config
+        .initial_solution
+        .as_ref()
+        .is_some_and(|class_list| !graph.is_valid_class_list(class_list))
+    {
+        return Err(ColoringError::InvalidConfig(
+            "initial_solution must be a valid coloring of the graph".to_string(),
+        ));
+    }
+
+    let start_time = Instant::now();
+    let out_of_time = || {
+        config
+            .time_budget
+            .is_some_and(|budget| start_time.elapsed() >= budget)
+    };
+    let mut solutions: BinaryHeap<Solution> = BinaryHeap::with_capacity(config.elite_size);
+    let mut stats = GraspStats::default();
+    let mut remaining = config.grasp_iterations;
+    let mut perturb = false;
+    // Reaching the lower bound proves optimality
+    let target_colors = graph
+        .clique_lower_bound()
+        .max(fractional_chromatic_lower_bound(graph).ceil() as usize);
+    let mut rng = match config.seed {
+        Some(seed) => SmallRng::seed_from_u64(seed),
+        None => SmallRng::from_entropy(),
+    };
+    let mut adaptive_csize = config
+        .adaptive_csize
+        .map(|(min, max)| AdaptiveCSize::new(min, max));
+
+    while remaining > 0 {
+        if solutions.len() == config.elite_size
+            && solutions
+                .peek()
+                .is_some_and(|worst| worst.0 <= target_colors)
+        {
+            break;
+        }
+
+        let first_batch = solutions.is_empty();
+
+        if !first_batch && out_of_time() {
+            break;
+        }
+
+        let batch_size = remaining.min(DIVERSITY_CHECK_INTERVAL);
+        remaining -= batch_size;
+
+        let start = if perturb {
+            stats.num_perturbations += 1;
+            let elite: Vec<&Solution> = solutions.iter().collect();
+            elite
+                .choose(&mut rng)
+                .and_then(|solution| perturbed_iteration(graph, solution, config, &mut rng))
+        } else if first_batch {
+            config
+                .initial_solution
+                .as_ref()
+                .map(|class_list| warm_start_iteration(graph, class_list, config, &mut rng))
+        } else {
+            None
+        };
+
+        let upper_bound = solutions
+            .iter()
+            .min()
+            .filter(|_| config.upper_bound_pruning)
+            .map(|(best_colors, _)| *best_colors);
+        let color_list_size = adaptive_csize.map_or(config.color_list_size, |size| size.current);
+        let seeds: Vec<u64> = (0..batch_size).map(|_| rng.gen()).collect();
+        let all: Vec<(Solution, Option<usize>)> = seeds
+            .into_par_iter()
+            .enumerate()
+            .filter_map(|(i, seed)| {
+                // The very first iteration always runs, so there is a solution to return
+                if (i > 0 || !first_batch) && out_of_time() {
+                    return None;
+                }
+
+                match (i, &start) {
+                    (0, Some(result)) => Some(Ok(result.clone())),
+                    _ => Some(grasp_iteration_with_list_size(
+                        graph,
+                        config,
+                        color_list_size,
+                        upper_bound,
+                        &mut SmallRng::seed_from_u64(seed),
+                    )),
+                }
+            })
+            .collect::<Result<_, _>>()?;
+
+        for (solution, num_merges) in all {
+            if let Some(num_merges) = num_merges {
+                stats.num_improve_phases += 1;
+                stats.num_merges += num_merges;
+            }
+
+            if let Some(size) = adaptive_csize.as_mut() {
+                size.update(
+                    stats
+                        .best_colors
+                        .last()
+                        .is_none_or(|best| solution.0 < *best),
+                );
+            }
+
+            let best = stats
+                .best_colors
+                .last()
+                .map_or(solution.0, |best| solution.0.min(*best));
+            stats.best_colors.push(best);
+
+            archive_insert(&mut solutions, solution, config.elite_size);
+
+            if let Some(IterationCallback(callback)) = &config.on_iteration {
+                // The solution was just considered, so the archive isn't empty and we can unwrap
+                let (best_colors, coloring) = solutions.iter().min().unwrap();
+                let class_list = get_class_list_from_coloring(coloring);
+                let callback = callback.lock().unwrap();
+
+                callback(stats.best_colors.len() - 1, *best_colors, &class_list);
+            }
+        }
+
+        let colorings: Vec<&[usize]> = solutions
+            .iter()
+            .map(|(_, coloring)| coloring.as_slice())
+            .collect();
+        perturb = colorings.len() > 1
+            && solution_diversity(&colorings, graph.num_vertices()) < config.diversity_threshold;
+    }
+
+    if let Some(bound) = config.color_upper_bound {
+        // At least one iteration is run, so we can unwrap
+        let best = solutions.iter().min().unwrap();
+
+        if best.0 > bound {
+            return Err(ColoringError::NoBoundedSolutionFound {
+                bound,
+                best: best.clone(),
+            });
+        }
+    }
+
+    Ok((solutions, stats))
+}
+
+/// Builds a coloring with the greedy randomized construction described by `config` and improves
+/// it, unless it uses at least `upper_bound` colors.
+///
+/// Returns the solution and the number of class merges of the improve phase, or `None` if it was
+/// skipped.
+pub(crate) fn grasp_iteration(
+    graph: &AdjList,
+    config: &GraspConfig,
+    upper_bound: Option<usize>,
+    rng: &mut impl Rng,
+) -> Result<(Solution, Option<usize>), ColoringError> {
+    grasp_iteration_with_list_size(graph, config, config.color_list_size, upper_bound, rng)
+}
+
+/// Same as [`grasp_iteration`], but with candidate lists of `color_list_size` vertices instead
+/// of the size of `config`.
+fn grasp_iteration_with_list_size(
+    graph: &AdjList,
+    config: &GraspConfig,
+    color_list_size: usize,
+    upper_bound: Option<usize>,
+    rng: &mut impl Rng,
+) -> Result<(Solution, Option<usize>), ColoringError> {
+    let max_colors = graph.num_vertices();
+    let mut num_color_classes = 0;
+    let mut vertex_set: Vec<usize> = (0..max_colors).collect();
+    let mut class_list: Vec<Vec<usize>> = Vec::new();
+    // The graph induced by the uncolored vertices
+    let mut residual = ResidualGraph::new(graph, &vertex_set);
+
+    class_list.resize(max_colors, Vec::new());
+
+    while !vertex_set.is_empty() {
+        if vertex_set.len() < config.exact_threshold {
+            // The remaining vertices have neighbors in every class, which is maximal, so they
+            // get new classes
+            let (num_tail_classes, tail_coloring) =
+                exact_chromatic_number(&graph.induced_subgraph(&vertex_set));
+            for (vertex, color) in vertex_set.iter().zip(tail_coloring) {
+                class_list[num_color_classes + color - 1].push(*vertex);
+            }
+
+            num_color_classes += num_tail_classes;
+            break;
+        }
+
+        let mut min_num_edges_remaining = usize::MAX;
+
+        num_color_classes += 1;
+
+        for _ in 0..config.color_iterations {
+            assign_color(
+                &vertex_set,
+                color_list_size,
+                config.vertex_scorer.as_deref(),
+                &residual,
+                &mut min_num_edges_remaining,
+                &mut class_list[num_color_classes - 1],
+                rng,
+            )?;
+        }
+
+        vertex_set.retain(|vertex| !class_list[num_color_classes - 1].contains(vertex));
+        residual.remove_vertices(&class_list[num_color_classes - 1]);
+    }
+
+    let num_merges = if upper_bound.is_some_and(|upper_bound| num_color_classes >= upper_bound) {
+        None
+    } else {
+        Some(improve_phase(
+            graph,
+            &mut num_color_classes,
+            &mut class_list,
+            config,
+            rng,
+        ))
+    };
+
+    let coloring = get_coloring_from_class_list(max_colors, &class_list);
+    Ok(((num_color_classes, coloring), num_merges))
+}
+
+/// Applies the improve phase to the valid coloring `class_list`, returning the solution and the
+/// number of class merges.
+fn warm_start_iteration(
+    graph: &AdjList,
+    class_list: &[Vec<usize>],
+    config: &GraspConfig,
+    rng: &mut impl Rng,
+) -> (Solution, Option<usize>) {
+    let mut class_list = compact_class_list_owned(class_list.to_vec());
+    let mut num_colors = class_list.len();
+    let num_merges = improve_phase(graph, &mut num_colors, &mut class_list, config, rng);
+
+    (
+        (
+            num_colors,
+            get_coloring_from_class_list(graph.num_vertices(), &class_list),
+        ),
+        Some(num_merges),
+    )
+}
+
+/// Randomly reassigns a fraction of the vertices of `solution` and repairs it with the local
+/// search, before applying the improve phase.
+///
+/// Returns `None` if the local search couldn't repair the perturbed coloring, and otherwise the
+/// solution and the number of class merges of the improve phase.
+fn perturbed_iteration(
+    graph: &AdjList,
+    solution: &Solution,
+    config: &GraspConfig,
+    rng: &mut impl Rng,
+) -> Option<(Solution, Option<usize>)> {
+    let num_vertices = graph.num_vertices();
+    let (mut num_colors, coloring) = solution.clone();
+    let mut coloring = coloring;
+    let num_perturbed = (num_vertices as f64 * PERTURBATION_RATIO).ceil() as usize;
+
+    for vertex in (0..num_vertices).choose_multiple(rng, num_perturbed) {
+        coloring[vertex] = rng.gen_range(1..=num_colors);
+    }
+
+    let mut class_list = get_class_list(&coloring, num_colors);
+
+    if local_search_with_objective(
+        graph,
+        &mut class_list,
+        config.weighted_objective,
+        &config.local_search_config(),
+        rng,
+    ) > 0
+    {
+        return None;
+    }
+
+    let num_merges = improve_phase(graph, &mut num_colors, &mut class_list, config, rng);
+
+    Some((
+        (
+            num_colors,
+            get_coloring_from_class_list(num_vertices, &class_list),
+        ),
+        Some(num_merges),
+    ))
+}
+
+/// Average pairwise Hamming distance between the `solutions` colorings, normalized by the
+/// number of vertices `n`. Equals 0 when there are less than two solutions.
+pub fn solution_diversity(solutions: &[&[usize]], n: usize) -> f64 {
+    let mut total = 0.0;
+    let mut num_pairs = 0;
+
+    for i in 0..solutions.len() {
+        for j in i + 1..solutions.len() {
+            let distance = solutions[i]
+                .iter()
+                .zip(solutions[j].iter())
+                .filter(|(lhs, rhs)| lhs != rhs)
+                .count();
+
+            total += distance as f64 / n as f64;
+            num_pairs += 1;
+        }
+    }
+
+    if num_pairs == 0 {
+        0.0
+    } else {
+        total / num_pairs as f64
+    }
+}
+
+/// Tries to replace the color class `color_class` with a new one built from the uncolored
+/// `vertex_set` following the greedy heuristic.
+///
+/// The color class is built by [`maximal_independent_set`], or by
+/// [`maximal_independent_set_with_scorer`] if a `scorer` is given. Among the attempts, the class
+/// that leaves the fewest edges in the remaining graph, `residual`, is kept.
+///
+/// Refer to the article for more information about the heuristic.
+///
+/// Returns [`ColoringError::EmptyCandidateList`] if `color_list_size` is 0.
+fn assign_color(
+    vertex_set: &[usize],
+    color_list_size: usize,
+    scorer: Option<&dyn VertexScorer>,
+    residual: &ResidualGraph,
+    min_num_edges_remaining: &mut usize,
+    color_class: &mut Vec<usize>,
+    rng: &mut impl Rng,
+) -> Result<(), ColoringError> {
+    let graph = residual.graph();
+    let current_color_class = match scorer {
+        Some(scorer) => {
+            maximal_independent_set_with_scorer(graph, vertex_set, color_list_size, scorer, rng)?
+        }
+        None => maximal_independent_set(graph, vertex_set, color_list_size, rng)?,
+    };
+
+    let remaining_edges = residual.num_edges_without(&current_color_class);
+
+    if remaining_edges < *min_num_edges_remaining {
+        *color_class = current_color_class;
+        *min_num_edges_remaining = remaining_edges;
+    }
+
+    Ok(())
+}
+
+/// Builds `k` independent sets of the subgraph induced by `vertex_set` at the same time, instead
+/// of one after the other as [`assign_color`] does.
+///
+/// The sets take turns: each one adds a random vertex among the `k` vertices that can still join
+/// it with the largest degree towards the vertices not yet taken (see [`get_n_best`]). This stops
+/// when no set can grow, and the vertices that can join no set are left out.
+pub fn assign_colors_interleaved(
+    vertex_set: &[usize],
+    k: usize,
+    graph: &AdjList,
+    rng: &mut impl Rng,
+) -> Vec<Vec<usize>> {
+    let mut classes: Vec<Vec<usize>> = vec![Vec::new(); k];
+    // Whether each vertex is adjacent to a vertex of each class
+    let mut blocked = vec![vec![false; graph.num_vertices()]; k];
+    // Kept sorted, as removals preserve the order, to count degrees by binary search
+    let mut remaining = vertex_set.to_vec();
+    remaining.sort();
+    // Number of consecutive turns in which a class couldn't grow
+    let mut num_stuck = 0;
+    let mut class = 0;
+
+    while num_stuck < k && !remaining.is_empty() {
+        let admissible: Vec<usize> = remaining
+            .iter()
+            .copied()
+            .filter(|vertex| !blocked[class][*vertex])
+            .collect();
+        let candidates = get_n_best(k, &admissible, |vertex| {
+            graph.get_degree_in_sorted_list(vertex, &remaining) as i64
+        });
+
+        if let Some(vertex) = candidates.choose(rng).copied() {
+            num_stuck = 0;
+            classes[class].push(vertex);
+            remaining.retain(|u| *u != vertex);
+
+            for neighbor in graph.adj_list()[vertex].iter() {
+                blocked[class][*neighbor] = true;
+            }
+        } else {
+            num_stuck += 1;
+        }
+
+        class = (class + 1) % k;
+    }
+
+    classes
+}
+
+/// Builds a `k`-class list of `graph` with [`assign_colors_interleaved`], giving each vertex left
+/// out the class where it has the fewest neighbors, and then repairs it with [`local_search`].
+///
+/// Returns the class list and the number of edges that are still forbidden, or
+/// [`ColoringError::EmptyCandidateList`] if `k` is 0 and `graph` isn't empty.
+pub fn interleaved_construction(
+    graph: &AdjList,
+    k: usize,
+    config: &LocalSearchConfig,
+    rng: &mut impl Rng,
+) -> Result<(Vec<Vec<usize>>, usize), ColoringError> {
+    if k == 0 && graph.num_vertices() > 0 {
+        return Err(ColoringError::EmptyCandidateList);
+    }
+
+    let vertex_set: Vec<usize> = (0..graph.num_vertices()).collect();
+    let mut class_list = assign_colors_interleaved(&vertex_set, k, graph, rng);
+    let mut colored = vec![false; graph.num_vertices()];
+    for vertex in class_list.iter().flatten() {
+        colored[*vertex] = true;
+    }
+
+    for vertex in vertex_set.iter().filter(|vertex| !colored[**vertex]) {
+        // `k` isn't 0, so we can unwrap
+        let class = (0..k)
+            .min_by_key(|class| graph.get_degree_in_list(*vertex, &class_list[*class]))
+            .unwrap();
+        class_list[class].push(*vertex);
+    }
+
+    let num_forbidden = local_search(graph, &mut class_list, config, rng);
+
+    Ok((class_list, num_forbidden))
+}
+
+/// Tries to improve the coloring from `class_list` by
+///
+/// 1. Merging the [`GraspConfig::merge_group_size`] smallest class colors, or the two smallest
+///    ones if that fails (see [`try_merge`])
+/// 2. Moving the conflicting vertices of the merged class to the other classes with
+///    [`neighborhood_swap`]
+/// 3. Applying a local search for the resulting class list, if conflicts remain and the merged
+///    classes pass [`GraspConfig::merge_oracle_threshold`]
+/// 4. Redistributing the vertices of a pair of classes with [`color_class_interchange`], if
+///    the local search got stuck, followed by another local search if that helped
+///
+/// The process repeats until a forbidden coloring is found, a single class is left or a merge
+/// removes fewer than [`GraspConfig::improve_min_reduction`] classes (that merge is still kept).
+/// See [`local_search_with_objective`] for [`GraspConfig::use_weighted_objective`].
+///
+/// The empty classes are removed at the end, so `num_classes` matches the length of
+/// `class_list`. Returns the number of successful merges.
+pub(super) fn improve_phase(
+    graph: &AdjList,
+    num_classes: &mut usize,
+    class_list: &mut Vec<Vec<usize>>,
+    config: &GraspConfig,
+    rng: &mut impl Rng,
+) -> usize {
+    let mut num_merges = 0;
+
+    // A single class can't be merged any further
+    while *num_classes > 1 {
+        // Larger groups first, falling back to smaller ones
+        let merged = (2..=config.merge_group_size.min(*num_classes))
+            .rev()
+            .find_map(|group_size| {
+                try_merge(graph, class_list, *num_classes, group_size, config, rng)
+            });
+
+        match merged {
+            Some(new_classes) => {
+                let reduction = *num_classes - new_classes.len();
+                num_merges += 1;
+                *num_classes = new_classes.len();
+                *class_list = new_classes;
+
+                if reduction < config.improve_min_reduction {
+                    break;
+                }
+            }
+            None => break,
+        }
+    }
+
+    compact_class_list(class_list);
+    *num_classes = class_list.len();
+
+    num_merges
+}
+
+/// Merges the `group_size` smallest classes among the first `num_classes` of `class_list` and
+/// repairs the result (steps 1 to 4 of [`improve_phase`]), returning it if no conflicts remain.
+///
+/// If conflicts remain after the neighborhood swaps and some pair of the merged classes has a
+/// [`class_merge_feasibility`] below [`GraspConfig::merge_oracle_threshold`], the merge fails
+/// without the local searches.
+fn try_merge(
+    graph: &AdjList,
+    class_list: &[Vec<usize>],
+    num_classes: usize,
+    group_size: usize,
+    config: &GraspConfig,
+    rng: &mut impl Rng,
+) -> Option<Vec<Vec<usize>>> {
+    let weighted_objective = config.weighted_objective;
+    let local_search_config = &config.local_search_config();
+    let group = smallest_group(class_list, num_classes, group_size);
+    let mut new_classes = merge_smallest_group(class_list, num_classes, group_size);
+
+    // The merged class comes first
+    for other in 1..new_classes.len() {
+        neighborhood_swap(graph, &mut new_classes, 0, other);
+    }
+
+    let mut num_forbidden = get_forbidden_vertices(graph, &new_classes).0;
+
+    if num_forbidden > 0 && config.merge_oracle_threshold > 0.0 {
+        let feasible = group.iter().enumerate().all(|(i, a)| {
+            group[i + 1..].iter().all(|b| {
+                class_merge_feasibility(graph, &class_list[*a], &class_list[*b])
+                    >= config.merge_oracle_threshold
+            })
+        });
+
+        if !feasible {
+            return None;
+        }
+    }
+
+    if num_forbidden > 0 {
+        num_forbidden = local_search_with_objective(
+            graph,
+            &mut new_classes,
+            weighted_objective,
+            local_search_config,
+            rng,
+        );
+    }
+
+    if num_forbidden > 0 {
+        let eliminated = color_class_interchange(graph, &mut new_classes);
+        num_forbidden -= eliminated;
+
+        if eliminated > 0 && num_forbidden > 0 {
+            num_forbidden = local_search_with_objective(
+                graph,
+                &mut new_classes,
+                weighted_objective,
+                local_search_config,
+                rng,
+            );
+        }
+    }
+
+    (num_forbidden == 0).then_some(new_classes)
+}
+
+/// Estimates how likely the merge of the classes `class_a` and `class_b` is to succeed, as one
+/// minus the fraction of the pairs of their vertices that are adjacent: 1 if no edge joins them
+/// and 0 if every pair does. Equals 1 if either class is empty.
+pub fn class_merge_feasibility(graph: &AdjList, class_a: &[usize], class_b: &[usize]) -> f64 {
+    let num_pairs = class_a.len() * class_b.len();
+
+    if num_pairs == 0 {
+        return 1.0;
+    }
+
+    let cross_edges: usize = class_a
+        .iter()
+        .map(|u| graph.get_degree_in_list(*u, class_b))
+        .sum();
+
+    1.0 - cross_edges as f64 / num_pairs as f64
+}
+
+/// Builds a new class list by combining the two smallest classes among the first `num_classes`
+/// of `class_list`. The combined class comes first, followed by the remaining non-empty classes.
+///
+/// The resulting class list may be forbidden.
+pub(super) fn merge_smallest_classes(
+    class_list: &[Vec<usize>],
+    num_classes: usize,
+) -> Vec<Vec<usize>> {
+    merge_smallest_group(class_list, num_classes, 2)
+}
+
+/// Indices of the `group_size` smallest classes among the first `num_classes` of `class_list`.
+fn smallest_group(class_list: &[Vec<usize>], num_classes: usize, group_size: usize) -> Vec<usize> {
+    let mut lenghts: Vec<(usize, usize)> = class_list
+        .iter()
+        .enumerate()
+        .map(|(index, class)| (index, class.len()))
+        .take(num_classes)
+        .collect();
+
+    lenghts.sort_by_key(|x| Reverse(x.1));
+
+    lenghts
+        .iter()
+        .rev()
+        .take(group_size)
+        .map(|(index, _)| *index)
+        .collect()
+}
+
+/// Same as [`merge_smallest_classes`], but combines the `group_size` smallest classes.
+fn merge_smallest_group(
+    class_list: &[Vec<usize>],
+    num_classes: usize,
+    group_size: usize,
+) -> Vec<Vec<usize>> {
+    let smallest_lengths = smallest_group(class_list, num_classes, group_size);
+
+    let mut combined_class: Vec<usize> = vec![];
+
+    for index in smallest_lengths.iter() {
+        combined_class.append(&mut class_list[*index].clone());
+    }
+
+    let mut new_classes: Vec<Vec<usize>> = vec![];
+
+    new_classes.push(combined_class);
+
+    for (index, class) in class_list.iter().enumerate() {
+        if smallest_lengths.contains(&index) || class.is_empty() {
+            continue;
+        }
+        new_classes.push(class.clone());
+    }
+
+    new_classes
+}
+
+/// Counts the number of forbidden edges in `graph` according to `class_list`.
+///
+/// Saves the corresponding vertices in a set.
+pub(super) fn get_forbidden_vertices(
+    graph: &AdjList,
+    class_list: &[Vec<usize>],
+) -> (usize, HashSet<usize>) {
+    let num_vertices = graph.num_vertices();
+    let adj_list = graph.adj_list();
+    let coloring = get_coloring_from_class_list(num_vertices, class_list);
+    let mut count = 0;
+    let mut forbidden = HashSet::new();
+    for (i, v) in adj_list.iter().enumerate() {
+        for j in v.iter() {
+            if coloring[i] == coloring[*j] {
+                count += 1;
+                forbidden.insert(i);
+                forbidden.insert(*j);
+            }
+        }
+    }
+    (count / 2, forbidden)
+}
+
+/// Applies a local search for `class_list` according to `graph`.
+///
+/// The local search works by selecting an illegal vertex and trying every possible color swap for
+/// said vertex to reduce the number of forbidden vertices in the graph.
+/// If we can improve, we update the `class_list`. Otherwise, an ejection chain starting from
+/// said vertex is tried.
+///
+/// Repeats the process while they are forbidden vertices
+/// or the number of iterations that haven't improved `class_list` reaches a threshold.
+/// When the number of forbidden edges stagnates for a while, a fraction of the vertices is
+/// randomly reassigned to diversify the search (see [`LocalSearchConfig`]).
+///
+/// With [`LocalSearchStrategy::BestImprovement`], the best move among all the forbidden vertices
+/// is applied instead, and the random vertex is only used for the ejection chain.
+///
+/// `class_list` is left with the best assignment found. Returns the number of edges that are
+/// still forbidden.
+pub(super) fn local_search(
+    graph: &AdjList,
+    class_list: &mut [Vec<usize>],
+    config: &LocalSearchConfig,
+    rng: &mut impl Rng,
+) -> usize {
+    local_search_with_objective(graph, class_list, false, config, rng)
+}
+
+/// Same as [`local_search`], but if `weighted_objective` is set, color swaps are chosen to reduce
+/// the total weight of the forbidden edges (see [`super::weighted_forbidden_count`]) instead of their
+/// number, and no ejection chains are tried.
+pub(super) fn local_search_with_objective(
+    graph: &AdjList,
+    class_list: &mut [Vec<usize>],
+    weighted_objective: bool,
+    config: &LocalSearchConfig,
+    rng: &mut impl Rng,
+) -> usize {
+    run_local_search(
+        graph,
+        class_list,
+        weighted_objective,
+        config,
+        rng,
+        None,
+        None,
+    )
+}
+
+/// Same as [`local_search`], but also returns how many times each vertex was selected as the
+/// random forbidden vertex, which points out the vertices that are repositioned the most.
+/// Vertices that were never selected are left out of the profile.
+pub fn local_search_profiled(
+    graph: &AdjList,
+    class_list: &mut [Vec<usize>],
+    config: &LocalSearchConfig,
+    rng: &mut impl Rng,
+) -> (usize, HashMap<usize, usize>) {
+    let mut profile = HashMap::new();
+    let num_forbidden = run_local_search(
+        graph,
+        class_list,
+        false,
+        config,
+        rng,
+        Some(&mut profile),
+        None,
+    );
+
+    (num_forbidden, profile)
+}
+
+/// Same as [`local_search`], but also returns the density of the conflict graph (see
+/// [`super::conflict_subgraph_density`]) after each iteration, which tells whether the search
+/// is still converging. Perturbations may make it increase.
+pub fn local_search_convergence(
+    graph: &AdjList,
+    class_list: &mut [Vec<usize>],
+    config: &LocalSearchConfig,
+    rng: &mut impl Rng,
+) -> (usize, Vec<f64>) {
+    let mut densities = Vec::new();
+    let num_forbidden = run_local_search(
+        graph,
+        class_list,
+        false,
+        config,
+        rng,
+        None,
+        Some(&mut densities),
+    );
+
+    (num_forbidden, densities)
+}
+
+/// Implementation of [`local_search_with_objective`], counting the selections of each vertex in
+/// `profile` and recording the density of the conflict graph after each iteration in `densities`,
+/// if given.
+fn run_local_search(
+    graph: &AdjList,
+    class_list: &mut [Vec<usize>],
+    weighted_objective: bool,
+    config: &LocalSearchConfig,
+    rng: &mut impl Rng,
+    mut profile: Option<&mut HashMap<usize, usize>>,
+    mut densities: Option<&mut Vec<f64>>,
+) -> usize {
+    let n = graph.num_vertices();
+    let num_pairs = (n * n.saturating_sub(1) / 2).max(1) as f64;
+    let mut assignment = ColorAssignment::from_class_list(graph, class_list);
+    let mut forbidden_vertices = assignment.forbidden_vertices(graph);
+    let no_improvement_ceil = match config.no_improvement_ceil {
+        0 => 2 * assignment.conflict_count(),
+        ceil => ceil,
+    };
+    // We use this variable to control how many iterations we can go by without improvement
+    let mut no_improvement = 0;
+    // Iterations since the number of forbidden edges last decreased
+    let mut stagnation = 0;
+    let mut num_perturbations = 0;
+    let mut best_count = assignment.conflict_count();
+    let mut best_class_list = class_list.to_vec();
+    let mut iterations = 0;
+    // Sideways moves left before the iterations without improvement count again
+    let mut sideways_remaining = config.max_sideways_moves;
+    // Recent recolorings, as (vertex, color)
+    let mut tabu = RingBuffer::new(config.tabu_size);
+
+    while assignment.conflict_count() > 0
+        && no_improvement < no_improvement_ceil
+        && (config.max_iterations == 0 || iterations < config.max_iterations)
+    {
+        let previous_count = assignment.conflict_count();
+        iterations += 1;
+
+        // Randomly choose an illegal vertex (i.e., one that is colored with the same color as an adjacent vertex).
+
+        // Since there are conflicts we can unwrap
+        let vertex = *forbidden_vertices.choose(rng).unwrap();
+
+        if let Some(profile) = profile.as_deref_mut() {
+            *profile.entry(vertex).or_insert(0) += 1;
+        }
+
+        // Make all possible attempts to switch v, or one of the neighbors it conflicts with (or
+        // any forbidden vertex, for the best improvement), to a different color, keeping the move
+        // that breaks the most conflicts.
+        let candidates = match config.strategy {
+            LocalSearchStrategy::RandomVertex => std::iter::once(vertex)
+                .chain(conflicting_neighbors(graph, assignment.coloring(), vertex))
+                .collect(),
+            LocalSearchStrategy::BestImprovement | LocalSearchStrategy::DoubleSwap => {
+                forbidden_vertices.clone()
+            }
+        };
+        let best_move = candidates
+            .iter()
+            .copied()
+            .filter_map(|candidate| {
+                best_recoloring(graph, &assignment, candidate, weighted_objective, &tabu)
+                    .map(|(color, gain)| (candidate, color, gain))
+            })
+            .max_by(|a, b| a.2.total_cmp(&b.2));
+        let best_swap = match config.strategy {
+            LocalSearchStrategy::DoubleSwap => best_color_swap(
+                graph,
+                &assignment,
+                &forbidden_vertices,
+                weighted_objective,
+                &tabu,
+            ),
+            _ => None,
+        }
+        .filter(|swap| best_move.is_none_or(|(_, _, gain)| swap.2 > gain));
+        let best_joint = (config.move_size > 1)
+            .then(|| {
+                let mut vertices = vec![vertex];
+                vertices.extend(
+                    graph.adj_list()[vertex]
+                        .choose_multiple(rng, config.move_size - 1)
+                        .copied(),
+                );
+
+                best_joint_recoloring(graph, &assignment, &vertices, weighted_objective, &tabu)
+                    .map(|(colors, gain)| (vertices, colors, gain))
+            })
+            .flatten()
+            .filter(|(_, _, gain)| {
+                best_move.is_none_or(|(_, _, move_gain)| *gain > move_gain)
+                    && best_swap.is_none_or(|(_, _, swap_gain)| *gain > swap_gain)
+            });
+
+        if let Some((vertices, colors, _)) = best_joint {
+            no_improvement = 0;
+
+            for (vertex, color) in vertices.into_iter().zip(colors) {
+                assignment.assign(graph, vertex, color);
+                tabu.push((vertex, color));
+            }
+            forbidden_vertices = assignment.forbidden_vertices(graph);
+        } else if let Some((u, v, _)) = best_swap {
+            no_improvement = 0;
+
+            // Both vertices are colored, so we can unwrap
+            let color_u = assignment.color_of(u).unwrap();
+            let color_v = assignment.color_of(v).unwrap();
+            assignment.assign(graph, u, color_v);
+            assignment.assign(graph, v, color_u);
+            tabu.push((u, color_v));
+            tabu.push((v, color_u));
+            forbidden_vertices = assignment.forbidden_vertices(graph);
+        } else if let Some((candidate, best_color, _)) = best_move {
+            no_improvement = 0;
+
+            assignment.assign(graph, candidate, best_color);
+            tabu.push((candidate, best_color));
+            forbidden_vertices = assignment.forbidden_vertices(graph);
+        } else if let Some((candidate, color)) = (sideways_remaining > 0)
+            .then(|| {
+                sideways_recoloring(
+                    graph,
+                    &assignment,
+                    &candidates,
+                    weighted_objective,
+                    &tabu,
+                    rng,
+                )
+            })
+            .flatten()
+        {
+            sideways_remaining -= 1;
+
+            assignment.assign(graph, candidate, color);
+            tabu.push((candidate, color));
+            forbidden_vertices = assignment.forbidden_vertices(graph);
+        } else if weighted_objective {
+            no_improvement += 1;
+        } else {
+            class_list.clone_from_slice(&assignment.to_class_list());
+
+            if ejection_chain_move(graph, class_list, vertex, EJECTION_CHAIN_LENGTH) {
+                no_improvement = 0;
+
+                assignment = ColorAssignment::from_class_list(graph, class_list);
+                forbidden_vertices = assignment.forbidden_vertices(graph);
+            } else {
+                no_improvement += 1;
+            }
+        }
+
+        if assignment.conflict_count() < best_count {
+            best_count = assignment.conflict_count();
+            best_class_list = assignment.to_class_list();
+        }
+
+        if assignment.conflict_count() < previous_count {
+            stagnation = 0;
+            sideways_remaining = config.max_sideways_moves;
+        } else {
+            stagnation += 1;
+        }
+
+        if stagnation >= config.stagnation_window
+            && num_perturbations < MAX_LOCAL_PERTURBATIONS
+            && assignment.conflict_count() > 0
+        {
+            perturb_assignment(graph, &mut assignment, config.perturbation_rate, rng);
+            forbidden_vertices = assignment.forbidden_vertices(graph);
+            num_perturbations += 1;
+            stagnation = 0;
+            no_improvement = 0;
+        }
+
+        // The conflict graph has the forbidden edges as its edges
+        if let Some(densities) = densities.as_deref_mut() {
+            densities.push(assignment.conflict_count() as f64 / num_pairs);
+        }
+    }
+
+    if assignment.conflict_count() <= best_count {
+        class_list.clone_from_slice(&assignment.to_class_list());
+    } else {
+        class_list.clone_from_slice(&best_class_list);
+    }
+
+    best_count.min(assignment.conflict_count())
+}
+
+/// Runs [`local_search`] with the [`LocalSearchStrategy::BestImprovement`] strategy for at most
+/// `max_iterations` iterations (unlimited if 0), returning the number of edges that are still
+/// forbidden.
+pub fn local_search_best_improvement(
+    graph: &AdjList,
+    class_list: &mut [Vec<usize>],
+    max_iterations: usize,
+) -> usize {
+    let config = LocalSearchConfig {
+        strategy: LocalSearchStrategy::BestImprovement,
+        max_iterations,
+        ..LocalSearchConfig::default()
+    };
+
+    local_search(graph, class_list, &config, &mut rand::thread_rng())
+}
+
+/// Runs [`local_search`] with joint moves of `move_size` vertices (see
+/// [`LocalSearchConfig::move_size`]) for at most `max_iterations` iterations (unlimited if 0),
+/// returning the number of edges that are still forbidden. With a single vertex, the search is the
+/// same as [`local_search`].
+pub fn multi_vertex_local_search(
+    graph: &AdjList,
+    class_list: &mut [Vec<usize>],
+    move_size: usize,
+    max_iterations: usize,
+) -> usize {
+    let config = LocalSearchConfig {
+        max_iterations,
+        ..LocalSearchConfig::default()
+    }
+    .move_size(move_size);
+
+    local_search(graph, class_list, &config, &mut rand::thread_rng())
+}
+
+/// Runs [`local_search`] with the [`LocalSearchStrategy::DoubleSwap`] strategy for at most
+/// `max_iterations` iterations (unlimited if 0), returning the number of edges that are still
+/// forbidden.
+pub fn double_swap_local_search(
+    graph: &AdjList,
+    class_list: &mut [Vec<usize>],
+    max_iterations: usize,
+) -> usize {
+    let config = LocalSearchConfig {
+        strategy: LocalSearchStrategy::DoubleSwap,
+        max_iterations,
+        ..LocalSearchConfig::default()
+    };
+
+    local_search(graph, class_list, &config, &mut rand::thread_rng())
+}
+
+/// A local search that recolors single vertices (1-opt moves) for up to
+/// [`HybridLocalSearch::phase1_iters`] iterations, and then, once no single recoloring
+/// improves, pairs of vertices at once (2-opt moves) for up to
+/// [`HybridLocalSearch::phase2_iters`] iterations.
+#[derive(Debug, Clone, Copy, PartialEq, Eq)]
+pub struct HybridLocalSearch {
+    pub phase1_iters: usize,
+    pub phase2_iters: usize,
+}
+
+impl HybridLocalSearch {
+    /// Searches for a coloring of `graph` with the classes of `class_list` and fewer forbidden
+    /// edges, leaving it in `class_list` and returning the number of those edges.
+    ///
+    /// Each 1-opt iteration makes the recoloring of a vertex of a forbidden edge that removes the
+    /// most of them. The search moves on to the 2-opt phase early once there is none. Each 2-opt
+    /// iteration picks two random vertices of forbidden edges and makes the pair of colors for
+    /// them that removes the most, if it removes any. The search stops once no edge is forbidden.
+    pub fn run(&self, graph: &AdjList, class_list: &mut [Vec<usize>], rng: &mut impl Rng) -> usize {
+        let mut assignment = ColorAssignment::from_class_list(graph, class_list);
+        let tabu = RingBuffer::new(0);
+
+        for _ in 0..self.phase1_iters {
+            if assignment.conflict_count() == 0 {
+                break;
+            }
+
+            let best_move = assignment
+                .forbidden_vertices(graph)
+                .into_iter()
+                .filter_map(|vertex| {
+                    best_recoloring(graph, &assignment, vertex, false, &tabu)
+                        .map(|(color, delta)| (vertex, color, delta))
+                })
+                .max_by(|a, b| a.2.total_cmp(&b.2));
+
+            // 1-opt stagnated
+            let Some((vertex, color, _)) = best_move else {
+                break;
+            };
+            assignment.assign(graph, vertex, color);
+        }
+
+        for _ in 0..self.phase2_iters {
+            let forbidden = assignment.forbidden_vertices(graph);
+
+            // Every forbidden edge has two vertices, so there are either none or at least two
+            if forbidden.is_empty() {
+                break;
+            }
+
+            let pair: Vec<usize> = forbidden.choose_multiple(rng, 2).copied().collect();
+            if let Some((colors, _)) =
+                best_joint_recoloring(graph, &assignment, &pair, false, &tabu)
+            {
+                for (vertex, color) in pair.iter().zip(colors) {
+                    assignment.assign(graph, *vertex, color);
+                }
+            }
+        }
+
+        class_list.clone_from_slice(&assignment.to_class_list());
+
+        assignment.conflict_count()
+    }
+}
+
+/// Runs [`HybridLocalSearch`] with `phase1_iters` 1-opt and `phase2_iters` 2-opt iterations,
+/// returning the number of edges that are still forbidden.
+pub fn hybrid_local_search(
+    graph: &AdjList,
+    class_list: &mut [Vec<usize>],
+    phase1_iters: usize,
+    phase2_iters: usize,
+) -> usize {
+    HybridLocalSearch {
+        phase1_iters,
+        phase2_iters,
+    }
+    .run(graph, class_list, &mut rand::thread_rng())
+}
+
+/// Parameters of [`hill_climbing_local_search`].
+#[derive(Debug, Clone, Copy, PartialEq)]
+pub struct HillClimbingConfig {
+    /// Number of random walks, each followed by another local search, after the first search.
+    pub max_restarts: usize,
+    /// Number of random moves attempted by each walk.
+    pub walk_length: usize,
+    /// Probability of accepting each random move of a walk.
+    pub walk_probability: f64,
+    /// Parameters of each local search.
+    pub local_search: LocalSearchConfig,
+}
+
+impl Default for HillClimbingConfig {
+    fn default() -> Self {
+        HillClimbingConfig {
+            max_restarts: 10,
+            walk_length: 3,
+            walk_probability: 0.5,
+            local_search: LocalSearchConfig::default(),
+        }
+    }
+}
+
+/// Runs [`local_search`] and, while forbidden edges remain, escapes the coloring it got stuck
+/// at with a random walk before searching again, up to [`HillClimbingConfig::max_restarts`]
+/// times.
+///
+/// Each walk attempts [`HillClimbingConfig::walk_length`] moves of a random vertex to a random
+/// color, accepting each with probability [`HillClimbingConfig::walk_probability`]. The search
+/// stops early if a walk accepts no move, so a probability of 0 runs a single local search.
+///
+/// `class_list` is left with the coloring of the fewest forbidden edges found, and the number of
+/// those edges is returned.
+pub fn hill_climbing_local_search(
+    graph: &AdjList,
+    class_list: &mut [Vec<usize>],
+    config: &HillClimbingConfig,
+) -> usize {
+    let mut rng = rand::thread_rng();
+    let mut best_count = local_search(graph, class_list, &config.local_search, &mut rng);
+    let mut best_class_list = class_list.to_vec();
+    let mut num_restarts = 0;
+
+    while best_count > 0 && num_restarts < config.max_restarts {
+        num_restarts += 1;
+
+        if !random_walk(
+            graph,
+            class_list,
+            config.walk_length,
+            config.walk_probability,
+            &mut rng,
+        ) {
+            break;
+        }
+
+        let num_forbidden = local_search(graph, class_list, &config.local_search, &mut rng);
+
+        if num_forbidden < best_count {
+            best_count = num_forbidden;
+            best_class_list = class_list.to_vec();
+        }
+    }
+
+    class_list.clone_from_slice(&best_class_list);
+
+    best_count
+}
+
+/// Attempts `length` moves of a random vertex of `class_list` to a random other color, each
+/// accepted with `probability`. Returns whether any move was accepted.
+fn random_walk(
+    graph: &AdjList,
+    class_list: &mut [Vec<usize>],
+    length: usize,
+    probability: f64,
+    rng: &mut impl Rng,
+) -> bool {
+    let mut assignment = ColorAssignment::from_class_list(graph, class_list);
+    let mut moved = false;
+
+    if graph.num_vertices() == 0 || assignment.num_colors() < 2 {
+        return false;
+    }
+
+    for _ in 0..length {
+        let vertex = rng.gen_range(0..graph.num_vertices());
+        let Some(current_color) = assignment.color_of(vertex) else {
+            continue;
+        };
+        // Skip the current color
+        let mut color = rng.gen_range(1..assignment.num_colors());
+        if color >= current_color {
+            color += 1;
+        }
+
+        if rng.gen::<f64>() < probability {
+            assignment.assign(graph, vertex, color);
+            moved = true;
+        }
+    }
+
+    if moved {
+        class_list.clone_from_slice(&assignment.to_class_list());
+    }
+
+    moved
+}
+
+/// Gives a random color to a `rate` fraction of the vertices of `assignment` (at least one).
+fn perturb_assignment(
+    graph: &AdjList,
+    assignment: &mut ColorAssignment,
+    rate: f64,
+    rng: &mut impl Rng,
+) {
+    let num_vertices = graph.num_vertices();
+    let num_perturbed = ((num_vertices as f64 * rate).ceil() as usize).clamp(1, num_vertices);
+
+    for vertex in (0..num_vertices).choose_multiple(rng, num_perturbed) {
+        let color = rng.gen_range(1..=assignment.num_colors());
+        assignment.assign(graph, vertex, color);
+    }
+}
+
+/// Finds the color of `vertex` with the fewest forbidden edges (or the smallest total weight of
+/// them, if `weighted_objective` is set), returning it along with the decrease in cost if it
+/// improves on the current color.
+///
+/// Recolorings in `tabu` are skipped, unless they leave no forbidden edges (aspiration).
+fn best_recoloring(
+    graph: &AdjList,
+    assignment: &ColorAssignment,
+    vertex: usize,
+    weighted_objective: bool,
+    tabu: &RingBuffer<(usize, usize)>,
+) -> Option<(usize, f64)> {
+    let cost = |color: usize| {
+        if weighted_objective {
+            assignment.weighted_conflicts_with_color(vertex, color, graph)
+        } else {
+            assignment.conflicts_with_color(vertex, color, graph) as f64
+        }
+    };
+    // The vertex is colored, so we can unwrap
+    let original_color = assignment.color_of(vertex).unwrap();
+    let original_cost = cost(original_color);
+
+    // Colors are 1-indexed. With the parallel feature, they're evaluated at once, which pays off
+    // for many colors
+    #[cfg(not(feature = "parallel"))]
+    let colors = 1..assignment.num_colors() + 1;
+    #[cfg(feature = "parallel")]
+    let colors = (1..assignment.num_colors() + 1).into_par_iter();
+
+    // Ties go to the smallest color, so the result doesn't depend on the evaluation order
+    let original_conflicts = assignment.conflicts_with_color(vertex, original_color, graph);
+    let (best_color, best_cost) = colors
+        .filter(|color| {
+            !tabu.contains(&(vertex, *color))
+                || assignment.conflict_count() - original_conflicts
+                    + assignment.conflicts_with_color(vertex, *color, graph)
+                    == 0
+        })
+        .map(|color| (color, cost(color)))
+        .min_by(|a, b| a.1.total_cmp(&b.1).then(a.0.cmp(&b.0)))?;
+
+    (best_cost < original_cost).then_some((best_color, original_cost - best_cost))
+}
+
+/// Picks a random recoloring of one of `candidates` to another color with the same number of
+/// forbidden edges (or the same total weight of them, if `weighted_objective` is set), a sideways
+/// move across a plateau of the search. Recolorings in `tabu` are skipped. Returns `None` if there
+/// is none.
+fn sideways_recoloring(
+    graph: &AdjList,
+    assignment: &ColorAssignment,
+    candidates: &[usize],
+    weighted_objective: bool,
+    tabu: &RingBuffer<(usize, usize)>,
+    rng: &mut impl Rng,
+) -> Option<(usize, usize)> {
+    let cost = |vertex: usize, color: usize| {
+        if weighted_objective {
+            assignment.weighted_conflicts_with_color(vertex, color, graph)
+        } else {
+            assignment.conflicts_with_color(vertex, color, graph) as f64
+        }
+    };
+    let mut moves = Vec::new();
+
+    for vertex in candidates.iter() {
+        // Candidates are colored, so we can unwrap
+        let original_color = assignment.color_of(*vertex).unwrap();
+        let original_cost = cost(*vertex, original_color);
+
+        // Colors are 1-indexed
+        for color in 1..assignment.num_colors() + 1 {
+            if color != original_color
+                && cost(*vertex, color) == original_cost
+                && !tabu.contains(&(*vertex, color))
+            {
+                moves.push((*vertex, color));
+            }
+        }
+    }
+
+    moves.choose(rng).copied()
+}
+
+/// Finds the colors of the distinct `vertices` that together give the fewest forbidden edges (or
+/// the smallest total weight of them, if `weighted_objective` is set), trying all of the `k^m`
+/// combinations for `m` vertices and `k` colors. Returns them, in the order of `vertices`, along
+/// with the decrease in cost if they improve on the current colors. Ties go to the first
+/// combination in lexicographic order.
+///
+/// Combinations giving a vertex a new color that is in `tabu` are skipped, unless they leave no
+/// forbidden edges (aspiration).
+fn best_joint_recoloring(
+    graph: &AdjList,
+    assignment: &ColorAssignment,
+    vertices: &[usize],
+    weighted_objective: bool,
+    tabu: &RingBuffer<(usize, usize)>,
+) -> Option<(Vec<usize>, f64)> {
+    let num_colors = assignment.num_colors();
+    let weight = |u: usize, v: usize| match graph.edge_weight(u, v) {
+        Some(weight) if weighted_objective => weight,
+        _ => 1.0,
+    };
+
+    // Cost and number of forbidden edges of each color of each vertex towards the vertices that
+    // keep their colors, and the edges among the recolored vertices
+    let mut external = vec![vec![(0.0, 0); num_colors]; vertices.len()];
+    let mut internal = Vec::new();
+    for (i, u) in vertices.iter().enumerate() {
+        for v in graph.adj_list()[*u].iter() {
+            match vertices.iter().position(|w| w == v) {
+                Some(j) if i < j => internal.push((i, j, weight(*u, *v))),
+                Some(_) => {}
+                None => {
+                    if let Some(color) = assignment.color_of(*v) {
+                        external[i][color - 1].0 += weight(*u, *v);
+                        external[i][color - 1].1 += 1;
+                    }
+                }
+            }
+        }
+    }
+    let cost = |colors: &[usize]| {
+        let (external_cost, external_count) = colors
+            .iter()
+            .enumerate()
+            .map(|(i, color)| external[i][color - 1])
+            .fold((0.0, 0), |(cost, count), (c, n)| (cost + c, count + n));
+        let (internal_cost, internal_count) = internal
+            .iter()
+            .filter(|(i, j, _)| colors[*i] == colors[*j])
+            .fold((0.0, 0), |(cost, count), (_, _, w)| (cost + w, count + 1));
+
+        (
+            external_cost + internal_cost,
+            external_count + internal_count,
+        )
+    };
+
+    // The vertices are colored, so we can unwrap
+    let original: Vec<usize> = vertices
+        .iter()
+        .map(|vertex| assignment.color_of(*vertex).unwrap())
+        .collect();
+    let (original_cost, original_count) = cost(&original);
+    // Forbidden edges that don't touch the vertices
+    let untouched_count = assignment.conflict_count() - original_count;
+    let allowed = |colors: &[usize], count: usize| {
+        untouched_count + count == 0
+            || vertices.iter().zip(colors.iter().zip(original.iter())).all(
+                |(vertex, (color, original_color))| {
+                    color == original_color || !tabu.contains(&(*vertex, *color))
+                },
+            )
+    };
+    let mut colors = vec![1; vertices.len()];
+    let mut best: Option<(Vec<usize>, f64)> = None;
+
+    // Colors are 1-indexed, and go through the combinations like the digits of a counter
+    loop {
+        let (combination_cost, combination_count) = cost(&colors);
+        if best
+            .as_ref()
+            .is_none_or(|(_, best_cost)| combination_cost < *best_cost)
+            && allowed(&colors, combination_count)
+        {
+            best = Some((colors.clone(), combination_cost));
+        }
+
+        let Some(i) = colors.iter().rposition(|color| *color < num_colors) else {
+            break;
+        };
+        colors[i] += 1;
+        colors[i + 1..].fill(1);
+    }
+
+    best.filter(|(_, best_cost)| *best_cost < original_cost)
+        .map(|(colors, best_cost)| (colors, original_cost - best_cost))
+}
+
+/// Finds the swap of colors between one of `vertices` and a vertex of another class that most
+/// decreases the number of forbidden edges (or their total weight, if `weighted_objective` is set),
+/// returning both vertices along with the decrease, if any swap improves.
+///
+/// Swaps giving either vertex a color that is in `tabu` are skipped, unless they leave no
+/// forbidden edges (aspiration).
+fn best_color_swap(
+    graph: &AdjList,
+    assignment: &ColorAssignment,
+    vertices: &[usize],
+    weighted_objective: bool,
+    tabu: &RingBuffer<(usize, usize)>,
+) -> Option<(usize, usize, f64)> {
+    let cost = |vertex: usize, color: usize| {
+        if weighted_objective {
+            assignment.weighted_conflicts_with_color(vertex, color, graph)
+        } else {
+            assignment.conflicts_with_color(vertex, color, graph) as f64
+        }
+    };
+    let mut best: Option<(usize, usize, f64)> = None;
+
+    for u in vertices.iter() {
+        // Only colored vertices are forbidden, so we can unwrap
+        let color_u = assignment.color_of(*u).unwrap();
+
+        for v in 0..graph.num_vertices() {
+            let Some(color_v) = assignment.color_of(v).filter(|color| *color != color_u) else {
+                continue;
+            };
+
+            // After the swap, u and v no longer hold the color they are moved to
+            let shared = match graph.edge_weight(*u, v) {
+                Some(weight) if weighted_objective => weight,
+                Some(_) => 1.0,
+                None => 0.0,
+            };
+            let gain = cost(*u, color_u) + cost(v, color_v)
+                - (cost(*u, color_v) - shared)
+                - (cost(v, color_u) - shared);
+            let is_tabu = || {
+                let conflicts = |vertex: usize, color: usize| {
+                    assignment.conflicts_with_color(vertex, color, graph)
+                };
+                let shared = usize::from(shared > 0.0);
+                let removed = conflicts(*u, color_u) + conflicts(v, color_v);
+                let added = conflicts(*u, color_v) + conflicts(v, color_u) - 2 * shared;
+
+                (tabu.contains(&(*u, color_v)) || tabu.contains(&(v, color_u)))
+                    && assignment.conflict_count() + added != removed
+            };
+
+            if gain > 0.0 && best.is_none_or(|(_, _, best_gain)| gain > best_gain) && !is_tabu() {
+                best = Some((*u, v, gain));
+            }
+        }
+    }
+
+    best
+}
+
+/// Groups the vertices of `coloring` into `num_classes` classes, the inverse of
+/// [`get_coloring_from_class_list`]. Colors are 1-indexed.
+pub(super) fn get_class_list(coloring: &[usize], num_classes: usize) -> Vec<Vec<usize>> {
+    let mut class_list = vec![Vec::new(); num_classes];
+
+    for (vertex, color) in coloring.iter().enumerate() {
+        class_list[*color - 1].push(vertex);
+    }
+
+    class_list
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+    use crate::{
+        algorithms::{
+            class_list_is_compact, conflict_subgraph_density, count_colors,
+            greedy::dsatur,
+            scoring::{RandomScorer, SaturationScorer},
+        },
+        graph::{
+            generators::{cycle_graph, path_graph},
+            random::{random_graph, random_k_colorable},
+            sparse::sparse_count_remaining_edges,
+        },
+        input,
+    };
+    use std::env;
+
+    #[test]
+    fn test_get_n_best() {
+        if let Ok(Some(graph)) = input::read_graph_from_file("data/myc/myciel3.col") {
+            let degree_in = |vertex, list: &[usize]| graph.get_degree_in_list(vertex, list) as i64;
+
+            // Score by the degree in the subgraph induced by a subset
+            let set_subset = vec![10, 3, 4, 5];
+            let largest_degrees = get_n_best(3, &set_subset, |v| degree_in(v, &set_subset));
+
+            assert_eq!(largest_degrees, vec![3, 5, 4]);
+
+            // Score by the degree in the entire graph
+            let set_entire_graph: Vec<usize> = (0..graph.num_vertices()).collect();
+            let largest_degrees =
+                get_n_best(5, &set_entire_graph, |v| degree_in(v, &set_entire_graph));
+
+            assert_eq!(largest_degrees, vec![10, 0, 1, 2, 3]);
+
+            // We don't care if the number of elements we're actually taking is smaller than the
+            // number we requested, due to a limitation in the subset length
+            let n_larger_than_subset = set_subset.len() + 1;
+            let largest_degrees = get_n_best(n_larger_than_subset, &set_subset, |v| {
+                degree_in(v, &set_subset)
+            });
+
+            assert_eq!(largest_degrees.len(), set_subset.len());
+
+            // We also don't care if we request too many elements overall
+            // i.e., more elements than the number of vertices in the graph
+            let too_many_elements = set_entire_graph.len() + 1;
+            let largest_degrees = get_n_best(too_many_elements, &set_entire_graph, |v| {
+                degree_in(v, &set_entire_graph)
+            });
+
+            assert_eq!(largest_degrees.len(), set_entire_graph.len());
+        } else {
+            panic!("The file containing the test graph is missing")
+        }
+
+        let mut graph = AdjList::complete(4);
+
+        // Given the vertices [0,1,3] (a K3), scored by their degree towards [1]
+        // The vertices with largest_degree ought to be [0,3] since they share an edge with [1]
+        let largest_degrees =
+            get_n_best(2, &[0, 1, 3], |v| graph.get_degree_in_list(v, &[1]) as i64);
+
+        assert_eq!(largest_degrees, vec![0, 3]);
+
+        // The vertex 2 is outside of [0,1,3], but the score doesn't depend on the candidates:
+        // only these vertices are considered, with their edges towards [2]
+        //
+        // Hence, when we remove an edge outside the induced subgraph,
+        // the return value should be updated accordingly
+        graph.remove_edge(0, 2);
+        let largest_degrees =
+            get_n_best(2, &[0, 1, 3], |v| graph.get_degree_in_list(v, &[2]) as i64);
+
+        assert_eq!(largest_degrees, vec![1, 3]);
+    }
+
+    #[test]
+    fn test_count_remaining_edges() {
+        if let Ok(Some(graph)) = input::read_graph_from_file("data/myc/myciel3.col") {
+            let list = vec![0, 1, 2];
+            let num_edges = ResidualGraph::new(&graph, &list).num_edges();
+
+            assert_eq!(num_edges, 2);
+
+            // The edges are those between the listed vertices, not their positions
+            assert_eq!(ResidualGraph::new(&graph, &[0, 6, 8, 10]).num_edges(), 4);
+
+            // Both representations agree
+            let sparse = graph.clone().into_sparse();
+            for list in [vec![0, 1, 2], vec![0, 6, 8, 10]] {
+                assert_eq!(
+                    sparse_count_remaining_edges(&list, &sparse),
+                    ResidualGraph::new(&graph, &list).num_edges()
+                );
+            }
+        } else {
+            panic!("The file containing the test graph is missing")
+        }
+    }
+
+    #[test]
+    fn test_grasp_wrapper() {
+        // Asserts GRASP provides a solution
+        if let Ok(Some(graph)) = input::read_graph_from_file("data/myc/myciel5.col") {
+            let (_, coloring) = grasp_wrapper(&graph, 10, 5, 5).unwrap();
+
+            assert!(graph.is_valid_coloring(&coloring));
+        } else {
+            panic!("The file containing the test graph is missing")
+        }
+    }
+
+    #[test]
+    fn test_grasp_with_config() {
+        if let Ok(Some(graph)) = input::read_graph_from_file("data/myc/myciel4.col") {
+            let config = GraspConfig::new(10, 5, 5).elite_size(3);
+            let (num_colors, coloring) = grasp_with_config(&graph, &config).unwrap();
+
+            assert!(graph.is_valid_coloring(&coloring));
+            assert!(num_colors >= 5);
+            // Every one of the colors is used
+            assert!(class_list_is_compact(&get_class_list(
+                &coloring, num_colors
+            )));
+        } else {
+            panic!("The file containing the test graph is missing")
+        }
+    }
+
+    #[test]
+    fn test_seed() {
+        if let Ok(Some(graph)) = input::read_graph_from_file("data/myc/myciel4.col") {
+            let config = GraspConfig::new(20, 5, 3).elite_size(3).seed(42);
+            let first = grasp_archive(&graph, &config).unwrap();
+            let second = grasp_archive(&graph, &config).unwrap();
+
+            assert_eq!(first, second);
+            assert!(first
+                .iter()
+                .all(|(_, coloring)| graph.is_valid_coloring(coloring)));
+        } else {
+            panic!("The file containing the test graph is missing")
+        }
+    }
+
+    #[test]
+    fn test_on_iteration() {
+        // The greedy clique of myciel3 has 2 vertices, so the execution can't stop early
+        if let Ok(Some(graph)) = input::read_graph_from_file("data/myc/myciel3.col") {
+            let calls = Arc::new(Mutex::new(Vec::new()));
+            let recorded = Arc::clone(&calls);
+            let config = GraspConfig::new(15, 5, 3).on_iteration(Box::new(
+                move |iteration, best_colors, class_list: &[Vec<usize>]| {
+                    assert_eq!(class_list.len(), best_colors);
+                    recorded.lock().unwrap().push((iteration, best_colors));
+                },
+            ));
+            let (num_colors, _) = grasp_with_config(&graph, &config).unwrap();
+            let calls = calls.lock().unwrap();
+
+            assert_eq!(calls.len(), 15);
+            assert!(calls.iter().enumerate().all(|(i, call)| call.0 == i));
+            assert!(calls.windows(2).all(|pair| pair[0].1 >= pair[1].1));
+            assert_eq!(calls.last().unwrap().1, num_colors);
+        } else {
+            panic!("The file containing the test graph is missing")
+        }
+    }
+
+    #[test]
+    fn test_lower_bound_target() {
+        // C_12 reaches its clique lower bound of 2: the execution stops after the first batch
+        let (solutions, stats) = run_grasp(&cycle_graph(12), &GraspConfig::new(100, 5, 3)).unwrap();
+
+        assert_eq!(solutions.peek().unwrap().0, 2);
+        assert_eq!(stats.best_colors.len(), DIVERSITY_CHECK_INTERVAL as usize);
+
+        // C_11 needs 3 colors, above its lower bounds of 2, so every iteration runs even though 4
+        // colors are enough for planar graphs
+        let (solutions, stats) = run_grasp(&cycle_graph(11), &GraspConfig::new(100, 5, 3)).unwrap();
+
+        assert_eq!(solutions.peek().unwrap().0, 3);
+        assert_eq!(stats.best_colors.len(), 100);
+    }
+
+    #[test]
+    fn test_invalid_config() {
+        assert!(matches!(
+            GraspConfig::new(10, 5, 0).build(),
+            Err(ColoringError::InvalidConfig(_))
+        ));
+        assert!(matches!(
+            GraspConfig::new(10, 5, 3).elite_size(0).build(),
+            Err(ColoringError::InvalidConfig(_))
+        ));
+        assert!(matches!(
+            GraspConfig::new(10, 5, 3).merge_group_size(4).build(),
+            Err(ColoringError::InvalidConfig(_))
+        ));
+        for (grasp_iterations, color_iterations) in [(0, 5), (-1, 5), (10, 0), (10, -3)] {
+            assert!(matches!(
+                GraspConfig::new(grasp_iterations, color_iterations, 3).build(),
+                Err(ColoringError::InvalidConfig(_))
+            ));
+        }
+        assert!(GraspConfig::new(10, 5, 3).build().is_ok());
+
+        // Invalid configurations are rejected before any color assignment
+        let graph = AdjList::complete(4);
+
+        assert!(matches!(
+            grasp_with_config(&graph, &GraspConfig::new(10, 5, 0)),
+            Err(ColoringError::InvalidConfig(_))
+        ));
+        assert!(GraspSolver::new(GraspConfig::new(10, 5, 0)).is_err());
+        assert!(matches!(
+            grasp_with_config(&graph, &GraspConfig::new(0, 5, 3)),
+            Err(ColoringError::InvalidConfig(_))
+        ));
+    }
+
+    #[test]
+    fn test_grasp_with_stats() {
+        if let Ok(Some(graph)) = input::read_graph_from_file("data/myc/myciel3.col") {
+            let (solution, stats) = grasp_with_stats(&graph, &GraspConfig::new(15, 5, 3)).unwrap();
+
+            assert!(!stats.best_colors.is_empty());
+            assert!(stats.best_colors.len() <= 15);
+            assert!(stats.best_colors.windows(2).all(|w| w[0] >= w[1]));
+            assert_eq!(stats.best_colors.last(), Some(&solution.0));
+        } else {
+            panic!("The file containing the test graph is missing")
+        }
+    }
+
+    #[test]
+    fn test_stats_csv_round_trip() {
+        let stats = GraspStats {
+            best_colors: vec![7, 6, 6, 5],
+            num_perturbations: 0,
+            num_merges: 0,
+            num_improve_phases: 0,
+        };
+        let path = env::temp_dir().join("gcp_heuristics_stats.csv");
+        let path = path.to_str().unwrap();
+
+        stats.to_csv(path).unwrap();
+
+        assert_eq!(
+            fs::read_to_string(path).unwrap(),
+            "iteration,best_colors,improvement_occurred\n\
+             0,7,true\n1,6,true\n2,6,false\n3,5,true\n"
+        );
+        assert_eq!(GraspStats::from_csv(path).unwrap(), stats);
+
+        fs::write(path, "iteration,best_colors\n0,7\n").unwrap();
+
+        assert!(GraspStats::from_csv(path).is_err());
+    }
+
+    #[test]
+    fn test_stats_csv_rows() {
+        if let Ok(Some(graph)) = input::read_graph_from_file("data/myc/myciel4.col") {
+            let (_, stats) = grasp_with_stats(&graph, &GraspConfig::new(15, 5, 3)).unwrap();
+            let path = env::temp_dir().join("gcp_heuristics_stats_rows.csv");
+            let path = path.to_str().unwrap();
+
+            stats.to_csv(path).unwrap();
+            let read = GraspStats::from_csv(path).unwrap();
+
+            // One row per iteration, besides the header
+            assert_eq!(fs::read_to_string(path).unwrap().lines().count(), 16);
+            assert_eq!(read.best_colors.len(), 15);
+            assert!(read.best_colors.windows(2).all(|w| w[0] >= w[1]));
+        } else {
+            panic!("The file containing the test graph is missing")
+        }
+    }
+
+    #[test]
+    fn test_stats_merge() {
+        let lhs = GraspStats {
+            best_colors: vec![7, 6],
+            num_perturbations: 1,
+            num_merges: 4,
+            num_improve_phases: 2,
+        };
+        let rhs = GraspStats {
+            best_colors: vec![8],
+            num_perturbations: 2,
+            num_merges: 1,
+            num_improve_phases: 1,
+        };
+
+        assert_eq!(
+            lhs.merge(&rhs),
+            GraspStats {
+                best_colors: vec![7, 6, 8],
+                num_perturbations: 3,
+                num_merges: 5,
+                num_improve_phases: 3,
+            }
+        );
+    }
+
+    #[test]
+    fn test_time_budget() {
+        if let Ok(Some(graph)) = input::read_graph_from_file("data/myc/myciel6.col") {
+            let config = GraspConfig::new(10000, 25, 3).time_budget(Duration::from_millis(10));
+            let ((_, coloring), stats) = grasp_with_stats(&graph, &config).unwrap();
+
+            assert!(graph.is_valid_coloring(&coloring));
+            assert!(stats.best_colors.len() < 10000);
+        } else {
+            panic!("The file containing the test graph is missing")
+        }
+    }
+
+    #[test]
+    fn test_archive_insert() {
+        let mut archive = BinaryHeap::new();
+
+        archive_insert(&mut archive, (3, vec![1, 2, 3]), 2);
+        archive_insert(&mut archive, (3, vec![1, 2, 3]), 2);
+
+        // Repeated colorings are discarded
+        assert_eq!(archive.len(), 1);
+
+        archive_insert(&mut archive, (2, vec![1, 2, 1]), 2);
+        archive_insert(&mut archive, (2, vec![2, 1, 2]), 2);
+
+        // The worst solution leaves the full archive
+        assert_eq!(
+            archive.into_sorted_vec(),
+            vec![(2, vec![1, 2, 1]), (2, vec![2, 1, 2])]
+        );
+    }
+
+    #[test]
+    fn test_grasp_archive() {
+        if let Ok(Some(graph)) = input::read_graph_from_file("data/myc/myciel4.col") {
+            let archive = grasp_archive(&graph, &GraspConfig::new(20, 5, 3).elite_size(4)).unwrap();
+
+            assert!(!archive.is_empty() && archive.len() <= 4);
+            assert!(archive.windows(2).all(|w| w[0].0 <= w[1].0));
+
+            for (num_colors, coloring) in archive.iter() {
+                assert!(graph.is_valid_coloring(coloring));
+                assert_eq!(*num_colors, count_colors(coloring));
+            }
+        } else {
+            panic!("The file containing the test graph is missing")
+        }
+    }
+
+    #[test]
+    fn test_solution_diversity() {
+        let lhs = [1, 2, 3, 4];
+        let rhs = [1, 2, 4, 3];
+        let far = [2, 1, 4, 3];
+
+        assert_eq!(solution_diversity(&[&lhs], 4), 0.0);
+        assert_eq!(solution_diversity(&[&lhs, &lhs], 4), 0.0);
+        assert_eq!(solution_diversity(&[&lhs, &rhs], 4), 0.5);
+        // Pairwise distances are 0.5, 1.0 and 0.5
+        assert!((solution_diversity(&[&lhs, &rhs, &far], 4) - 2.0 / 3.0).abs() < 1e-9);
+    }
+
+    #[test]
+    fn test_diversity_perturbation() {
+        if let Ok(Some(graph)) = input::read_graph_from_file("data/myc/myciel4.col") {
+            // Any pool of solutions has a diversity smaller than 1
+            let config = GraspConfig::new(30, 5, 3)
+                .elite_size(5)
+                .diversity_threshold(1.0);
+            let (solutions, stats) = run_grasp(&graph, &config).unwrap();
+
+            assert_eq!(stats.num_perturbations, 2);
+
+            for (_, coloring) in solutions {
+                assert!(graph.is_valid_coloring(&coloring));
+            }
+
+            // While the diversity can't be smaller than 0
+            let config = config.diversity_threshold(0.0);
+            let (_, stats) = run_grasp(&graph, &config).unwrap();
+
+            assert_eq!(stats.num_perturbations, 0);
+        } else {
+            panic!("The file containing the test graph is missing")
+        }
+    }
+
+    #[test]
+    fn test_upper_bound_pruning() {
+        if let Ok(Some(graph)) = input::read_graph_from_file("data/myc/myciel3.col") {
+            let config = GraspConfig::new(30, 5, 3).seed(42);
+            let (_, pruned) = grasp_with_stats(&graph, &config).unwrap();
+            let ((num_colors, coloring), unpruned) =
+                grasp_with_stats(&graph, &config.upper_bound_pruning(false)).unwrap();
+
+            assert_eq!(num_colors, 4);
+            assert!(graph.is_valid_coloring(&coloring));
+            assert_eq!(unpruned.num_improve_phases, 30);
+            // The first batch has nothing to compare to
+            assert!(pruned.num_improve_phases >= DIVERSITY_CHECK_INTERVAL as usize);
+            assert!(pruned.num_improve_phases < unpruned.num_improve_phases);
+            assert_eq!(pruned.best_colors.last(), Some(&4));
+        } else {
+            panic!("The file containing the test graph is missing")
+        }
+    }
+
+    #[test]
+    fn test_initial_solution() {
+        let graph = random_k_colorable(60, 5, 500, 3);
+        let (dsatur_colors, coloring) = dsatur(&graph);
+        let config = GraspConfig::new(3, 3, 3)
+            .seed(1)
+            .initial_solution(get_class_list_from_coloring(&coloring))
+            .build()
+            .unwrap();
+        let (num_colors, coloring) = grasp_with_config(&graph, &config).unwrap();
+
+        assert!(num_colors <= dsatur_colors);
+        assert!(graph.is_valid_coloring(&coloring));
+
+        // Vertex 0 is missing, which can only be told with the graph
+        let config = GraspConfig::new(3, 3, 3).initial_solution(vec![(1..60).collect()]);
+
+        assert!(config.clone().build().is_ok());
+        assert!(matches!(
+            grasp_with_config(&graph, &config),
+            Err(ColoringError::InvalidConfig(_))
+        ));
+        assert!(GraspConfig::new(3, 3, 3)
+            .initial_solution(vec![vec![0, 1], vec![1]])
+            .build()
+            .is_err());
+    }
+
+    #[test]
+    fn test_color_upper_bound() {
+        // The chromatic number of myciel3 is 4
+        if let Ok(Some(graph)) = input::read_graph_from_file("data/myc/myciel3.col") {
+            let config = GraspConfig::new(10, 5, 3).seed(0);
+
+            match grasp_with_config(&graph, &config.clone().color_upper_bound(3)) {
+                Err(ColoringError::NoBoundedSolutionFound { bound, best }) => {
+                    assert_eq!(bound, 3);
+                    assert!(best.0 >= 4);
+                    assert!(graph.is_valid_coloring(&best.1));
+                }
+                result => panic!("Expected a missed bound, got {result:?}"),
+            }
+
+            let (num_colors, coloring) =
+                grasp_with_config(&graph, &config.clone().color_upper_bound(4)).unwrap();
+
+            assert_eq!(num_colors, 4);
+            assert!(graph.is_valid_coloring(&coloring));
+
+            // The solver falls back to the best solution
+            let solver = GraspSolver::new(config.color_upper_bound(3)).unwrap();
+
+            assert_eq!(solver.solve(&graph).0, 4);
+        } else {
+            panic!("The file containing the test graph is missing")
+        }
+    }
+
+    #[test]
+    fn test_vertex_scorer() {
+        if let Ok(Some(graph)) = input::read_graph_from_file("data/myc/myciel6.col") {
+            // Average number of colors of the constructions alone, skipping the improve phase
+            let average_colors = |with_scorer: &dyn Fn(GraspConfig) -> GraspConfig| {
+                let config = with_scorer(GraspConfig::new(1, 1, 3));
+
+                (0..100)
+                    .map(|seed| {
+                        let mut rng = SmallRng::seed_from_u64(seed);
+                        let ((num_colors, coloring), _) =
+                            grasp_iteration(&graph, &config, Some(0), &mut rng).unwrap();
+
+                        assert!(graph.is_valid_coloring(&coloring));
+                        num_colors
+                    })
+                    .sum::<usize>() as f64
+                    / 100.0
+            };
+
+            // On myciel4, both almost always reach the chromatic number of 5, so a larger graph
+            // of the family is used to tell them apart
+            assert!(
+                average_colors(&|config| config.vertex_scorer(SaturationScorer))
+                    < average_colors(&|config| config.vertex_scorer(RandomScorer))
+            );
+
+            let config = GraspConfig::new(5, 5, 3).vertex_scorer(SaturationScorer);
+            let (num_colors, coloring) = grasp_with_config(&graph, &config).unwrap();
+
+            assert!(num_colors >= 7);
+            assert!(graph.is_valid_coloring(&coloring));
+        } else {
+            panic!("The file containing the test graph is missing")
+        }
+    }
+
+    #[test]
+    fn test_improve_phase() {
+        let mut graph = AdjList::new(6);
+        graph.add_edge(0, 1);
+        graph.add_edge(1, 2);
+        graph.add_edge(2, 3);
+        graph.add_edge(2, 4);
+        graph.add_edge(2, 5);
+
+        let mut num_classes = 4;
+        let mut class_list = vec![vec![1], vec![2], vec![4, 5], vec![0, 3]];
+
+        improve_phase(
+            &graph,
+            &mut num_classes,
+            &mut class_list,
+            &GraspConfig::default(),
+            &mut SmallRng::seed_from_u64(0),
+        );
+
+        assert!(num_classes <= 4);
+        assert_eq!(class_list.len(), num_classes);
+        assert!(class_list_is_compact(&class_list));
+
+        // Since the algorithm is randomized, we can't compare to an expected result
+        // But it should still be valid nonetheless
+        let coloring = get_coloring_from_class_list(6, &class_list);
+
+        assert!(graph.is_valid_coloring(&coloring));
+    }
+
+    #[test]
+    fn test_improve_min_reduction() {
+        if let Ok(Some(graph)) = input::read_graph_from_file("data/myc/myciel4.col") {
+            // One class per vertex, which any merge of the improve phase reduces
+            let singletons: Vec<Vec<usize>> = (0..graph.num_vertices()).map(|v| vec![v]).collect();
+            let mut rng = SmallRng::seed_from_u64(0);
+
+            let mut num_classes = singletons.len();
+            let mut class_list = singletons.clone();
+            let num_merges = improve_phase(
+                &graph,
+                &mut num_classes,
+                &mut class_list,
+                &GraspConfig::default(),
+                &mut rng,
+            );
+
+            assert!(num_merges > 1);
+            assert!(graph.is_valid_class_list(&class_list));
+
+            // Merging pairs removes a single class, so the phase stops after the first merge
+            let mut num_classes = singletons.len();
+            let mut class_list = singletons.clone();
+            let num_merges = improve_phase(
+                &graph,
+                &mut num_classes,
+                &mut class_list,
+                &GraspConfig::default().improve_min_reduction(2),
+                &mut rng,
+            );
+
+            assert_eq!(num_merges, 1);
+            assert_eq!(num_classes, singletons.len() - 1);
+            assert!(graph.is_valid_class_list(&class_list));
+        } else {
+            panic!("The file containing the test graph is missing")
+        }
+    }
+
+    #[test]
+    fn test_interleaved_construction() {
+        // Difference between the sizes of the largest and the smallest class
+        let spread = |sizes: Vec<usize>| sizes.iter().max().unwrap() - sizes.iter().min().unwrap();
+        let (mut sequential_spread, mut interleaved_spread) = (0, 0);
+
+        for seed in 0..10 {
+            // Random 5-colorable graphs with classes of 12 vertices
+            let graph = random_k_colorable(60, 5, 400, seed);
+            let mut rng = SmallRng::seed_from_u64(seed);
+
+            // The sequential construction, one class after the other
+            let mut remaining: Vec<usize> = (0..graph.num_vertices()).collect();
+            let mut sizes = Vec::new();
+            while !remaining.is_empty() {
+                let class = maximal_independent_set(&graph, &remaining, 5, &mut rng).unwrap();
+                remaining.retain(|vertex| !class.contains(vertex));
+                sizes.push(class.len());
+            }
+
+            sequential_spread += spread(sizes);
+
+            let (class_list, num_forbidden) =
+                interleaved_construction(&graph, 5, &LocalSearchConfig::default(), &mut rng)
+                    .unwrap();
+
+            assert_eq!(class_list.len(), 5);
+            assert_eq!(class_list.iter().map(Vec::len).sum::<usize>(), 60);
+            if num_forbidden == 0 {
+                assert!(graph.is_valid_class_list(&class_list));
+            }
+
+            interleaved_spread += spread(class_list.iter().map(Vec::len).collect());
+        }
+
+        assert!(interleaved_spread < sequential_spread);
+
+        // The partial classes are independent
+        let graph = random_k_colorable(30, 3, 100, 0);
+        let classes = assign_colors_interleaved(
+            &(0..30).collect::<Vec<_>>(),
+            3,
+            &graph,
+            &mut SmallRng::seed_from_u64(0),
+        );
+
+        assert_eq!(classes.len(), 3);
+        assert!(classes.iter().all(|class| class
+            .iter()
+            .all(|u| graph.get_degree_in_list(*u, class) == 0)));
+        assert!(matches!(
+            interleaved_construction(
+                &graph,
+                0,
+                &LocalSearchConfig::default(),
+                &mut SmallRng::seed_from_u64(0)
+            ),
+            Err(ColoringError::EmptyCandidateList)
+        ));
+    }
+
+    #[test]
+    fn test_class_merge_feasibility() {
+        let graph = cycle_graph(6);
+
+        assert_eq!(class_merge_feasibility(&graph, &[0, 3], &[1, 4]), 0.5);
+        assert_eq!(class_merge_feasibility(&graph, &[0], &[3]), 1.0);
+        assert_eq!(class_merge_feasibility(&graph, &[0], &[1, 5]), 0.0);
+        assert_eq!(class_merge_feasibility(&graph, &[], &[1]), 1.0);
+    }
+
+    #[test]
+    fn test_exact_threshold() {
+        if let Ok(Some(graph)) = input::read_graph_from_file("data/myc/myciel3.col") {
+            // Skipping the improve phase leaves the construction alone
+            let construct = |threshold: usize, seed: u64| {
+                let config = GraspConfig::new(1, 1, 3).exact_threshold(threshold);
+                let mut rng = SmallRng::seed_from_u64(seed);
+
+                grasp_iteration(&graph, &config, Some(1), &mut rng)
+                    .unwrap()
+                    .0
+            };
+
+            // Coloring every vertex exactly is optimal
+            let (num_colors, coloring) = construct(12, 0);
+
+            assert_eq!(num_colors, 4);
+            assert!(graph.is_valid_coloring(&coloring));
+
+            // Only the last (at most 5) vertices are colored exactly
+            for seed in 0..10 {
+                let (num_colors, coloring) = construct(6, seed);
+
+                assert_eq!(count_colors(&coloring), num_colors);
+                assert!(graph.is_valid_coloring(&coloring));
+            }
+
+            let config = GraspConfig::new(5, 2, 3).exact_threshold(6).seed(0);
+            let (num_colors, coloring) = grasp_with_config(&graph, &config).unwrap();
+
+            assert_eq!(num_colors, 4);
+            assert!(graph.is_valid_coloring(&coloring));
+            assert!(matches!(
+                grasp_with_config(&graph, &GraspConfig::new(1, 1, 3).exact_threshold(31)),
+                Err(ColoringError::InvalidConfig(_))
+            ));
+        } else {
+            panic!("The file containing the test graph is missing")
+        }
+    }
+
+    #[test]
+    fn test_adaptive_csize() {
+        // Improvements in the first 5 iterations, and stagnation for the next 15
+        let mut size = AdaptiveCSize::new(2, 6);
+        let mut sizes = Vec::new();
+        for iteration in 0..20 {
+            size.update(iteration < 5);
+            sizes.push(size.current);
+        }
+
+        assert_eq!(sizes[..5], [5, 4, 3, 2, 2]);
+        assert_eq!(sizes[5..9], [3, 4, 5, 6]);
+        assert!(sizes[9..].iter().all(|size| *size == 6));
+
+        if let Ok(Some(graph)) = input::read_graph_from_file("data/myc/myciel4.col") {
+            let config = GraspConfig::new(20, 3, 1).adaptive_csize(1, 4).seed(0);
+            let (num_colors, coloring) = grasp_with_config(&graph, &config).unwrap();
+
+            assert_eq!(count_colors(&coloring), num_colors);
+            assert!(graph.is_valid_coloring(&coloring));
+
+            for (min, max) in [(0, 3), (4, 3)] {
+                assert!(matches!(
+                    grasp_with_config(&graph, &GraspConfig::new(1, 1, 3).adaptive_csize(min, max)),
+                    Err(ColoringError::InvalidConfig(_))
+                ));
+            }
+        } else {
+            panic!("The file containing the test graph is missing")
+        }
+    }
+
+    #[test]
+    fn test_merge_oracle_threshold() {
+        // C_6 colored with 3 colors: merging two of its classes, whose feasibility is 1/2, needs
+        // the local search to reach the 2-coloring
+        let graph = cycle_graph(6);
+        let class_list = vec![vec![0, 3], vec![1, 4], vec![2, 5]];
+
+        for (threshold, expected_classes) in [(0.0, 2), (0.4, 2), (0.6, 3), (1.0, 3)] {
+            for seed in 0..10 {
+                let mut num_classes = 3;
+                let mut new_classes = class_list.clone();
+                let config = GraspConfig::default().merge_oracle_threshold(threshold);
+
+                improve_phase(
+                    &graph,
+                    &mut num_classes,
+                    &mut new_classes,
+                    &config,
+                    &mut SmallRng::seed_from_u64(seed),
+                );
+
+                assert_eq!(num_classes, expected_classes);
+                assert!(graph.is_valid_class_list(&new_classes));
+            }
+        }
+
+        // A threshold of 0 is the default
+        if let Ok(Some(graph)) = input::read_graph_from_file("data/myc/myciel4.col") {
+            let singletons: Vec<Vec<usize>> = (0..graph.num_vertices()).map(|v| vec![v]).collect();
+            let mut results = Vec::new();
+
+            for config in [
+                GraspConfig::default(),
+                GraspConfig::default().merge_oracle_threshold(0.0),
+            ] {
+                let mut num_classes = singletons.len();
+                let mut class_list = singletons.clone();
+                improve_phase(
+                    &graph,
+                    &mut num_classes,
+                    &mut class_list,
+                    &config,
+                    &mut SmallRng::seed_from_u64(0),
+                );
+                results.push(class_list);
+            }
+
+            assert_eq!(results[0], results[1]);
+        } else {
+            panic!("The file containing the test graph is missing")
+        }
+
+        assert!(matches!(
+            GraspConfig::default().merge_oracle_threshold(1.5).build(),
+            Err(ColoringError::InvalidConfig(_))
+        ));
+    }
+
+    #[test]
+    fn test_merge_group_size() {
+        // A 5-colorable graph on which merging pairs of classes often gets stuck early
+        let graph = random_k_colorable(60, 5, 500, 5);
+        let total_colors = |config: &GraspConfig| {
+            (0..10)
+                .map(|seed| {
+                    let mut rng = SmallRng::seed_from_u64(seed);
+                    let ((num_colors, coloring), _) =
+                        grasp_iteration(&graph, config, None, &mut rng).unwrap();
+
+                    assert!(graph.is_valid_coloring(&coloring));
+                    num_colors
+                })
+                .sum::<usize>()
+        };
+        let config = GraspConfig::new(1, 1, 3);
+
+        assert!(total_colors(&config.clone().merge_group_size(3)) < total_colors(&config));
+    }
+
+    #[test]
+    fn test_improve_phase_merges() {
+        // The triangle is already colored optimally, so no merge succeeds
+        let graph = AdjList::complete(3);
+        let mut num_classes = 3;
+        let mut class_list = vec![vec![0], vec![1], vec![2]];
+        let mut rng = SmallRng::seed_from_u64(0);
+
+        assert_eq!(
+            improve_phase(
+                &graph,
+                &mut num_classes,
+                &mut class_list,
+                &GraspConfig::default(),
+                &mut rng
+            ),
+            0
+        );
+        assert_eq!(num_classes, 3);
+
+        // The 4-cycle colored with one extra color
+        let graph = cycle_graph(4);
+        let mut num_classes = 3;
+        let mut class_list = vec![vec![0], vec![2], vec![1, 3]];
+
+        assert_eq!(
+            improve_phase(
+                &graph,
+                &mut num_classes,
+                &mut class_list,
+                &GraspConfig::default(),
+                &mut rng
+            ),
+            1
+        );
+        assert_eq!(num_classes, 2);
+        assert!(graph.is_valid_class_list(&class_list));
+    }
+
+    #[test]
+    fn test_improve_phase_myciel3() {
+        if let Ok(Some(graph)) = input::read_graph_from_file("data/myc/myciel3.col") {
+            let (num_colors, coloring) = dsatur(&graph);
+            let optimal = get_class_list(&coloring, num_colors);
+
+            // The chromatic number of myciel3 is 4
+            assert_eq!(num_colors, 4);
+
+            for seed in 0..10 {
+                let mut rng = SmallRng::seed_from_u64(seed);
+
+                // An optimal coloring can't be improved, so it is kept
+                let mut num_classes = 4;
+                let mut class_list = optimal.clone();
+
+                assert_eq!(
+                    improve_phase(
+                        &graph,
+                        &mut num_classes,
+                        &mut class_list,
+                        &GraspConfig::default(),
+                        &mut rng
+                    ),
+                    0
+                );
+                assert_eq!(num_classes, 4);
+                assert_eq!(class_list, optimal);
+
+                // Moving a vertex of the largest class to a class of its own adds an extra color,
+                // which the improve phase removes
+                let mut class_list = optimal.clone();
+                let largest = (0..4).max_by_key(|i| class_list[*i].len()).unwrap();
+                let vertex = class_list[largest].pop().unwrap();
+                class_list.push(vec![vertex]);
+                let mut num_classes = 5;
+
+                assert!(graph.is_valid_class_list(&class_list));
+
+                improve_phase(
+                    &graph,
+                    &mut num_classes,
+                    &mut class_list,
+                    &GraspConfig::default(),
+                    &mut rng,
+                );
+
+                assert_eq!(num_classes, 4);
+                assert!(graph.is_valid_class_list(&class_list));
+            }
+        } else {
+            panic!("The file containing the test graph is missing")
+        }
+    }
+
+    #[test]
+    fn test_get_forbidden_vertices() {
+        // The complete graph
+        let graph = AdjList::complete(5);
+        let color_classes = vec![vec![0], vec![1], vec![2, 3, 4]];
+
+        let (count, forbidden) = get_forbidden_vertices(&graph, &color_classes);
+
+        assert_eq!(forbidden, HashSet::from([2, 3, 4]));
+        assert_eq!(count, 3)
+    }
+
+    #[test]
     fn test_local_search() {
         // Basically a linked list colored as 1---2---2---3
         let mut graph = AdjList::new(4);
@@ -461,26 +3066,628 @@ mod tests {
         graph.add_edge(2, 3);
         let mut color_classes = vec![vec![0], vec![1, 2], vec![3]];
 
-        let num_forbidden = local_search(&graph, &mut color_classes);
+        let num_forbidden = local_search(
+            &graph,
+            &mut color_classes,
+            &LocalSearchConfig::default(),
+            &mut SmallRng::seed_from_u64(0),
+        );
 
         assert_eq!(num_forbidden, 0);
     }
 
     #[test]
-    fn test_get_coloring_from_class_list() {
-        let class_list = vec![vec![0], vec![1, 2], vec![3]];
-        let coloring = get_coloring_from_class_list(4, &class_list);
+    fn test_multi_vertex_local_search() {
+        // The path 0---1---2---3---4---5 colored as 1, 2, 2, 1, 1, 2: every single recoloring
+        // keeps two forbidden edges, but recoloring 2 and 3 at once removes both
+        let graph = path_graph(6);
+        let class_list = vec![vec![0, 3, 4], vec![1, 2, 5]];
+        let assignment = ColorAssignment::from_class_list(&graph, &class_list);
+
+        for vertex in 0..6 {
+            assert_eq!(
+                best_joint_recoloring(&graph, &assignment, &[vertex], false, &RingBuffer::new(0)),
+                None
+            );
+        }
+        assert_eq!(
+            best_joint_recoloring(&graph, &assignment, &[2, 3], false, &RingBuffer::new(0)),
+            Some((vec![1, 2], 2.0))
+        );
+        assert_eq!(
+            best_joint_recoloring(&graph, &assignment, &[1, 2], false, &RingBuffer::new(0)),
+            None
+        );
+
+        // The joint move is tabu unless it removes every forbidden edge, as it does here
+        let mut tabu = RingBuffer::new(1);
+        tabu.push((2, 1));
+
+        assert_eq!(
+            best_joint_recoloring(&graph, &assignment, &[2, 3], false, &tabu),
+            Some((vec![1, 2], 2.0))
+        );
+
+        let mut with_conflict = graph.clone();
+        with_conflict.add_vertex();
+        with_conflict.add_vertex();
+        with_conflict.add_edge(6, 7);
+        let assignment =
+            ColorAssignment::from_class_list(&with_conflict, &[vec![0, 3, 4, 6, 7], vec![1, 2, 5]]);
+
+        assert_eq!(
+            best_joint_recoloring(&with_conflict, &assignment, &[2, 3], false, &tabu),
+            None
+        );
+
+        // Without perturbations, nor ejection chains (which the weighted objective skips), single
+        // recolorings keep cycling on both forbidden edges while a joint move removes them. It
+        // needs the right vertex and neighbor, so the search gets enough attempts
+        for (move_size, expected) in [(1, 2), (2, 0)] {
+            let config = LocalSearchConfig {
+                no_improvement_ceil: 100,
+                stagnation_window: usize::MAX,
+                ..LocalSearchConfig::default()
+            }
+            .move_size(move_size);
+
+            for seed in 0..10 {
+                let mut color_classes = class_list.clone();
+                let mut rng = SmallRng::seed_from_u64(seed);
+
+                assert_eq!(
+                    local_search_with_objective(
+                        &graph,
+                        &mut color_classes,
+                        true,
+                        &config,
+                        &mut rng
+                    ),
+                    expected
+                );
+                assert_eq!(graph.is_valid_class_list(&color_classes), expected == 0);
+            }
+        }
+
+        // The chain of test_local_search
+        let mut graph = AdjList::new(4);
+        graph.add_edge(0, 1);
+        graph.add_edge(1, 2);
+        graph.add_edge(2, 3);
+        let mut color_classes = vec![vec![0], vec![1, 2], vec![3]];
+
+        assert_eq!(
+            multi_vertex_local_search(&graph, &mut color_classes, 1, 0),
+            0
+        );
+    }
+
+    #[test]
+    fn test_local_search_single_conflict() {
+        // C_7 colored as 1, 2, 1, 2, 3, 2, 1, where only the edge 6---0 is forbidden
+        let graph = cycle_graph(7);
+
+        for seed in 0..10 {
+            let mut class_list = vec![vec![0, 2, 6], vec![1, 3, 5], vec![4]];
+            let mut rng = SmallRng::seed_from_u64(seed);
+
+            assert_eq!(get_forbidden_vertices(&graph, &class_list).0, 1);
+
+            let num_forbidden = local_search(
+                &graph,
+                &mut class_list,
+                &LocalSearchConfig::default(),
+                &mut rng,
+            );
+
+            assert_eq!(num_forbidden, 0);
+            assert_eq!(class_list.len(), 3);
+            assert!(graph.is_valid_class_list(&class_list));
+        }
+    }
+
+    #[test]
+    fn test_best_recoloring_ties() {
+        let graph = cycle_graph(7);
+        let assignment =
+            ColorAssignment::from_class_list(&graph, &[vec![0, 2, 6], vec![1, 3, 5], vec![4]]);
+
+        assert_eq!(
+            best_recoloring(&graph, &assignment, 6, false, &RingBuffer::new(0)),
+            Some((3, 1.0))
+        );
+        assert_eq!(
+            best_recoloring(&graph, &assignment, 1, false, &RingBuffer::new(0)),
+            None
+        );
+
+        // The center of a star conflicts with every leaf, and the free colors tie
+        let mut graph = AdjList::new(5);
+        for leaf in 1..5 {
+            graph.add_edge(0, leaf);
+        }
+        let assignment =
+            ColorAssignment::from_class_list(&graph, &[(0..5).collect(), vec![], vec![], vec![]]);
 
-        assert_eq!(coloring, [1, 2, 2, 3])
+        assert_eq!(
+            best_recoloring(&graph, &assignment, 0, false, &RingBuffer::new(0)),
+            Some((2, 4.0))
+        );
     }
 
     #[test]
-    fn test_count_forbidden_per_vertex() {
-        let graph = AdjList::complete(5);
-        let coloring = [1, 1, 1, 1, 1];
+    fn test_local_search_bipartite() {
+        // K_{3,3} with every vertex in the first of two classes
+        let mut graph = AdjList::new(6);
+        for u in 0..3 {
+            for v in 3..6 {
+                graph.add_edge(u, v);
+            }
+        }
+
+        for seed in 0..10 {
+            let mut class_list = vec![(0..6).collect(), Vec::new()];
+            let mut rng = SmallRng::seed_from_u64(seed);
+
+            let num_forbidden = local_search(
+                &graph,
+                &mut class_list,
+                &LocalSearchConfig::default(),
+                &mut rng,
+            );
+
+            assert_eq!(num_forbidden, 0);
+
+            // The only 2-coloring separates the sides
+            let mut sides = class_list.clone();
+            sides.iter_mut().for_each(|class| class.sort());
+            sides.sort();
+
+            assert_eq!(sides, vec![vec![0, 1, 2], vec![3, 4, 5]]);
+        }
+    }
+
+    #[test]
+    fn test_local_search_profiled() {
+        // The chain 1---2---2---3 again, where only the central vertices conflict
+        let mut graph = AdjList::new(4);
+        graph.add_edge(0, 1);
+        graph.add_edge(1, 2);
+        graph.add_edge(2, 3);
+        let mut total_profile: HashMap<usize, usize> = HashMap::new();
+
+        for seed in 0..10 {
+            let mut color_classes = vec![vec![0], vec![1, 2], vec![3]];
+            let (num_forbidden, profile) = local_search_profiled(
+                &graph,
+                &mut color_classes,
+                &LocalSearchConfig::default(),
+                &mut SmallRng::seed_from_u64(seed),
+            );
+
+            assert_eq!(num_forbidden, 0);
+            for (vertex, count) in profile {
+                *total_profile.entry(vertex).or_insert(0) += count;
+            }
+        }
+
+        let count = |vertex| total_profile.get(&vertex).copied().unwrap_or(0);
+
+        assert!(count(1) + count(2) > count(0) + count(3));
+        assert!(count(1) > count(0) && count(2) > count(3));
+    }
+
+    #[test]
+    fn test_local_search_convergence() {
+        if let Ok(Some(graph)) = input::read_graph_from_file("data/myc/myciel3.col") {
+            let mut rng = SmallRng::seed_from_u64(0);
+            // myciel3 needs 4 colors, so 3 classes keep some conflicts
+            let coloring: Vec<usize> = (0..graph.num_vertices()).map(|v| v % 3 + 1).collect();
+            let initial_density = conflict_subgraph_density(&graph, &coloring);
+            let mut class_list = get_class_list_from_coloring(&coloring);
+
+            let (num_forbidden, densities) = local_search_convergence(
+                &graph,
+                &mut class_list,
+                &LocalSearchConfig::default(),
+                &mut rng,
+            );
+
+            assert!(!densities.is_empty());
+            assert!(densities
+                .iter()
+                .all(|density| (0.0..=1.0).contains(density)));
+
+            // The result is the least dense conflict graph seen
+            let coloring = get_coloring_from_class_list(graph.num_vertices(), &class_list);
+            let final_density = conflict_subgraph_density(&graph, &coloring);
+
+            assert!(num_forbidden > 0);
+            assert_eq!(final_density, num_forbidden as f64 / 55.0);
+            assert!(final_density <= initial_density);
+            assert!(densities.iter().all(|density| final_density <= *density));
+        } else {
+            panic!("The file containing the test graph is missing")
+        }
+    }
+
+    #[test]
+    fn test_hybrid_local_search() {
+        // The path of test_multi_vertex_local_search, where 1-opt moves alone get stuck
+        let graph = path_graph(6);
+        let class_list = vec![vec![0, 3, 4], vec![1, 2, 5]];
+        let mut color_classes = class_list.clone();
+
+        assert_eq!(hybrid_local_search(&graph, &mut color_classes, 100, 0), 2);
+        assert_eq!(color_classes, class_list);
+
+        // The 2-opt move needs the right pair, so the search gets enough attempts
+        let search = HybridLocalSearch {
+            phase1_iters: 100,
+            phase2_iters: 50,
+        };
+        for seed in 0..10 {
+            let mut color_classes = class_list.clone();
+            let mut rng = SmallRng::seed_from_u64(seed);
+
+            assert_eq!(search.run(&graph, &mut color_classes, &mut rng), 0);
+            assert!(graph.is_valid_class_list(&color_classes));
+        }
+
+        // 1-opt moves are enough for the chain 0---1---2 colored as 1, 1, 2
+        let graph = path_graph(3);
+        let mut color_classes = vec![vec![0, 1], vec![2]];
+
+        assert_eq!(hybrid_local_search(&graph, &mut color_classes, 100, 0), 0);
+        assert!(graph.is_valid_class_list(&color_classes));
+
+        let mut color_classes = class_list.clone();
+
+        assert!(hybrid_local_search(&path_graph(6), &mut color_classes, 0, 1) <= 2);
+    }
+
+    #[test]
+    fn test_double_swap_local_search() {
+        // The clique 3---4---5---6 with 0 adjacent to 3 and 4, 1 to 3 and 6, and 2 to 3. The only
+        // conflict is 4---6, and recoloring any vertex just moves it, but swapping the colors of
+        // 6 and 1 solves it
+        let mut graph = AdjList::new(7);
+        for (u, v) in [(0, 3), (0, 4), (1, 3), (1, 6), (2, 3)] {
+            graph.add_edge(u, v);
+        }
+        for (u, v) in [(3, 4), (3, 5), (3, 6), (4, 5), (4, 6), (5, 6)] {
+            graph.add_edge(u, v);
+        }
+        let class_list = vec![vec![3], vec![0, 1, 2], vec![4, 6], vec![5]];
+
+        // Without perturbations, the random vertex strategy can't make any move
+        let random_vertex = LocalSearchConfig {
+            max_iterations: 100,
+            stagnation_window: usize::MAX,
+            ..LocalSearchConfig::default()
+        };
+        for seed in 0..10 {
+            let mut color_classes = class_list.clone();
+            let num_forbidden = local_search(
+                &graph,
+                &mut color_classes,
+                &random_vertex,
+                &mut SmallRng::seed_from_u64(seed),
+            );
+
+            assert_eq!(num_forbidden, 1);
+        }
+
+        let mut color_classes = class_list.clone();
+
+        assert_eq!(double_swap_local_search(&graph, &mut color_classes, 100), 0);
+        assert!(graph.is_valid_class_list(&color_classes));
+
+        // Swapping 0 and 4 solves it as well. Tabu swaps are still made if they remove every
+        // forbidden edge
+        let mut tabu = RingBuffer::new(4);
+        for (vertex, color) in [(0, 3), (4, 2), (1, 3), (6, 2)] {
+            tabu.push((vertex, color));
+        }
+        let assignment = ColorAssignment::from_class_list(&graph, &class_list);
+        let swapped =
+            |swap: Option<(usize, usize, f64)>| swap.map(|(u, v, _)| (u.min(v), u.max(v)));
+
+        assert!(best_color_swap(&graph, &assignment, &[4, 6], false, &tabu).is_some());
+
+        // But not otherwise, with the 7---8 conflict left
+        let mut graph = graph;
+        graph.add_vertex();
+        graph.add_vertex();
+        graph.add_edge(7, 8);
+        let class_list = vec![vec![3], vec![0, 1, 2, 7, 8], vec![4, 6], vec![5]];
+        let assignment = ColorAssignment::from_class_list(&graph, &class_list);
+        let swap = swapped(best_color_swap(
+            &graph,
+            &assignment,
+            &[4, 6],
+            false,
+            &RingBuffer::new(0),
+        ));
+
+        assert!(swap == Some((0, 4)) || swap == Some((1, 6)));
+        assert_eq!(
+            best_color_swap(&graph, &assignment, &[4, 6], false, &tabu),
+            None
+        );
+    }
+
+    #[test]
+    fn test_hill_climbing_local_search() {
+        // The graph of test_double_swap_local_search, where the local search without
+        // perturbations is stuck at a single conflict
+        let mut graph = AdjList::new(7);
+        for (u, v) in [(0, 3), (0, 4), (1, 3), (1, 6), (2, 3)] {
+            graph.add_edge(u, v);
+        }
+        for (u, v) in [(3, 4), (3, 5), (3, 6), (4, 5), (4, 6), (5, 6)] {
+            graph.add_edge(u, v);
+        }
+        let class_list = vec![vec![3], vec![0, 1, 2], vec![4, 6], vec![5]];
+
+        let config = HillClimbingConfig {
+            max_restarts: 20,
+            walk_length: 3,
+            walk_probability: 1.0,
+            local_search: LocalSearchConfig {
+                max_iterations: 100,
+                stagnation_window: usize::MAX,
+                ..LocalSearchConfig::default()
+            },
+        };
+        for _ in 0..10 {
+            let mut color_classes = class_list.clone();
+
+            assert_eq!(
+                hill_climbing_local_search(&graph, &mut color_classes, &config),
+                0
+            );
+            assert!(graph.is_valid_class_list(&color_classes));
+        }
+
+        // Without walks, only the first local search runs
+        let config = HillClimbingConfig {
+            walk_probability: 0.0,
+            ..config
+        };
+        let mut color_classes = class_list.clone();
+
+        assert_eq!(
+            hill_climbing_local_search(&graph, &mut color_classes, &config),
+            1
+        );
+        assert_eq!(color_classes, class_list);
+    }
+
+    #[test]
+    fn test_plateau_moves() {
+        // The graph of test_double_swap_local_search: moving 6 to the class of 1 keeps a single
+        // conflict, 1---6, which recoloring 1 with the color left by 6 then solves
+        let mut graph = AdjList::new(7);
+        for (u, v) in [(0, 3), (0, 4), (1, 3), (1, 6), (2, 3)] {
+            graph.add_edge(u, v);
+        }
+        for (u, v) in [(3, 4), (3, 5), (3, 6), (4, 5), (4, 6), (5, 6)] {
+            graph.add_edge(u, v);
+        }
+        let class_list = vec![vec![3], vec![0, 1, 2], vec![4, 6], vec![5]];
+
+        let config = LocalSearchConfig {
+            max_iterations: 100,
+            stagnation_window: usize::MAX,
+            ..LocalSearchConfig::default()
+        };
+        let plateau = LocalSearchConfig {
+            max_sideways_moves: 5,
+            ..config
+        };
+        for seed in 0..10 {
+            let mut color_classes = class_list.clone();
+
+            assert_eq!(
+                local_search(
+                    &graph,
+                    &mut color_classes,
+                    &config,
+                    &mut SmallRng::seed_from_u64(seed)
+                ),
+                1
+            );
+
+            let mut color_classes = class_list.clone();
+
+            assert_eq!(
+                local_search(
+                    &graph,
+                    &mut color_classes,
+                    &plateau,
+                    &mut SmallRng::seed_from_u64(seed)
+                ),
+                0
+            );
+            assert!(graph.is_valid_class_list(&color_classes));
+        }
+
+        assert_eq!(
+            GraspConfig::default()
+                .plateau_moves(5)
+                .local_search_config()
+                .max_sideways_moves,
+            5
+        );
+    }
+
+    #[test]
+    fn test_tabu_local_search() {
+        // Without the tabu, the best improvement search gets stuck with a conflict here for every
+        // seed
+        let graph = random_graph(20, 60, 14).unwrap();
+        let class_list: Vec<Vec<usize>> = (0..4)
+            .map(|color| (0..20).filter(|v| v % 4 == color).collect())
+            .collect();
+
+        let config = LocalSearchConfig {
+            strategy: LocalSearchStrategy::BestImprovement,
+            max_iterations: 200,
+            stagnation_window: usize::MAX,
+            max_sideways_moves: 5,
+            ..LocalSearchConfig::default()
+        };
+        let run = |config: &LocalSearchConfig, seed: u64| {
+            let mut color_classes = class_list.clone();
+            let conflicts = local_search(
+                &graph,
+                &mut color_classes,
+                config,
+                &mut SmallRng::seed_from_u64(seed),
+            );
+
+            (conflicts, color_classes)
+        };
+
+        assert!((0..10).all(|seed| run(&config, seed).0 == 1));
+        assert!((0..10).any(|seed| {
+            let (conflicts, color_classes) = run(&config.tabu_size(5), seed);
+            conflicts == 0 && graph.is_valid_class_list(&color_classes)
+        }));
+
+        // An empty tabu changes nothing
+        assert_eq!(config.tabu_size, 0);
+        for seed in 0..10 {
+            assert_eq!(run(&config.tabu_size(0), seed), run(&config, seed));
+        }
+
+        let mut tabu = RingBuffer::new(2);
+        tabu.push((0, 1));
+        tabu.push((1, 2));
+        tabu.push((2, 3));
+
+        assert!(!tabu.contains(&(0, 1)));
+        assert!(tabu.contains(&(1, 2)) && tabu.contains(&(2, 3)));
+
+        let mut disabled = RingBuffer::new(0);
+        disabled.push((0, 1));
+
+        assert!(!disabled.contains(&(0, 1)));
+    }
+
+    #[test]
+    fn test_local_search_best_improvement() {
+        // A path with a single color, fixed by recoloring every other vertex
+        let graph = path_graph(40);
+        let single_class = || vec![(0..40).collect::<Vec<usize>>(), Vec::new()];
+
+        let mut class_list = single_class();
+        assert_eq!(
+            local_search_best_improvement(&graph, &mut class_list, 20),
+            0
+        );
+        assert!(graph.is_valid_class_list(&class_list));
+
+        // Picking a random vertex wastes moves on the ones next to the recolored vertices
+        let config = LocalSearchConfig {
+            max_iterations: 20,
+            ..LocalSearchConfig::default()
+        };
+        let solved = (0..10)
+            .filter(|seed| {
+                local_search(
+                    &graph,
+                    &mut single_class(),
+                    &config,
+                    &mut SmallRng::seed_from_u64(*seed),
+                ) == 0
+            })
+            .count();
+
+        assert!(solved < 10);
+    }
+
+    #[test]
+    fn test_local_search_stagnation() {
+        // A sparse 3-colorable graph and a random coloring from which the local search usually
+        // gets stuck in a local minimum
+        let graph = random_k_colorable(15, 3, 25, 14);
+        let mut rng = SmallRng::seed_from_u64(14);
+        let coloring: Vec<usize> = (0..15).map(|_| rng.gen_range(1..=3)).collect();
+        let class_list = get_class_list(&coloring, 3);
+
+        let without_perturbations = LocalSearchConfig {
+            stagnation_window: usize::MAX,
+            ..Default::default()
+        };
+        let with_perturbations = LocalSearchConfig {
+            stagnation_window: 2,
+            ..Default::default()
+        };
+        let num_stuck = (0..10)
+            .filter(|seed| {
+                let mut class_list = class_list.clone();
+                let mut rng = SmallRng::seed_from_u64(*seed);
+
+                local_search(&graph, &mut class_list, &without_perturbations, &mut rng) > 0
+            })
+            .count();
+
+        assert!(num_stuck >= 5);
+
+        for seed in 0..10 {
+            let mut class_list = class_list.clone();
+            let mut rng = SmallRng::seed_from_u64(seed);
+
+            assert_eq!(
+                local_search(&graph, &mut class_list, &with_perturbations, &mut rng),
+                0
+            );
+            assert!(graph.is_valid_class_list(&class_list));
+        }
+    }
+
+    #[test]
+    fn test_best_recoloring() {
+        // A star colored as 1 in the center, with the leaves colored as 1, 2 and 2. The conflict
+        // with the first leaf weighs more than the other two
+        let mut graph = AdjList::new(4);
+        graph.add_weighted_edge(0, 1, 10.0);
+        graph.add_weighted_edge(0, 2, 1.0);
+        graph.add_weighted_edge(0, 3, 1.0);
+        let assignment = ColorAssignment::from_class_list(&graph, &[vec![0, 1], vec![2, 3]]);
+
+        // Moving the center would create two conflicts instead of one
+        assert_eq!(
+            best_recoloring(&graph, &assignment, 0, false, &RingBuffer::new(0)),
+            None
+        );
+
+        // But it resolves the heavier one
+        assert_eq!(
+            best_recoloring(&graph, &assignment, 0, true, &RingBuffer::new(0)),
+            Some((2, 8.0))
+        );
+    }
+
+    #[test]
+    fn test_weighted_local_search() {
+        if let Ok(Some(graph)) = input::read_graph_from_file("data/myc/myciel3.col") {
+            let config = GraspConfig::new(5, 5, 3).use_weighted_objective(true);
+            let (num_colors, coloring) = grasp_with_config(&graph, &config).unwrap();
+
+            assert!(num_colors >= 4);
+            assert!(graph.is_valid_coloring(&coloring));
+        } else {
+            panic!("The file containing the test graph is missing")
+        }
+    }
 
-        let num_forbidden = count_forbidden_per_vertex(&graph, &coloring, 1);
+    #[test]
+    fn test_get_class_list() {
+        let class_list = get_class_list(&[1, 2, 2, 3], 3);
 
-        assert_eq!(num_forbidden, 4);
+        assert_eq!(class_list, vec![vec![0], vec![1, 2], vec![3]]);
     }
 }
@@ -1,8 +1,11 @@
-use super::{count_colors, grasp::grasp, is_coloring_valid, Solution};
+use super::{count_colors, grasp::grasp, reindex_colors, Solution};
 use crate::graph::adj_list::AdjList;
 
 pub fn grasp_path_relinking(graph: &AdjList, num_solutions_grasp: usize) -> Solution {
-    let mut solutions = grasp(graph, 25, 25, 3, num_solutions_grasp).into_sorted_vec();
+    // The GRASP parameters are valid, so we can unwrap
+    let mut solutions = grasp(graph, 25, 25, 3, num_solutions_grasp)
+        .unwrap()
+        .into_sorted_vec();
     solutions.reverse();
     let mut best_solution = solutions.pop().unwrap().clone();
 
@@ -23,7 +26,7 @@ pub fn grasp_path_relinking(graph: &AdjList, num_solutions_grasp: usize) -> Solu
                 continue;
             }
 
-            if !is_coloring_valid(graph, &new_coloring) {
+            if !graph.is_valid_coloring(&new_coloring) {
                 continue;
             }
 
@@ -38,6 +41,32 @@ pub fn grasp_path_relinking(graph: &AdjList, num_solutions_grasp: usize) -> Solu
     best_solution
 }
 
+/// Walks from the `initial` coloring to the `guiding` one, giving the guiding color to one
+/// differing vertex at a time, and returns the valid coloring with the fewest colors along the
+/// path. Both colorings must be valid colorings of `graph`.
+///
+/// The colors of the returned solution are renamed to `1..=k`, see [`reindex_colors`].
+pub fn path_relinking(graph: &AdjList, initial: &[usize], guiding: &[usize]) -> Solution {
+    let mut best_solution = (count_colors(initial), initial.to_vec());
+    let mut new_coloring = initial.to_vec();
+
+    for vertex in simmetric_difference(initial, guiding) {
+        new_coloring[vertex] = guiding[vertex];
+
+        let num_colors = count_colors(&new_coloring);
+
+        // Avoid checking if the coloring is valid (since it's more expensive) if the number of
+        // colors doesn't improve
+        if num_colors < best_solution.0 && graph.is_valid_coloring(&new_coloring) {
+            best_solution = (num_colors, new_coloring.clone());
+        }
+    }
+
+    reindex_colors(&mut best_solution.1);
+
+    best_solution
+}
+
 /// Calculates the indexes where `lhs` and `rhs` differ, given that they have the same length.
 /// Else, create a new vector.
 fn simmetric_difference(lhs: &[usize], rhs: &[usize]) -> Vec<usize> {
@@ -59,7 +88,7 @@ fn simmetric_difference(lhs: &[usize], rhs: &[usize]) -> Vec<usize> {
 #[cfg(test)]
 mod tests {
     use super::*;
-    use crate::{algorithms::is_coloring_valid, input};
+    use crate::{graph::generators::cycle_graph, input};
 
     #[test]
     fn test_simmetric_difference() {
@@ -69,13 +98,30 @@ mod tests {
         assert_eq!(simmetric_difference(&lhs, &rhs), vec![1, 2]);
     }
 
+    #[test]
+    fn test_path_relinking() {
+        // Two colorings of the 4-cycle with 3 colors, where the first step of the path, giving 1
+        // the color of 3, leaves only 2 colors
+        let graph = cycle_graph(4);
+        let initial = vec![1, 2, 1, 3];
+        let guiding = vec![1, 3, 2, 3];
+
+        let (num_colors, coloring) = path_relinking(&graph, &initial, &guiding);
+
+        assert_eq!(num_colors, 2);
+        assert_eq!(coloring, vec![1, 2, 1, 2]);
+
+        // Walking to the same coloring keeps it
+        assert_eq!(path_relinking(&graph, &initial, &initial), (3, initial));
+    }
+
     #[test]
     fn test_grasp_path_relinking() {
         // Asserts GRASP + PR provides a solution
         if let Ok(Some(graph)) = input::read_graph_from_file("data/myc/myciel5.col") {
             let (_, coloring) = grasp_path_relinking(&graph, 5);
 
-            assert!(is_coloring_valid(&graph, &coloring));
+            assert!(graph.is_valid_coloring(&coloring));
         } else {
             panic!("The file containing the test graph is missing")
         }
@@ -0,0 +1,672 @@
+use super::{
+    count_colors, get_class_list_from_coloring, reindex_colors, vns::kempe_chain,
+    ColoringAlgorithm, Solution,
+};
+use crate::graph::adj_list::AdjList;
+use std::cmp::Reverse;
+use std::collections::HashSet;
+use std::time::Instant;
+
+/// Smallest color (1-indexed) not used by the already colored neighbors of `vertex`. Uncolored
+/// vertices have color 0.
+fn first_available_color(graph: &AdjList, coloring: &[usize], vertex: usize) -> usize {
+    let used: Vec<usize> = graph.adj_list()[vertex]
+        .iter()
+        .map(|u| coloring[*u])
+        .collect();
+
+    (1..).find(|color| !used.contains(color)).unwrap()
+}
+
+/// Colors the vertices of `graph` in the given `order`, each with the smallest color not used by
+/// its neighbors (first-fit).
+pub fn greedy_sequential(graph: &AdjList, order: &[usize]) -> Solution {
+    let mut coloring = vec![0; graph.num_vertices()];
+
+    for vertex in order.iter() {
+        coloring[*vertex] = first_available_color(graph, &coloring, *vertex);
+    }
+
+    (count_colors(&coloring), coloring)
+}
+
+/// Tries to move every vertex of color `color` in the valid `coloring` of `graph` with `num_colors`
+/// colors to another color, leaving `coloring` untouched unless all of them move.
+///
+/// Each vertex `v` takes a color `j` missing from its neighbors if there is one. Otherwise, for
+/// another color `i`, the Kempe chains over `i` and `j` of the neighbors of `v` colored `j` are
+/// interchanged, if they hold no neighbor of `v` colored `i`, which frees `j` for `v`.
+fn empty_color_class(
+    graph: &AdjList,
+    coloring: &mut [usize],
+    color: usize,
+    num_colors: usize,
+) -> bool {
+    let mut new_coloring = coloring.to_vec();
+    let class: Vec<usize> = (0..coloring.len())
+        .filter(|vertex| coloring[*vertex] == color)
+        .collect();
+
+    for vertex in class {
+        let others = || (1..=num_colors).filter(|other| *other != color);
+        let neighbors_with = |coloring: &[usize], other: usize| -> Vec<usize> {
+            graph.adj_list()[vertex]
+                .iter()
+                .copied()
+                .filter(|u| coloring[*u] == other)
+                .collect()
+        };
+
+        let moved = others().any(|j| {
+            let blocking = neighbors_with(&new_coloring, j);
+
+            if blocking.is_empty() {
+                new_coloring[vertex] = j;
+                return true;
+            }
+
+            others().filter(|i| *i != j).any(|i| {
+                let chains: HashSet<usize> = blocking
+                    .iter()
+                    .flat_map(|u| kempe_chain(graph, &new_coloring, *u, i, usize::MAX))
+                    .collect();
+
+                if neighbors_with(&new_coloring, i)
+                    .iter()
+                    .any(|u| chains.contains(u))
+                {
+                    return false;
+                }
+
+                for u in chains {
+                    new_coloring[u] = if new_coloring[u] == i { j } else { i };
+                }
+                new_coloring[vertex] = j;
+
+                true
+            })
+        });
+
+        if !moved {
+            return false;
+        }
+    }
+
+    coloring.clone_from_slice(&new_coloring);
+
+    true
+}
+
+/// Colors `graph` first-fit in the given `ordering` (see [`greedy_sequential`]), then repeatedly
+/// empties a color class, starting from the smallest ones, by moving its vertices to other
+/// colors with Kempe chain interchanges, until no class can be emptied. Returns the number of
+/// colors and the class list.
+///
+/// The interchanges keep the coloring valid, so this is a cheap improvement over the greedy
+/// coloring, although usually not as good as GRASP.
+pub fn greedy_with_interchange(graph: &AdjList, ordering: &[usize]) -> (usize, Vec<Vec<usize>>) {
+    let (mut num_colors, mut coloring) = greedy_sequential(graph, ordering);
+
+    loop {
+        let mut colors: Vec<usize> = (1..=num_colors).collect();
+        colors.sort_by_key(|color| coloring.iter().filter(|c| *c == color).count());
+
+        if !colors
+            .into_iter()
+            .any(|color| empty_color_class(graph, &mut coloring, color, num_colors))
+        {
+            break;
+        }
+
+        reindex_colors(&mut coloring);
+        num_colors -= 1;
+    }
+
+    (num_colors, get_class_list_from_coloring(&coloring))
+}
+
+/// State of the depth-first search of [`greedy_with_backtrack`] and [`backtrack_below`].
+struct BacktrackSearch<'a> {
+    graph: &'a AdjList,
+    ordering: &'a [usize],
+    coloring: Vec<usize>,
+    best: Solution,
+    // Position in the ordering of the first vertex holding the last color of the best coloring
+    jump: usize,
+    lower_bound: usize,
+    num_backtracks: usize,
+    max_backtracks: usize,
+    deadline: Option<Instant>,
+    // Whether the search ran out of backtracks or time before going through every choice
+    stopped: bool,
+}
+
+impl BacktrackSearch<'_> {
+    /// Colors the vertices from `position` on, with `num_used` colors used by the ones before.
+    fn search(&mut self, position: usize, num_used: usize) {
+        if position == self.ordering.len() {
+            if num_used < self.best.0 {
+                self.best = (num_used, self.coloring.clone());
+                // Every vertex from there on was colored with the colors left by the ones before
+                // it, so the search resumes right before it
+                self.jump = self
+                    .ordering
+                    .iter()
+                    .position(|vertex| self.coloring[*vertex] == num_used)
+                    .unwrap_or(0);
+            }
+            return;
+        }
+
+        let vertex = self.ordering[position];
+        let mut tried = false;
+
+        for color in 1..=(num_used + 1).min(self.best.0 - 1) {
+            if self.graph.adj_list()[vertex]
+                .iter()
+                .any(|u| self.coloring[*u] == color)
+            {
+                continue;
+            }
+
+            // Trying another color for a vertex is a backtrack
+            if tried {
+                if self.num_backtracks == self.max_backtracks
+                    || self
+                        .deadline
+                        .is_some_and(|deadline| Instant::now() >= deadline)
+                {
+                    self.stopped = true;
+                    return;
+                }
+                self.num_backtracks += 1;
+            }
+            tried = true;
+
+            self.coloring[vertex] = color;
+            self.search(position + 1, num_used.max(color));
+            self.coloring[vertex] = 0;
+
+            if self.best.0 <= self.lower_bound || self.jump <= position {
+                return;
+            }
+            self.jump = usize::MAX;
+        }
+    }
+}
+
+/// Colors `graph` first-fit in the given `ordering` of its vertices (see [`greedy_sequential`]),
+/// then searches for colorings with fewer colors by backtracking over the choices of colors, in
+/// the same ordering. Returns the number of colors and the class list of the best coloring found.
+///
+/// Each vertex tries the colors in increasing order, up to one more than the colors used before
+/// it and below the best number of colors found. When a coloring with `k` colors is found, the
+/// search backtracks to the vertex before the first one that got color `k`, since no choice
+/// after it can avoid that color. Every other color tried for a vertex counts as a backtrack,
+/// and the search stops after `max_backtracks` of them, or when the best coloring uses as many
+/// colors as a greedy clique has vertices. With no backtracks, this is [`greedy_sequential`].
+pub fn greedy_with_backtrack(
+    graph: &AdjList,
+    ordering: &[usize],
+    max_backtracks: usize,
+) -> (usize, Vec<Vec<usize>>) {
+    let mut search = BacktrackSearch {
+        graph,
+        ordering,
+        coloring: vec![0; graph.num_vertices()],
+        best: (usize::MAX, Vec::new()),
+        jump: usize::MAX,
+        lower_bound: graph.clique_lower_bound(),
+        num_backtracks: 0,
+        max_backtracks,
+        deadline: None,
+        stopped: false,
+    };
+
+    search.search(0, 0);
+
+    (search.best.0, get_class_list_from_coloring(&search.best.1))
+}
+
+/// Runs the search of [`greedy_with_backtrack`], without a limit on the backtracks, for a
+/// coloring with fewer colors than the valid `upper_bound`, until `deadline` if given.
+///
+/// Returns the best coloring found (`upper_bound` if there is none), and whether the search went
+/// through every choice or reached the greedy clique bound, which proves that coloring optimal.
+pub(super) fn backtrack_below(
+    graph: &AdjList,
+    ordering: &[usize],
+    upper_bound: Solution,
+    deadline: Option<Instant>,
+) -> (Solution, bool) {
+    let lower_bound = graph.clique_lower_bound();
+
+    if upper_bound.0 <= lower_bound {
+        return (upper_bound, true);
+    }
+
+    let mut search = BacktrackSearch {
+        graph,
+        ordering,
+        coloring: vec![0; graph.num_vertices()],
+        best: upper_bound,
+        jump: usize::MAX,
+        lower_bound,
+        num_backtracks: 0,
+        max_backtracks: usize::MAX,
+        deadline,
+        stopped: false,
+    };
+
+    search.search(0, 0);
+
+    (search.best, !search.stopped)
+}
+
+/// Colors `graph` with the Welsh-Powell heuristic.
+///
+/// Vertices are sorted by decreasing degree. Each color is then given, in that order, to every
+/// uncolored vertex that isn't adjacent to a vertex already holding it.
+pub fn welsh_powell(graph: &AdjList) -> Solution {
+    let num_vertices = graph.num_vertices();
+    let mut order: Vec<usize> = (0..num_vertices).collect();
+    order.sort_by_key(|vertex| Reverse(graph.get_degree(*vertex)));
+
+    let mut coloring = vec![0; num_vertices];
+    let mut color = 0;
+
+    while coloring.contains(&0) {
+        color += 1;
+
+        for vertex in order.iter() {
+            if coloring[*vertex] == 0
+                && !graph.adj_list()[*vertex]
+                    .iter()
+                    .any(|u| coloring[*u] == color)
+            {
+                coloring[*vertex] = color;
+            }
+        }
+    }
+
+    (color, coloring)
+}
+
+/// Colors `graph` with the DSatur heuristic.
+///
+/// At each step, the uncolored vertex with the largest saturation degree (number of distinct
+/// colors among its neighbors) receives the smallest available color. Ties are broken by the
+/// largest degree and then by the smallest index.
+pub fn dsatur(graph: &AdjList) -> Solution {
+    let mut coloring = vec![0; graph.num_vertices()];
+
+    dsatur_complete(graph, &mut coloring);
+
+    (count_colors(&coloring), coloring)
+}
+
+/// Uncolored vertex of `coloring` picked next by [`dsatur`], if any.
+pub(super) fn dsatur_next_vertex(graph: &AdjList, coloring: &[usize]) -> Option<usize> {
+    (0..graph.num_vertices())
+        .filter(|v| coloring[*v] == 0)
+        .max_by_key(|v| {
+            let neighbor_colors: Vec<usize> = graph.adj_list()[*v]
+                .iter()
+                .map(|u| coloring[*u])
+                .filter(|color| *color != 0)
+                .collect();
+
+            (
+                count_colors(&neighbor_colors),
+                graph.get_degree(*v),
+                Reverse(*v),
+            )
+        })
+}
+
+/// Colors the uncolored vertices (color 0) of the partial `coloring` in the order of [`dsatur`],
+/// keeping the colors already assigned.
+pub(super) fn dsatur_complete(graph: &AdjList, coloring: &mut [usize]) {
+    while let Some(vertex) = dsatur_next_vertex(graph, coloring) {
+        coloring[vertex] = first_available_color(graph, coloring, vertex);
+    }
+}
+
+/// Colors `graph` first-fit in the order of a Maximum Cardinality Search (see
+/// [`AdjList::maximum_cardinality_search`]), returning the number of colors and the class list.
+///
+/// The order is the reverse of a perfect elimination ordering on chordal graphs, so the colored
+/// neighbors of each vertex form a clique and the coloring is optimal, with as many colors as the
+/// largest clique. On other graphs the coloring is still valid, but may use more colors.
+pub fn chordal_coloring(graph: &AdjList) -> (usize, Vec<Vec<usize>>) {
+    let (num_colors, coloring) = greedy_sequential(graph, &graph.maximum_cardinality_search());
+
+    (num_colors, get_class_list_from_coloring(&coloring))
+}
+
+/// Colors the interval graph `graph` (see [`AdjList::is_interval_graph`]) optimally, with as many
+/// colors as its largest clique, returning the number of colors and the class list.
+///
+/// Interval graphs are chordal, so this is [`chordal_coloring`], whose order plays the role of the
+/// sweep over the intervals by their left endpoints without building them.
+pub fn interval_graph_optimal_coloring(graph: &AdjList) -> (usize, Vec<Vec<usize>>) {
+    chordal_coloring(graph)
+}
+
+/// Colors `graph` with first-fit in the [`AdjList::degeneracy_ordering`], returning the number of
+/// colors and the class list.
+///
+/// Each vertex has at most `d` neighbors colored before it, where `d` is the degeneracy, so at
+/// most `d + 1` colors are used. This suits sparse graphs, whose degeneracy is usually much
+/// smaller than their maximum degree.
+pub fn degeneracy_coloring(graph: &AdjList) -> (usize, Vec<Vec<usize>>) {
+    let (num_colors, coloring) = greedy_sequential(graph, &graph.degeneracy_ordering());
+
+    (num_colors, get_class_list_from_coloring(&coloring))
+}
+
+/// Colors the edges of `graph` so that edges sharing an endpoint have different colors, by
+/// coloring its [`AdjList::line_graph`] with first-fit in the order of [`AdjList::edge_list`].
+///
+/// Returns the number of colors and the color (1-indexed) of each edge, in the order of
+/// [`AdjList::edge_list`]. By Vizing's theorem the edge chromatic number is `Δ` or `Δ + 1`, where
+/// `Δ` is the maximum degree (see [`AdjList::chromatic_index_bounds`]), but the greedy coloring
+/// may use up to `2Δ - 1` colors.
+pub fn edge_chromatic_number_approx(graph: &AdjList) -> Solution {
+    let line_graph = graph.line_graph();
+    let order: Vec<usize> = (0..line_graph.num_vertices()).collect();
+
+    greedy_sequential(&line_graph, &order)
+}
+
+/// [`ColoringAlgorithm`] running [`welsh_powell`].
+#[derive(Debug, Clone, Copy, Default)]
+pub struct WelshPowellSolver;
+
+impl ColoringAlgorithm for WelshPowellSolver {
+    fn solve(&self, graph: &AdjList) -> Solution {
+        welsh_powell(graph)
+    }
+}
+
+/// [`ColoringAlgorithm`] running [`dsatur`].
+#[derive(Debug, Clone, Copy, Default)]
+pub struct DSaturSolver;
+
+impl ColoringAlgorithm for DSaturSolver {
+    fn solve(&self, graph: &AdjList) -> Solution {
+        dsatur(graph)
+    }
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+    use crate::{
+        graph::generators::{cycle_graph, interval_graph, path_graph},
+        input,
+    };
+    use rand::rngs::SmallRng;
+    use rand::{Rng, SeedableRng};
+
+    #[test]
+    fn test_greedy_sequential() {
+        // A path 0---1---2---3 colored from its ends needs 3 colors instead of 2
+        let mut graph = AdjList::new(4);
+        graph.add_edge(0, 1);
+        graph.add_edge(1, 2);
+        graph.add_edge(2, 3);
+
+        assert_eq!(
+            greedy_sequential(&graph, &[0, 1, 2, 3]),
+            (2, vec![1, 2, 1, 2])
+        );
+        assert_eq!(greedy_sequential(&graph, &[0, 3, 1, 2]).0, 3);
+
+        if let Ok(Some(graph)) = input::read_graph_from_file("data/myc/myciel4.col") {
+            let ordering = graph.cuthill_mckee_ordering();
+            let (num_colors, coloring) = greedy_sequential(&graph, &ordering);
+
+            assert!(num_colors >= 5);
+            assert!(graph.is_valid_coloring(&coloring));
+        } else {
+            panic!("The file containing the test graph is missing")
+        }
+    }
+
+    #[test]
+    fn test_interval_graph_optimal_coloring() {
+        let graph = path_graph(5);
+        let (num_colors, class_list) = interval_graph_optimal_coloring(&graph);
+
+        assert_eq!(num_colors, 2);
+        assert!(graph.is_valid_class_list(&class_list));
+        assert_eq!(interval_graph_optimal_coloring(&AdjList::complete(4)).0, 4);
+
+        // Random interval graphs use as many colors as their largest clique, the largest of the
+        // maximal cliques of their clique tree
+        let mut rng = SmallRng::seed_from_u64(0);
+        for _ in 0..20 {
+            let intervals: Vec<(f64, f64)> = (0..30)
+                .map(|_| {
+                    let start = rng.gen_range(0.0..100.0);
+                    (start, start + rng.gen_range(0.0..20.0))
+                })
+                .collect();
+            let graph = interval_graph(&intervals);
+
+            assert!(graph.is_interval_graph());
+
+            let (num_colors, class_list) = interval_graph_optimal_coloring(&graph);
+            let clique_number = graph
+                .clique_tree()
+                .unwrap()
+                .cliques
+                .iter()
+                .map(Vec::len)
+                .max()
+                .unwrap();
+
+            assert_eq!(num_colors, clique_number);
+            assert!(graph.is_valid_class_list(&class_list));
+        }
+    }
+
+    #[test]
+    fn test_greedy_with_interchange() {
+        if let Ok(Some(graph)) = input::read_graph_from_file("data/myc/myciel3.col") {
+            // An order where first-fit needs 5 colors, one more than the chromatic number
+            let order = [10, 7, 3, 0, 1, 5, 8, 4, 9, 6, 2];
+
+            assert_eq!(greedy_sequential(&graph, &order).0, 5);
+
+            let (num_colors, class_list) = greedy_with_interchange(&graph, &order);
+
+            assert_eq!(num_colors, 4);
+            assert_eq!(class_list.len(), 4);
+            assert!(graph.is_valid_class_list(&class_list));
+        } else {
+            panic!("The file containing the test graph is missing")
+        }
+
+        // The path 0---1---2---3 colored from its ends needs 3 colors before the interchanges
+        let graph = path_graph(4);
+
+        assert_eq!(greedy_sequential(&graph, &[0, 3, 1, 2]).0, 3);
+        assert_eq!(greedy_with_interchange(&graph, &[0, 3, 1, 2]).0, 2);
+    }
+
+    #[test]
+    fn test_greedy_with_backtrack() {
+        if let Ok(Some(graph)) = input::read_graph_from_file("data/myc/myciel3.col") {
+            // The order of test_greedy_with_interchange, where first-fit needs 5 colors
+            let order = [10, 7, 3, 0, 1, 5, 8, 4, 9, 6, 2];
+            let (num_colors, coloring) = greedy_sequential(&graph, &order);
+
+            assert_eq!(
+                greedy_with_backtrack(&graph, &order, 0),
+                (num_colors, get_class_list_from_coloring(&coloring))
+            );
+
+            let (num_colors, class_list) = greedy_with_backtrack(&graph, &order, 100);
+
+            assert_eq!(num_colors, 4);
+            assert_eq!(class_list.len(), 4);
+            assert!(graph.is_valid_class_list(&class_list));
+        } else {
+            panic!("The file containing the test graph is missing")
+        }
+
+        // Stops at the greedy clique of K_5 and of the path
+        assert_eq!(
+            greedy_with_backtrack(&AdjList::complete(5), &[0, 1, 2, 3, 4], 100).0,
+            5
+        );
+        assert_eq!(
+            greedy_with_backtrack(&path_graph(4), &[0, 3, 1, 2], 100).0,
+            2
+        );
+        assert_eq!(greedy_with_backtrack(&AdjList::new(0), &[], 100).0, 0);
+    }
+
+    #[test]
+    fn test_degeneracy_coloring() {
+        if let Ok(Some(graph)) = input::read_graph_from_file("data/myc/myciel3.col") {
+            let degeneracy = graph.k_core_decomposition().into_iter().max().unwrap();
+            let (num_colors, class_list) = degeneracy_coloring(&graph);
+
+            assert!(graph.is_valid_class_list(&class_list));
+            assert_eq!(class_list.len(), num_colors);
+            assert!(num_colors <= degeneracy + 1);
+        } else {
+            panic!("The file containing the test graph is missing")
+        }
+
+        // Trees are 1-degenerate, so 2 colors suffice
+        let mut tree = AdjList::new(15);
+        for vertex in 1..15 {
+            tree.add_edge(vertex, (vertex - 1) / 2);
+        }
+
+        let (num_colors, class_list) = degeneracy_coloring(&tree);
+
+        assert_eq!(num_colors, 2);
+        assert!(tree.is_valid_class_list(&class_list));
+        assert_eq!(degeneracy_coloring(&AdjList::new(0)), (0, vec![]));
+    }
+
+    #[test]
+    fn test_edge_chromatic_number_approx() {
+        // K_4 has maximum degree 3 and a perfect matching decomposition
+        let graph = AdjList::complete(4);
+        let (num_colors, edge_coloring) = edge_chromatic_number_approx(&graph);
+
+        assert_eq!(num_colors, 3);
+        assert_eq!(edge_coloring.len(), 6);
+        assert!(graph.line_graph().is_valid_coloring(&edge_coloring));
+
+        // Odd cycles need one color more than their maximum degree
+        let (num_colors, edge_coloring) = edge_chromatic_number_approx(&cycle_graph(5));
+
+        assert_eq!(num_colors, 3);
+        assert!(cycle_graph(5)
+            .line_graph()
+            .is_valid_coloring(&edge_coloring));
+
+        assert_eq!(edge_chromatic_number_approx(&cycle_graph(6)).0, 2);
+
+        // Class 2 graphs can't be colored with fewer than Δ + 1 colors
+        for graph in [cycle_graph(7), AdjList::complete(5)] {
+            assert_eq!(graph.is_class_one_graph(), Some(false));
+            assert!(edge_chromatic_number_approx(&graph).0 >= graph.chromatic_index_bounds().1);
+        }
+        assert_eq!(edge_chromatic_number_approx(&AdjList::new(4)), (0, vec![]));
+    }
+
+    #[test]
+    fn test_chordal_coloring() {
+        // A complete binary tree on 15 vertices
+        let mut tree = AdjList::new(15);
+        for vertex in 1..15 {
+            tree.add_edge(vertex, (vertex - 1) / 2);
+        }
+
+        let (num_colors, class_list) = chordal_coloring(&tree);
+
+        assert_eq!(num_colors, 2);
+        assert!(tree.is_valid_class_list(&class_list));
+
+        // Two copies of K_4 sharing the edge 2---3, with a triangle hanging from 5, have clique
+        // number 4
+        let mut graph = AdjList::new(8);
+        for (u, v) in [(0, 1), (0, 2), (0, 3), (1, 2), (1, 3), (2, 3)] {
+            graph.add_edge(u, v);
+        }
+        for (u, v) in [
+            (2, 4),
+            (2, 5),
+            (3, 4),
+            (3, 5),
+            (4, 5),
+            (5, 6),
+            (5, 7),
+            (6, 7),
+        ] {
+            graph.add_edge(u, v);
+        }
+
+        assert!(graph.is_chordal());
+
+        let (num_colors, class_list) = chordal_coloring(&graph);
+
+        assert_eq!(num_colors, 4);
+        assert_eq!(class_list.len(), 4);
+        assert!(graph.is_valid_class_list(&class_list));
+
+        // Still valid on graphs that aren't chordal
+        if let Ok(Some(graph)) = input::read_graph_from_file("data/myc/myciel4.col") {
+            let (num_colors, class_list) = chordal_coloring(&graph);
+
+            assert!(num_colors >= 5);
+            assert!(graph.is_valid_class_list(&class_list));
+        } else {
+            panic!("The file containing the test graph is missing")
+        }
+    }
+
+    #[test]
+    fn test_welsh_powell() {
+        let graph = AdjList::complete(5);
+
+        assert_eq!(welsh_powell(&graph).0, 5);
+
+        if let Ok(Some(graph)) = input::read_graph_from_file("data/myc/myciel4.col") {
+            let (num_colors, coloring) = welsh_powell(&graph);
+
+            assert!(num_colors >= 5);
+            assert_eq!(num_colors, count_colors(&coloring));
+            assert!(graph.is_valid_coloring(&coloring));
+        } else {
+            panic!("The file containing the test graph is missing")
+        }
+    }
+
+    #[test]
+    fn test_dsatur() {
+        let graph = AdjList::complete(5);
+
+        assert_eq!(dsatur(&graph), (5, vec![1, 2, 3, 4, 5]));
+
+        // DSatur is exact on bipartite graphs, such as even cycles
+        assert_eq!(dsatur(&cycle_graph(6)).0, 2);
+
+        if let Ok(Some(graph)) = input::read_graph_from_file("data/myc/myciel3.col") {
+            let (num_colors, coloring) = dsatur(&graph);
+
+            assert_eq!(num_colors, 4);
+            assert!(graph.is_valid_coloring(&coloring));
+        } else {
+            panic!("The file containing the test graph is missing")
+        }
+    }
+}
@@ -0,0 +1,118 @@
+use super::grasp::{improve_phase, GraspConfig};
+use super::greedy::{dsatur, welsh_powell};
+use super::rlf::rlf;
+use super::{compact_class_list_owned, get_class_list_from_coloring, Solution};
+use crate::graph::adj_list::AdjList;
+use rand::rngs::SmallRng;
+use rand::SeedableRng;
+
+/// Numbers of colors of the heuristics run by [`ensemble_greedy_with_stats`], and of classes
+/// removed from each of their colorings by the improve phase, in the same order (DSatur,
+/// Welsh-Powell and RLF).
+#[derive(Debug, Clone, Copy, Default, PartialEq, Eq)]
+pub struct EnsembleStats {
+    pub dsatur_colors: usize,
+    pub welsh_powell_colors: usize,
+    pub rlf_colors: usize,
+    pub improve_phase_reductions: [usize; 3],
+}
+
+impl EnsembleStats {
+    /// Index, in the order of [`EnsembleStats::improve_phase_reductions`], of the heuristic that
+    /// led to the fewest colors after the improve phase, the first one among ties.
+    pub fn best_heuristic(&self) -> usize {
+        let final_colors = [
+            self.dsatur_colors - self.improve_phase_reductions[0],
+            self.welsh_powell_colors - self.improve_phase_reductions[1],
+            self.rlf_colors - self.improve_phase_reductions[2],
+        ];
+
+        // There are three heuristics, so we can unwrap
+        (0..3).min_by_key(|i| final_colors[*i]).unwrap()
+    }
+}
+
+/// Colors `graph` with DSatur, Welsh-Powell and RLF, applies the improve phase of GRASP (with
+/// the default [`GraspConfig`]) to each coloring and returns the one with the fewest classes,
+/// the first one among ties. The improve phases share a random number generator seeded with
+/// `seed`.
+pub fn ensemble_greedy(graph: &AdjList, seed: u64) -> (usize, Vec<Vec<usize>>) {
+    ensemble_greedy_with_stats(graph, seed).0
+}
+
+/// Same as [`ensemble_greedy`], but also returns the contribution of each heuristic.
+pub fn ensemble_greedy_with_stats(
+    graph: &AdjList,
+    seed: u64,
+) -> ((usize, Vec<Vec<usize>>), EnsembleStats) {
+    let config = GraspConfig::default();
+    let mut rng = SmallRng::seed_from_u64(seed);
+    let mut stats = EnsembleStats::default();
+    let mut best: Option<(usize, Vec<Vec<usize>>)> = None;
+
+    for (i, heuristic) in [dsatur, welsh_powell, rlf].iter().enumerate() {
+        let (num_colors, coloring): Solution = heuristic(graph);
+        let mut class_list = compact_class_list_owned(get_class_list_from_coloring(&coloring));
+        let mut num_classes = class_list.len();
+
+        improve_phase(graph, &mut num_classes, &mut class_list, &config, &mut rng);
+
+        match i {
+            0 => stats.dsatur_colors = num_colors,
+            1 => stats.welsh_powell_colors = num_colors,
+            _ => stats.rlf_colors = num_colors,
+        }
+        stats.improve_phase_reductions[i] = num_colors - num_classes;
+
+        if best
+            .as_ref()
+            .is_none_or(|(best_classes, _)| num_classes < *best_classes)
+        {
+            best = Some((num_classes, class_list));
+        }
+    }
+
+    // There are three heuristics, so we can unwrap
+    (best.unwrap(), stats)
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+    use crate::input;
+
+    #[test]
+    fn test_ensemble_greedy() {
+        // The chromatic numbers of myciel3 and myciel4 are 4 and 5
+        for (file, chromatic_number) in [("data/myc/myciel3.col", 4), ("data/myc/myciel4.col", 5)] {
+            if let Ok(Some(graph)) = input::read_graph_from_file(file) {
+                let ((num_colors, class_list), stats) = ensemble_greedy_with_stats(&graph, 0);
+
+                assert_eq!(num_colors, class_list.len());
+                assert!(graph.is_valid_class_list(&class_list));
+                assert!(num_colors >= chromatic_number);
+                for heuristic in [dsatur, welsh_powell, rlf] {
+                    assert!(num_colors <= heuristic(&graph).0);
+                }
+
+                assert_eq!(stats.dsatur_colors, dsatur(&graph).0);
+                assert_eq!(stats.welsh_powell_colors, welsh_powell(&graph).0);
+                assert_eq!(stats.rlf_colors, rlf(&graph).0);
+                let best = stats.best_heuristic();
+                let heuristic_colors = [
+                    stats.dsatur_colors,
+                    stats.welsh_powell_colors,
+                    stats.rlf_colors,
+                ];
+                assert_eq!(
+                    heuristic_colors[best] - stats.improve_phase_reductions[best],
+                    num_colors
+                );
+
+                assert_eq!(ensemble_greedy(&graph, 0).0, num_colors);
+            } else {
+                panic!("The file containing the test graph is missing")
+            }
+        }
+    }
+}
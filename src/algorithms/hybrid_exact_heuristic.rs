@@ -0,0 +1,99 @@
+use super::grasp::{grasp_with_config, GraspConfig};
+use super::greedy::backtrack_below;
+use super::{get_class_list_from_coloring, ColoringError, Solution};
+use crate::graph::adj_list::AdjList;
+use std::time::{Duration, Instant};
+
+/// Colors `graph` with GRASP and then looks for a coloring with fewer colors with the
+/// branch-and-bound search of [`greedy_with_backtrack`](super::greedy::greedy_with_backtrack),
+/// bounded by the GRASP solution, until `time_budget` runs out (GRASP included).
+///
+/// Returns the number of colors, the class list of the best coloring and whether it was proved
+/// optimal, either by the search going through every coloring with fewer colors or by the greedy
+/// clique bound. The GRASP solution is kept when it exceeds the bound of `grasp_config`, and
+/// [`ColoringError::InvalidConfig`] is returned if the configuration is invalid.
+pub fn hybrid_coloring(
+    graph: &AdjList,
+    time_budget: Duration,
+    grasp_config: GraspConfig,
+) -> Result<(usize, Vec<Vec<usize>>, bool), ColoringError> {
+    let deadline = Instant::now() + time_budget;
+
+    let (num_colors, coloring): Solution = match grasp_with_config(graph, &grasp_config) {
+        Ok(solution) | Err(ColoringError::NoBoundedSolutionFound { best: solution, .. }) => {
+            solution
+        }
+        Err(error) => return Err(error),
+    };
+
+    // The vertices of a class are colored together, so the search starts along the GRASP
+    // coloring
+    let mut ordering: Vec<usize> = (0..graph.num_vertices()).collect();
+    ordering.sort_by_key(|vertex| coloring[*vertex]);
+
+    let ((num_colors, coloring), proved) =
+        backtrack_below(graph, &ordering, (num_colors, coloring), Some(deadline));
+
+    Ok((num_colors, get_class_list_from_coloring(&coloring), proved))
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+    use crate::input;
+
+    #[test]
+    fn test_hybrid_coloring() {
+        if let Ok(Some(graph)) = input::read_graph_from_file("data/myc/myciel3.col") {
+            let config = GraspConfig::new(5, 10, 3).seed(0);
+            let (num_colors, _) = grasp_with_config(&graph, &config).unwrap();
+
+            assert_eq!(num_colors, 4);
+
+            let (num_colors, class_list, proved) =
+                hybrid_coloring(&graph, Duration::from_secs(10), config).unwrap();
+
+            // myciel3 has chromatic number 4, which its largest clique (an edge) doesn't prove
+            assert_eq!(num_colors, 4);
+            assert!(proved);
+            assert_eq!(class_list.iter().map(Vec::len).sum::<usize>(), 11);
+        } else {
+            panic!("The file containing the test graph is missing")
+        }
+    }
+
+    #[test]
+    fn test_hybrid_coloring_out_of_time() {
+        if let Ok(Some(graph)) = input::read_graph_from_file("data/myc/myciel5.col") {
+            let config = GraspConfig::new(5, 10, 3).seed(0);
+            let (grasp_colors, _) = grasp_with_config(&graph, &config).unwrap();
+            let (num_colors, class_list, proved) =
+                hybrid_coloring(&graph, Duration::from_millis(50), config).unwrap();
+
+            // myciel5 has chromatic number 6, which GRASP can't beat
+            assert_eq!(num_colors, grasp_colors);
+            assert!(!proved);
+
+            let mut coloring = vec![0; graph.num_vertices()];
+            for (color, class) in class_list.iter().enumerate() {
+                for vertex in class.iter() {
+                    coloring[*vertex] = color + 1;
+                }
+            }
+
+            assert!(graph.is_valid_coloring(&coloring));
+        } else {
+            panic!("The file containing the test graph is missing")
+        }
+    }
+
+    #[test]
+    fn test_hybrid_coloring_invalid_config() {
+        let graph = AdjList::complete(3);
+
+        assert!(matches!(
+            hybrid_coloring(&graph, Duration::from_secs(1), GraspConfig::new(5, 10, 0)),
+            Err(ColoringError::InvalidConfig(_))
+        ));
+    }
+}
@@ -0,0 +1,195 @@
+use super::{compact_class_list_owned, get_class_list_from_coloring};
+use crate::graph::adj_list::AdjList;
+use rand::rngs::StdRng;
+use rand::seq::IteratorRandom;
+use rand::{Rng, SeedableRng};
+
+/// Fraction of the vertices on which two antibodies must differ for both to survive the
+/// suppression of [`immune_algorithm`].
+const SUPPRESSION_DISTANCE_RATIO: f64 = 0.05;
+
+/// Number of edges whose endpoints share a color in `coloring`.
+fn num_conflicts(graph: &AdjList, coloring: &[usize]) -> usize {
+    graph
+        .edge_list_as_slice()
+        .iter()
+        .filter(|(u, v)| coloring[*u] == coloring[*v])
+        .count()
+}
+
+/// Reassigns `num_mutations` distinct random vertices of `antibody` to random colors among the
+/// first `k`.
+fn hypermutate(antibody: &mut [usize], k: usize, num_mutations: usize, rng: &mut impl Rng) {
+    let num_vertices = antibody.len();
+
+    for vertex in (0..num_vertices).choose_multiple(rng, num_mutations.min(num_vertices)) {
+        antibody[vertex] = rng.gen_range(1..=k);
+    }
+}
+
+/// Colors `graph` with an Immune Algorithm, evolving a population of `population_size`
+/// antibodies, colorings with a fixed number `k` of colors whose fitness is their number of
+/// conflicts.
+///
+/// Every generation, the antibody of rank `i` (from 0, the fittest first) is cloned
+/// `clone_factor * population_size / (i + 1)` times (at least once), and each clone reassigns
+/// `mutations * (i + 1)` random vertices to random colors, so the fittest antibodies get the most
+/// clones and the mildest mutations. The antibodies and their clones are then sorted by fitness,
+/// and the ones too close to a fitter antibody (differing on less than 5% of the vertices, at
+/// least one) are suppressed. The fittest survivors make up the next population, topped up with
+/// random antibodies.
+///
+/// The search starts with `k` one more than the maximum degree, which always allows a coloring.
+/// Whenever an antibody without conflicts is found, it is kept and the search goes on with one
+/// color less, the antibodies starting from it with the vertices of the removed color
+/// reassigned at random. It stops after `generations` generations in total, or once there are
+/// as many colors as vertices in a greedy clique, returning the number of colors of the best
+/// coloring found and its class list. The random choices are drawn from a generator seeded with
+/// `seed`.
+pub fn immune_algorithm(
+    graph: &AdjList,
+    population_size: usize,
+    clone_factor: f64,
+    mutations: usize,
+    generations: usize,
+    seed: u64,
+) -> (usize, Vec<Vec<usize>>) {
+    let num_vertices = graph.num_vertices();
+    let mut rng = StdRng::seed_from_u64(seed);
+    let lower_bound = graph.clique_lower_bound();
+    let min_distance = ((num_vertices as f64 * SUPPRESSION_DISTANCE_RATIO).ceil() as usize).max(1);
+    // Every vertex with its own color, until a better coloring is found
+    let mut best: Vec<usize> = (1..=num_vertices).collect();
+    let mut k = (graph.max_degree() + 1).min(num_vertices);
+    let random_antibody = |k: usize, rng: &mut StdRng| -> Vec<usize> {
+        (0..num_vertices).map(|_| rng.gen_range(1..=k)).collect()
+    };
+
+    let mut population: Vec<(usize, Vec<usize>)> = (0..population_size)
+        .map(|_| {
+            let antibody = random_antibody(k, &mut rng);
+            (num_conflicts(graph, &antibody), antibody)
+        })
+        .collect();
+
+    for _ in 0..generations {
+        if k == 0 || population.is_empty() {
+            break;
+        }
+
+        // Clonal selection and hypermutation, the population being sorted by fitness
+        let mut pool = population.clone();
+        for (rank, (_, antibody)) in population.iter().enumerate() {
+            let num_clones =
+                ((clone_factor * population_size as f64 / (rank + 1) as f64) as usize).max(1);
+
+            for _ in 0..num_clones {
+                let mut clone = antibody.clone();
+                hypermutate(&mut clone, k, mutations * (rank + 1), &mut rng);
+                pool.push((num_conflicts(graph, &clone), clone));
+            }
+        }
+        pool.sort_by_key(|(conflicts, _)| *conflicts);
+
+        // Suppression of the antibodies close to a fitter one
+        population.clear();
+        for (conflicts, antibody) in pool {
+            if population.len() == population_size {
+                break;
+            }
+
+            let distinct = population.iter().all(|(_, kept)| {
+                kept.iter().zip(&antibody).filter(|(a, b)| a != b).count() >= min_distance
+            });
+            if distinct {
+                population.push((conflicts, antibody));
+            }
+        }
+        while population.len() < population_size {
+            let antibody = random_antibody(k, &mut rng);
+            population.push((num_conflicts(graph, &antibody), antibody));
+        }
+        population.sort_by_key(|(conflicts, _)| *conflicts);
+
+        if population[0].0 == 0 {
+            best = population[0].1.clone();
+            k -= 1;
+
+            if k == 0 || k < lower_bound {
+                break;
+            }
+
+            population = (0..population_size)
+                .map(|_| {
+                    let antibody: Vec<usize> = best
+                        .iter()
+                        .map(|color| {
+                            if *color > k {
+                                rng.gen_range(1..=k)
+                            } else {
+                                *color
+                            }
+                        })
+                        .collect();
+                    (num_conflicts(graph, &antibody), antibody)
+                })
+                .collect();
+            population.sort_by_key(|(conflicts, _)| *conflicts);
+        }
+    }
+
+    let class_list = compact_class_list_owned(get_class_list_from_coloring(&best));
+
+    (class_list.len(), class_list)
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+    use crate::input;
+
+    #[test]
+    fn test_num_conflicts() {
+        let graph = AdjList::complete(3);
+
+        assert_eq!(num_conflicts(&graph, &[1, 2, 3]), 0);
+        assert_eq!(num_conflicts(&graph, &[1, 1, 2]), 1);
+        assert_eq!(num_conflicts(&graph, &[1, 1, 1]), 3);
+    }
+
+    #[test]
+    fn test_immune_algorithm() {
+        if let Ok(Some(graph)) = input::read_graph_from_file("data/myc/myciel4.col") {
+            let mut small_total = 0;
+            let mut large_total = 0;
+
+            for seed in 0..5 {
+                let (num_colors, class_list) = immune_algorithm(&graph, 2, 0.5, 1, 30, seed);
+
+                assert_eq!(num_colors, class_list.len());
+                assert!(graph.is_valid_class_list(&class_list));
+                small_total += num_colors;
+
+                let (num_colors, class_list) = immune_algorithm(&graph, 20, 0.5, 1, 30, seed);
+
+                // myciel4 has chromatic number 5
+                assert!(num_colors >= 5);
+                assert!(graph.is_valid_class_list(&class_list));
+                large_total += num_colors;
+            }
+
+            assert!(large_total < small_total);
+        } else {
+            panic!("The file containing the test graph is missing")
+        }
+
+        assert_eq!(
+            immune_algorithm(&AdjList::complete(4), 5, 1.0, 1, 10, 0).0,
+            4
+        );
+        assert_eq!(
+            immune_algorithm(&AdjList::new(0), 5, 1.0, 1, 10, 0),
+            (0, vec![])
+        );
+    }
+}
@@ -0,0 +1,299 @@
+use super::{grasp::get_n_best, scoring::VertexScorer, ColoringError};
+use crate::graph::adj_list::AdjList;
+use rand::seq::SliceRandom;
+use rand::Rng;
+
+/// Number of constructions performed by [`maximum_independent_set_approx`].
+const REPETITIONS: usize = 20;
+
+/// Builds a maximal independent set of the subgraph induced by `subset` following the GRASP
+/// construction heuristic.
+///
+/// The greedy heuristic chooses an available vertex: a vertex such that none of its neighbors
+/// have been chosen. The candidates are the `list_size` vertices with largest degree towards the
+/// vertices that can no longer be chosen (or within `subset`, at first). It tries to cover the
+/// subset entirely (or until no candidates remain).
+///
+/// Returns [`ColoringError::EmptyCandidateList`] if `list_size` is 0 and `subset` isn't empty.
+pub fn maximal_independent_set(
+    graph: &AdjList,
+    subset: &[usize],
+    list_size: usize,
+    rng: &mut impl Rng,
+) -> Result<Vec<usize>, ColoringError> {
+    build_independent_set(graph, subset, list_size, rng, |_, _, _, _| {})
+}
+
+/// A step of the construction of [`maximal_independent_set_traced`].
+#[cfg(feature = "trace")]
+#[derive(Debug, Clone, PartialEq, Eq)]
+pub struct ColorAssignmentTrace {
+    pub step: usize,
+    pub candidate_list: Vec<usize>,
+    pub chosen_vertex: usize,
+    /// Number of vertices that could still be chosen before the step.
+    pub admissible_count_before: usize,
+    /// Number of vertices that could no longer be chosen for being adjacent to a chosen one,
+    /// before the step.
+    pub inadmissible_count_before: usize,
+}
+
+/// Same as [`maximal_independent_set`], but also returns the candidate list and the chosen
+/// vertex of each step, to debug the construction.
+#[cfg(feature = "trace")]
+pub fn maximal_independent_set_traced(
+    graph: &AdjList,
+    subset: &[usize],
+    list_size: usize,
+    rng: &mut impl Rng,
+) -> Result<(Vec<usize>, Vec<ColorAssignmentTrace>), ColoringError> {
+    let mut trace = Vec::new();
+    let independent_set = build_independent_set(
+        graph,
+        subset,
+        list_size,
+        rng,
+        |candidate_list, chosen_vertex, admissible_count_before, inadmissible_count_before| {
+            trace.push(ColorAssignmentTrace {
+                step: trace.len(),
+                candidate_list: candidate_list.to_vec(),
+                chosen_vertex,
+                admissible_count_before,
+                inadmissible_count_before,
+            });
+        },
+    )?;
+
+    Ok((independent_set, trace))
+}
+
+/// Construction of [`maximal_independent_set`], calling `on_step` with the candidate list, the
+/// chosen vertex and the numbers of admissible and inadmissible vertices before each step.
+fn build_independent_set(
+    graph: &AdjList,
+    subset: &[usize],
+    list_size: usize,
+    rng: &mut impl Rng,
+    mut on_step: impl FnMut(&[usize], usize, usize, usize),
+) -> Result<Vec<usize>, ColoringError> {
+    // Kept sorted, as removals preserve the order, to count degrees by binary search
+    let mut admissible: Vec<usize> = subset.to_vec();
+    admissible.sort();
+    let mut independent_set: Vec<usize> = Vec::new();
+
+    while !admissible.is_empty() {
+        // The vertices that can no longer be chosen are the neighbors of the chosen ones
+        let inadmissible = graph.neighbors_union(&independent_set);
+        let candidate_list = get_n_best(list_size, &admissible, |v| {
+            if inadmissible.is_empty() {
+                graph.get_degree_in_sorted_list(v, &admissible) as i64
+            } else {
+                graph.adj_list()[v]
+                    .iter()
+                    .filter(|u| inadmissible.contains(u))
+                    .count() as i64
+            }
+        });
+        let vertex = candidate_list.choose(rng);
+
+        let Some(vertex) = vertex else {
+            return Err(ColoringError::EmptyCandidateList);
+        };
+
+        on_step(
+            &candidate_list,
+            *vertex,
+            admissible.len(),
+            inadmissible.len(),
+        );
+
+        independent_set.push(*vertex);
+        let neighbors = &graph.adj_list()[*vertex];
+        admissible.retain(|node| node != vertex && !neighbors.contains(node));
+    }
+
+    Ok(independent_set)
+}
+
+/// Same as [`maximal_independent_set`], but the candidates are the `list_size` available
+/// vertices with the highest scores given by `scorer`, computed once with `subset` as the
+/// uncolored vertices.
+///
+/// Returns [`ColoringError::EmptyCandidateList`] if `list_size` is 0 and `subset` isn't empty.
+pub fn maximal_independent_set_with_scorer(
+    graph: &AdjList,
+    subset: &[usize],
+    list_size: usize,
+    scorer: &dyn VertexScorer,
+    rng: &mut impl Rng,
+) -> Result<Vec<usize>, ColoringError> {
+    let mut admissible: Vec<(usize, f64)> = subset
+        .iter()
+        .map(|vertex| (*vertex, scorer.score(*vertex, graph, subset)))
+        .collect();
+    admissible.sort_by(|a, b| b.1.total_cmp(&a.1));
+    let mut independent_set: Vec<usize> = Vec::new();
+
+    while !admissible.is_empty() {
+        // The admissible vertices are kept sorted by decreasing score
+        let candidate_list = &admissible[..list_size.min(admissible.len())];
+
+        let Some((vertex, _)) = candidate_list.choose(rng).copied() else {
+            return Err(ColoringError::EmptyCandidateList);
+        };
+
+        independent_set.push(vertex);
+        let neighbors = &graph.adj_list()[vertex];
+        admissible.retain(|(node, _)| *node != vertex && !neighbors.contains(node));
+    }
+
+    Ok(independent_set)
+}
+
+/// Approximates a maximum independent set of `graph` by repeating the greedy construction
+/// and keeping the largest set found.
+pub fn maximum_independent_set_approx(graph: &AdjList) -> Vec<usize> {
+    let vertex_set: Vec<usize> = (0..graph.num_vertices()).collect();
+    let mut rng = rand::thread_rng();
+    let mut best = Vec::new();
+
+    for _ in 0..REPETITIONS {
+        // The candidate list is never empty, so we can unwrap
+        let independent_set = maximal_independent_set(graph, &vertex_set, 3, &mut rng).unwrap();
+
+        if independent_set.len() > best.len() {
+            best = independent_set;
+        }
+    }
+
+    best
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+    use crate::{algorithms::scoring::DegreeScorer, input};
+    use rand::rngs::SmallRng;
+    use rand::SeedableRng;
+
+    fn is_independent(graph: &AdjList, set: &[usize]) -> bool {
+        set.iter()
+            .all(|u| !graph.adj_list()[*u].iter().any(|v| set.contains(v)))
+    }
+
+    #[cfg(feature = "trace")]
+    #[test]
+    fn test_maximal_independent_set_traced() {
+        if let Ok(Some(graph)) = input::read_graph_from_file("data/myc/myciel4.col") {
+            let vertex_set: Vec<usize> = (0..graph.num_vertices()).collect();
+            let (set, trace) = maximal_independent_set_traced(
+                &graph,
+                &vertex_set,
+                1,
+                &mut SmallRng::seed_from_u64(0),
+            )
+            .unwrap();
+
+            assert!(is_independent(&graph, &set));
+            assert_eq!(trace.len(), set.len());
+            assert_eq!(trace[0].admissible_count_before, graph.num_vertices());
+            assert_eq!(trace[0].inadmissible_count_before, 0);
+
+            for (step, entry) in trace.iter().enumerate() {
+                assert_eq!(entry.step, step);
+                assert_eq!(entry.candidate_list, vec![entry.chosen_vertex]);
+                assert_eq!(entry.chosen_vertex, set[step]);
+            }
+
+            // The admissible vertices only decrease
+            assert!(trace
+                .windows(2)
+                .all(|pair| pair[1].admissible_count_before < pair[0].admissible_count_before));
+        } else {
+            panic!("The file containing the test graph is missing")
+        }
+    }
+
+    #[test]
+    fn test_maximal_independent_set_with_scorer() {
+        if let Ok(Some(graph)) = input::read_graph_from_file("data/myc/myciel4.col") {
+            let mut rng = SmallRng::seed_from_u64(0);
+            let vertex_set: Vec<usize> = (0..graph.num_vertices()).collect();
+            let set = maximal_independent_set_with_scorer(
+                &graph,
+                &vertex_set,
+                3,
+                &DegreeScorer,
+                &mut rng,
+            )
+            .unwrap();
+
+            assert!(is_independent(&graph, &set));
+            // The set is maximal
+            assert!(vertex_set
+                .iter()
+                .all(|u| set.contains(u) || graph.adj_list()[*u].iter().any(|v| set.contains(v))));
+
+            assert!(matches!(
+                maximal_independent_set_with_scorer(
+                    &graph,
+                    &vertex_set,
+                    0,
+                    &DegreeScorer,
+                    &mut rng
+                ),
+                Err(ColoringError::EmptyCandidateList)
+            ));
+        } else {
+            panic!("The file containing the test graph is missing")
+        }
+    }
+
+    #[test]
+    fn test_maximal_independent_set() {
+        if let Ok(Some(graph)) = input::read_graph_from_file("data/myc/myciel4.col") {
+            let vertex_set: Vec<usize> = (0..graph.num_vertices()).collect();
+            let mut rng = SmallRng::seed_from_u64(0);
+
+            for _ in 0..10 {
+                let set = maximal_independent_set(&graph, &vertex_set, 3, &mut rng).unwrap();
+
+                assert!(is_independent(&graph, &set));
+
+                // Maximal: every other vertex has a neighbor in the set
+                assert!(vertex_set.iter().all(
+                    |u| set.contains(u) || graph.adj_list()[*u].iter().any(|v| set.contains(v))
+                ));
+            }
+
+            // Only vertices from the subset are taken into account
+            let subset = vec![0, 1, 2, 3];
+            let set = maximal_independent_set(&graph, &subset, 3, &mut rng).unwrap();
+
+            assert!(set.iter().all(|u| subset.contains(u)));
+
+            // Without candidates no vertex can be chosen
+            assert_eq!(
+                maximal_independent_set(&graph, &subset, 0, &mut rng),
+                Err(ColoringError::EmptyCandidateList)
+            );
+        } else {
+            panic!("The file containing the test graph is missing")
+        }
+    }
+
+    #[test]
+    fn test_maximum_independent_set_approx() {
+        let graph = AdjList::complete(5);
+
+        assert_eq!(maximum_independent_set_approx(&graph).len(), 1);
+
+        if let Ok(Some(graph)) = input::read_graph_from_file("data/myc/myciel3.col") {
+            let set = maximum_independent_set_approx(&graph);
+
+            assert!(is_independent(&graph, &set));
+        } else {
+            panic!("The file containing the test graph is missing")
+        }
+    }
+}
@@ -0,0 +1,194 @@
+use crate::graph::adj_list::AdjList;
+use rand::seq::index::sample;
+
+/// Recursively colors the vertices from `vertex` onwards, each with a color of its `domain`.
+///
+/// After a color is assigned, it is removed from the domains of the uncolored neighbors (forward
+/// checking). The assignment is undone as soon as one of them runs out of colors.
+///
+/// When every vertex had the same list, given as `palette`, the colors are interchangeable, so
+/// only the first `num_used` colors of the palette (the ones already used) and the next one are
+/// tried. In particular, the first vertex is fixed to the first color.
+fn backtrack(
+    graph: &AdjList,
+    vertex: usize,
+    domains: &mut [Vec<usize>],
+    coloring: &mut [usize],
+    palette: Option<&[usize]>,
+    num_used: usize,
+) -> bool {
+    if vertex == graph.num_vertices() {
+        return true;
+    }
+
+    for color in domains[vertex].clone() {
+        let mut next_used = num_used;
+
+        if let Some(palette) = palette {
+            // The palette contains every color of the domains
+            let index = palette.iter().position(|c| *c == color).unwrap();
+
+            if index > num_used {
+                continue;
+            }
+            next_used = num_used.max(index + 1);
+        }
+
+        let mut pruned = Vec::new();
+        let mut wiped_out = false;
+
+        for neighbor in graph.adj_list()[vertex].iter().filter(|u| **u > vertex) {
+            if let Some(index) = domains[*neighbor].iter().position(|c| *c == color) {
+                domains[*neighbor].swap_remove(index);
+                pruned.push(*neighbor);
+
+                if domains[*neighbor].is_empty() {
+                    wiped_out = true;
+                    break;
+                }
+            }
+        }
+
+        if !wiped_out {
+            coloring[vertex] = color;
+
+            if backtrack(graph, vertex + 1, domains, coloring, palette, next_used) {
+                return true;
+            }
+        }
+
+        for neighbor in pruned {
+            domains[neighbor].push(color);
+        }
+    }
+
+    false
+}
+
+/// Looks for a coloring of `graph` where each vertex `v` receives a color from `lists[v]` and no
+/// two adjacent vertices share a color.
+///
+/// Uses backtracking with forward checking, breaking the symmetry between colors when all the
+/// lists are equal. Returns `None` if there is no such coloring.
+pub fn list_coloring(graph: &AdjList, lists: &[Vec<usize>]) -> Option<Vec<usize>> {
+    let num_vertices = graph.num_vertices();
+    let mut domains: Vec<Vec<usize>> = lists.to_vec();
+    let mut coloring = vec![0; num_vertices];
+
+    if domains.iter().any(|domain| domain.is_empty()) {
+        return None;
+    }
+
+    let mut palette = lists.first().cloned().unwrap_or_default();
+    palette.sort();
+    palette.dedup();
+    let symmetric = lists.iter().all(|list| {
+        let mut list = list.clone();
+        list.sort();
+        list.dedup();
+        list == palette
+    });
+
+    if backtrack(
+        graph,
+        0,
+        &mut domains,
+        &mut coloring,
+        symmetric.then_some(palette.as_slice()),
+        0,
+    ) {
+        Some(coloring)
+    } else {
+        None
+    }
+}
+
+/// Approximates whether `graph` is `k`-choosable, i.e., whether it can be colored from any
+/// assignment of lists of `k` colors.
+///
+/// Samples `samples` random list assignments, where each vertex gets `k` distinct colors from
+/// `1..=2k`, and checks that all of them can be colored. A `false` answer is always correct,
+/// while a `true` answer may miss uncolorable assignments.
+pub fn is_k_choosable_approx(graph: &AdjList, k: usize, samples: usize) -> bool {
+    let mut rng = rand::thread_rng();
+
+    (0..samples).all(|_| {
+        let lists: Vec<Vec<usize>> = (0..graph.num_vertices())
+            .map(|_| {
+                sample(&mut rng, 2 * k, k)
+                    .into_iter()
+                    .map(|color| color + 1)
+                    .collect()
+            })
+            .collect();
+
+        list_coloring(graph, &lists).is_some()
+    })
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+    use crate::input;
+
+    #[test]
+    fn test_list_coloring() {
+        let graph = AdjList::complete(3);
+        let lists = vec![vec![1, 2], vec![2, 3], vec![1, 3]];
+
+        if let Some(coloring) = list_coloring(&graph, &lists) {
+            assert!(graph.is_valid_coloring(&coloring));
+            assert!((0..3).all(|v| lists[v].contains(&coloring[v])));
+        } else {
+            panic!("K_3 should be colorable from the lists")
+        }
+
+        assert_eq!(list_coloring(&graph, &[vec![1], vec![1], vec![1]]), None);
+        assert_eq!(
+            list_coloring(&graph, &[vec![1, 2], vec![1, 2], vec![1, 2]]),
+            None
+        );
+    }
+
+    #[test]
+    fn test_list_coloring_myciel3() {
+        // With the same 4 colors for every vertex, list coloring is 4-coloring
+        if let Ok(Some(graph)) = input::read_graph_from_file("data/myc/myciel3.col") {
+            let lists = vec![vec![1, 2, 3, 4]; graph.num_vertices()];
+
+            if let Some(coloring) = list_coloring(&graph, &lists) {
+                assert!(graph.is_valid_coloring(&coloring));
+            } else {
+                panic!("myciel3 should be 4-colorable")
+            }
+
+            let lists = vec![vec![1, 2, 3]; graph.num_vertices()];
+
+            assert_eq!(list_coloring(&graph, &lists), None);
+        } else {
+            panic!("The file containing the test graph is missing")
+        }
+    }
+
+    #[test]
+    fn test_list_coloring_symmetric() {
+        // With equal lists the first vertex gets the first color, and colors are used in order
+        let graph = AdjList::complete(5);
+        let lists = vec![vec![3, 1, 2, 4, 5]; 5];
+
+        assert_eq!(list_coloring(&graph, &lists), Some(vec![1, 2, 3, 4, 5]));
+        assert_eq!(list_coloring(&graph, &vec![vec![4, 3, 2, 1]; 5]), None);
+    }
+
+    #[test]
+    fn test_is_k_choosable_approx() {
+        // Every graph is (max degree + 1)-choosable
+        assert!(is_k_choosable_approx(&AdjList::complete(3), 3, 50));
+
+        // An edge isn't 1-choosable, and half of the samples give both endpoints the same color
+        let mut graph = AdjList::new(2);
+        graph.add_edge(0, 1);
+
+        assert!(!is_k_choosable_approx(&graph, 1, 50));
+    }
+}
@@ -0,0 +1,189 @@
+use super::{
+    count_colors, get_class_list_from_coloring,
+    greedy::{dsatur_complete, dsatur_next_vertex},
+};
+use crate::graph::adj_list::AdjList;
+use rand::rngs::StdRng;
+use rand::seq::SliceRandom;
+use rand::SeedableRng;
+
+/// A node of the search tree, standing for the partial coloring given by the assignments on the
+/// path from the root.
+#[derive(Debug, Clone)]
+struct Node {
+    /// Assignment leading to the node, `None` for the root.
+    assignment: Option<(usize, usize)>,
+    /// Indices of the children in the arena, empty until the node is expanded.
+    children: Vec<usize>,
+    expanded: bool,
+    visits: usize,
+    /// Sum of the rewards of the rollouts through the node.
+    total_reward: f64,
+}
+
+impl Node {
+    fn new(assignment: Option<(usize, usize)>) -> Self {
+        Node {
+            assignment,
+            children: Vec::new(),
+            expanded: false,
+            visits: 0,
+            total_reward: 0.0,
+        }
+    }
+}
+
+/// Assignments that extend the partial `coloring`: the vertex picked next by DSatur with each
+/// color that none of its neighbors holds.
+///
+/// Since the colors are interchangeable, only the colors already used and a single new one are
+/// considered.
+fn expansions(graph: &AdjList, coloring: &[usize]) -> Vec<(usize, usize)> {
+    let Some(vertex) = dsatur_next_vertex(graph, coloring) else {
+        return Vec::new();
+    };
+    let num_colors = coloring.iter().copied().max().unwrap_or(0);
+
+    (1..=num_colors + 1)
+        .filter(|color| {
+            !graph.adj_list()[vertex]
+                .iter()
+                .any(|u| coloring[*u] == *color)
+        })
+        .map(|color| (vertex, color))
+        .collect()
+}
+
+/// Child of `parent` maximizing UCB1, `mean + c * sqrt(ln(N) / n)`, where `N` and `n` are the
+/// visits of the parent and of the child. Unvisited children come first.
+fn select_child(arena: &[Node], parent: usize, exploration_constant: f64) -> usize {
+    let log_visits = (arena[parent].visits as f64).ln();
+    let ucb1 = |child: &usize| {
+        let node = &arena[*child];
+
+        if node.visits == 0 {
+            return f64::INFINITY;
+        }
+
+        node.total_reward / node.visits as f64
+            + exploration_constant * (log_visits / node.visits as f64).sqrt()
+    };
+
+    // Expanded nodes always have children, so we can unwrap
+    *arena[parent]
+        .children
+        .iter()
+        .max_by(|a, b| ucb1(a).total_cmp(&ucb1(b)))
+        .unwrap()
+}
+
+/// Colors `graph` with a Monte Carlo Tree Search over the partial colorings.
+///
+/// Each node extends the coloring of its parent by giving a color to the vertex picked next by
+/// DSatur. Every iteration descends the tree with UCB1, expands the reached node with a random
+/// child and completes its partial coloring with DSatur. The reward of the rollout, minus its
+/// number of colors, is then added to the nodes of the path. `exploration_constant` is thus
+/// measured in colors.
+///
+/// Runs at least one iteration, and returns the number of colors of the best rollout and its
+/// class list. The random choices are drawn from a generator seeded with `seed`.
+pub fn mcts_coloring(
+    graph: &AdjList,
+    iterations: usize,
+    exploration_constant: f64,
+    seed: u64,
+) -> (usize, Vec<Vec<usize>>) {
+    let num_vertices = graph.num_vertices();
+    let mut rng = StdRng::seed_from_u64(seed);
+    let mut arena = vec![Node::new(None)];
+    let mut best: Option<(usize, Vec<usize>)> = None;
+
+    for _ in 0..iterations.max(1) {
+        let mut coloring = vec![0; num_vertices];
+        let mut path = vec![0];
+        let mut node = 0;
+
+        // Selection, down to a node that isn't expanded or has no children (a full coloring)
+        while arena[node].expanded && !arena[node].children.is_empty() {
+            node = select_child(&arena, node, exploration_constant);
+
+            // Only the root has no assignment
+            let (vertex, color) = arena[node].assignment.unwrap();
+            coloring[vertex] = color;
+            path.push(node);
+        }
+
+        // Expansion
+        if !arena[node].expanded {
+            arena[node].expanded = true;
+
+            for assignment in expansions(graph, &coloring) {
+                arena.push(Node::new(Some(assignment)));
+                let child = arena.len() - 1;
+                arena[node].children.push(child);
+            }
+
+            if let Some(child) = arena[node].children.choose(&mut rng).copied() {
+                let (vertex, color) = arena[child].assignment.unwrap();
+                coloring[vertex] = color;
+                path.push(child);
+            }
+        }
+
+        // Rollout
+        dsatur_complete(graph, &mut coloring);
+        let num_colors = count_colors(&coloring);
+
+        // Backpropagation
+        for node in path {
+            arena[node].visits += 1;
+            arena[node].total_reward -= num_colors as f64;
+        }
+
+        if best.as_ref().is_none_or(|(colors, _)| num_colors < *colors) {
+            best = Some((num_colors, coloring));
+        }
+    }
+
+    // At least one iteration is run, so we can unwrap
+    let (num_colors, coloring) = best.unwrap();
+
+    (num_colors, get_class_list_from_coloring(&coloring))
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+    use crate::{graph::random::random_k_colorable, input};
+
+    #[test]
+    fn test_mcts_coloring() {
+        // The chromatic number of myciel4 is 5
+        if let Ok(Some(graph)) = input::read_graph_from_file("data/myc/myciel4.col") {
+            let (num_colors, class_list) = mcts_coloring(&graph, 50, 1.0, 0);
+
+            assert!(num_colors >= 5);
+            assert_eq!(class_list.len(), num_colors);
+            assert!(graph.is_valid_class_list(&class_list));
+        } else {
+            panic!("The file containing the test graph is missing")
+        }
+
+        let (num_colors, class_list) = mcts_coloring(&AdjList::new(0), 10, 1.0, 0);
+
+        assert_eq!(num_colors, 0);
+        assert!(class_list.is_empty());
+    }
+
+    #[test]
+    fn test_mcts_iterations() {
+        // A single rollout is DSatur, which needs 5 colors on this 4-colorable graph
+        let graph = random_k_colorable(40, 4, 150, 0);
+        let (few, _) = mcts_coloring(&graph, 1, 1.0, 0);
+        let (many, class_list) = mcts_coloring(&graph, 200, 1.0, 0);
+
+        assert_eq!(few, 5);
+        assert_eq!(many, 4);
+        assert!(graph.is_valid_class_list(&class_list));
+    }
+}
@@ -1,11 +1,101 @@
 use crate::graph::adj_list::AdjList;
-use std::collections::HashSet;
+use std::collections::{HashMap, HashSet};
+use std::error::Error;
+use std::fmt;
+use std::time::{Duration, Instant};
 
+pub mod bounds;
+pub mod cache;
+pub mod chromatic_polynomial;
+pub mod class_interchange;
+pub mod clique_cover;
+pub mod color_assignment;
+pub mod color_class;
+pub mod color_refinement;
+pub mod coloring_lp_relaxation;
+pub mod ejection;
 pub mod genetic;
 pub mod grasp;
 pub mod grasp_pr;
+pub mod greedy;
+pub mod greedy_dsatur_rlf_ensemble;
+pub mod hybrid_exact_heuristic;
+pub mod immune;
+pub mod independent_set;
+pub mod list_coloring;
+pub mod mcts;
+pub mod neighborhood_swap;
+pub mod online;
+pub mod partition;
+pub mod population_grasp;
+pub mod random_walk;
+pub mod register_allocation;
+pub mod repair;
+pub mod rlf;
+pub mod sat_coloring;
+pub mod scoring;
+pub mod streaming;
+pub mod tabu;
+pub mod transfer;
+pub mod vns;
 
-type Solution = (usize, Vec<usize>);
+pub type Solution = (usize, Vec<usize>);
+
+/// Errors returned by the heuristics.
+#[derive(Debug, Clone, PartialEq, Eq)]
+pub enum ColoringError {
+    /// No candidate vertex was available to build a color class.
+    EmptyCandidateList,
+    /// The parameters of the heuristic are invalid. Holds the reason.
+    InvalidConfig(String),
+    /// No solution with at most `bound` colors was found. Holds the best solution found instead.
+    NoBoundedSolutionFound { bound: usize, best: Solution },
+}
+
+impl fmt::Display for ColoringError {
+    fn fmt(&self, f: &mut fmt::Formatter<'_>) -> fmt::Result {
+        match self {
+            ColoringError::EmptyCandidateList => write!(f, "the candidate list is empty"),
+            ColoringError::InvalidConfig(reason) => write!(f, "invalid configuration: {reason}"),
+            ColoringError::NoBoundedSolutionFound { bound, best } => write!(
+                f,
+                "no solution with at most {bound} colors was found, the best one uses {}",
+                best.0
+            ),
+        }
+    }
+}
+
+impl Error for ColoringError {}
+
+/// A heuristic that colors a graph, so that callers can run any of them through the same
+/// interface (e.g., to compare them).
+pub trait ColoringAlgorithm {
+    /// Colors `graph`, returning the number of colors used and the coloring.
+    fn solve(&self, graph: &AdjList) -> Solution;
+
+    /// Time the algorithm is expected to run within, if any.
+    fn time_budget(&self) -> Option<Duration> {
+        None
+    }
+
+    /// Runs [`ColoringAlgorithm::solve`] and, if it exceeded its time budget, also runs
+    /// `fallback`, returning the solution with fewer colors.
+    ///
+    /// The primary algorithm isn't interrupted, so this is meant for solvers that may overrun
+    /// their budget and return a poor solution as a result.
+    fn solve_with_fallback(&self, graph: &AdjList, fallback: &dyn ColoringAlgorithm) -> Solution {
+        let start = Instant::now();
+        let solution = self.solve(graph);
+
+        match self.time_budget() {
+            Some(time_budget) if start.elapsed() > time_budget => {
+                solution.min(fallback.solve(graph))
+            }
+            _ => solution,
+        }
+    }
+}
 
 /// Checks if the current color assignment of a node and his neighborhood is valid.
 fn is_valid_color_assignment(graph: &AdjList, solution: &[usize], node: usize) -> bool {
@@ -14,21 +104,250 @@ fn is_valid_color_assignment(graph: &AdjList, solution: &[usize], node: usize) -
         .any(|x| solution[node] == solution[*x])
 }
 
-/// Counts the number of colors used in a GCP solution.
-fn count_colors(solution: &[usize]) -> usize {
+/// Counts the number of colors used in a GCP solution, which is smaller than the largest color
+/// when some colors are skipped.
+pub fn count_colors(solution: &[usize]) -> usize {
     let colors: HashSet<&usize> = solution.iter().collect();
 
     colors.len()
 }
 
-/// Checks if `coloring` is valid for `graph`.
-fn is_coloring_valid(graph: &AdjList, coloring: &[usize]) -> bool {
-    (0..graph.num_vertices()).all(|x| is_valid_color_assignment(graph, coloring, x))
+/// Renames the colors of `coloring` to `1..=k`, where `k` is the number of colors used, keeping
+/// the same partition.
+///
+/// Colors are renamed in order of first appearance: the color of vertex 0 becomes 1, the next
+/// color found becomes 2, and so on.
+pub fn reindex_colors(coloring: &mut [usize]) {
+    let mut new_colors: HashMap<usize, usize> = HashMap::new();
+
+    for color in coloring.iter_mut() {
+        let next = new_colors.len() + 1;
+        *color = *new_colors.entry(*color).or_insert(next);
+    }
+}
+
+/// Number of vertices whose colors differ in `a` and `b`, two colorings of the same vertices, under
+/// the renaming of the colors of `b` that makes them agree the most. Equivalent colorings, which
+/// only differ in how their colors are named, are thus at distance 0.
+///
+/// Both colorings are reindexed (see [`reindex_colors`]), and the best renaming is found with the
+/// Hungarian algorithm on the numbers of vertices shared by each pair of colors, in `O(k^3)` time
+/// for `k` colors.
+pub fn normalized_hamming_distance(a: &[usize], b: &[usize]) -> usize {
+    assert_eq!(a.len(), b.len());
+
+    let (mut a, mut b) = (a.to_vec(), b.to_vec());
+    reindex_colors(&mut a);
+    reindex_colors(&mut b);
+
+    let k = count_colors(&a).max(count_colors(&b));
+    let mut shared = vec![vec![0; k]; k];
+    for (color_a, color_b) in a.iter().zip(b.iter()) {
+        shared[color_a - 1][color_b - 1] += 1;
+    }
+
+    let assignment = max_weight_assignment(&shared);
+    let num_agreeing: usize = assignment
+        .iter()
+        .enumerate()
+        .map(|(row, column)| shared[row][*column])
+        .sum();
+
+    a.len() - num_agreeing
+}
+
+/// Assignment of the rows of the square matrix `weights` to distinct columns with the largest
+/// total weight, found by the Hungarian algorithm. Returns the column of each row.
+fn max_weight_assignment(weights: &[Vec<usize>]) -> Vec<usize> {
+    let k = weights.len();
+    let max = weights.iter().flatten().copied().max().unwrap_or(0) as i64;
+    // Minimizing `max - weight`, with the rows and columns 1-indexed and 0 as a sentinel column
+    let cost = |row: usize, column: usize| max - weights[row - 1][column - 1] as i64;
+    let mut row_potential = vec![0; k + 1];
+    let mut column_potential = vec![0; k + 1];
+    let mut row_of = vec![0; k + 1];
+    let mut previous = vec![0; k + 1];
+
+    for row in 1..=k {
+        row_of[0] = row;
+        let mut column = 0;
+        let mut min_slack = vec![i64::MAX; k + 1];
+        let mut used = vec![false; k + 1];
+
+        // Grows an alternating path from `row` until it reaches a free column
+        loop {
+            used[column] = true;
+            let current_row = row_of[column];
+            let mut delta = i64::MAX;
+            let mut next_column = 0;
+
+            for j in 1..=k {
+                if !used[j] {
+                    let slack =
+                        cost(current_row, j) - row_potential[current_row] - column_potential[j];
+
+                    if slack < min_slack[j] {
+                        min_slack[j] = slack;
+                        previous[j] = column;
+                    }
+
+                    if min_slack[j] < delta {
+                        delta = min_slack[j];
+                        next_column = j;
+                    }
+                }
+            }
+
+            for j in 0..=k {
+                if used[j] {
+                    row_potential[row_of[j]] += delta;
+                    column_potential[j] -= delta;
+                } else {
+                    min_slack[j] -= delta;
+                }
+            }
+
+            column = next_column;
+
+            if row_of[column] == 0 {
+                break;
+            }
+        }
+
+        // Flips the path
+        while column != 0 {
+            let previous_column = previous[column];
+            row_of[column] = row_of[previous_column];
+            column = previous_column;
+        }
+    }
+
+    let mut column_of = vec![0; k];
+    for column in 1..=k {
+        column_of[row_of[column] - 1] = column - 1;
+    }
+
+    column_of
+}
+
+/// Counts the number of forbidden edges from `vertex` in `graph` according to `coloring`, that is,
+/// the number of neighbors of `vertex` that share its color.
+pub fn count_forbidden_edges_for_vertex(
+    graph: &AdjList,
+    coloring: &[usize],
+    vertex: usize,
+) -> usize {
+    graph.adj_list()[vertex]
+        .iter()
+        .filter(|x| coloring[**x] == coloring[vertex])
+        .count()
+}
+
+/// Neighbors of `vertex` in `graph` that share its color in `coloring`, the other endpoints of
+/// its forbidden edges.
+pub fn conflicting_neighbors(graph: &AdjList, coloring: &[usize], vertex: usize) -> Vec<usize> {
+    graph.adj_list()[vertex]
+        .iter()
+        .filter(|x| coloring[**x] == coloring[vertex])
+        .copied()
+        .collect()
+}
+
+/// Sums [`count_forbidden_edges_for_vertex`] over the vertices of the class of index `class_idx` in
+/// `class_list`.
+///
+/// Every forbidden edge within the class is counted twice, once for each endpoint.
+pub fn count_forbidden_per_color_class(
+    graph: &AdjList,
+    class_list: &[Vec<usize>],
+    class_idx: usize,
+) -> usize {
+    let coloring = get_coloring_from_class_list(graph.num_vertices(), class_list);
+
+    class_list[class_idx]
+        .iter()
+        .map(|vertex| count_forbidden_edges_for_vertex(graph, &coloring, *vertex))
+        .sum()
+}
+
+/// Sums the weights of the forbidden edges of `graph` according to `coloring`.
+pub fn weighted_forbidden_count(graph: &AdjList, coloring: &[usize]) -> f64 {
+    graph
+        .edge_list_as_slice()
+        .iter()
+        .filter(|(u, v)| coloring[*u] == coloring[*v])
+        .map(|(u, v)| graph.edge_weight(*u, *v).unwrap())
+        .sum()
+}
+
+/// Density of the conflict graph of `coloring` (see [`AdjList::conflict_subgraph`]), the fraction
+/// of the pairs of vertices joined by a forbidden edge.
+pub fn conflict_subgraph_density(graph: &AdjList, coloring: &[usize]) -> f64 {
+    graph.conflict_subgraph(coloring).density()
+}
+
+/// Turn a "Class List" into a traditional coloring. A class list assigns each index in a vector to
+/// a vector of vertices, which represent a given color.
+pub fn get_coloring_from_class_list(num_vertices: usize, class_list: &[Vec<usize>]) -> Vec<usize> {
+    let mut coloring: Vec<usize> = vec![0; num_vertices];
+
+    for (i, class) in class_list.iter().enumerate() {
+        for vertex in class {
+            assert_eq!(coloring[*vertex], 0);
+
+            coloring[*vertex] = i + 1;
+        }
+    }
+
+    coloring
+}
+
+/// Removes the empty classes of `class_list` in place, keeping the order of the others.
+pub fn compact_class_list(class_list: &mut Vec<Vec<usize>>) {
+    class_list.retain(|class| !class.is_empty());
+}
+
+/// Same as [`compact_class_list`], but takes ownership of `class_list`.
+pub fn compact_class_list_owned(mut class_list: Vec<Vec<usize>>) -> Vec<Vec<usize>> {
+    compact_class_list(&mut class_list);
+    class_list
+}
+
+/// Checks whether `class_list` has no empty classes.
+pub fn class_list_is_compact(class_list: &[Vec<usize>]) -> bool {
+    class_list.iter().all(|class| !class.is_empty())
+}
+
+/// Groups the vertices of `coloring` by color, the inverse of [`get_coloring_from_class_list`].
+///
+/// Classes are sorted by color. Colors don't need to be contiguous: the `i`th class holds the
+/// vertices with the `i`th smallest color used.
+pub fn get_class_list_from_coloring(coloring: &[usize]) -> Vec<Vec<usize>> {
+    let mut colors: Vec<usize> = coloring.to_vec();
+    colors.sort();
+    colors.dedup();
+
+    let mut class_list = vec![Vec::new(); colors.len()];
+
+    for (vertex, color) in coloring.iter().enumerate() {
+        // Every color is in the list, so we can unwrap
+        let index = colors.binary_search(color).unwrap();
+        class_list[index].push(vertex);
+    }
+
+    class_list
 }
 
 #[cfg(test)]
 mod tests {
     use super::*;
+    use crate::{
+        algorithms::{
+            grasp::{GraspConfig, GraspSolver},
+            greedy::WelshPowellSolver,
+        },
+        input,
+    };
 
     #[test]
     fn test_count_colors() {
@@ -50,15 +369,238 @@ mod tests {
         assert!(!is_valid_color_assignment(&graph, &[1, 2, 2, 1], 2));
     }
 
+    /// Solver that always returns the trivial coloring after sleeping past its budget.
+    struct SlowSolver;
+
+    impl ColoringAlgorithm for SlowSolver {
+        fn solve(&self, graph: &AdjList) -> Solution {
+            std::thread::sleep(Duration::from_millis(5));
+
+            let num_vertices = graph.num_vertices();
+            (num_vertices, (1..=num_vertices).collect())
+        }
+
+        fn time_budget(&self) -> Option<Duration> {
+            Some(Duration::from_millis(1))
+        }
+    }
+
+    #[test]
+    fn test_coloring_algorithm() {
+        if let Ok(Some(graph)) = input::read_graph_from_file("data/myc/myciel4.col") {
+            let algorithms: Vec<Box<dyn ColoringAlgorithm>> = vec![
+                Box::new(GraspSolver::new(GraspConfig::new(5, 5, 3)).unwrap()),
+                Box::new(WelshPowellSolver),
+            ];
+
+            for algorithm in algorithms.iter() {
+                let (num_colors, coloring) = algorithm.solve(&graph);
+
+                assert!(num_colors >= 5);
+                assert!(graph.is_valid_coloring(&coloring));
+            }
+        } else {
+            panic!("The file containing the test graph is missing")
+        }
+    }
+
+    #[test]
+    fn test_solve_with_fallback() {
+        let graph = AdjList::complete(4);
+        let mut path = AdjList::new(3);
+        path.add_edge(0, 1);
+        path.add_edge(1, 2);
+
+        // The fallback only runs when the budget is exceeded
+        assert_eq!(
+            WelshPowellSolver.solve_with_fallback(&path, &SlowSolver).0,
+            2
+        );
+        assert_eq!(
+            SlowSolver.solve_with_fallback(&path, &WelshPowellSolver).0,
+            2
+        );
+        assert_eq!(
+            SlowSolver.solve_with_fallback(&graph, &WelshPowellSolver).0,
+            4
+        );
+    }
+
+    #[test]
+    fn test_count_forbidden_edges_for_vertex() {
+        let graph = AdjList::complete(5);
+
+        assert_eq!(
+            count_forbidden_edges_for_vertex(&graph, &[1, 1, 1, 1, 1], 1),
+            4
+        );
+        assert_eq!(
+            count_forbidden_edges_for_vertex(&graph, &[1, 2, 1, 1, 2], 1),
+            1
+        );
+        assert_eq!(
+            count_forbidden_edges_for_vertex(&graph, &[1, 2, 3, 4, 5], 1),
+            0
+        );
+    }
+
+    #[test]
+    fn test_conflicting_neighbors() {
+        let graph = AdjList::complete(3);
+
+        for vertex in 0..3 {
+            assert_eq!(conflicting_neighbors(&graph, &[1, 1, 1], vertex).len(), 2);
+        }
+
+        assert_eq!(conflicting_neighbors(&graph, &[1, 2, 1], 0), vec![2]);
+        assert!(conflicting_neighbors(&graph, &[1, 2, 3], 0).is_empty());
+    }
+
     #[test]
-    fn test_is_coloring_valid() {
+    fn test_count_forbidden_per_color_class() {
+        // A path colored as 1---2---2---2
         let mut graph = AdjList::new(4);
         graph.add_edge(0, 1);
-        graph.add_edge(0, 2);
         graph.add_edge(1, 2);
         graph.add_edge(2, 3);
+        let class_list = vec![vec![0], vec![1, 2, 3]];
+
+        assert_eq!(count_forbidden_per_color_class(&graph, &class_list, 0), 0);
+        // Edges 1-2 and 2-3, each counted from both endpoints
+        assert_eq!(count_forbidden_per_color_class(&graph, &class_list, 1), 4);
+    }
+
+    #[test]
+    fn test_conflict_subgraph_density() {
+        let graph = AdjList::complete(4);
+
+        assert_eq!(conflict_subgraph_density(&graph, &[1, 1, 1, 1]), 1.0);
+        assert_eq!(conflict_subgraph_density(&graph, &[1, 1, 2, 2]), 2.0 / 6.0);
+        assert_eq!(conflict_subgraph_density(&graph, &[1, 2, 3, 4]), 0.0);
+    }
+
+    #[test]
+    fn test_weighted_forbidden_count() {
+        let mut graph = AdjList::new(3);
+        graph.add_weighted_edge(0, 1, 2.5);
+        graph.add_weighted_edge(1, 2, 4.0);
+        graph.add_edge(0, 2);
+
+        assert_eq!(weighted_forbidden_count(&graph, &[1, 1, 2]), 2.5);
+        assert_eq!(weighted_forbidden_count(&graph, &[1, 1, 1]), 7.5);
+        assert_eq!(weighted_forbidden_count(&graph, &[1, 2, 3]), 0.0);
+    }
+
+    #[test]
+    fn test_get_coloring_from_class_list() {
+        let class_list = vec![vec![0], vec![1, 2], vec![3]];
+        let coloring = get_coloring_from_class_list(4, &class_list);
+
+        assert_eq!(coloring, [1, 2, 2, 3])
+    }
+
+    #[test]
+    fn test_compact_class_list() {
+        let mut class_list = vec![vec![], vec![2, 0], vec![], vec![1], vec![]];
+
+        assert!(!class_list_is_compact(&class_list));
+
+        compact_class_list(&mut class_list);
+
+        assert_eq!(class_list, vec![vec![2, 0], vec![1]]);
+        assert!(class_list_is_compact(&class_list));
+        assert_eq!(
+            compact_class_list_owned(vec![vec![], vec![3]]),
+            vec![vec![3]]
+        );
+        assert!(class_list_is_compact(&[]));
+    }
+
+    #[test]
+    fn test_reindex_colors() {
+        let mut coloring = vec![1, 3, 3, 1, 7];
+        reindex_colors(&mut coloring);
+
+        assert_eq!(coloring, vec![1, 2, 2, 1, 3]);
+        assert_eq!(count_colors(&coloring), 3);
+
+        let mut coloring = vec![4, 2, 4, 9];
+        reindex_colors(&mut coloring);
+
+        assert_eq!(coloring, vec![1, 2, 1, 3]);
+    }
+
+    #[test]
+    fn test_normalized_hamming_distance() {
+        let coloring = vec![1, 2, 3, 1, 2];
+
+        assert_eq!(normalized_hamming_distance(&coloring, &coloring), 0);
+
+        // The same partition of K_5 with the colors named differently
+        assert_eq!(
+            normalized_hamming_distance(&[1, 2, 3, 4, 5], &[5, 4, 3, 2, 1]),
+            0
+        );
+        assert_eq!(normalized_hamming_distance(&[1, 1, 2, 2], &[7, 7, 3, 3]), 0);
+
+        // Moving a single vertex to another class
+        let a = vec![1, 1, 1, 2, 2, 2];
+        let b = vec![2, 2, 1, 1, 1, 1];
+
+        assert_eq!(normalized_hamming_distance(&a, &b), 1);
+        assert_eq!(normalized_hamming_distance(&b, &a), 1);
+
+        // A single class against singletons, with different numbers of colors
+        let a = vec![1; 4];
+        let b = vec![1, 2, 3, 4];
+
+        assert_eq!(normalized_hamming_distance(&a, &b), 3);
+        assert_eq!(normalized_hamming_distance(&b, &a), 3);
+        assert_eq!(normalized_hamming_distance(&[], &[]), 0);
+    }
+
+    #[test]
+    fn test_max_weight_assignment() {
+        // The greedy choice of the 9 leads to 9 + 1 + 1, while the best total is 8 + 8 + 7
+        let weights = vec![vec![9, 8, 0], vec![8, 1, 0], vec![0, 0, 7]];
+
+        assert_eq!(max_weight_assignment(&weights), vec![1, 0, 2]);
+        assert!(max_weight_assignment(&[]).is_empty());
+    }
+
+    #[test]
+    fn test_get_class_list_from_coloring() {
+        assert_eq!(
+            get_class_list_from_coloring(&[1, 2, 2, 3]),
+            vec![vec![0], vec![1, 2], vec![3]]
+        );
+
+        // Colors that aren't contiguous are mapped to consecutive classes
+        assert_eq!(
+            get_class_list_from_coloring(&[5, 1, 3, 1]),
+            vec![vec![1, 3], vec![2], vec![0]]
+        );
+
+        assert!(get_class_list_from_coloring(&[]).is_empty());
+    }
+
+    #[test]
+    fn test_class_list_round_trip() {
+        let class_list = vec![vec![4, 1], vec![0], vec![2, 3, 5]];
+        let coloring = get_coloring_from_class_list(6, &class_list);
+        let mut round_trip = get_class_list_from_coloring(&coloring);
+
+        // Same partition, possibly reordered
+        let normalize = |class_list: &mut Vec<Vec<usize>>| {
+            for class in class_list.iter_mut() {
+                class.sort();
+            }
+            class_list.sort();
+        };
+        let mut expected = class_list.clone();
+        normalize(&mut expected);
+        normalize(&mut round_trip);
 
-        assert!(is_coloring_valid(&graph, &[1, 2, 3, 1]));
-        assert!(!is_coloring_valid(&graph, &[1, 2, 2, 1]));
+        assert_eq!(round_trip, expected);
     }
 }
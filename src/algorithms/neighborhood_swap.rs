@@ -0,0 +1,132 @@
+use super::{
+    count_forbidden_edges_for_vertex, get_coloring_from_class_list, grasp::get_class_list,
+};
+use crate::graph::adj_list::AdjList;
+
+/// Number of neighbors of `vertex` colored with `color` in `coloring`.
+fn conflicts_with_color(graph: &AdjList, coloring: &[usize], vertex: usize, color: usize) -> usize {
+    graph.adj_list()[vertex]
+        .iter()
+        .filter(|u| coloring[**u] == color)
+        .count()
+}
+
+/// Number of forbidden edges of `coloring`.
+fn count_forbidden(graph: &AdjList, coloring: &[usize]) -> usize {
+    (0..graph.num_vertices())
+        .map(|vertex| count_forbidden_edges_for_vertex(graph, coloring, vertex))
+        .sum::<usize>()
+        / 2
+}
+
+/// Moves a subset of the vertices of the class `class_a` of `class_list` to the class `class_b`,
+/// a larger move than recoloring a single vertex.
+///
+/// The vertices of `class_a` are moved one at a time, the one with the largest reduction of its
+/// forbidden edges first, while some move reduces them. Then, the vertices of `class_b` left in
+/// conflict with a moved vertex are recolored with their best class other than the two, if it
+/// reduces their forbidden edges.
+///
+/// Returns `true` (and updates `class_list`) if the number of forbidden edges decreased;
+/// otherwise `class_list` is left untouched.
+pub fn neighborhood_swap(
+    graph: &AdjList,
+    class_list: &mut [Vec<usize>],
+    class_a: usize,
+    class_b: usize,
+) -> bool {
+    let num_classes = class_list.len();
+    let mut coloring = get_coloring_from_class_list(graph.num_vertices(), class_list);
+    // Colors are 1-indexed
+    let (color_a, color_b) = (class_a + 1, class_b + 1);
+    let before = count_forbidden(graph, &coloring);
+    let mut moved = Vec::new();
+
+    loop {
+        let best = class_list[class_a]
+            .iter()
+            .filter(|v| coloring[**v] == color_a)
+            .map(|v| {
+                let gain = conflicts_with_color(graph, &coloring, *v, color_a) as isize
+                    - conflicts_with_color(graph, &coloring, *v, color_b) as isize;
+                (gain, *v)
+            })
+            .filter(|(gain, _)| *gain > 0)
+            .max_by_key(|(gain, v)| (*gain, std::cmp::Reverse(*v)));
+
+        match best {
+            Some((_, vertex)) => {
+                coloring[vertex] = color_b;
+                moved.push(vertex);
+            }
+            None => break,
+        }
+    }
+
+    if moved.is_empty() {
+        return false;
+    }
+
+    let mut displaced: Vec<usize> = moved
+        .iter()
+        .flat_map(|v| graph.adj_list()[*v].iter().copied())
+        .filter(|u| coloring[*u] == color_b && !moved.contains(u))
+        .collect();
+    displaced.sort();
+    displaced.dedup();
+
+    for vertex in displaced {
+        let current = conflicts_with_color(graph, &coloring, vertex, color_b);
+        let best = (1..num_classes + 1)
+            .filter(|color| *color != color_a && *color != color_b)
+            .map(|color| (conflicts_with_color(graph, &coloring, vertex, color), color))
+            .min();
+
+        if let Some((_, color)) = best.filter(|(conflicts, _)| *conflicts < current) {
+            coloring[vertex] = color;
+        }
+    }
+
+    if count_forbidden(graph, &coloring) < before {
+        class_list.clone_from_slice(&get_class_list(&coloring, num_classes));
+
+        true
+    } else {
+        false
+    }
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+    use crate::graph::generators::path_graph;
+
+    #[test]
+    fn test_neighborhood_swap_path() {
+        // A path 0---1---2---3 with the conflict 2---3
+        let graph = path_graph(4);
+        let mut class_list = vec![vec![0, 2, 3], vec![1]];
+
+        assert!(neighborhood_swap(&graph, &mut class_list, 0, 1));
+        assert_eq!(class_list, vec![vec![0, 2], vec![1, 3]]);
+
+        // Nothing to improve on a valid coloring
+        assert!(!neighborhood_swap(&graph, &mut class_list, 0, 1));
+        assert_eq!(class_list, vec![vec![0, 2], vec![1, 3]]);
+    }
+
+    #[test]
+    fn test_neighborhood_swap_ejection() {
+        // A star centered at 0 with leaves 1, 2 and 3
+        let graph = AdjList::complete_minus(4, &[(1, 2), (1, 3), (2, 3)]);
+
+        // Moving 0 to the second class displaces 3 to the third one
+        let mut class_list = vec![vec![0, 1, 2], vec![3], vec![]];
+
+        assert!(neighborhood_swap(&graph, &mut class_list, 0, 1));
+        assert_eq!(class_list, vec![vec![1, 2], vec![0], vec![3]]);
+
+        let coloring = get_coloring_from_class_list(graph.num_vertices(), &class_list);
+        assert!(graph.is_valid_coloring(&coloring));
+    }
+}
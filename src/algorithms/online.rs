@@ -0,0 +1,107 @@
+use super::count_colors;
+use crate::graph::adj_list::AdjList;
+
+/// Colors the vertices of a graph as they arrive, without knowledge of the future ones.
+///
+/// Once assigned, a color never changes. Each vertex receives the smallest color not used by its
+/// neighbors (first-fit).
+pub struct OnlineColoring {
+    graph: AdjList,
+    coloring: Vec<usize>,
+}
+
+impl OnlineColoring {
+    pub fn new() -> Self {
+        OnlineColoring {
+            graph: AdjList::new(0),
+            coloring: Vec::new(),
+        }
+    }
+
+    /// Adds a vertex adjacent to `neighbors` (all of them previously seen) and returns the
+    /// color assigned to it. Colors are 1-indexed.
+    pub fn assign_next_vertex(&mut self, neighbors: &[usize]) -> usize {
+        let vertex = self.graph.add_vertex();
+
+        for neighbor in neighbors {
+            self.graph.add_edge(vertex, *neighbor);
+        }
+
+        let used: Vec<usize> = neighbors.iter().map(|u| self.coloring[*u]).collect();
+        let color = (1..).find(|color| !used.contains(color)).unwrap();
+
+        self.coloring.push(color);
+
+        color
+    }
+
+    pub fn graph(&self) -> &AdjList {
+        &self.graph
+    }
+
+    pub fn coloring(&self) -> &[usize] {
+        &self.coloring
+    }
+
+    pub fn num_colors(&self) -> usize {
+        count_colors(&self.coloring)
+    }
+
+    /// Ratio between the colors used and the optimal number of colors `opt_colors`.
+    pub fn competitive_ratio(&self, opt_colors: usize) -> f64 {
+        self.num_colors() as f64 / opt_colors as f64
+    }
+}
+
+impl Default for OnlineColoring {
+    fn default() -> Self {
+        Self::new()
+    }
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+
+    #[test]
+    fn test_online_path() {
+        let mut online = OnlineColoring::new();
+
+        assert_eq!(online.assign_next_vertex(&[]), 1);
+
+        for i in 1..10 {
+            online.assign_next_vertex(&[i - 1]);
+        }
+
+        assert_eq!(online.num_colors(), 2);
+        assert_eq!(online.competitive_ratio(2), 1.0);
+        assert!(online.graph().is_valid_coloring(online.coloring()));
+    }
+
+    #[test]
+    fn test_online_complete() {
+        let mut online = OnlineColoring::new();
+
+        for i in 0..6 {
+            let neighbors: Vec<usize> = (0..i).collect();
+
+            assert_eq!(online.assign_next_vertex(&neighbors), i + 1);
+        }
+
+        assert_eq!(online.num_colors(), 6);
+        assert!(online.graph().is_valid_coloring(online.coloring()));
+    }
+
+    #[test]
+    fn test_competitive_ratio() {
+        // First-fit is fooled by the order of arrival of a path: 0 - 3 - 2 - 1
+        let mut online = OnlineColoring::new();
+        online.assign_next_vertex(&[]);
+        online.assign_next_vertex(&[]);
+        online.assign_next_vertex(&[1]);
+        online.assign_next_vertex(&[0, 2]);
+
+        assert_eq!(online.num_colors(), 3);
+        assert_eq!(online.competitive_ratio(2), 1.5);
+    }
+}
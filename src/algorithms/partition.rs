@@ -0,0 +1,202 @@
+use super::{get_class_list_from_coloring, get_coloring_from_class_list};
+use crate::graph::adj_list::AdjList;
+use std::cmp::Ordering;
+
+/// The color classes of a coloring, with metrics of how their sizes are distributed, which tell
+/// apart colorings with the same number of colors (e.g., balanced ones suit register allocation
+/// better).
+#[derive(Debug, Clone, PartialEq)]
+pub struct ColorPartition {
+    class_list: Vec<Vec<usize>>,
+}
+
+/// Groups the vertices of `coloring` by color, see [`get_class_list_from_coloring`].
+pub fn coloring_to_partition(coloring: &[usize]) -> ColorPartition {
+    ColorPartition {
+        class_list: get_class_list_from_coloring(coloring),
+    }
+}
+
+impl ColorPartition {
+    pub fn class_list(&self) -> &[Vec<usize>] {
+        &self.class_list
+    }
+
+    pub fn num_classes(&self) -> usize {
+        self.class_list.len()
+    }
+
+    pub fn num_vertices(&self) -> usize {
+        self.class_list.iter().map(Vec::len).sum()
+    }
+
+    /// Sizes of the classes in non-increasing order.
+    pub fn class_sizes(&self) -> Vec<usize> {
+        let mut sizes: Vec<usize> = self.class_list.iter().map(Vec::len).collect();
+        sizes.sort_by(|a, b| b.cmp(a));
+        sizes
+    }
+
+    /// Difference between the sizes of the largest and smallest classes divided by the number of
+    /// vertices, from 0 for equitable colorings up to almost 1. Equals 0 without vertices.
+    pub fn imbalance(&self) -> f64 {
+        let sizes = self.class_sizes();
+
+        match (sizes.first(), sizes.last()) {
+            (Some(largest), Some(smallest)) => {
+                (largest - smallest) as f64 / self.num_vertices() as f64
+            }
+            _ => 0.0,
+        }
+    }
+
+    /// Shannon entropy, in bits, of the distribution of the vertices among the classes, which is
+    /// largest, `log2(k)` for `k` classes, when they all have the same size.
+    pub fn entropy(&self) -> f64 {
+        let n = self.num_vertices() as f64;
+
+        self.class_list
+            .iter()
+            .map(|class| class.len() as f64 / n)
+            .map(|p| -p * p.log2())
+            .sum()
+    }
+}
+
+/// Outcome of [`ColoringComparator::compare`], on each criterion and overall. Every ordering
+/// compares the first coloring to the second, with [`Ordering::Less`] meaning the first one is
+/// better, so sorting by them puts the best colorings first.
+#[derive(Debug, Clone, Copy, PartialEq, Eq)]
+pub struct ComparisonResult {
+    pub fewer_colors: Ordering,
+    pub fewer_conflicts: Ordering,
+    /// On the [`ColorPartition::imbalance`] of the classes.
+    pub more_balanced: Ordering,
+    /// The criteria in order of priority: colors, then conflicts, then balance.
+    pub overall: Ordering,
+}
+
+/// Compares colorings on several criteria at once, without collapsing them into a single score.
+#[derive(Debug, Clone, Copy, Default)]
+pub struct ColoringComparator;
+
+impl ColoringComparator {
+    /// Compares the class lists `a` and `b` of `graph` on their numbers of (non-empty) classes,
+    /// their numbers of forbidden edges and their balance.
+    pub fn compare(a: &[Vec<usize>], b: &[Vec<usize>], graph: &AdjList) -> ComparisonResult {
+        let metrics = |class_list: &[Vec<usize>]| {
+            let partition = ColorPartition {
+                class_list: class_list
+                    .iter()
+                    .filter(|class| !class.is_empty())
+                    .cloned()
+                    .collect(),
+            };
+            let coloring = get_coloring_from_class_list(graph.num_vertices(), class_list);
+            let num_conflicts = graph
+                .edge_list_as_slice()
+                .iter()
+                .filter(|(u, v)| coloring[*u] != 0 && coloring[*u] == coloring[*v])
+                .count();
+
+            (
+                partition.num_classes(),
+                num_conflicts,
+                partition.imbalance(),
+            )
+        };
+        let (colors_a, conflicts_a, imbalance_a) = metrics(a);
+        let (colors_b, conflicts_b, imbalance_b) = metrics(b);
+
+        let fewer_colors = colors_a.cmp(&colors_b);
+        let fewer_conflicts = conflicts_a.cmp(&conflicts_b);
+        let more_balanced = imbalance_a.total_cmp(&imbalance_b);
+
+        ComparisonResult {
+            fewer_colors,
+            fewer_conflicts,
+            more_balanced,
+            overall: fewer_colors.then(fewer_conflicts).then(more_balanced),
+        }
+    }
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+    use crate::graph::generators::cycle_graph;
+
+    #[test]
+    fn test_coloring_to_partition() {
+        // The only coloring of K_6 with 6 colors is equitable
+        let partition = coloring_to_partition(&[1, 2, 3, 4, 5, 6]);
+
+        assert_eq!(partition.num_classes(), 6);
+        assert_eq!(partition.class_sizes(), vec![1; 6]);
+        assert_eq!(partition.imbalance(), 0.0);
+        assert!((partition.entropy() - 6.0f64.log2()).abs() < 1e-9);
+
+        let partition = coloring_to_partition(&[2, 1, 2, 3, 2]);
+
+        assert_eq!(partition.class_list(), &[vec![1], vec![0, 2, 4], vec![3]]);
+        assert_eq!(partition.class_sizes(), vec![3, 1, 1]);
+        assert!((partition.imbalance() - 0.4).abs() < 1e-9);
+
+        // Every vertex but one in the same class
+        let mut coloring = vec![1; 100];
+        coloring[0] = 2;
+        let partition = coloring_to_partition(&coloring);
+
+        assert!((partition.imbalance() - 0.98).abs() < 1e-9);
+        assert!(partition.entropy() < 0.1);
+
+        let partition = coloring_to_partition(&[]);
+
+        assert_eq!(partition.num_classes(), 0);
+        assert_eq!(partition.imbalance(), 0.0);
+        assert_eq!(partition.entropy(), 0.0);
+    }
+
+    #[test]
+    fn test_coloring_comparator() {
+        // C_8, which can be colored with 2 to 8 colors
+        let graph = cycle_graph(8);
+        let three = vec![vec![0, 2, 4], vec![1, 3, 5], vec![6]];
+        let four = vec![vec![0, 2, 4], vec![1, 3, 5], vec![6], vec![7]];
+
+        let result = ColoringComparator::compare(&three, &four, &graph);
+
+        assert_eq!(result.fewer_colors, Ordering::Less);
+        assert_eq!(result.overall, Ordering::Less);
+        assert_eq!(
+            ColoringComparator::compare(&four, &three, &graph).overall,
+            Ordering::Greater
+        );
+
+        // 4---5 is forbidden in the second coloring, which is more balanced
+        let conflicting = vec![vec![0, 2], vec![1, 3], vec![4, 5], vec![6, 7]];
+        let result = ColoringComparator::compare(&four, &conflicting, &graph);
+
+        assert_eq!(result.fewer_colors, Ordering::Equal);
+        assert_eq!(result.fewer_conflicts, Ordering::Less);
+        assert_eq!(result.more_balanced, Ordering::Greater);
+        assert_eq!(result.overall, Ordering::Less);
+
+        // Without conflicts, the classes of size 2 win
+        let balanced = vec![vec![0, 2], vec![1, 3], vec![4, 6], vec![5, 7]];
+        let result = ColoringComparator::compare(&four, &balanced, &graph);
+
+        assert_eq!(result.fewer_conflicts, Ordering::Equal);
+        assert_eq!(result.more_balanced, Ordering::Greater);
+        assert_eq!(result.overall, Ordering::Greater);
+
+        // Empty classes aren't colors
+        let mut padded = balanced.clone();
+        padded.push(Vec::new());
+
+        assert_eq!(
+            ColoringComparator::compare(&padded, &balanced, &graph).overall,
+            Ordering::Equal
+        );
+    }
+}
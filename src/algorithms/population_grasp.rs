@@ -0,0 +1,179 @@
+use super::{
+    get_class_list_from_coloring, get_coloring_from_class_list,
+    grasp::{grasp_iteration, solution_diversity, GraspConfig},
+    grasp_pr::path_relinking,
+    ColoringError,
+};
+use crate::graph::adj_list::AdjList;
+use rand::seq::SliceRandom;
+use rand::Rng;
+
+/// GRASP keeping a population of [`GraspConfig::elite_size`] class lists across iterations,
+/// instead of a single best solution, so that experiments can drive the search one iteration at
+/// a time.
+///
+/// Each iteration builds a solution with the randomized construction and improve phase of
+/// [`grasp_with_config`], relinks it with a random member of the population (see
+/// [`path_relinking`]) and replaces the worst member with the result if it uses fewer colors.
+///
+/// [`grasp_with_config`]: super::grasp::grasp_with_config
+#[derive(Debug, Clone)]
+pub struct PopulationGrasp {
+    config: GraspConfig,
+    population: Vec<Vec<Vec<usize>>>,
+}
+
+impl PopulationGrasp {
+    /// Builds an empty population for `config`, filled by [`PopulationGrasp::initialize`].
+    pub fn new(config: GraspConfig) -> Self {
+        PopulationGrasp {
+            config,
+            population: Vec::new(),
+        }
+    }
+
+    /// Replaces the population with [`GraspConfig::elite_size`] new solutions of `graph`.
+    ///
+    /// Returns [`ColoringError::InvalidConfig`] if the configuration is invalid.
+    pub fn initialize(&mut self, graph: &AdjList, rng: &mut impl Rng) -> Result<(), ColoringError> {
+        self.config.validate()?;
+        self.population.clear();
+
+        while self.population.len() < self.config.elite_size {
+            let ((_, coloring), _) = grasp_iteration(graph, &self.config, None, rng)?;
+            self.population
+                .push(get_class_list_from_coloring(&coloring));
+        }
+
+        Ok(())
+    }
+
+    /// Runs one GRASP iteration on `graph`, relinking the new solution with a random member of
+    /// the population, and returns whether the best solution improved.
+    ///
+    /// The relinked solution joins the population while it is smaller than
+    /// [`GraspConfig::elite_size`], and otherwise replaces the worst member if it uses fewer
+    /// colors, so the size of the population never decreases.
+    pub fn iterate(&mut self, graph: &AdjList, rng: &mut impl Rng) -> Result<bool, ColoringError> {
+        let previous_best = self.population.iter().map(Vec::len).min();
+        let (solution, _) = grasp_iteration(graph, &self.config, None, rng)?;
+
+        let (num_colors, coloring) = match self.population.choose(rng) {
+            Some(member) => {
+                let guiding = get_coloring_from_class_list(graph.num_vertices(), member);
+                path_relinking(graph, &solution.1, &guiding)
+            }
+            None => solution,
+        };
+        let class_list = get_class_list_from_coloring(&coloring);
+
+        if self.population.len() < self.config.elite_size {
+            self.population.push(class_list);
+        } else {
+            // The population is full, and the elite size is at least 1, so we can unwrap
+            let (worst, worst_colors) = self
+                .population
+                .iter()
+                .map(Vec::len)
+                .enumerate()
+                .max_by_key(|(_, num_colors)| *num_colors)
+                .unwrap();
+
+            if num_colors < worst_colors {
+                self.population[worst] = class_list;
+            }
+        }
+
+        Ok(previous_best.is_none_or(|best| num_colors < best))
+    }
+
+    /// The member of the population with the fewest colors, or an empty class list if the
+    /// population is empty.
+    pub fn best(&self) -> &[Vec<usize>] {
+        self.population
+            .iter()
+            .min_by_key(|class_list| class_list.len())
+            .map_or(&[], Vec::as_slice)
+    }
+
+    /// Average pairwise Hamming distance between the members of the population, see
+    /// [`solution_diversity`].
+    pub fn diversity(&self) -> f64 {
+        let num_vertices = self.best().iter().map(Vec::len).sum();
+        let colorings: Vec<Vec<usize>> = self
+            .population
+            .iter()
+            .map(|class_list| get_coloring_from_class_list(num_vertices, class_list))
+            .collect();
+        let colorings: Vec<&[usize]> = colorings.iter().map(Vec::as_slice).collect();
+
+        solution_diversity(&colorings, num_vertices)
+    }
+
+    /// The class lists of the population.
+    pub fn population(&self) -> &[Vec<Vec<usize>>] {
+        &self.population
+    }
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+    use crate::input;
+    use rand::{rngs::SmallRng, SeedableRng};
+
+    #[test]
+    fn test_population_grasp() {
+        if let Ok(Some(graph)) = input::read_graph_from_file("data/myc/myciel4.col") {
+            let mut population = PopulationGrasp::new(GraspConfig::new(1, 3, 3).elite_size(4));
+            let mut rng = SmallRng::seed_from_u64(0);
+
+            population.initialize(&graph, &mut rng).unwrap();
+
+            assert_eq!(population.population().len(), 4);
+
+            let mut best_colors = population.best().len();
+
+            for _ in 0..20 {
+                let improved = population.iterate(&graph, &mut rng).unwrap();
+
+                assert_eq!(population.population().len(), 4);
+                assert!(population.best().len() <= best_colors);
+                assert_eq!(improved, population.best().len() < best_colors);
+                assert!(population
+                    .population()
+                    .iter()
+                    .all(|class_list| graph.is_valid_class_list(class_list)));
+
+                best_colors = population.best().len();
+            }
+
+            // The chromatic number of myciel4 is 5
+            assert!(best_colors >= 5);
+            assert!(population.diversity() >= 0.0);
+        } else {
+            panic!("The file containing the test graph is missing")
+        }
+    }
+
+    #[test]
+    fn test_population_grasp_without_initialization() {
+        let graph = AdjList::complete(3);
+        let mut population = PopulationGrasp::new(GraspConfig::new(1, 1, 1).elite_size(2));
+        let mut rng = SmallRng::seed_from_u64(0);
+
+        assert!(population.best().is_empty());
+        assert_eq!(population.diversity(), 0.0);
+        assert!(population.iterate(&graph, &mut rng).unwrap());
+        assert!(!population.iterate(&graph, &mut rng).unwrap());
+        assert_eq!(population.population().len(), 2);
+        assert_eq!(population.best().len(), 3);
+
+        let mut population = PopulationGrasp::new(GraspConfig::new(1, 1, 0));
+
+        assert!(matches!(
+            population.initialize(&graph, &mut rng),
+            Err(ColoringError::InvalidConfig(_))
+        ));
+    }
+}
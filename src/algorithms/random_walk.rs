@@ -0,0 +1,183 @@
+use super::color_assignment::ColorAssignment;
+use crate::graph::adj_list::AdjList;
+use rand::Rng;
+
+/// Random walk over the colorings of a graph with a fixed number of colors, recording the number
+/// of forbidden edges after each step, to study the structure of the search landscape.
+///
+/// Each step moves a random vertex to a random color other than its own.
+#[derive(Debug, Clone)]
+pub struct RandomWalkColoring {
+    graph: AdjList,
+    assignment: ColorAssignment,
+    steps_taken: usize,
+    forbidden_count_history: Vec<usize>,
+    best_forbidden_count: usize,
+}
+
+impl RandomWalkColoring {
+    /// Starts a walk on `graph` from `class_list`, which must cover all vertices, with as many
+    /// colors as classes.
+    pub fn new(graph: AdjList, class_list: &[Vec<usize>]) -> Self {
+        let assignment = ColorAssignment::from_class_list(&graph, class_list);
+        let forbidden_count = assignment.conflict_count();
+
+        RandomWalkColoring {
+            graph,
+            assignment,
+            steps_taken: 0,
+            forbidden_count_history: vec![forbidden_count],
+            best_forbidden_count: forbidden_count,
+        }
+    }
+
+    /// Moves a random vertex to a random color other than its own. With less than two colors or
+    /// no vertices the coloring is left unchanged, but the step is still recorded.
+    pub fn step(&mut self, rng: &mut impl Rng) {
+        let n = self.graph.num_vertices();
+        let k = self.assignment.num_colors();
+
+        if n > 0 && k > 1 {
+            let vertex = rng.gen_range(0..n);
+            // The class list covers all vertices, so we can unwrap
+            let current = self.assignment.color_of(vertex).unwrap();
+            // Skips the current color
+            let mut color = rng.gen_range(1..k);
+            if color >= current {
+                color += 1;
+            }
+
+            self.assignment.assign(&self.graph, vertex, color);
+        }
+
+        let forbidden_count = self.assignment.conflict_count();
+
+        self.steps_taken += 1;
+        self.forbidden_count_history.push(forbidden_count);
+        self.best_forbidden_count = self.best_forbidden_count.min(forbidden_count);
+    }
+
+    pub fn graph(&self) -> &AdjList {
+        &self.graph
+    }
+
+    /// The current coloring as a class list, each class sorted.
+    pub fn class_list(&self) -> Vec<Vec<usize>> {
+        self.assignment.to_class_list()
+    }
+
+    pub fn steps_taken(&self) -> usize {
+        self.steps_taken
+    }
+
+    /// Number of forbidden edges of the current coloring.
+    pub fn forbidden_count(&self) -> usize {
+        self.assignment.conflict_count()
+    }
+
+    /// Number of forbidden edges at the start and after each step.
+    pub fn forbidden_count_history(&self) -> &[usize] {
+        &self.forbidden_count_history
+    }
+
+    /// Fewest forbidden edges seen along the walk, including its start.
+    pub fn best_forbidden_count(&self) -> usize {
+        self.best_forbidden_count
+    }
+
+    /// Estimates the number of steps after which the forbidden count is uncorrelated with the
+    /// starting one, by the correlation length `-1 / ln(ρ(1))`, where `ρ(1)` is the
+    /// autocorrelation of [`RandomWalkColoring::forbidden_count_history`] between consecutive
+    /// steps.
+    ///
+    /// Returns 0 when the history is constant or `ρ(1)` isn't positive, so that no correlation is
+    /// observed, and infinity when `ρ(1)` is at least 1.
+    pub fn autocorrelation_length(&self) -> f64 {
+        let history = &self.forbidden_count_history;
+        let len = history.len() as f64;
+        let mean = history.iter().sum::<usize>() as f64 / len;
+
+        let variance = history
+            .iter()
+            .map(|count| (*count as f64 - mean).powi(2))
+            .sum::<f64>()
+            / len;
+
+        if variance == 0.0 {
+            return 0.0;
+        }
+
+        let covariance = history
+            .windows(2)
+            .map(|pair| (pair[0] as f64 - mean) * (pair[1] as f64 - mean))
+            .sum::<f64>()
+            / (len - 1.0);
+        let correlation = covariance / variance;
+
+        if correlation <= 0.0 {
+            0.0
+        } else if correlation >= 1.0 {
+            f64::INFINITY
+        } else {
+            -1.0 / correlation.ln()
+        }
+    }
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+    use crate::{
+        algorithms::{get_class_list_from_coloring, greedy::dsatur},
+        graph::generators::cycle_graph,
+        input,
+    };
+    use rand::{rngs::SmallRng, SeedableRng};
+
+    #[test]
+    fn test_random_walk_coloring() {
+        let graph = cycle_graph(6);
+        let class_list = vec![vec![0, 2, 4], vec![1, 3, 5]];
+        let mut walk = RandomWalkColoring::new(graph, &class_list);
+        let mut rng = SmallRng::seed_from_u64(0);
+
+        assert_eq!(walk.steps_taken(), 0);
+        assert_eq!(walk.forbidden_count(), 0);
+        assert_eq!(walk.forbidden_count_history(), &[0]);
+        assert_eq!(walk.autocorrelation_length(), 0.0);
+
+        // Any move of a vertex of the 2-coloring of C_6 conflicts with both of its neighbors
+        walk.step(&mut rng);
+
+        assert_eq!(walk.steps_taken(), 1);
+        assert!(walk.forbidden_count() > 0);
+        assert_eq!(walk.forbidden_count_history(), &[0, 2]);
+        assert_eq!(walk.best_forbidden_count(), 0);
+        assert_eq!(walk.class_list().concat().len(), 6);
+    }
+
+    #[test]
+    fn test_autocorrelation_length() {
+        if let Ok(Some(graph)) = input::read_graph_from_file("data/myc/myciel4.col") {
+            let (_, coloring) = dsatur(&graph);
+            let class_list = get_class_list_from_coloring(&coloring);
+            let mut walk = RandomWalkColoring::new(graph, &class_list);
+            let mut rng = SmallRng::seed_from_u64(0);
+
+            for _ in 0..1000 {
+                walk.step(&mut rng);
+            }
+
+            assert_eq!(walk.forbidden_count_history().len(), 1001);
+            assert_eq!(walk.best_forbidden_count(), 0);
+
+            // Each step changes the conflicts of a single vertex, so consecutive counts are
+            // strongly correlated
+            let length = walk.autocorrelation_length();
+
+            assert!(length > 1.0 && length.is_finite());
+        } else {
+            panic!("The file containing the test graph is missing")
+        }
+    }
+}
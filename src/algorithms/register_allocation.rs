@@ -0,0 +1,87 @@
+use super::{
+    grasp::{grasp_with_config, GraspConfig},
+    ColoringError,
+};
+use crate::graph::adj_list::AdjList;
+
+/// Approximates the fewest color classes, i.e., independent sets, that cover `graph`: its
+/// chromatic number, by the number of colors of a GRASP coloring with the default parameters.
+pub fn min_coloring_classes_needed(graph: &AdjList) -> usize {
+    if graph.num_vertices() == 0 {
+        return 0;
+    }
+
+    // The default GRASP parameters are valid, so we can unwrap
+    grasp_with_config(graph, &GraspConfig::default()).unwrap().0
+}
+
+/// Assigns one of `num_registers` registers to each variable of `interference_graph`, whose
+/// edges join the variables that are live at the same time, so that interfering variables get
+/// different registers.
+///
+/// The assignment is a coloring with at most `num_registers` colors found by GRASP (see
+/// [`GraspConfig::color_upper_bound`]), with the registers as the colors from 1 onwards. Returns
+/// `None` if GRASP doesn't find one, e.g., when there are too few registers.
+pub fn register_allocation_coloring(
+    interference_graph: &AdjList,
+    num_registers: usize,
+) -> Option<Vec<usize>> {
+    if interference_graph.num_vertices() == 0 {
+        return Some(Vec::new());
+    }
+
+    let config = GraspConfig::default().color_upper_bound(num_registers);
+
+    match grasp_with_config(interference_graph, &config) {
+        Ok((_, coloring)) => Some(coloring),
+        Err(ColoringError::NoBoundedSolutionFound { .. }) => None,
+        // The default GRASP parameters are valid, so no other error can happen
+        Err(err) => unreachable!("{err}"),
+    }
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+    use crate::graph::generators::path_graph;
+
+    /// Interference graph of the program
+    ///
+    /// ```text
+    /// a = 1; b = 2; c = a + b; d = a * c; e = b + d; return c + e
+    /// ```
+    ///
+    /// where `a`, `b` and `c` are live together, as are `b`, `c` and `d`, and then `c` and `e`.
+    fn toy_program() -> AdjList {
+        let (a, b, c, d, e) = (0, 1, 2, 3, 4);
+        let mut graph = AdjList::new(5);
+        for (u, v) in [(a, b), (a, c), (b, c), (b, d), (c, d), (c, e)] {
+            graph.add_edge(u, v);
+        }
+
+        graph
+    }
+
+    #[test]
+    fn test_register_allocation_coloring() {
+        for (graph, registers) in [
+            (AdjList::complete(4), 4),
+            (path_graph(4), 2),
+            (toy_program(), 3),
+        ] {
+            assert_eq!(min_coloring_classes_needed(&graph), registers);
+
+            let coloring = register_allocation_coloring(&graph, registers).unwrap();
+
+            assert!(graph.is_valid_coloring(&coloring));
+            assert!(coloring.iter().all(|register| *register <= registers));
+            assert_eq!(register_allocation_coloring(&graph, registers - 1), None);
+        }
+
+        assert_eq!(
+            register_allocation_coloring(&AdjList::new(0), 0),
+            Some(vec![])
+        );
+        assert_eq!(min_coloring_classes_needed(&AdjList::new(0)), 0);
+    }
+}
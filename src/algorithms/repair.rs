@@ -0,0 +1,116 @@
+use super::{
+    count_forbidden_edges_for_vertex, get_coloring_from_class_list,
+    grasp::{get_class_list, get_forbidden_vertices},
+};
+use crate::graph::adj_list::AdjList;
+
+/// Tries to remove the forbidden edges of `class_list` with at most `max_iterations` moves, for
+/// colorings that are already close to valid (e.g., after a perturbation).
+///
+/// Each iteration recolors the conflicting vertex (see [`get_forbidden_vertices`]) whose move to
+/// another class removes the most forbidden edges, ties being broken by the smallest vertex and
+/// color. Unlike the local search, moves that don't reduce the number of forbidden edges are never
+/// made, so the repair stops early when there is none left. The number of classes is kept.
+///
+/// Returns `true` if every forbidden edge was removed.
+pub fn repair_coloring(
+    graph: &AdjList,
+    class_list: &mut [Vec<usize>],
+    max_iterations: usize,
+) -> bool {
+    let num_classes = class_list.len();
+    let mut coloring = get_coloring_from_class_list(graph.num_vertices(), class_list);
+
+    for _ in 0..max_iterations {
+        let (num_forbidden, forbidden) =
+            get_forbidden_vertices(graph, &get_class_list(&coloring, num_classes));
+
+        if num_forbidden == 0 {
+            break;
+        }
+
+        let mut forbidden: Vec<usize> = forbidden.into_iter().collect();
+        forbidden.sort();
+
+        // Best move as (vertex, color, number of forbidden edges removed)
+        let mut best: Option<(usize, usize, usize)> = None;
+
+        for vertex in forbidden {
+            let original_color = coloring[vertex];
+            let before = count_forbidden_edges_for_vertex(graph, &coloring, vertex);
+
+            // Colors are 1-indexed
+            for color in (1..num_classes + 1).filter(|color| *color != original_color) {
+                coloring[vertex] = color;
+                let after = count_forbidden_edges_for_vertex(graph, &coloring, vertex);
+
+                if after < before && best.is_none_or(|(_, _, gain)| before - after > gain) {
+                    best = Some((vertex, color, before - after));
+                }
+            }
+
+            coloring[vertex] = original_color;
+        }
+
+        match best {
+            Some((vertex, color, _)) => coloring[vertex] = color,
+            None => break,
+        }
+    }
+
+    class_list.clone_from_slice(&get_class_list(&coloring, num_classes));
+
+    get_forbidden_vertices(graph, class_list).0 == 0
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+    use crate::{algorithms::greedy::dsatur, input};
+
+    #[test]
+    fn test_repair_coloring() {
+        if let Ok(Some(graph)) = input::read_graph_from_file("data/myc/myciel4.col") {
+            let (num_colors, mut coloring) = dsatur(&graph);
+
+            // Gives vertex 0 the color of exactly one of its neighbors
+            let neighbors = &graph.adj_list()[0];
+            let color = neighbors
+                .iter()
+                .map(|u| coloring[*u])
+                .find(|color| neighbors.iter().filter(|u| coloring[**u] == *color).count() == 1)
+                .unwrap();
+            coloring[0] = color;
+            let mut class_list = get_class_list(&coloring, num_colors);
+
+            assert_eq!(get_forbidden_vertices(&graph, &class_list).0, 1);
+            assert!(repair_coloring(
+                &graph,
+                &mut class_list,
+                graph.num_vertices()
+            ));
+            assert_eq!(class_list.len(), num_colors);
+
+            let coloring = get_coloring_from_class_list(graph.num_vertices(), &class_list);
+            assert!(graph.is_valid_coloring(&coloring));
+        } else {
+            panic!("The file containing the test graph is missing")
+        }
+    }
+
+    #[test]
+    fn test_repair_coloring_stuck() {
+        // A triangle with only 2 colors can't be repaired
+        let graph = AdjList::complete(3);
+        let mut class_list = vec![vec![0, 1], vec![2]];
+
+        assert!(!repair_coloring(&graph, &mut class_list, 10));
+        assert_eq!(get_forbidden_vertices(&graph, &class_list).0, 1);
+
+        // No moves are needed for a valid coloring
+        let mut class_list = vec![vec![0], vec![1], vec![2]];
+
+        assert!(repair_coloring(&graph, &mut class_list, 0));
+        assert_eq!(class_list, vec![vec![0], vec![1], vec![2]]);
+    }
+}
@@ -0,0 +1,94 @@
+use super::{ColoringAlgorithm, Solution};
+use crate::graph::adj_list::AdjList;
+use std::cmp::Reverse;
+
+/// Colors `graph` with the Recursive Largest First heuristic, building one color class at a time.
+///
+/// Each class starts with the uncolored vertex with the most uncolored neighbors. Then, while
+/// there are uncolored vertices not adjacent to the class, the one with the most neighbors
+/// adjacent to the class is added (ties are broken by the fewest neighbors among the remaining
+/// candidates).
+pub fn rlf(graph: &AdjList) -> Solution {
+    let mut coloring = vec![0; graph.num_vertices()];
+    let mut uncolored: Vec<usize> = (0..graph.num_vertices()).collect();
+    let mut num_colors = 0;
+
+    while !uncolored.is_empty() {
+        num_colors += 1;
+
+        // Uncolored vertices that can still join the class, and the ones adjacent to it
+        let mut candidates = uncolored.clone();
+        let mut adjacent: Vec<usize> = Vec::new();
+
+        // There are uncolored vertices left, so we can unwrap
+        let mut vertex = *candidates
+            .iter()
+            .max_by_key(|v| graph.get_degree_in_list(**v, &uncolored))
+            .unwrap();
+
+        loop {
+            coloring[vertex] = num_colors;
+
+            let neighbors = &graph.adj_list()[vertex];
+            candidates.retain(|v| *v != vertex && !neighbors.contains(v));
+            adjacent.extend(neighbors.iter().filter(|v| coloring[**v] == 0));
+            adjacent.sort();
+            adjacent.dedup();
+
+            let next = candidates.iter().max_by_key(|v| {
+                (
+                    graph.get_degree_in_list(**v, &adjacent),
+                    Reverse(graph.get_degree_in_list(**v, &candidates)),
+                )
+            });
+
+            match next {
+                Some(next) => vertex = *next,
+                None => break,
+            }
+        }
+
+        uncolored.retain(|v| coloring[*v] == 0);
+    }
+
+    (num_colors, coloring)
+}
+
+/// [`ColoringAlgorithm`] running [`rlf`].
+#[derive(Debug, Clone, Copy, Default)]
+pub struct RlfSolver;
+
+impl ColoringAlgorithm for RlfSolver {
+    fn solve(&self, graph: &AdjList) -> Solution {
+        rlf(graph)
+    }
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+    use crate::{algorithms::count_colors, input};
+
+    #[test]
+    fn test_rlf_complete() {
+        for n in 1..6 {
+            assert_eq!(rlf(&AdjList::complete(n)).0, n);
+        }
+    }
+
+    #[test]
+    fn test_rlf() {
+        // The chromatic numbers of myciel3 and myciel4 are 4 and 5
+        for (file, chromatic_number) in [("data/myc/myciel3.col", 4), ("data/myc/myciel4.col", 5)] {
+            if let Ok(Some(graph)) = input::read_graph_from_file(file) {
+                let (num_colors, coloring) = rlf(&graph);
+
+                assert_eq!(num_colors, chromatic_number);
+                assert_eq!(count_colors(&coloring), num_colors);
+                assert!(graph.is_valid_coloring(&coloring));
+            } else {
+                panic!("The file containing the test graph is missing")
+            }
+        }
+    }
+}
@@ -0,0 +1,257 @@
+use super::Solution;
+use crate::graph::adj_list::AdjList;
+
+/// A literal is a variable index and its polarity (`true` for the positive literal).
+type Literal = (usize, bool);
+
+/// A formula in Conjunctive Normal Form.
+struct Cnf {
+    num_variables: usize,
+    clauses: Vec<Vec<Literal>>,
+}
+
+/// Index of the variable `x_{v,c}`, which is true iff vertex `v` receives color `c` (0-indexed).
+fn variable(vertex: usize, color: usize, k: usize) -> usize {
+    vertex * k + color
+}
+
+/// Encodes the `k`-coloring of `graph` as a SAT instance.
+///
+/// Each vertex receives at least one and at most one color, and adjacent vertices can't share
+/// the same color.
+fn encode(graph: &AdjList, k: usize) -> Cnf {
+    let num_vertices = graph.num_vertices();
+    let mut clauses = Vec::new();
+
+    for v in 0..num_vertices {
+        clauses.push((0..k).map(|c| (variable(v, c, k), true)).collect());
+
+        for c1 in 0..k {
+            for c2 in c1 + 1..k {
+                clauses.push(vec![
+                    (variable(v, c1, k), false),
+                    (variable(v, c2, k), false),
+                ]);
+            }
+        }
+
+        for u in graph.adj_list()[v].iter().filter(|u| **u > v) {
+            for c in 0..k {
+                clauses.push(vec![
+                    (variable(v, c, k), false),
+                    (variable(*u, c, k), false),
+                ]);
+            }
+        }
+    }
+
+    Cnf {
+        num_variables: num_vertices * k,
+        clauses,
+    }
+}
+
+/// Value of `literal` under the (partial) `assignment`.
+fn literal_value(assignment: &[Option<bool>], (variable, polarity): Literal) -> Option<bool> {
+    assignment[variable].map(|value| value == polarity)
+}
+
+/// Applies Boolean Constraint Propagation: while there is a clause with a single unassigned
+/// literal (and the remaining ones false), that literal is forced to be true.
+///
+/// Every forced variable is pushed to `trail`. Returns `false` if a clause becomes unsatisfied.
+fn unit_propagate(cnf: &Cnf, assignment: &mut [Option<bool>], trail: &mut Vec<usize>) -> bool {
+    let mut changed = true;
+
+    while changed {
+        changed = false;
+
+        for clause in cnf.clauses.iter() {
+            let mut satisfied = false;
+            let mut unassigned = None;
+            let mut num_unassigned = 0;
+
+            for literal in clause.iter() {
+                match literal_value(assignment, *literal) {
+                    Some(true) => {
+                        satisfied = true;
+                        break;
+                    }
+                    Some(false) => {}
+                    None => {
+                        num_unassigned += 1;
+                        unassigned = Some(*literal);
+                    }
+                }
+            }
+
+            if satisfied {
+                continue;
+            }
+
+            match (num_unassigned, unassigned) {
+                (0, _) => return false,
+                (1, Some((variable, polarity))) => {
+                    assignment[variable] = Some(polarity);
+                    trail.push(variable);
+                    changed = true;
+                }
+                _ => {}
+            }
+        }
+    }
+
+    true
+}
+
+/// Solves `cnf` with the DPLL backtracking procedure, extending `assignment` into a model if
+/// it is satisfiable.
+fn dpll(cnf: &Cnf, assignment: &mut [Option<bool>]) -> bool {
+    let mut trail = Vec::new();
+
+    if unit_propagate(cnf, assignment, &mut trail) {
+        match assignment.iter().position(|value| value.is_none()) {
+            None => return true,
+            Some(variable) => {
+                for value in [true, false] {
+                    assignment[variable] = Some(value);
+
+                    if dpll(cnf, assignment) {
+                        return true;
+                    }
+                }
+
+                assignment[variable] = None;
+            }
+        }
+    }
+
+    for variable in trail {
+        assignment[variable] = None;
+    }
+
+    false
+}
+
+/// Checks whether `graph` admits a coloring with (at most) `k` colors.
+pub fn is_k_colorable(graph: &AdjList, k: usize) -> bool {
+    find_k_coloring_via_sat(graph, k).is_some()
+}
+
+/// Looks for a coloring of `graph` with (at most) `k` colors by solving its SAT encoding.
+///
+/// Colors are 1-indexed.
+pub fn find_k_coloring_via_sat(graph: &AdjList, k: usize) -> Option<Vec<usize>> {
+    let cnf = encode(graph, k);
+    let mut assignment = vec![None; cnf.num_variables];
+
+    if !dpll(&cnf, &mut assignment) {
+        return None;
+    }
+
+    let coloring = (0..graph.num_vertices())
+        .map(|v| {
+            // A model assigns exactly one color to each vertex
+            (0..k)
+                .position(|c| assignment[variable(v, c, k)] == Some(true))
+                .unwrap()
+                + 1
+        })
+        .collect();
+
+    Some(coloring)
+}
+
+/// Colors `graph` with the fewest colors, trying each number of colors from the size of a greedy
+/// clique (see [`AdjList::clique_lower_bound`]) with [`find_k_coloring_via_sat`], which takes
+/// exponential time. Returns the chromatic number along with the coloring.
+pub fn exact_chromatic_number(graph: &AdjList) -> Solution {
+    if graph.num_vertices() == 0 {
+        return (0, Vec::new());
+    }
+
+    // Every graph is n-colorable, so the search ends
+    (graph.clique_lower_bound().max(1)..=graph.num_vertices())
+        .find_map(|k| find_k_coloring_via_sat(graph, k).map(|coloring| (k, coloring)))
+        .unwrap()
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+    use crate::input;
+
+    #[test]
+    fn test_encode() {
+        let graph = AdjList::complete(3);
+        let cnf = encode(&graph, 2);
+
+        assert_eq!(cnf.num_variables, 6);
+        // 3 at-least-one, 3 at-most-one and 3 * 2 edge clauses
+        assert_eq!(cnf.clauses.len(), 12);
+    }
+
+    #[test]
+    fn test_unit_propagate() {
+        // (x0) and (!x0 or x1)
+        let cnf = Cnf {
+            num_variables: 2,
+            clauses: vec![vec![(0, true)], vec![(0, false), (1, true)]],
+        };
+        let mut assignment = vec![None; 2];
+        let mut trail = Vec::new();
+
+        assert!(unit_propagate(&cnf, &mut assignment, &mut trail));
+        assert_eq!(assignment, vec![Some(true), Some(true)]);
+        assert_eq!(trail, vec![0, 1]);
+
+        // (x0) and (!x0)
+        let cnf = Cnf {
+            num_variables: 1,
+            clauses: vec![vec![(0, true)], vec![(0, false)]],
+        };
+        let mut assignment = vec![None; 1];
+
+        assert!(!unit_propagate(&cnf, &mut assignment, &mut Vec::new()));
+    }
+
+    #[test]
+    fn test_is_k_colorable() {
+        let graph = AdjList::complete(5);
+
+        assert!(!is_k_colorable(&graph, 4));
+        assert!(is_k_colorable(&graph, 5));
+    }
+
+    #[test]
+    fn test_exact_chromatic_number() {
+        if let Ok(Some(graph)) = input::read_graph_from_file("data/myc/myciel3.col") {
+            let (num_colors, coloring) = exact_chromatic_number(&graph);
+
+            assert_eq!(num_colors, 4);
+            assert!(graph.is_valid_coloring(&coloring));
+        } else {
+            panic!("The file containing the test graph is missing")
+        }
+
+        assert_eq!(exact_chromatic_number(&AdjList::new(3)), (1, vec![1, 1, 1]));
+        assert_eq!(exact_chromatic_number(&AdjList::new(0)), (0, vec![]));
+    }
+
+    #[test]
+    fn test_find_k_coloring_via_sat() {
+        if let Ok(Some(graph)) = input::read_graph_from_file("data/myc/myciel3.col") {
+            // The chromatic number of myciel3 is 4
+            assert!(find_k_coloring_via_sat(&graph, 3).is_none());
+
+            if let Some(coloring) = find_k_coloring_via_sat(&graph, 4) {
+                assert!(graph.is_valid_coloring(&coloring));
+                assert!(coloring.iter().all(|color| (1..=4).contains(color)));
+            } else {
+                panic!("myciel3 should be 4-colorable")
+            }
+        } else {
+            panic!("The file containing the test graph is missing")
+        }
+    }
+}
@@ -0,0 +1,83 @@
+use crate::graph::adj_list::AdjList;
+use std::fmt;
+
+/// Criterion used to build the candidate lists of the GRASP construction (see
+/// [`super::independent_set::maximal_independent_set_with_scorer`]), where the candidates with
+/// the highest scores are kept.
+pub trait VertexScorer: fmt::Debug + Send + Sync {
+    /// Score of `vertex` of `graph`, where `subset` holds the vertices that are still uncolored.
+    fn score(&self, vertex: usize, graph: &AdjList, subset: &[usize]) -> f64;
+}
+
+/// Scores a vertex by its number of uncolored neighbors.
+#[derive(Debug, Clone, Copy, Default)]
+pub struct DegreeScorer;
+
+impl VertexScorer for DegreeScorer {
+    fn score(&self, vertex: usize, graph: &AdjList, subset: &[usize]) -> f64 {
+        graph.get_degree_in_list(vertex, subset) as f64
+    }
+}
+
+/// Scores a vertex by its number of colored neighbors, i.e., the ones outside `subset`, an
+/// approximation of its saturation degree (the number of distinct colors among them) that
+/// doesn't need the colors themselves.
+#[derive(Debug, Clone, Copy, Default)]
+pub struct SaturationScorer;
+
+impl VertexScorer for SaturationScorer {
+    fn score(&self, vertex: usize, graph: &AdjList, subset: &[usize]) -> f64 {
+        (graph.get_degree(vertex) - graph.get_degree_in_list(vertex, subset)) as f64
+    }
+}
+
+/// Scores every vertex with a uniform random number drawn from `thread_rng`.
+#[derive(Debug, Clone, Copy, Default)]
+pub struct RandomScorer;
+
+impl VertexScorer for RandomScorer {
+    fn score(&self, _vertex: usize, _graph: &AdjList, _subset: &[usize]) -> f64 {
+        rand::random()
+    }
+}
+
+/// Scores a vertex by the sum of the scores of the scorers, each multiplied by its weight.
+/// Extra scorers or weights are ignored.
+#[derive(Debug)]
+pub struct CombinedScorer(pub Vec<Box<dyn VertexScorer>>, pub Vec<f64>);
+
+impl VertexScorer for CombinedScorer {
+    fn score(&self, vertex: usize, graph: &AdjList, subset: &[usize]) -> f64 {
+        self.0
+            .iter()
+            .zip(self.1.iter())
+            .map(|(scorer, weight)| weight * scorer.score(vertex, graph, subset))
+            .sum()
+    }
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+    use crate::graph::generators::path_graph;
+
+    #[test]
+    fn test_scorers() {
+        // A path 0---1---2---3 where 0 and 1 are colored
+        let graph = path_graph(4);
+        let subset = [2, 3];
+
+        assert_eq!(DegreeScorer.score(2, &graph, &subset), 1.0);
+        assert_eq!(SaturationScorer.score(2, &graph, &subset), 1.0);
+        assert_eq!(SaturationScorer.score(3, &graph, &subset), 0.0);
+        assert!((0.0..1.0).contains(&RandomScorer.score(2, &graph, &subset)));
+
+        let combined = CombinedScorer(
+            vec![Box::new(DegreeScorer), Box::new(SaturationScorer)],
+            vec![2.0, 0.5],
+        );
+
+        assert_eq!(combined.score(2, &graph, &subset), 2.5);
+        assert_eq!(combined.score(3, &graph, &subset), 2.0);
+    }
+}
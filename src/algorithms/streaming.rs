@@ -0,0 +1,145 @@
+use super::count_colors;
+use crate::graph::adj_list::AdjList;
+
+/// Change of color of a vertex made by [`StreamingColoring::process_edge`]. Colors are
+/// 1-indexed.
+#[derive(Debug, Clone, Copy, PartialEq, Eq)]
+pub struct RecoloringMove {
+    pub vertex: usize,
+    pub from: usize,
+    pub to: usize,
+}
+
+/// Colors a graph whose edges arrive one at a time, keeping the coloring valid after each one.
+///
+/// Every vertex starts with color 1. When an edge joins two vertices of the same color, one of
+/// them moves to the smallest color among the `k` target ones unused by its neighbors. If both
+/// endpoints have all `k` colors around them, one gets a color beyond `k`, so the coloring stays
+/// valid but may use more than `k` colors.
+pub struct StreamingColoring {
+    graph: AdjList,
+    coloring: Vec<usize>,
+    k: usize,
+}
+
+impl StreamingColoring {
+    /// Starts with the `n` vertices and no edges, aiming for `k` colors.
+    pub fn new(n: usize, k: usize) -> Self {
+        StreamingColoring {
+            graph: AdjList::new(n),
+            coloring: vec![1; n],
+            k,
+        }
+    }
+
+    /// Smallest color not used by the neighbors of `vertex`.
+    fn smallest_free_color(&self, vertex: usize) -> usize {
+        let used: Vec<usize> = self.graph.adj_list()[vertex]
+            .iter()
+            .map(|u| self.coloring[*u])
+            .collect();
+
+        // There are finitely many neighbors, so we can unwrap
+        (1..).find(|color| !used.contains(color)).unwrap()
+    }
+
+    /// Adds the edge between `u` and `v` and, if they share a color, recolors one of them,
+    /// returning the move. `v` is preferred, unless only `u` has a free color among the target
+    /// ones. Edges already seen need no move.
+    pub fn process_edge(&mut self, u: usize, v: usize) -> Option<RecoloringMove> {
+        self.graph.add_edge(u, v);
+
+        if self.coloring[u] != self.coloring[v] {
+            return None;
+        }
+
+        let (vertex, to) = [v, u]
+            .into_iter()
+            .map(|vertex| (vertex, self.smallest_free_color(vertex)))
+            .find(|(_, color)| *color <= self.k)
+            .unwrap_or_else(|| (v, self.smallest_free_color(v)));
+
+        let from = self.coloring[vertex];
+        self.coloring[vertex] = to;
+
+        Some(RecoloringMove { vertex, from, to })
+    }
+
+    /// The graph of the edges seen so far.
+    pub fn graph(&self) -> &AdjList {
+        &self.graph
+    }
+
+    pub fn coloring(&self) -> &[usize] {
+        &self.coloring
+    }
+
+    /// Number of colors used by the coloring.
+    pub fn color_count(&self) -> usize {
+        count_colors(&self.coloring)
+    }
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+    use crate::input;
+
+    #[test]
+    fn test_streaming_coloring() {
+        if let Ok(Some(graph)) = input::read_graph_from_file("data/myc/myciel3.col") {
+            let mut streaming = StreamingColoring::new(graph.num_vertices(), 4);
+
+            for (u, v) in graph.edge_list_as_slice().iter().copied() {
+                let before = streaming.coloring().to_vec();
+                let recoloring = streaming.process_edge(u, v);
+
+                // At most one vertex changes, as described by the move
+                match recoloring {
+                    Some(RecoloringMove { vertex, from, to }) => {
+                        assert!(vertex == u || vertex == v);
+                        assert_eq!(before[vertex], from);
+                        assert_eq!(streaming.coloring()[vertex], to);
+                    }
+                    None => assert_eq!(streaming.coloring(), before),
+                }
+
+                assert!(streaming.graph().is_valid_coloring(streaming.coloring()));
+            }
+
+            assert_eq!(streaming.graph().num_edges(), graph.num_edges());
+            assert!(graph.is_valid_coloring(streaming.coloring()));
+            assert!(streaming.color_count() >= 4);
+        } else {
+            panic!("The file containing the test graph is missing")
+        }
+    }
+
+    #[test]
+    fn test_streaming_coloring_moves() {
+        let mut streaming = StreamingColoring::new(3, 2);
+
+        assert_eq!(
+            streaming.process_edge(0, 1),
+            Some(RecoloringMove {
+                vertex: 1,
+                from: 1,
+                to: 2
+            })
+        );
+        assert_eq!(streaming.process_edge(1, 2), None);
+        assert_eq!(streaming.process_edge(0, 1), None);
+        assert_eq!(streaming.color_count(), 2);
+
+        // Closing the triangle needs a third color, beyond the target
+        assert_eq!(
+            streaming.process_edge(0, 2),
+            Some(RecoloringMove {
+                vertex: 2,
+                from: 1,
+                to: 3
+            })
+        );
+        assert_eq!(streaming.color_count(), 3);
+    }
+}
@@ -0,0 +1,331 @@
+use super::{count_colors, grasp::get_class_list, greedy::dsatur, ColoringAlgorithm, Solution};
+use crate::graph::adj_list::AdjList;
+use rand::rngs::ThreadRng;
+use rand::seq::SliceRandom;
+use rand::Rng;
+use std::collections::hash_map::DefaultHasher;
+use std::collections::HashSet;
+use std::hash::{Hash, Hasher};
+
+/// Fraction of the current conflicts added to the tabu tenure of a move.
+const TENURE_RATIO: f64 = 0.6;
+
+/// Upper bound (exclusive) of the random part of the tabu tenure of a move.
+const TENURE_RANDOM: usize = 10;
+
+/// Number of moves without revisiting an assignment after which the reactive tenure is halved.
+const REACTIVE_WINDOW: usize = 50;
+
+/// How the tabu tenure of a move, i.e., for how many iterations it stays forbidden, is chosen.
+#[derive(Debug, Clone, Copy, PartialEq, Eq)]
+pub enum TabuTenureStrategy {
+    /// Always the same tenure.
+    Fixed(usize),
+    /// A tenure drawn uniformly from `[low, high]`.
+    Random(usize, usize),
+    /// A tenure that doubles whenever an assignment is revisited, and halves after
+    /// 50 moves without revisits.
+    Reactive,
+}
+
+/// State of the [`TabuTenureStrategy::Reactive`] tenure.
+struct ReactiveTenure {
+    tenure: usize,
+    max_tenure: usize,
+    visited: HashSet<u64>,
+    since_repetition: usize,
+}
+
+impl ReactiveTenure {
+    fn new(num_vertices: usize) -> Self {
+        ReactiveTenure {
+            tenure: 1,
+            max_tenure: num_vertices.max(1),
+            visited: HashSet::new(),
+            since_repetition: 0,
+        }
+    }
+
+    /// Updates the tenure with the assignment reached by the last move, and returns it.
+    fn next(&mut self, coloring: &[usize]) -> usize {
+        let mut hasher = DefaultHasher::new();
+        coloring.hash(&mut hasher);
+
+        if self.visited.insert(hasher.finish()) {
+            self.since_repetition += 1;
+
+            if self.since_repetition >= REACTIVE_WINDOW {
+                self.tenure = (self.tenure / 2).max(1);
+                self.since_repetition = 0;
+            }
+        } else {
+            self.tenure = (2 * self.tenure).min(self.max_tenure);
+            self.since_repetition = 0;
+        }
+
+        self.tenure
+    }
+}
+
+/// Looks for a coloring of `graph` with `k` colors using TabuCol.
+///
+/// Starting from a random assignment, each iteration moves a conflicting vertex to the color that
+/// most reduces (or least increases) the number of forbidden edges. Returning a vertex to a color
+/// it just left is tabu for a number of iterations proportional to the current conflicts, unless
+/// the move leads to the best assignment found so far (aspiration).
+///
+/// Returns `None` if no valid coloring is found within `max_iterations`. Colors are 1-indexed.
+pub fn tabucol(graph: &AdjList, k: usize, max_iterations: usize) -> Option<Vec<usize>> {
+    tabucol_with_tenure_fn(graph, k, max_iterations, |_, conflicts, rng| {
+        (TENURE_RATIO * conflicts as f64) as usize + rng.gen_range(0..TENURE_RANDOM)
+    })
+}
+
+/// Same as [`tabucol`], but with the tabu tenure chosen by `strategy`.
+pub fn tabucol_with_tenure(
+    graph: &AdjList,
+    k: usize,
+    max_iterations: usize,
+    strategy: TabuTenureStrategy,
+) -> Option<Vec<usize>> {
+    match strategy {
+        TabuTenureStrategy::Fixed(tenure) => {
+            tabucol_with_tenure_fn(graph, k, max_iterations, |_, _, _| tenure)
+        }
+        TabuTenureStrategy::Random(low, high) => {
+            tabucol_with_tenure_fn(graph, k, max_iterations, |_, _, rng| {
+                rng.gen_range(low..=high)
+            })
+        }
+        TabuTenureStrategy::Reactive => {
+            let mut reactive = ReactiveTenure::new(graph.num_vertices());
+
+            tabucol_with_tenure_fn(graph, k, max_iterations, |coloring, _, _| {
+                reactive.next(coloring)
+            })
+        }
+    }
+}
+
+/// Runs [`tabucol`] with [`TabuTenureStrategy::Reactive`], returning the `k` color classes of the
+/// coloring found.
+pub fn reactive_tabu_search(
+    graph: &AdjList,
+    k: usize,
+    max_iterations: usize,
+) -> Option<Vec<Vec<usize>>> {
+    tabucol_with_tenure(graph, k, max_iterations, TabuTenureStrategy::Reactive)
+        .map(|coloring| get_class_list(&coloring, k))
+}
+
+/// The TabuCol search, where `tenure` gives the tenure of each move from the assignment it
+/// leads to (with 0-indexed colors) and its number of forbidden edges.
+fn tabucol_with_tenure_fn(
+    graph: &AdjList,
+    k: usize,
+    max_iterations: usize,
+    mut tenure: impl FnMut(&[usize], usize, &mut ThreadRng) -> usize,
+) -> Option<Vec<usize>> {
+    let num_vertices = graph.num_vertices();
+    let mut rng = rand::thread_rng();
+
+    if k == 0 {
+        return if num_vertices == 0 {
+            Some(Vec::new())
+        } else {
+            None
+        };
+    }
+
+    // 0-indexed colors during the search
+    let mut coloring: Vec<usize> = (0..num_vertices).map(|_| rng.gen_range(0..k)).collect();
+    // Number of neighbors of each vertex holding each color
+    let mut gamma = vec![vec![0; k]; num_vertices];
+    let mut tabu = vec![vec![0; k]; num_vertices];
+
+    for (vertex, neighbors) in graph.adj_list().iter().enumerate() {
+        for neighbor in neighbors {
+            gamma[vertex][coloring[*neighbor]] += 1;
+        }
+    }
+
+    let mut conflicts: usize = (0..num_vertices)
+        .map(|v| gamma[v][coloring[v]])
+        .sum::<usize>()
+        / 2;
+    let mut best_conflicts = conflicts;
+
+    for iteration in 0..max_iterations {
+        if conflicts == 0 {
+            break;
+        }
+
+        let mut best_delta = isize::MAX;
+        let mut best_moves = Vec::new();
+
+        for vertex in (0..num_vertices).filter(|v| gamma[*v][coloring[*v]] > 0) {
+            for color in (0..k).filter(|c| *c != coloring[vertex]) {
+                let delta =
+                    gamma[vertex][color] as isize - gamma[vertex][coloring[vertex]] as isize;
+                let aspiration = (conflicts as isize + delta) < best_conflicts as isize;
+
+                if tabu[vertex][color] > iteration && !aspiration {
+                    continue;
+                }
+
+                if delta < best_delta {
+                    best_delta = delta;
+                    best_moves.clear();
+                }
+
+                if delta == best_delta {
+                    best_moves.push((vertex, color));
+                }
+            }
+        }
+
+        // Every move is tabu
+        let Some((vertex, color)) = best_moves.choose(&mut rng).copied() else {
+            continue;
+        };
+
+        let old_color = coloring[vertex];
+
+        for neighbor in graph.adj_list()[vertex].iter() {
+            gamma[*neighbor][old_color] -= 1;
+            gamma[*neighbor][color] += 1;
+        }
+
+        coloring[vertex] = color;
+        conflicts = (conflicts as isize + best_delta) as usize;
+        best_conflicts = best_conflicts.min(conflicts);
+
+        tabu[vertex][old_color] = iteration + 1 + tenure(&coloring, conflicts, &mut rng);
+    }
+
+    if conflicts == 0 {
+        Some(coloring.iter().map(|color| color + 1).collect())
+    } else {
+        None
+    }
+}
+
+/// Starts from a [`dsatur`] coloring and repeatedly runs [`tabucol`] with one color less, each
+/// attempt limited to `max_iterations`, until it fails.
+pub fn tabu_search(graph: &AdjList, max_iterations: usize) -> Solution {
+    let (mut num_colors, mut coloring) = dsatur(graph);
+
+    while num_colors > 1 {
+        match tabucol(graph, num_colors - 1, max_iterations) {
+            Some(new_coloring) => {
+                // Some of the colors may end up unused
+                num_colors = count_colors(&new_coloring);
+                coloring = new_coloring;
+            }
+            None => break,
+        }
+    }
+
+    (num_colors, coloring)
+}
+
+/// [`ColoringAlgorithm`] running [`tabu_search`].
+#[derive(Debug, Clone, Copy)]
+pub struct TabuSolver {
+    pub max_iterations: usize,
+}
+
+impl Default for TabuSolver {
+    fn default() -> Self {
+        TabuSolver {
+            max_iterations: 10000,
+        }
+    }
+}
+
+impl ColoringAlgorithm for TabuSolver {
+    fn solve(&self, graph: &AdjList) -> Solution {
+        tabu_search(graph, self.max_iterations)
+    }
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+    use crate::{graph::random::random_k_colorable, input};
+
+    #[test]
+    fn test_tabucol() {
+        if let Ok(Some(graph)) = input::read_graph_from_file("data/myc/myciel3.col") {
+            // The chromatic number of myciel3 is 4
+            assert!(tabucol(&graph, 3, 1000).is_none());
+
+            if let Some(coloring) = tabucol(&graph, 4, 1000) {
+                assert!(graph.is_valid_coloring(&coloring));
+                assert!(coloring.iter().all(|color| (1..=4).contains(color)));
+            } else {
+                panic!("TabuCol should find a 4-coloring of myciel3")
+            }
+        } else {
+            panic!("The file containing the test graph is missing")
+        }
+    }
+
+    #[test]
+    fn test_tabucol_with_tenure() {
+        if let Ok(Some(graph)) = input::read_graph_from_file("data/myc/myciel3.col") {
+            for strategy in [
+                TabuTenureStrategy::Fixed(5),
+                TabuTenureStrategy::Random(2, 8),
+                TabuTenureStrategy::Reactive,
+            ] {
+                assert!(tabucol_with_tenure(&graph, 3, 1000, strategy).is_none());
+
+                if let Some(coloring) = tabucol_with_tenure(&graph, 4, 1000, strategy) {
+                    assert!(graph.is_valid_coloring(&coloring));
+                } else {
+                    panic!("TabuCol should find a 4-coloring of myciel3")
+                }
+            }
+        } else {
+            panic!("The file containing the test graph is missing")
+        }
+    }
+
+    #[test]
+    fn test_reactive_tabu_search() {
+        // A sparse 3-colorable graph full of local minima. Without tenure, the search keeps
+        // undoing its last move, so it cycles between two assignments and rarely gets out
+        let graph = random_k_colorable(100, 3, 200, 0);
+        let fixed_successes = (0..10)
+            .filter(|_| {
+                tabucol_with_tenure(&graph, 3, 5000, TabuTenureStrategy::Fixed(0)).is_some()
+            })
+            .count();
+
+        assert!(fixed_successes < 5);
+
+        for _ in 0..10 {
+            if let Some(class_list) = reactive_tabu_search(&graph, 3, 5000) {
+                assert_eq!(class_list.len(), 3);
+                assert!(graph.is_valid_class_list(&class_list));
+            } else {
+                panic!("The reactive tenure should escape the cycles")
+            }
+        }
+    }
+
+    #[test]
+    fn test_tabu_search() {
+        if let Ok(Some(graph)) = input::read_graph_from_file("data/myc/myciel4.col") {
+            let (num_colors, coloring) = tabu_search(&graph, 1000);
+
+            // The chromatic number of myciel4 is 5
+            assert_eq!(num_colors, 5);
+            assert_eq!(count_colors(&coloring), 5);
+            assert!(graph.is_valid_coloring(&coloring));
+        } else {
+            panic!("The file containing the test graph is missing")
+        }
+    }
+}
@@ -0,0 +1,124 @@
+use super::{
+    count_forbidden_edges_for_vertex, get_coloring_from_class_list, grasp::get_class_list,
+    repair::repair_coloring,
+};
+use crate::graph::adj_list::AdjList;
+
+/// Carries a coloring of a graph over to a related graph, such as the same graph with some edges
+/// added or removed, to warm-start the search on a sequence of related instances.
+pub trait CrossGraphColoring {
+    /// Colors `target_graph` starting from `source_coloring`, a coloring of `source_graph`.
+    ///
+    /// By default, the vertices of both graphs keep their colors and the new ones get color 1.
+    /// The conflicts that appear, e.g., from added edges, are fixed by [`repair_coloring`] within
+    /// the same colors and, if that gets stuck, by moving each vertex still in conflict to the
+    /// smallest color unused by its neighbors, which may add colors. The result is valid.
+    fn transfer_coloring(
+        source_graph: &AdjList,
+        source_coloring: &[usize],
+        target_graph: &AdjList,
+    ) -> Vec<usize> {
+        let n = target_graph.num_vertices();
+        let mut coloring: Vec<usize> = (0..n)
+            .map(|v| {
+                if v < source_graph.num_vertices() {
+                    source_coloring[v]
+                } else {
+                    1
+                }
+            })
+            .collect();
+
+        let num_classes = coloring.iter().copied().max().unwrap_or(0);
+        let mut class_list = get_class_list(&coloring, num_classes);
+
+        let repaired = repair_coloring(target_graph, &mut class_list, n);
+        coloring = get_coloring_from_class_list(n, &class_list);
+
+        if !repaired {
+            for vertex in 0..n {
+                if count_forbidden_edges_for_vertex(target_graph, &coloring, vertex) > 0 {
+                    let used: Vec<usize> = target_graph.adj_list()[vertex]
+                        .iter()
+                        .map(|u| coloring[*u])
+                        .collect();
+                    coloring[vertex] = (1..).find(|color| !used.contains(color)).unwrap();
+                }
+            }
+        }
+
+        coloring
+    }
+}
+
+/// Transfers colorings between graphs that differ by a few edges or vertices, with the default
+/// [`CrossGraphColoring::transfer_coloring`].
+#[derive(Debug, Clone, Copy, Default)]
+pub struct DeltaTransfer;
+
+impl CrossGraphColoring for DeltaTransfer {}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+    use crate::{
+        algorithms::{count_colors, greedy::dsatur},
+        input,
+    };
+
+    #[test]
+    fn test_transfer_coloring() {
+        if let Ok(Some(graph)) = input::read_graph_from_file("data/myc/myciel3.col") {
+            let (num_colors, coloring) = dsatur(&graph);
+
+            assert_eq!(num_colors, 4);
+
+            // Adds an edge between two vertices of the same color
+            let (u, v) = (0..graph.num_vertices())
+                .flat_map(|u| (u + 1..graph.num_vertices()).map(move |v| (u, v)))
+                .find(|(u, v)| coloring[*u] == coloring[*v])
+                .unwrap();
+            let mut target = graph.clone();
+            target.add_edge(u, v);
+
+            assert!(!target.is_valid_coloring(&coloring));
+
+            let transferred = DeltaTransfer::transfer_coloring(&graph, &coloring, &target);
+
+            assert!(target.is_valid_coloring(&transferred));
+            assert!(count_colors(&transferred) <= 5);
+
+            // Removing an edge keeps the coloring valid, so nothing changes
+            let mut target = graph.clone();
+            let (u, v) = target.edge_list_as_slice()[0];
+            target.remove_edge(u, v);
+
+            let transferred = DeltaTransfer::transfer_coloring(&graph, &coloring, &target);
+
+            assert_eq!(transferred, coloring);
+            assert!(target.is_valid_coloring(&transferred));
+        } else {
+            panic!("The file containing the test graph is missing")
+        }
+    }
+
+    #[test]
+    fn test_transfer_coloring_stuck() {
+        // Closing a 2-colored path into a triangle needs a new color
+        let mut source = AdjList::new(3);
+        source.add_edge(0, 1);
+        source.add_edge(1, 2);
+        let target = AdjList::complete(3);
+
+        let transferred = DeltaTransfer::transfer_coloring(&source, &[1, 2, 1], &target);
+
+        assert!(target.is_valid_coloring(&transferred));
+        assert_eq!(count_colors(&transferred), 3);
+
+        // New vertices are colored too
+        let target = AdjList::complete(4);
+        let transferred = DeltaTransfer::transfer_coloring(&source, &[1, 2, 1], &target);
+
+        assert!(target.is_valid_coloring(&transferred));
+    }
+}
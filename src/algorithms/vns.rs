@@ -0,0 +1,247 @@
+use super::{
+    get_coloring_from_class_list,
+    grasp::{
+        get_class_list, get_forbidden_vertices, grasp_wrapper, local_search,
+        merge_smallest_classes, LocalSearchConfig,
+    },
+    Solution,
+};
+use crate::graph::adj_list::AdjList;
+use rand::seq::SliceRandom;
+use rand::Rng;
+use std::collections::VecDeque;
+
+/// Neighborhood structures used to shake a class list. Each one is parameterized by the current
+/// neighborhood index `k`.
+#[derive(Debug, Clone, Copy)]
+enum Neighborhood {
+    /// Randomly reassigns `k` vertices.
+    Swap,
+    /// Interchanges the two colors of a Kempe chain explored up to depth `k`.
+    KempeChain,
+    /// Merges `k` random pairs of color classes.
+    ClassMerge,
+}
+
+const NEIGHBORHOODS: [Neighborhood; 3] = [
+    Neighborhood::Swap,
+    Neighborhood::KempeChain,
+    Neighborhood::ClassMerge,
+];
+
+/// Vertices reachable from `start` (within `depth` steps) in the subgraph induced by the vertices
+/// colored with the color of `start` or `b`, the Kempe chain of `start`, in breadth-first order.
+pub(super) fn kempe_chain(
+    graph: &AdjList,
+    coloring: &[usize],
+    start: usize,
+    b: usize,
+    depth: usize,
+) -> Vec<usize> {
+    let a = coloring[start];
+    let mut visited = vec![false; graph.num_vertices()];
+    let mut queue = VecDeque::from([(start, 0)]);
+    let mut chain = Vec::new();
+
+    visited[start] = true;
+
+    while let Some((vertex, level)) = queue.pop_front() {
+        chain.push(vertex);
+
+        if level == depth {
+            continue;
+        }
+
+        for neighbor in graph.adj_list()[vertex].iter() {
+            if !visited[*neighbor] && (coloring[*neighbor] == a || coloring[*neighbor] == b) {
+                visited[*neighbor] = true;
+                queue.push_back((*neighbor, level + 1));
+            }
+        }
+    }
+
+    chain
+}
+
+/// Swaps colors `a` and `b` for every vertex reachable from `start` (within `depth` steps)
+/// in the subgraph induced by the vertices colored with `a` or `b`.
+fn kempe_chain_interchange(
+    graph: &AdjList,
+    coloring: &mut [usize],
+    start: usize,
+    b: usize,
+    depth: usize,
+) {
+    let a = coloring[start];
+
+    for vertex in kempe_chain(graph, coloring, start, b, depth) {
+        coloring[vertex] = if coloring[vertex] == a { b } else { a };
+    }
+}
+
+/// Applies a random neighborhood structure of index `k` to `class_list`, returning the
+/// perturbed class list. The number of classes is kept, although some of them may end up empty.
+fn shake(graph: &AdjList, class_list: &[Vec<usize>], k: usize) -> Vec<Vec<usize>> {
+    let num_vertices = graph.num_vertices();
+    let num_classes = class_list.len();
+    let mut rng = rand::thread_rng();
+    let mut coloring = get_coloring_from_class_list(num_vertices, class_list);
+
+    // Since the neighborhood list is not empty we can unwrap
+    match NEIGHBORHOODS.choose(&mut rng).unwrap() {
+        Neighborhood::Swap => {
+            for _ in 0..k {
+                let vertex = rng.gen_range(0..num_vertices);
+                coloring[vertex] = rng.gen_range(1..=num_classes);
+            }
+        }
+        Neighborhood::KempeChain => {
+            let start = rng.gen_range(0..num_vertices);
+            let color = rng.gen_range(1..=num_classes);
+
+            if color != coloring[start] {
+                kempe_chain_interchange(graph, &mut coloring, start, color, k);
+            }
+        }
+        Neighborhood::ClassMerge => {
+            for _ in 0..k {
+                let a = rng.gen_range(1..=num_classes);
+                let b = rng.gen_range(1..=num_classes);
+
+                for color in coloring.iter_mut() {
+                    if *color == b {
+                        *color = a;
+                    }
+                }
+            }
+        }
+    }
+
+    get_class_list(&coloring, num_classes)
+}
+
+/// Runs a Variable Neighborhood Search starting from a GRASP solution.
+///
+/// Whenever a valid coloring is known, the search tries to find one with a color less by merging
+/// its two smallest classes. The resulting (forbidden) class list is then shaken with the `k`th
+/// neighborhood and improved by the GRASP local search. If the number of forbidden edges
+/// decreases, the search restarts from `k = 1`; otherwise `k` is incremented up to `k_max`.
+pub fn variable_neighborhood_search(
+    graph: &AdjList,
+    k_max: usize,
+    max_iterations: usize,
+) -> Solution {
+    let num_vertices = graph.num_vertices();
+    // The GRASP parameters are valid, so we can unwrap
+    let (mut num_colors, mut coloring) = grasp_wrapper(graph, 25, 25, 3).unwrap();
+    let mut target = merge_smallest_classes(&get_class_list(&coloring, num_colors), num_colors);
+    let (mut target_forbidden, _) = get_forbidden_vertices(graph, &target);
+    let mut k = 1;
+
+    for _ in 0..max_iterations {
+        // A single color can't be improved upon
+        if num_colors <= 1 {
+            break;
+        }
+
+        if target_forbidden == 0 {
+            target.retain(|class| !class.is_empty());
+            num_colors = target.len();
+            coloring = get_coloring_from_class_list(num_vertices, &target);
+
+            target = merge_smallest_classes(&target, num_colors);
+            (target_forbidden, _) = get_forbidden_vertices(graph, &target);
+            k = 1;
+            continue;
+        }
+
+        let mut candidate = shake(graph, &target, k);
+        let candidate_forbidden = local_search(
+            graph,
+            &mut candidate,
+            &LocalSearchConfig::default(),
+            &mut rand::thread_rng(),
+        );
+
+        if candidate_forbidden < target_forbidden {
+            target = candidate;
+            target_forbidden = candidate_forbidden;
+            k = 1;
+        } else if k < k_max {
+            k += 1;
+        } else {
+            k = 1;
+        }
+    }
+
+    if target_forbidden == 0 && num_colors > 1 {
+        target.retain(|class| !class.is_empty());
+        num_colors = target.len();
+        coloring = get_coloring_from_class_list(num_vertices, &target);
+    }
+
+    (num_colors, coloring)
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+    use crate::input;
+
+    #[test]
+    fn test_kempe_chain_interchange() {
+        // A path colored as 1---2---1---3
+        let mut graph = AdjList::new(4);
+        graph.add_edge(0, 1);
+        graph.add_edge(1, 2);
+        graph.add_edge(2, 3);
+        let mut coloring = vec![1, 2, 1, 3];
+
+        // The chain from 0 over the colors {1, 2} is [0, 1, 2]
+        kempe_chain_interchange(&graph, &mut coloring, 0, 2, 3);
+
+        assert_eq!(coloring, vec![2, 1, 2, 3]);
+        assert!(graph.is_valid_coloring(&coloring));
+
+        // Limiting the depth only swaps the closest vertices
+        kempe_chain_interchange(&graph, &mut coloring, 0, 1, 1);
+
+        assert_eq!(coloring, vec![1, 2, 2, 3]);
+    }
+
+    #[test]
+    fn test_shake() {
+        if let Ok(Some(graph)) = input::read_graph_from_file("data/myc/myciel3.col") {
+            let (num_colors, coloring) = grasp_wrapper(&graph, 5, 5, 3).unwrap();
+            let class_list = get_class_list(&coloring, num_colors);
+
+            for k in 1..=3 {
+                let shaken = shake(&graph, &class_list, k);
+
+                // Every vertex is still assigned to exactly one class
+                assert_eq!(shaken.len(), class_list.len());
+                assert_eq!(
+                    shaken.iter().map(|class| class.len()).sum::<usize>(),
+                    graph.num_vertices()
+                );
+            }
+        } else {
+            panic!("The file containing the test graph is missing")
+        }
+    }
+
+    #[test]
+    fn test_variable_neighborhood_search() {
+        // The chromatic number of myciel4 is 5
+        if let Ok(Some(graph)) = input::read_graph_from_file("data/myc/myciel4.col") {
+            let (num_colors, coloring) = variable_neighborhood_search(&graph, 3, 200);
+            let class_list = get_class_list(&coloring, num_colors);
+
+            assert_eq!(num_colors, 5);
+            assert!(graph.is_valid_coloring(&coloring));
+            assert_eq!(get_forbidden_vertices(&graph, &class_list).0, 0);
+        } else {
+            panic!("The file containing the test graph is missing")
+        }
+    }
+}
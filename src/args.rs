@@ -3,12 +3,18 @@ pub enum Algorithm {
     Genetic,
     Grasp,
     GraspPR,
+    Vns,
+    WelshPowell,
+    Dsatur,
+    Tabu,
+    Rlf,
 }
 
 #[derive(Debug, clap::Parser)]
 #[clap(author, version, about)]
 pub struct Args {
-    /// Path to a Graph Coloring instance
+    /// Path to a Graph Coloring instance (DIMACS, binary DIMACS if it ends with .col.b, or Matrix
+    /// Market if it ends with .mtx)
     #[arg(short, long)]
     pub path: String,
 
@@ -37,6 +43,11 @@ pub struct Args {
     /// Defaults to 5 if not provided.
     pub color_list_size: Option<usize>,
 
+    #[arg(long)]
+    /// Time budget for GRASP, in milliseconds.
+    /// Unlimited if not provided.
+    pub time_budget: Option<u64>,
+
     #[arg(long)]
     /// Number of generations for the Genetic Algorithm.
     /// Defaults to 10000 if not provided.
@@ -61,4 +72,19 @@ pub struct Args {
     /// Population selection ratio for the Genetic Algorithm
     /// Defaults to 0.2 if not provided.
     pub population_ratio: Option<f64>,
+
+    #[arg(long)]
+    /// Largest neighborhood index for the Variable Neighborhood Search.
+    /// Defaults to 3 if not provided.
+    pub k_max: Option<usize>,
+
+    #[arg(long)]
+    /// Number of iterations for the Variable Neighborhood Search.
+    /// Defaults to 200 if not provided.
+    pub vns_iterations: Option<usize>,
+
+    #[arg(long)]
+    /// Iterations per number of colors attempted by the Tabu Search.
+    /// Defaults to 10000 if not provided.
+    pub tabu_iterations: Option<usize>,
 }
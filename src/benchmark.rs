@@ -0,0 +1,204 @@
+use crate::algorithms::ColoringAlgorithm;
+use crate::graph::adj_list::AdjList;
+use crate::input;
+use std::error::Error;
+use std::fmt;
+use std::fs;
+use std::time::{Duration, Instant};
+
+/// A set of named graphs on which algorithms are compared.
+pub struct BenchmarkSuite {
+    pub graphs: Vec<(String, AdjList)>,
+}
+
+/// Outcome of running an algorithm on a graph of a [`BenchmarkSuite`].
+#[derive(Debug, Clone)]
+pub struct BenchmarkEntry {
+    pub graph: String,
+    pub algorithm: String,
+    pub elapsed: Duration,
+    pub num_colors: usize,
+    pub valid: bool,
+}
+
+/// Results of [`BenchmarkSuite::run`], one entry per graph and algorithm.
+#[derive(Debug, Clone, Default)]
+pub struct BenchmarkReport {
+    pub entries: Vec<BenchmarkEntry>,
+}
+
+impl BenchmarkSuite {
+    pub fn new(graphs: Vec<(String, AdjList)>) -> Self {
+        BenchmarkSuite { graphs }
+    }
+
+    /// Reads every `.col` file of `dir` (in DIMACS format), named after the file and sorted by
+    /// name.
+    pub fn from_directory(dir: &str) -> Result<BenchmarkSuite, Box<dyn Error>> {
+        let mut paths: Vec<_> = fs::read_dir(dir)?
+            .map(|entry| entry.map(|entry| entry.path()))
+            .collect::<Result<_, _>>()?;
+        paths.retain(|path| path.extension().is_some_and(|extension| extension == "col"));
+        paths.sort();
+
+        let mut graphs = Vec::new();
+
+        for path in paths {
+            let name = path.file_name().unwrap().to_string_lossy().into_owned();
+
+            match input::read_graph_from_file(&path.to_string_lossy())? {
+                Some(graph) => graphs.push((name, graph)),
+                None => return Err(format!("No graph found in {name}").into()),
+            }
+        }
+
+        Ok(BenchmarkSuite { graphs })
+    }
+
+    /// Runs every named algorithm on every graph, measuring the time and checking the coloring.
+    pub fn run(&self, algorithms: &[(&str, &dyn ColoringAlgorithm)]) -> BenchmarkReport {
+        let mut report = BenchmarkReport::default();
+
+        for (graph_name, graph) in self.graphs.iter() {
+            for (algorithm_name, algorithm) in algorithms {
+                let start = Instant::now();
+                let (num_colors, coloring) = algorithm.solve(graph);
+                let elapsed = start.elapsed();
+
+                report.entries.push(BenchmarkEntry {
+                    graph: graph_name.clone(),
+                    algorithm: algorithm_name.to_string(),
+                    elapsed,
+                    num_colors,
+                    valid: graph.is_valid_coloring(&coloring),
+                });
+            }
+        }
+
+        report
+    }
+}
+
+impl BenchmarkReport {
+    /// Prints the report to stdout as an ASCII table.
+    pub fn print_table(&self) {
+        print!("{self}");
+    }
+}
+
+/// Writes `rows` under `header` as an ASCII table, with each column as wide as its widest cell.
+pub(crate) fn write_table(
+    f: &mut fmt::Formatter<'_>,
+    header: &[&str],
+    rows: &[Vec<String>],
+) -> fmt::Result {
+    let mut widths: Vec<usize> = header.iter().map(|cell| cell.len()).collect();
+    for row in rows.iter() {
+        for (width, cell) in widths.iter_mut().zip(row.iter()) {
+            *width = (*width).max(cell.len());
+        }
+    }
+
+    let separator: String = widths
+        .iter()
+        .map(|width| format!("+{}", "-".repeat(width + 2)))
+        .collect::<String>()
+        + "+";
+
+    writeln!(f, "{separator}")?;
+    for (cell, width) in header.iter().zip(widths.iter()) {
+        write!(f, "| {cell:<width$} ")?;
+    }
+    writeln!(f, "|")?;
+    writeln!(f, "{separator}")?;
+
+    for row in rows.iter() {
+        for (cell, width) in row.iter().zip(widths.iter()) {
+            write!(f, "| {cell:<width$} ")?;
+        }
+        writeln!(f, "|")?;
+    }
+
+    writeln!(f, "{separator}")
+}
+
+impl fmt::Display for BenchmarkReport {
+    fn fmt(&self, f: &mut fmt::Formatter<'_>) -> fmt::Result {
+        let rows: Vec<Vec<String>> = self
+            .entries
+            .iter()
+            .map(|entry| {
+                vec![
+                    entry.graph.clone(),
+                    entry.algorithm.clone(),
+                    entry.num_colors.to_string(),
+                    format!("{:.3}", entry.elapsed.as_secs_f64() * 1000.0),
+                    entry.valid.to_string(),
+                ]
+            })
+            .collect();
+
+        write_table(
+            f,
+            &["Graph", "Algorithm", "Colors", "Time (ms)", "Valid"],
+            &rows,
+        )
+    }
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+    use crate::algorithms::{
+        grasp::{GraspConfig, GraspSolver},
+        greedy::WelshPowellSolver,
+    };
+
+    #[test]
+    fn test_benchmark_suite() {
+        let mut graphs = Vec::new();
+
+        for name in ["myciel3.col", "myciel4.col"] {
+            if let Ok(Some(graph)) = input::read_graph_from_file(&format!("data/myc/{name}")) {
+                graphs.push((name.to_string(), graph));
+            } else {
+                panic!("The file containing the test graph is missing")
+            }
+        }
+
+        let suite = BenchmarkSuite::new(graphs);
+        let grasp = GraspSolver::new(GraspConfig::new(5, 5, 3)).unwrap();
+        let report = suite.run(&[("Welsh-Powell", &WelshPowellSolver), ("GRASP", &grasp)]);
+
+        assert_eq!(report.entries.len(), 4);
+        assert!(report.entries.iter().all(|entry| entry.valid));
+        assert!(report.entries[0].num_colors >= 4);
+        assert!(report.entries[2].num_colors >= 5);
+
+        let table = report.to_string();
+
+        // A row per entry, plus the header and 3 separators
+        assert_eq!(table.lines().count(), 8);
+        assert!(table.contains("| myciel4.col | GRASP "));
+    }
+
+    #[test]
+    fn test_from_directory() {
+        let suite = BenchmarkSuite::from_directory("data/myc").unwrap();
+        let names: Vec<&str> = suite.graphs.iter().map(|(name, _)| name.as_str()).collect();
+
+        assert_eq!(
+            names,
+            vec![
+                "myciel3.col",
+                "myciel4.col",
+                "myciel5.col",
+                "myciel6.col",
+                "myciel7.col"
+            ]
+        );
+        assert_eq!(suite.graphs[0].1.num_vertices(), 11);
+
+        assert!(BenchmarkSuite::from_directory("data/missing").is_err());
+    }
+}
@@ -1,6 +1,102 @@
+use rand::rngs::StdRng;
+use rand::seq::SliceRandom;
+use rand::SeedableRng;
+use std::cmp::Reverse;
+use std::collections::{BinaryHeap, HashMap, HashSet, VecDeque};
+use std::error::Error;
+use std::fmt;
+use std::sync::OnceLock;
+
+#[derive(Debug, Clone)]
 pub struct AdjList {
     adj_list: Vec<Vec<usize>>,
     num_vertices: usize,
+    num_edges: usize,
+    // Lazily computed, cleared whenever the structure changes
+    max_degree: OnceLock<usize>,
+    degree_sequence: OnceLock<Vec<usize>>,
+    edge_list: OnceLock<Vec<(usize, usize)>>,
+    // Weights of the edges (u, v), with u < v, that don't have the default weight of 1
+    weights: HashMap<(usize, usize), f64>,
+    mode: GraphMode,
+}
+
+/// Graphs are equal if they are structurally equal (see [`AdjList::structural_equality`]).
+impl PartialEq for AdjList {
+    fn eq(&self, other: &Self) -> bool {
+        self.structural_equality(other)
+    }
+}
+
+impl Eq for AdjList {}
+
+/// How [`AdjList::try_add_edge`] handles an edge that is already in the graph.
+#[derive(Debug, Clone, Copy, PartialEq, Eq, Default)]
+pub enum GraphMode {
+    /// The edge is ignored.
+    #[default]
+    Permissive,
+    /// The edge is rejected with [`GraphError::DuplicateEdge`].
+    Strict,
+}
+
+/// Errors raised while building a graph.
+#[derive(Debug, Clone, PartialEq, Eq)]
+pub enum GraphError {
+    /// The adjacency matrix isn't square: row `row` has `len` entries instead of `n`.
+    NotSquare { row: usize, len: usize, n: usize },
+    /// The adjacency matrix or lists aren't symmetric at `(i, j)`.
+    Asymmetric { i: usize, j: usize },
+    /// The adjacency lists or edges have a self-loop at `vertex`.
+    SelfLoop { vertex: usize },
+    /// The adjacency lists or edges hold `vertex`, but the graph only has `n` vertices.
+    VertexOutOfRange { vertex: usize, n: usize },
+    /// The graphs have `left` and `right` vertices, but should have the same number.
+    VertexCountMismatch { left: usize, right: usize },
+    /// The edge `(u, v)` is already in the graph.
+    DuplicateEdge { u: usize, v: usize },
+}
+
+impl fmt::Display for GraphError {
+    fn fmt(&self, f: &mut fmt::Formatter<'_>) -> fmt::Result {
+        match self {
+            GraphError::NotSquare { row, len, n } => write!(
+                f,
+                "row {row} of the adjacency matrix has {len} entries, expected {n}"
+            ),
+            GraphError::Asymmetric { i, j } => {
+                write!(f, "the adjacency isn't symmetric at ({i}, {j})")
+            }
+            GraphError::SelfLoop { vertex } => write!(f, "there is a self-loop at {vertex}"),
+            GraphError::VertexOutOfRange { vertex, n } => {
+                write!(f, "the vertex {vertex} is out of range for {n} vertices")
+            }
+            GraphError::VertexCountMismatch { left, right } => {
+                write!(f, "the graphs have {left} and {right} vertices")
+            }
+            GraphError::DuplicateEdge { u, v } => {
+                write!(f, "the edge ({u}, {v}) is already in the graph")
+            }
+        }
+    }
+}
+
+impl Error for GraphError {}
+
+/// Largest number of vertices for which [`AdjList::find_automorphism_orbits`] is exact.
+pub const MAX_EXACT_ORBIT_VERTICES: usize = 8;
+
+/// Key of the edge between `u` and `v` in the weights map.
+fn edge_key(u: usize, v: usize) -> (usize, usize) {
+    (u.min(v), u.max(v))
+}
+
+/// Root of `v` in the disjoint-set forest given by the `parent` of every vertex.
+fn find_root(parent: &[usize], mut v: usize) -> usize {
+    while parent[v] != v {
+        v = parent[v];
+    }
+    v
 }
 
 impl AdjList {
@@ -10,10 +106,16 @@ impl AdjList {
         AdjList {
             num_vertices,
             adj_list,
+            num_edges: 0,
+            max_degree: OnceLock::new(),
+            degree_sequence: OnceLock::new(),
+            edge_list: OnceLock::new(),
+            weights: HashMap::new(),
+            mode: GraphMode::Permissive,
         }
     }
 
-    #[cfg(test)]
+    /// Builds the complete graph `K_n` on `num_vertices` vertices.
     pub fn complete(num_vertices: usize) -> Self {
         let mut adj_list: Vec<Vec<usize>> = Vec::new();
         adj_list.resize(num_vertices, Vec::new());
@@ -27,13 +129,307 @@ impl AdjList {
         AdjList {
             num_vertices,
             adj_list,
+            num_edges: num_vertices * num_vertices.saturating_sub(1) / 2,
+            max_degree: OnceLock::new(),
+            degree_sequence: OnceLock::new(),
+            edge_list: OnceLock::new(),
+            weights: HashMap::new(),
+            mode: GraphMode::Permissive,
+        }
+    }
+
+    /// Builds the graph with the given adjacency `matrix`, where `matrix[i][j]` tells whether `i`
+    /// and `j` are adjacent. The diagonal is ignored, since self-loops aren't allowed.
+    ///
+    /// Returns [`GraphError::NotSquare`] or [`GraphError::Asymmetric`] if the matrix isn't a valid
+    /// adjacency matrix.
+    pub fn from_adjacency_matrix(matrix: Vec<Vec<bool>>) -> Result<AdjList, GraphError> {
+        let n = matrix.len();
+
+        if let Some((row, entries)) = matrix.iter().enumerate().find(|(_, row)| row.len() != n) {
+            return Err(GraphError::NotSquare {
+                row,
+                len: entries.len(),
+                n,
+            });
+        }
+
+        let mut graph = AdjList::new(n);
+
+        for (i, row) in matrix.iter().enumerate() {
+            for (j, adjacent) in row.iter().enumerate().skip(i + 1) {
+                if *adjacent != matrix[j][i] {
+                    return Err(GraphError::Asymmetric { i, j });
+                }
+
+                if *adjacent {
+                    graph.add_edge(i, j);
+                }
+            }
+        }
+
+        Ok(graph)
+    }
+
+    /// Builds the weighted graph with the given `matrix`, where `matrix[i][j]` is the weight of
+    /// the edge between `i` and `j`, or `None` if they aren't adjacent. The diagonal is ignored,
+    /// as in [`AdjList::from_adjacency_matrix`].
+    ///
+    /// Returns [`GraphError::NotSquare`] if the matrix isn't square, and [`GraphError::Asymmetric`]
+    /// if an edge is missing or has a different weight in the other direction.
+    pub fn from_adjacency_matrix_with_weights(
+        matrix: Vec<Vec<Option<f64>>>,
+    ) -> Result<AdjList, GraphError> {
+        let n = matrix.len();
+
+        if let Some((row, entries)) = matrix.iter().enumerate().find(|(_, row)| row.len() != n) {
+            return Err(GraphError::NotSquare {
+                row,
+                len: entries.len(),
+                n,
+            });
+        }
+
+        let mut graph = AdjList::new(n);
+
+        for (i, row) in matrix.iter().enumerate() {
+            for (j, weight) in row.iter().enumerate().skip(i + 1) {
+                if *weight != matrix[j][i] {
+                    return Err(GraphError::Asymmetric { i, j });
+                }
+
+                if let Some(weight) = weight {
+                    graph.add_weighted_edge(i, j, *weight);
+                }
+            }
+        }
+
+        Ok(graph)
+    }
+
+    /// Builds the graph on `n` vertices where `adj[u]` lists the neighbors of `u`. Repeated
+    /// neighbors are ignored.
+    ///
+    /// Returns [`GraphError::VertexCountMismatch`] if there aren't `n` lists,
+    /// [`GraphError::VertexOutOfRange`] if a neighbor isn't a vertex, [`GraphError::SelfLoop`] if a
+    /// vertex is its own neighbor, or [`GraphError::Asymmetric`] if `v` is a neighbor of `u` but
+    /// not the other way around.
+    pub fn from_adjacency_list(n: usize, adj: Vec<Vec<usize>>) -> Result<AdjList, GraphError> {
+        if adj.len() != n {
+            return Err(GraphError::VertexCountMismatch {
+                left: n,
+                right: adj.len(),
+            });
+        }
+
+        for (u, neighbors) in adj.iter().enumerate() {
+            for v in neighbors.iter() {
+                if *v >= n {
+                    return Err(GraphError::VertexOutOfRange { vertex: *v, n });
+                }
+
+                if *v == u {
+                    return Err(GraphError::SelfLoop { vertex: u });
+                }
+
+                if !adj[*v].contains(&u) {
+                    return Err(GraphError::Asymmetric { i: u, j: *v });
+                }
+            }
+        }
+
+        let mut graph = AdjList::new(n);
+
+        for (u, neighbors) in adj.iter().enumerate() {
+            for v in neighbors.iter().filter(|v| u < **v) {
+                graph.add_edge(u, *v);
+            }
+        }
+
+        Ok(graph)
+    }
+
+    /// Builds `K_n` without the `forbidden_edges`, for near-complete instances.
+    pub fn complete_minus(n: usize, forbidden_edges: &[(usize, usize)]) -> Self {
+        let mut graph = AdjList::complete(n);
+
+        for (u, v) in forbidden_edges {
+            graph.remove_edge(*u, *v);
+        }
+
+        graph
+    }
+
+    /// Builds the edgeless graph on `n` vertices.
+    pub fn empty_graph(n: usize) -> Self {
+        AdjList::new(n)
+    }
+
+    /// Builds the complement, with an edge between every pair of distinct vertices that aren't
+    /// adjacent in this graph. Edge weights are dropped.
+    pub fn complement(&self) -> AdjList {
+        let mut complement = AdjList::new(self.num_vertices);
+
+        for u in 0..self.num_vertices {
+            let mut adjacent = vec![false; self.num_vertices];
+            for v in self.adj_list[u].iter() {
+                adjacent[*v] = true;
+            }
+
+            for v in (u + 1..self.num_vertices).filter(|v| !adjacent[*v]) {
+                complement.add_edge(u, v);
+            }
+        }
+
+        complement
+    }
+
+    /// Builds the line graph, whose vertices are the edges of this graph, numbered by their
+    /// position in [`AdjList::edge_list`], with an edge between every pair of edges that share an
+    /// endpoint. Edge weights are dropped.
+    pub fn line_graph(&self) -> AdjList {
+        let edges = self.edge_list_as_slice();
+        let mut incident_edges: Vec<Vec<usize>> = vec![Vec::new(); self.num_vertices];
+        for (i, (u, v)) in edges.iter().enumerate() {
+            incident_edges[*u].push(i);
+            incident_edges[*v].push(i);
+        }
+
+        let mut line_graph = AdjList::new(edges.len());
+
+        // Two distinct edges share at most one endpoint, so each pair is added once
+        for incident in incident_edges.iter() {
+            for (k, a) in incident.iter().enumerate() {
+                for b in incident[k + 1..].iter() {
+                    line_graph.add_edge(*a, *b);
+                }
+            }
+        }
+
+        line_graph
+    }
+
+    /// Builds the graph on the vertices of `a` and `b` with an edge between every pair of
+    /// vertices for which `keep` holds, given whether they are adjacent in `a` and in `b`.
+    fn combine(
+        a: &AdjList,
+        b: &AdjList,
+        keep: impl Fn(bool, bool) -> bool,
+    ) -> Result<AdjList, GraphError> {
+        if a.num_vertices != b.num_vertices {
+            return Err(GraphError::VertexCountMismatch {
+                left: a.num_vertices,
+                right: b.num_vertices,
+            });
+        }
+
+        let n = a.num_vertices;
+        let mut combined = AdjList::new(n);
+
+        for u in 0..n {
+            let mut in_a = vec![false; n];
+            let mut in_b = vec![false; n];
+            for v in a.adj_list[u].iter() {
+                in_a[*v] = true;
+            }
+            for v in b.adj_list[u].iter() {
+                in_b[*v] = true;
+            }
+
+            for v in (u + 1..n).filter(|v| keep(in_a[*v], in_b[*v])) {
+                combined.add_edge(u, v);
+            }
+        }
+
+        Ok(combined)
+    }
+
+    /// Builds the graph with the edges of either `a` or `b`. Edge weights are dropped.
+    ///
+    /// Returns [`GraphError::VertexCountMismatch`] if the graphs have different numbers of
+    /// vertices.
+    pub fn union(a: &AdjList, b: &AdjList) -> Result<AdjList, GraphError> {
+        AdjList::combine(a, b, |in_a, in_b| in_a || in_b)
+    }
+
+    /// Builds the graph with the edges of both `a` and `b`. Edge weights are dropped.
+    ///
+    /// Returns [`GraphError::VertexCountMismatch`] if the graphs have different numbers of
+    /// vertices.
+    pub fn intersection(a: &AdjList, b: &AdjList) -> Result<AdjList, GraphError> {
+        AdjList::combine(a, b, |in_a, in_b| in_a && in_b)
+    }
+
+    /// Builds the graph with the edges of exactly one of `a` and `b`. Edge weights are dropped.
+    ///
+    /// Returns [`GraphError::VertexCountMismatch`] if the graphs have different numbers of
+    /// vertices.
+    pub fn symmetric_difference(a: &AdjList, b: &AdjList) -> Result<AdjList, GraphError> {
+        AdjList::combine(a, b, |in_a, in_b| in_a != in_b)
+    }
+
+    /// Builds a product of `a` and `b`, whose vertices are the pairs `(u, v)`, numbered
+    /// `u * |V(b)| + v`. The pairs `(u1, v1)` and `(u2, v2)` are adjacent if `tensor` and both
+    /// `u1 u2` and `v1 v2` are edges, or if `cartesian` and one of them is an edge while the
+    /// other coordinates are equal.
+    fn product(a: &AdjList, b: &AdjList, tensor: bool, cartesian: bool) -> AdjList {
+        let m = b.num_vertices;
+        let mut product = AdjList::new(a.num_vertices * m);
+
+        for (u1, u2) in a.edge_list_as_slice().iter() {
+            if cartesian {
+                for v in 0..m {
+                    product.add_edge(u1 * m + v, u2 * m + v);
+                }
+            }
+
+            if tensor {
+                for (v1, v2) in b.edge_list_as_slice().iter() {
+                    product.add_edge(u1 * m + v1, u2 * m + v2);
+                    product.add_edge(u1 * m + v2, u2 * m + v1);
+                }
+            }
+        }
+
+        if cartesian {
+            for u in 0..a.num_vertices {
+                for (v1, v2) in b.edge_list_as_slice().iter() {
+                    product.add_edge(u * m + v1, u * m + v2);
+                }
+            }
         }
+
+        product
+    }
+
+    /// Builds the tensor (categorical) product of `a` and `b`, where `(u1, v1)` and `(u2, v2)`
+    /// are adjacent iff `u1 u2` is an edge of `a` and `v1 v2` is an edge of `b`. The pair
+    /// `(u, v)` is vertex `u * |V(b)| + v`.
+    pub fn tensor_product(a: &AdjList, b: &AdjList) -> AdjList {
+        AdjList::product(a, b, true, false)
+    }
+
+    /// Builds the Cartesian product of `a` and `b`, where `(u1, v1)` and `(u2, v2)` are adjacent
+    /// iff `u1 = u2` and `v1 v2` is an edge of `b`, or `v1 = v2` and `u1 u2` is an edge of `a`.
+    /// The pair `(u, v)` is vertex `u * |V(b)| + v`.
+    pub fn cartesian_product(a: &AdjList, b: &AdjList) -> AdjList {
+        AdjList::product(a, b, false, true)
+    }
+
+    /// Builds the strong product of `a` and `b`, the union of their tensor and Cartesian
+    /// products. The pair `(u, v)` is vertex `u * |V(b)| + v`.
+    pub fn strong_product(a: &AdjList, b: &AdjList) -> AdjList {
+        AdjList::product(a, b, true, true)
     }
 
     pub fn adj_list(&self) -> &[Vec<usize>] {
         self.adj_list.as_ref()
     }
 
+    /// Gives direct access to the adjacency list.
+    ///
+    /// The cached structural properties are not updated: call [`AdjList::reset_cache`] after
+    /// modifying the graph through this reference.
     pub fn adj_list_mut(&mut self) -> &mut Vec<Vec<usize>> {
         &mut self.adj_list
     }
@@ -42,6 +438,191 @@ impl AdjList {
         self.num_vertices
     }
 
+    /// Number of edges in the graph, kept up to date as edges are added or removed.
+    pub fn edge_count(&self) -> usize {
+        self.num_edges
+    }
+
+    /// Counts the edges from the adjacency lists, never counting self-loops. Matches the cached
+    /// [`AdjList::edge_count`] for graphs without them.
+    pub fn num_edges(&self) -> usize {
+        self.adj_list
+            .iter()
+            .enumerate()
+            .map(|(u, neighbors)| neighbors.iter().filter(|v| u < **v).count())
+            .sum()
+    }
+
+    /// Edges `(u, v)` of the graph, with `u < v`, in lexicographic order. Self-loops are left out.
+    pub fn edge_list(&self) -> Vec<(usize, usize)> {
+        self.edge_list_as_slice().to_vec()
+    }
+
+    /// Same as [`AdjList::edge_list`], but borrows the list, which is computed on the first call
+    /// and cached until the structure changes.
+    pub fn edge_list_as_slice(&self) -> &[(usize, usize)] {
+        self.edge_list.get_or_init(|| {
+            let mut edges: Vec<(usize, usize)> = self
+                .adj_list
+                .iter()
+                .enumerate()
+                .flat_map(|(u, neighbors)| {
+                    neighbors
+                        .iter()
+                        .filter(move |v| u < **v)
+                        .map(move |v| (u, *v))
+                })
+                .collect();
+            edges.sort();
+            edges
+        })
+    }
+
+    /// Hash of the number of vertices and the [`AdjList::edge_list`] of the graph, which doesn't
+    /// depend on the order of the adjacency lists, nor on the edge weights.
+    ///
+    /// Equal graphs have the same fingerprint, while different graphs have different ones with
+    /// very high probability. The hash is FNV-1a, so the fingerprints are the same across runs
+    /// and platforms.
+    pub fn fingerprint(&self) -> u64 {
+        const FNV_OFFSET_BASIS: u64 = 0xcbf2_9ce4_8422_2325;
+        const FNV_PRIME: u64 = 0x0100_0000_01b3;
+
+        std::iter::once(self.num_vertices)
+            .chain(self.edge_list_as_slice().iter().flat_map(|(u, v)| [*u, *v]))
+            .flat_map(|value| (value as u64).to_le_bytes())
+            .fold(FNV_OFFSET_BASIS, |hash, byte| {
+                (hash ^ byte as u64).wrapping_mul(FNV_PRIME)
+            })
+    }
+
+    /// Checks whether the graph has the same vertices and edges as `other`, ignoring the edge
+    /// weights, in `O(n + m)` time once both edge lists are computed. Unlike comparing
+    /// [`AdjList::fingerprint`]s, this is always right.
+    pub fn structural_equality(&self, other: &AdjList) -> bool {
+        self.num_vertices == other.num_vertices
+            && self.edge_list_as_slice() == other.edge_list_as_slice()
+    }
+
+    /// Largest vertex degree in the graph. Computed on the first call and cached until the
+    /// structure changes.
+    pub fn max_degree(&self) -> usize {
+        *self.max_degree.get_or_init(|| {
+            self.adj_list
+                .iter()
+                .map(|neighbors| neighbors.len())
+                .max()
+                .unwrap_or(0)
+        })
+    }
+
+    /// Recomputes the cached structural properties from the adjacency list.
+    pub fn reset_cache(&mut self) {
+        self.num_edges = self
+            .adj_list
+            .iter()
+            .map(|neighbors| neighbors.len())
+            .sum::<usize>()
+            / 2;
+        self.clear_cache();
+    }
+
+    /// Clears the lazily computed properties, after a change in the structure.
+    fn clear_cache(&mut self) {
+        self.max_degree = OnceLock::new();
+        self.degree_sequence = OnceLock::new();
+        self.edge_list = OnceLock::new();
+    }
+
+    /// Number of neighbors of `v`, the length of its adjacency list.
+    pub fn get_degree(&self, v: usize) -> usize {
+        self.adj_list[v].len()
+    }
+
+    /// Degrees of the vertices in non-increasing order. Computed on the first call and cached
+    /// until the structure changes.
+    pub fn degree_sequence(&self) -> &[usize] {
+        self.degree_sequence.get_or_init(|| {
+            let mut degrees: Vec<usize> = self
+                .adj_list
+                .iter()
+                .map(|neighbors| neighbors.len())
+                .collect();
+            degrees.sort_by_key(|degree| Reverse(*degree));
+            degrees
+        })
+    }
+
+    /// Checks whether every vertex has degree exactly `k`.
+    pub fn is_regular(&self, k: usize) -> bool {
+        self.adj_list.iter().all(|neighbors| neighbors.len() == k)
+    }
+
+    /// Checks whether all the vertices have the same degree, which is necessary for the graph to
+    /// be vertex-transitive, but not sufficient.
+    pub fn is_vertex_transitive_approx(&self) -> bool {
+        self.degree_sequence()
+            .first()
+            .is_none_or(|max_degree| self.is_regular(*max_degree))
+    }
+
+    /// Builds a [`AdjList::max_degree`]-regular graph that contains the graph as the induced
+    /// subgraph on its first vertices, with König's construction.
+    ///
+    /// While some vertex has a smaller degree, the graph is replaced by two copies of itself, the
+    /// second shifted by the number of vertices, with an edge between the copies of each such
+    /// vertex. Each round raises the minimum degree by one, so the result has `2^(Δ - δ) n`
+    /// vertices for the maximum and minimum degrees `Δ` and `δ`.
+    pub fn regularize(&self) -> AdjList {
+        let max_degree = self.max_degree();
+        let mut graph = self.clone();
+
+        while graph
+            .degree_sequence()
+            .last()
+            .is_some_and(|min_degree| *min_degree < max_degree)
+        {
+            let n = graph.num_vertices();
+            let mut doubled = AdjList::new(2 * n);
+
+            for (u, v) in graph.edge_list_as_slice().iter() {
+                let weight = graph.edge_weight(*u, *v).unwrap();
+                doubled.add_weighted_edge(*u, *v, weight);
+                doubled.add_weighted_edge(n + u, n + v, weight);
+            }
+            for v in (0..n).filter(|v| graph.get_degree(*v) < max_degree) {
+                doubled.add_edge(v, n + v);
+            }
+
+            graph = doubled;
+        }
+
+        graph
+    }
+
+    /// Vertices adjacent to some vertex of `vertices`, other than `vertices` themselves.
+    pub fn neighbors_union(&self, vertices: &[usize]) -> HashSet<usize> {
+        vertices
+            .iter()
+            .flat_map(|v| self.adj_list[*v].iter().copied())
+            .filter(|u| !vertices.contains(u))
+            .collect()
+    }
+
+    /// Vertices adjacent to every vertex of `vertices`, their common neighborhood. Empty if
+    /// `vertices` is.
+    pub fn neighbors_intersection(&self, vertices: &[usize]) -> HashSet<usize> {
+        let Some((first, rest)) = vertices.split_first() else {
+            return HashSet::new();
+        };
+
+        self.adj_list[*first]
+            .iter()
+            .copied()
+            .filter(|u| rest.iter().all(|v| self.adj_list[*v].contains(u)))
+            .collect()
+    }
+
     pub fn get_degree_in_list(&self, i: usize, list: &[usize]) -> usize {
         if i < self.num_vertices {
             self.adj_list()[i]
@@ -53,40 +634,2198 @@ impl AdjList {
         }
     }
 
-    #[cfg(test)]
-    pub fn add_edge(&mut self, u: usize, v: usize) {
-        self.adj_list_mut()[u].push(v);
-        self.adj_list_mut()[v].push(u);
+    /// Same as [`AdjList::get_degree_in_list`], for a `sorted_list` in increasing order, where
+    /// each neighbor is looked up by binary search: `O(deg(i) log |list|)` instead of
+    /// `O(deg(i) |list|)`.
+    pub fn get_degree_in_sorted_list(&self, i: usize, sorted_list: &[usize]) -> usize {
+        if i < self.num_vertices {
+            self.adj_list()[i]
+                .iter()
+                .filter(|x| sorted_list.binary_search(x).is_ok())
+                .count()
+        } else {
+            0
+        }
     }
 
-    #[cfg(test)]
-    pub fn sub_edge(&mut self, u: usize, v: usize) {
-        if let Some(index) = self.adj_list[u].iter().position(|x| *x == v) {
-            self.adj_list[u].swap_remove(index);
+    /// Greedily builds a clique from every vertex, returning the largest one.
+    ///
+    /// Starting from a vertex, the clique is repeatedly extended with the candidate (i.e., a
+    /// vertex adjacent to the whole clique) that has the most neighbors among the other
+    /// candidates.
+    pub fn greedy_clique(&self) -> Vec<usize> {
+        let mut best = Vec::new();
+
+        for start in 0..self.num_vertices {
+            let mut clique = vec![start];
+            let mut candidates = self.adj_list[start].clone();
+
+            while !candidates.is_empty() {
+                // Since there are candidates we can unwrap
+                let vertex = *candidates
+                    .iter()
+                    .max_by_key(|v| self.get_degree_in_list(**v, &candidates))
+                    .unwrap();
+
+                clique.push(vertex);
+                candidates.retain(|v| *v != vertex && self.adj_list[vertex].contains(v));
+            }
+
+            if clique.len() > best.len() {
+                best = clique;
+            }
         }
-        if let Some(index) = self.adj_list[v].iter().position(|x| *x == u) {
-            self.adj_list[v].swap_remove(index);
+
+        best
+    }
+
+    /// Lists the cliques of at most `k` vertices that can't be extended into another one: the
+    /// maximal cliques with at most `k` vertices, along with the cliques of exactly `k` vertices
+    /// inside larger ones. Each clique is sorted, and so is the list.
+    ///
+    /// The cliques are enumerated with the Bron-Kerbosch algorithm, without pivoting, as the
+    /// pivots would skip the cliques of `k` vertices that aren't maximal, and it stops growing
+    /// a clique once it has `k` vertices, which bounds the search to `O(n^k)` cliques.
+    pub fn find_all_cliques_up_to_size(&self, k: usize) -> Vec<Vec<usize>> {
+        let mut cliques = Vec::new();
+
+        if k > 0 {
+            self.bron_kerbosch(
+                k,
+                &mut Vec::new(),
+                (0..self.num_vertices).collect(),
+                Vec::new(),
+                &mut cliques,
+            );
+        }
+
+        for clique in cliques.iter_mut() {
+            clique.sort();
         }
+        cliques.sort();
+
+        cliques
     }
-}
 
-#[cfg(test)]
-mod tests {
-    use crate::input;
+    /// Reports in `cliques` the extensions of `clique` with the `candidates`, which are adjacent
+    /// to all of it, that [`AdjList::find_all_cliques_up_to_size`] lists. The `excluded` vertices
+    /// are adjacent to all of `clique` too, but their extensions were already explored.
+    fn bron_kerbosch(
+        &self,
+        k: usize,
+        clique: &mut Vec<usize>,
+        mut candidates: Vec<usize>,
+        mut excluded: Vec<usize>,
+        cliques: &mut Vec<Vec<usize>>,
+    ) {
+        if clique.len() == k || (candidates.is_empty() && excluded.is_empty()) {
+            cliques.push(clique.clone());
+            return;
+        }
 
-    #[test]
-    fn test_get_degree_in_list() {
-        if let Ok(Some(graph)) = input::read_graph_from_file("data/myc/myciel3.col") {
-            let list = vec![0, 1, 2];
-            let num_vertices = graph.num_vertices();
-            let degree = graph.get_degree_in_list(1, &list);
+        while let Some(vertex) = candidates.pop() {
+            let neighbors = &self.adj_list[vertex];
 
-            assert_eq!(degree, 2);
+            clique.push(vertex);
+            self.bron_kerbosch(
+                k,
+                clique,
+                candidates
+                    .iter()
+                    .copied()
+                    .filter(|u| neighbors.contains(u))
+                    .collect(),
+                excluded
+                    .iter()
+                    .copied()
+                    .filter(|u| neighbors.contains(u))
+                    .collect(),
+                cliques,
+            );
+            clique.pop();
 
-            let degree = graph.get_degree_in_list(num_vertices + 1, &list);
-            assert_eq!(degree, 0);
+            excluded.push(vertex);
+        }
+    }
+
+    /// A lower bound for the chromatic number, given by the size of a greedy clique.
+    pub fn clique_lower_bound(&self) -> usize {
+        self.greedy_clique().len()
+    }
+
+    /// Checks whether `coloring` gives each vertex a color (from 1 onwards) different from the
+    /// colors of its neighbors. Colorings of the wrong length are invalid.
+    pub fn is_valid_coloring(&self, coloring: &[usize]) -> bool {
+        coloring.len() == self.num_vertices
+            && coloring.iter().all(|color| *color > 0)
+            && (0..self.num_vertices)
+                .all(|u| self.adj_list[u].iter().all(|v| coloring[u] != coloring[*v]))
+    }
+
+    /// Checks whether `class_list` puts every vertex in exactly one class, with no two adjacent
+    /// vertices in the same class. Empty classes are allowed.
+    pub fn is_valid_class_list(&self, class_list: &[Vec<usize>]) -> bool {
+        let mut class_of = vec![None; self.num_vertices];
+
+        for (i, class) in class_list.iter().enumerate() {
+            for vertex in class.iter() {
+                if *vertex >= self.num_vertices || class_of[*vertex].is_some() {
+                    return false;
+                }
+                class_of[*vertex] = Some(i);
+            }
+        }
+
+        class_of.iter().all(|class| class.is_some())
+            && (0..self.num_vertices)
+                .all(|u| self.adj_list[u].iter().all(|v| class_of[u] != class_of[*v]))
+    }
+
+    /// Builds the conflict graph of `coloring`, a possibly invalid coloring: the graph on the same
+    /// vertices with only the forbidden edges, those whose endpoints share a color, along with
+    /// their weights. It has no edges if the coloring is valid.
+    pub fn conflict_subgraph(&self, coloring: &[usize]) -> AdjList {
+        let mut graph = AdjList::new(self.num_vertices);
+
+        for (u, v) in self.edge_list_as_slice().iter().copied() {
+            if coloring[u] == coloring[v] {
+                // The edge exists, so we can unwrap
+                graph.add_weighted_edge(u, v, self.edge_weight(u, v).unwrap());
+            }
+        }
+
+        graph
+    }
+
+    /// Checks whether `cover` partitions the vertices into cliques, i.e., every vertex is in
+    /// exactly one set and the vertices of each set are pairwise adjacent.
+    pub fn is_clique_cover_valid(&self, cover: &[Vec<usize>]) -> bool {
+        let mut covered = vec![false; self.num_vertices];
+
+        for clique in cover.iter() {
+            for (i, u) in clique.iter().enumerate() {
+                if *u >= self.num_vertices || covered[*u] {
+                    return false;
+                }
+                covered[*u] = true;
+
+                if clique[i + 1..]
+                    .iter()
+                    .any(|v| !self.adj_list[*u].contains(v))
+                {
+                    return false;
+                }
+            }
+        }
+
+        covered.iter().all(|covered| *covered)
+    }
+
+    /// Checks whether the vertices can be split into two sets with no edges within them (see
+    /// [`AdjList::bipartition`]).
+    pub fn is_bipartite(&self) -> bool {
+        self.bipartition().is_some()
+    }
+
+    /// Lower and upper bounds on the chromatic index (the fewest colors of the edges such that
+    /// edges sharing an endpoint have different colors), `Δ` and `Δ + 1` by Vizing's theorem,
+    /// where `Δ` is the maximum degree.
+    pub fn chromatic_index_bounds(&self) -> (usize, usize) {
+        (self.max_degree(), self.max_degree() + 1)
+    }
+
+    /// Whether the chromatic index is the lower bound of [`AdjList::chromatic_index_bounds`]
+    /// (class 1) or the upper one (class 2), when it is known without coloring the edges.
+    ///
+    /// Bipartite graphs (by König's theorem) and complete graphs with an even number of vertices
+    /// are class 1. Graphs with more than `Δ ⌊n / 2⌋` edges (e.g., odd cycles and complete
+    /// graphs with an odd number of vertices) are class 2, since each color takes at most
+    /// `⌊n / 2⌋` edges. `None` otherwise.
+    pub fn is_class_one_graph(&self) -> Option<bool> {
+        let n = self.num_vertices;
+        let num_edges = self.num_edges();
+
+        if self.is_bipartite() || (n.is_multiple_of(2) && num_edges == n * n.saturating_sub(1) / 2)
+        {
+            Some(true)
+        } else if num_edges > self.max_degree() * (n / 2) {
+            Some(false)
         } else {
-            panic!("The file containing the test graph is missing")
+            None
+        }
+    }
+
+    /// Splits the vertices into two sets with no edges within them, by 2-coloring each connected
+    /// component with a BFS, returning the side of each vertex. `None` if the graph isn't
+    /// bipartite.
+    pub fn bipartition(&self) -> Option<Vec<bool>> {
+        let mut side: Vec<Option<bool>> = vec![None; self.num_vertices];
+        let mut queue = VecDeque::new();
+
+        for start in 0..self.num_vertices {
+            if side[start].is_some() {
+                continue;
+            }
+
+            side[start] = Some(false);
+            queue.push_back(start);
+
+            while let Some(vertex) = queue.pop_front() {
+                let vertex_side = side[vertex];
+
+                for neighbor in self.adj_list[vertex].iter() {
+                    match side[*neighbor] {
+                        None => {
+                            side[*neighbor] = vertex_side.map(|s| !s);
+                            queue.push_back(*neighbor);
+                        }
+                        Some(neighbor_side) if Some(neighbor_side) == vertex_side => return None,
+                        _ => {}
+                    }
+                }
+            }
         }
+
+        // Every vertex got a side, so we can unwrap
+        Some(side.into_iter().map(|side| side.unwrap()).collect())
+    }
+
+    /// Returns `Some((m, n))` if the graph is the complete bipartite graph `K_{m,n}`, `m` being
+    /// the size of the side of vertex 0. An edgeless graph on `n` vertices is `K_{n,0}`.
+    pub fn is_complete_bipartite(&self) -> Option<(usize, usize)> {
+        if self.edge_count() == 0 {
+            return Some((self.num_vertices, 0));
+        }
+
+        // With an edge, K_{m,n} is connected, so its bipartition is unique
+        let side = self.bipartition()?;
+        let m = side.iter().filter(|s| **s == side[0]).count();
+        let n = self.num_vertices - m;
+
+        (self.edge_count() == m * n).then_some((m, n))
+    }
+
+    /// Distances from `source` to every vertex computed by a BFS, or `None` for the unreachable
+    /// ones. The search stops after `max_depth` levels, if given.
+    fn bfs_distances(&self, source: usize, max_depth: Option<usize>) -> Vec<Option<usize>> {
+        let mut distances = vec![None; self.num_vertices];
+        let mut queue = VecDeque::new();
+
+        distances[source] = Some(0);
+        queue.push_back((source, 0));
+
+        while let Some((vertex, distance)) = queue.pop_front() {
+            if max_depth.is_some_and(|max_depth| distance >= max_depth) {
+                continue;
+            }
+
+            for neighbor in self.adj_list[vertex].iter() {
+                if distances[*neighbor].is_none() {
+                    distances[*neighbor] = Some(distance + 1);
+                    queue.push_back((*neighbor, distance + 1));
+                }
+            }
+        }
+
+        distances
+    }
+
+    /// Vertices whose shortest path from `v` has exactly `d` edges, in increasing order.
+    pub fn neighbors_at_distance(&self, v: usize, d: usize) -> Vec<usize> {
+        self.bfs_distances(v, Some(d))
+            .iter()
+            .enumerate()
+            .filter(|(_, distance)| **distance == Some(d))
+            .map(|(vertex, _)| vertex)
+            .collect()
+    }
+
+    /// Largest distance from `v` to another vertex, or `None` if some vertex can't be reached.
+    pub fn eccentricity(&self, v: usize) -> Option<usize> {
+        self.bfs_distances(v, None)
+            .into_iter()
+            .try_fold(0, |eccentricity, distance| {
+                distance.map(|distance| eccentricity.max(distance))
+            })
+    }
+
+    /// Largest distance between two vertices, running a BFS from every vertex, or `None` if the
+    /// graph is disconnected.
+    pub fn diameter(&self) -> Option<usize> {
+        (0..self.num_vertices).try_fold(0, |diameter, v| {
+            self.eccentricity(v)
+                .map(|eccentricity| diameter.max(eccentricity))
+        })
+    }
+
+    /// Length of the shortest cycle of the graph, or `None` if it is a forest.
+    ///
+    /// Runs a BFS from every vertex: an edge to an already visited vertex other than the parent
+    /// closes a cycle through the source of length at most the sum of the depths of its endpoints
+    /// plus one, which is exact for the sources on a shortest cycle. Each search stops once no
+    /// shorter cycle can be found, in `O(n(n + m))` time overall.
+    pub fn girth(&self) -> Option<usize> {
+        let mut girth: Option<usize> = None;
+        let mut distances = vec![None; self.num_vertices];
+        let mut parents = vec![None; self.num_vertices];
+
+        for source in 0..self.num_vertices {
+            distances.fill(None);
+            parents.fill(None);
+            distances[source] = Some(0);
+            let mut queue = VecDeque::from([(source, 0)]);
+
+            while let Some((vertex, distance)) = queue.pop_front() {
+                // Cycles found deeper would be at least as long
+                if girth.is_some_and(|girth| 2 * distance + 1 >= girth) {
+                    break;
+                }
+
+                for neighbor in self.adj_list[vertex].iter() {
+                    match distances[*neighbor] {
+                        None => {
+                            distances[*neighbor] = Some(distance + 1);
+                            parents[*neighbor] = Some(vertex);
+                            queue.push_back((*neighbor, distance + 1));
+                        }
+                        Some(other) if parents[vertex] != Some(*neighbor) => {
+                            let length = distance + other + 1;
+                            girth = Some(girth.map_or(length, |girth| girth.min(length)));
+                        }
+                        _ => {}
+                    }
+                }
+            }
+        }
+
+        girth
+    }
+
+    /// Checks whether the graph has no triangles, i.e., whether its girth is larger than 3.
+    pub fn is_triangle_free(&self) -> bool {
+        self.girth().is_none_or(|girth| girth > 3)
+    }
+
+    /// A path from `u` to `v` with the fewest edges, found by a BFS, as the sequence of its
+    /// vertices. Returns `None` if `v` can't be reached from `u`.
+    pub fn shortest_path(&self, u: usize, v: usize) -> Option<Vec<usize>> {
+        let mut parent = vec![None; self.num_vertices];
+        let mut visited = vec![false; self.num_vertices];
+        let mut queue = VecDeque::new();
+
+        visited[u] = true;
+        queue.push_back(u);
+
+        while let Some(vertex) = queue.pop_front() {
+            if vertex == v {
+                break;
+            }
+
+            for neighbor in self.adj_list[vertex].iter() {
+                if !visited[*neighbor] {
+                    visited[*neighbor] = true;
+                    parent[*neighbor] = Some(vertex);
+                    queue.push_back(*neighbor);
+                }
+            }
+        }
+
+        if !visited[v] {
+            return None;
+        }
+
+        let mut path = vec![v];
+        while let Some(previous) = parent[*path.last().unwrap()] {
+            path.push(previous);
+        }
+        path.reverse();
+
+        Some(path)
+    }
+
+    /// Number of edges of a shortest path from `u` to `v`, or `None` if `v` can't be reached
+    /// from `u`.
+    pub fn shortest_path_length(&self, u: usize, v: usize) -> Option<usize> {
+        self.bfs_distances(u, None)[v]
+    }
+
+    /// Distances between every pair of vertices, running a BFS from every vertex, or `None` for
+    /// the pairs in different components.
+    pub fn all_pairs_shortest_paths(&self) -> Vec<Vec<Option<usize>>> {
+        (0..self.num_vertices)
+            .map(|v| self.bfs_distances(v, None))
+            .collect()
+    }
+
+    /// Orders the vertices following the Cuthill-McKee heuristic, which keeps adjacent vertices
+    /// close to each other.
+    ///
+    /// Each connected component is traversed by a BFS starting from its vertex with the smallest
+    /// degree, visiting the neighbors of each vertex by increasing degree. Ties are broken by the
+    /// smallest index.
+    pub fn cuthill_mckee_ordering(&self) -> Vec<usize> {
+        let degree = |v: usize| self.adj_list[v].len();
+        let mut by_degree: Vec<usize> = (0..self.num_vertices).collect();
+        by_degree.sort_by_key(|v| degree(*v));
+
+        let mut visited = vec![false; self.num_vertices];
+        let mut ordering = Vec::with_capacity(self.num_vertices);
+        let mut queue = VecDeque::new();
+
+        for start in by_degree {
+            if visited[start] {
+                continue;
+            }
+
+            visited[start] = true;
+            queue.push_back(start);
+
+            while let Some(vertex) = queue.pop_front() {
+                ordering.push(vertex);
+
+                let mut neighbors: Vec<usize> = self.adj_list[vertex]
+                    .iter()
+                    .copied()
+                    .filter(|u| !visited[*u])
+                    .collect();
+                neighbors.sort_by_key(|u| (degree(*u), *u));
+
+                for neighbor in neighbors {
+                    visited[neighbor] = true;
+                    queue.push_back(neighbor);
+                }
+            }
+        }
+
+        ordering
+    }
+
+    /// Density of the subgraph induced by `vertices`: the ratio between its edges and the edges of
+    /// a complete graph on the same vertices. Equals 0 when there are less than two vertices.
+    pub fn subgraph_density(&self, vertices: &[usize]) -> f64 {
+        let n = vertices.len();
+
+        if n < 2 {
+            return 0.0;
+        }
+
+        let num_edges: usize = vertices
+            .iter()
+            .map(|v| self.get_degree_in_list(*v, vertices))
+            .sum::<usize>()
+            / 2;
+
+        num_edges as f64 / (n * (n - 1) / 2) as f64
+    }
+
+    /// Approximates the independence number by building `trials` greedy independent sets, each
+    /// one from a random order of the vertices, and returning the size of the largest one.
+    ///
+    /// The orders are drawn from a generator seeded with `seed`.
+    pub fn independence_number_approx(&self, trials: usize, seed: u64) -> usize {
+        let mut rng = StdRng::seed_from_u64(seed);
+        let mut order: Vec<usize> = (0..self.num_vertices).collect();
+        let mut best = 0;
+
+        for _ in 0..trials {
+            order.shuffle(&mut rng);
+
+            let mut independent_set: Vec<usize> = Vec::new();
+
+            for vertex in order.iter() {
+                if !self.adj_list[*vertex]
+                    .iter()
+                    .any(|u| independent_set.contains(u))
+                {
+                    independent_set.push(*vertex);
+                }
+            }
+
+            best = best.max(independent_set.len());
+        }
+
+        best
+    }
+
+    /// Approximates a minimum vertex cover, a set of vertices touching every edge.
+    ///
+    /// Returns the smaller of two greedy covers: the endpoints of a maximal matching, which is at
+    /// most twice as large as a minimum cover, and the one built by repeatedly taking the vertex
+    /// covering the most remaining edges, which is usually smaller. The cover is sorted.
+    pub fn min_vertex_cover_approx(&self) -> Vec<usize> {
+        let mut matching_cover = vec![false; self.num_vertices];
+
+        for u in 0..self.num_vertices {
+            if matching_cover[u] {
+                continue;
+            }
+
+            if let Some(v) = self.adj_list[u]
+                .iter()
+                .find(|v| **v != u && !matching_cover[**v])
+            {
+                matching_cover[u] = true;
+                matching_cover[*v] = true;
+            }
+        }
+
+        let mut degree: Vec<usize> = (0..self.num_vertices)
+            .map(|u| self.adj_list[u].iter().filter(|v| **v != u).count())
+            .collect();
+        let mut greedy_cover = vec![false; self.num_vertices];
+
+        // Since the remaining degrees only decrease, the heap entries are lazily refreshed
+        let mut heap: BinaryHeap<(usize, Reverse<usize>)> = (0..self.num_vertices)
+            .map(|u| (degree[u], Reverse(u)))
+            .collect();
+
+        while let Some((d, Reverse(u))) = heap.pop() {
+            if d == 0 {
+                break;
+            }
+            if greedy_cover[u] || d != degree[u] {
+                continue;
+            }
+
+            greedy_cover[u] = true;
+            degree[u] = 0;
+
+            for v in self.adj_list[u].iter().filter(|v| !greedy_cover[**v]) {
+                degree[*v] -= 1;
+                heap.push((degree[*v], Reverse(*v)));
+            }
+        }
+
+        let to_set = |in_cover: Vec<bool>| -> Vec<usize> {
+            (0..self.num_vertices).filter(|u| in_cover[*u]).collect()
+        };
+        let (matching_cover, greedy_cover) = (to_set(matching_cover), to_set(greedy_cover));
+
+        if greedy_cover.len() <= matching_cover.len() {
+            greedy_cover
+        } else {
+            matching_cover
+        }
+    }
+
+    /// A lower bound for the independence number, given by the vertices outside
+    /// [`AdjList::min_vertex_cover_approx`], which are pairwise non-adjacent.
+    pub fn independence_number_from_cover(&self) -> usize {
+        self.num_vertices - self.min_vertex_cover_approx().len()
+    }
+
+    /// Partitions the vertices into the orbits of the automorphism group, the classes of vertices
+    /// that some automorphism maps onto each other. Each orbit is sorted, and the orbits are
+    /// sorted by their first vertex.
+    ///
+    /// Graphs with at most [`MAX_EXACT_ORBIT_VERTICES`] vertices are handled exactly, by trying
+    /// every permutation consistent with the adjacencies. Larger graphs are split by color
+    /// refinement instead, repeatedly separating vertices by their degree and the classes of
+    /// their neighbors. Vertices of the same orbit always end up in the same class, but a class
+    /// may join several orbits.
+    pub fn find_automorphism_orbits(&self) -> Vec<Vec<usize>> {
+        let labels = if self.num_vertices <= MAX_EXACT_ORBIT_VERTICES {
+            self.exact_orbit_labels()
+        } else {
+            self.refined_orbit_labels()
+        };
+
+        let mut orbits: Vec<Vec<usize>> = Vec::new();
+        let mut orbit_index: HashMap<usize, usize> = HashMap::new();
+
+        for (vertex, label) in labels.iter().enumerate() {
+            let index = *orbit_index.entry(*label).or_insert_with(|| {
+                orbits.push(Vec::new());
+                orbits.len() - 1
+            });
+            orbits[index].push(vertex);
+        }
+
+        orbits
+    }
+
+    /// The orbit of [`AdjList::find_automorphism_orbits`] containing `v`.
+    pub fn orbit_of(&self, v: usize) -> Vec<usize> {
+        self.find_automorphism_orbits()
+            .into_iter()
+            .find(|orbit| orbit.contains(&v))
+            .unwrap()
+    }
+
+    /// Labels every vertex with the smallest vertex of its orbit, found by enumerating the
+    /// automorphisms and merging each vertex with its images in a disjoint-set forest.
+    fn exact_orbit_labels(&self) -> Vec<usize> {
+        let n = self.num_vertices;
+        let mut adjacent = vec![vec![false; n]; n];
+
+        for (u, neighbors) in self.adj_list.iter().enumerate() {
+            for v in neighbors.iter() {
+                adjacent[u][*v] = true;
+            }
+        }
+
+        let mut labels: Vec<usize> = (0..n).collect();
+        let mut image = Vec::with_capacity(n);
+        let mut used = vec![false; n];
+
+        self.extend_automorphism(&adjacent, &mut image, &mut used, &mut labels);
+
+        (0..n).map(|v| find_root(&labels, v)).collect()
+    }
+
+    /// Tries every image for the next vertex of the partial automorphism `image` that keeps the
+    /// adjacencies with the vertices already mapped, merging the orbits of the complete ones in
+    /// the forest `labels`.
+    fn extend_automorphism(
+        &self,
+        adjacent: &[Vec<bool>],
+        image: &mut Vec<usize>,
+        used: &mut [bool],
+        labels: &mut [usize],
+    ) {
+        let vertex = image.len();
+
+        if vertex == self.num_vertices {
+            for (u, v) in image.iter().enumerate() {
+                let (u, v) = (find_root(labels, u), find_root(labels, *v));
+                labels[u.max(v)] = u.min(v);
+            }
+            return;
+        }
+
+        for target in 0..self.num_vertices {
+            if used[target]
+                || self.get_degree(target) != self.get_degree(vertex)
+                || adjacent[vertex][vertex] != adjacent[target][target]
+                || (0..vertex).any(|u| adjacent[u][vertex] != adjacent[image[u]][target])
+            {
+                continue;
+            }
+
+            used[target] = true;
+            image.push(target);
+            self.extend_automorphism(adjacent, image, used, labels);
+            image.pop();
+            used[target] = false;
+        }
+    }
+
+    /// Labels every vertex with its class after color refinement, starting from the degrees.
+    fn refined_orbit_labels(&self) -> Vec<usize> {
+        let mut labels: Vec<usize> = (0..self.num_vertices).map(|v| self.get_degree(v)).collect();
+        let mut num_classes = 0;
+
+        loop {
+            let mut signatures: HashMap<(usize, Vec<usize>), usize> = HashMap::new();
+            let refined: Vec<usize> = (0..self.num_vertices)
+                .map(|v| {
+                    let mut neighbor_labels: Vec<usize> =
+                        self.adj_list[v].iter().map(|u| labels[*u]).collect();
+                    neighbor_labels.sort();
+
+                    let next = signatures.len();
+                    *signatures
+                        .entry((labels[v], neighbor_labels))
+                        .or_insert(next)
+                })
+                .collect();
+
+            // Classes are only ever split, so the refinement is stable once none was
+            if signatures.len() == num_classes {
+                return refined;
+            }
+
+            num_classes = signatures.len();
+            labels = refined;
+        }
+    }
+
+    /// Appends a new isolated vertex to the graph, returning its index.
+    pub fn add_vertex(&mut self) -> usize {
+        self.adj_list.push(Vec::new());
+        self.num_vertices += 1;
+        self.clear_cache();
+        self.num_vertices - 1
+    }
+
+    /// Removes `v` and all its incident edges from the graph.
+    ///
+    /// The index space is contracted: every vertex with an index larger than `v` is shifted down by one.
+    pub fn remove_vertex(&mut self, v: usize) {
+        let neighbors = self.adj_list.remove(v);
+        self.num_vertices -= 1;
+        self.num_edges -= neighbors.len();
+        self.clear_cache();
+
+        let shift = |u: usize| if u > v { u - 1 } else { u };
+        self.weights = self
+            .weights
+            .drain()
+            .filter(|((a, b), _)| *a != v && *b != v)
+            .map(|((a, b), weight)| ((shift(a), shift(b)), weight))
+            .collect();
+
+        for neighbors in self.adj_list.iter_mut() {
+            neighbors.retain(|u| *u != v);
+
+            for u in neighbors.iter_mut() {
+                if *u > v {
+                    *u -= 1;
+                }
+            }
+        }
+    }
+
+    /// Builds a copy of the graph without `v` and its incident edges, with the indices larger
+    /// than `v` shifted down by one (see [`AdjList::remove_vertex`]).
+    pub fn delete_vertex(&self, v: usize) -> AdjList {
+        let mut graph = self.clone();
+        graph.remove_vertex(v);
+        graph
+    }
+
+    /// Builds a copy of the graph without the edge between `u` and `v`, keeping the vertices.
+    pub fn delete_edge(&self, u: usize, v: usize) -> AdjList {
+        let mut graph = self.clone();
+        graph.remove_edge(u, v);
+        graph
+    }
+
+    /// Builds the graph obtained by merging `v` into `u`: the merged vertex is adjacent to the
+    /// neighbors of both, without loops or parallel edges. `u` and `v` don't need to be adjacent.
+    /// Edges that become parallel keep the largest weight.
+    ///
+    /// As in [`AdjList::remove_vertex`], the indices larger than `v` are shifted down by one.
+    pub fn contract_edge(&self, u: usize, v: usize) -> AdjList {
+        let index = |w: usize| {
+            let w = if w == v { u } else { w };
+            if w > v {
+                w - 1
+            } else {
+                w
+            }
+        };
+        let mut graph = AdjList::new(self.num_vertices - 1);
+
+        for (a, neighbors) in self.adj_list.iter().enumerate() {
+            for b in neighbors.iter().filter(|b| a < **b) {
+                let weight = self.edge_weight(a, *b).unwrap();
+                let (a, b) = (index(a), index(*b));
+
+                if a == b {
+                    continue;
+                }
+
+                match graph.edge_weight(a, b) {
+                    None => graph.add_weighted_edge(a, b, weight),
+                    Some(current) if weight > current => {
+                        graph.weights.insert(edge_key(a, b), weight);
+                    }
+                    _ => {}
+                }
+            }
+        }
+
+        graph
+    }
+
+    /// Builds the graphs obtained by merging the vertices of `order` one after the other with
+    /// [`AdjList::contract_edge`]: the sequence starts with a copy of the graph, and each next
+    /// graph merges `order[i]` into `order[i + 1]`, so it has `order.len()` graphs and the last
+    /// one merges all the vertices of `order` into one. The vertices of `order` must be distinct.
+    ///
+    /// When `order[i]` and `order[i + 1]` aren't adjacent at that point, giving both the color of
+    /// the merged vertex turns a coloring of the next graph into one of the previous graph, so the
+    /// chromatic number never decreases along such a sequence. Merging adjacent vertices gives no
+    /// such bound.
+    pub fn vertex_contraction_sequence(&self, order: &[usize]) -> Vec<AdjList> {
+        let mut sequence = Vec::with_capacity(order.len());
+        if order.is_empty() {
+            return sequence;
+        }
+
+        // Index of the vertex holding each original vertex in the last graph
+        let mut index: Vec<usize> = (0..self.num_vertices).collect();
+        sequence.push(self.clone());
+
+        for pair in order.windows(2) {
+            let (v, u) = (index[pair[0]], index[pair[1]]);
+            // The sequence isn't empty, so we can unwrap
+            let graph = sequence.last().unwrap().contract_edge(u, v);
+
+            for w in index.iter_mut() {
+                if *w == v {
+                    *w = u;
+                }
+                if *w > v {
+                    *w -= 1;
+                }
+            }
+            sequence.push(graph);
+        }
+
+        sequence
+    }
+
+    /// Builds the subgraph induced by `vertices`, keeping the edge weights. The `i`th vertex of
+    /// the subgraph is `vertices[i]`.
+    pub fn induced_subgraph(&self, vertices: &[usize]) -> AdjList {
+        let mut index = vec![None; self.num_vertices];
+        for (i, vertex) in vertices.iter().enumerate() {
+            index[*vertex] = Some(i);
+        }
+
+        let mut graph = AdjList::new(vertices.len());
+
+        for (i, u) in vertices.iter().enumerate() {
+            for v in self.adj_list[*u].iter() {
+                if let Some(j) = index[*v].filter(|j| i < *j) {
+                    // The edge exists, so we can unwrap
+                    graph.add_weighted_edge(i, j, self.edge_weight(*u, *v).unwrap());
+                }
+            }
+        }
+
+        graph
+    }
+
+    /// Repeatedly removes a vertex with the smallest degree in the remaining graph (the smallest
+    /// index on ties), returning the order of removal and the coreness of each vertex, the largest
+    /// of these degrees seen up to its removal.
+    fn peel_min_degree(&self) -> (Vec<usize>, Vec<usize>) {
+        let mut degrees: Vec<usize> = self.adj_list.iter().map(Vec::len).collect();
+        let mut removed = vec![false; self.num_vertices];
+        let mut coreness = vec![0; self.num_vertices];
+        let mut order = Vec::with_capacity(self.num_vertices);
+        let mut heap: BinaryHeap<Reverse<(usize, usize)>> = (0..self.num_vertices)
+            .map(|v| Reverse((degrees[v], v)))
+            .collect();
+        let mut k = 0;
+
+        while let Some(Reverse((degree, vertex))) = heap.pop() {
+            // Skips the outdated entries
+            if removed[vertex] || degree != degrees[vertex] {
+                continue;
+            }
+
+            k = k.max(degree);
+            coreness[vertex] = k;
+            removed[vertex] = true;
+            order.push(vertex);
+
+            for neighbor in self.adj_list[vertex].iter().filter(|u| !removed[**u]) {
+                degrees[*neighbor] -= 1;
+                heap.push(Reverse((degrees[*neighbor], *neighbor)));
+            }
+        }
+
+        (order, coreness)
+    }
+
+    /// Computes the coreness of each vertex, the largest `k` such that it belongs to the `k`-core,
+    /// the maximal subgraph where every vertex has degree at least `k`.
+    ///
+    /// Repeatedly removes a vertex with the smallest degree in the remaining graph. The coreness
+    /// of a vertex is the largest of these degrees seen up to its removal.
+    pub fn k_core_decomposition(&self) -> Vec<usize> {
+        self.peel_min_degree().1
+    }
+
+    /// Orders the vertices so that each has at most `d` neighbors before it, where the
+    /// degeneracy `d` is the largest coreness (see [`AdjList::k_core_decomposition`]).
+    ///
+    /// This is the reverse of the order in which the vertices of smallest degree are removed.
+    pub fn degeneracy_ordering(&self) -> Vec<usize> {
+        let mut order = self.peel_min_degree().0;
+        order.reverse();
+
+        order
+    }
+
+    /// Builds the `k`-core, the subgraph induced by the vertices with coreness at least `k` (see
+    /// [`AdjList::k_core_decomposition`]), keeping their relative order.
+    pub fn k_core(&self, k: usize) -> AdjList {
+        self.induced_subgraph(&self.k_core_vertices(k))
+    }
+
+    /// Vertices with coreness at least `k`, in increasing order.
+    pub(crate) fn k_core_vertices(&self, k: usize) -> Vec<usize> {
+        self.k_core_decomposition()
+            .iter()
+            .enumerate()
+            .filter(|(_, coreness)| **coreness >= k)
+            .map(|(vertex, _)| vertex)
+            .collect()
+    }
+
+    /// Sets how repeated edges are handled, see [`GraphMode`].
+    pub fn with_mode(mut self, mode: GraphMode) -> Self {
+        self.mode = mode;
+        self
+    }
+
+    pub fn mode(&self) -> GraphMode {
+        self.mode
+    }
+
+    /// Checks whether `u` and `v` are adjacent.
+    pub fn has_edge(&self, u: usize, v: usize) -> bool {
+        self.adj_list[u].contains(&v)
+    }
+
+    /// Adds an edge between `u` and `v`, ignoring it if they are already adjacent.
+    ///
+    /// Panics on a repeated edge in [`GraphMode::Strict`], see [`AdjList::try_add_edge`] to
+    /// handle it instead.
+    pub fn add_edge(&mut self, u: usize, v: usize) {
+        if let Err(err) = self.try_add_edge(u, v) {
+            panic!("{err}");
+        }
+    }
+
+    /// Adds an edge between `u` and `v`. If they are already adjacent, the graph is unchanged
+    /// and, in [`GraphMode::Strict`], [`GraphError::DuplicateEdge`] is returned.
+    pub fn try_add_edge(&mut self, u: usize, v: usize) -> Result<(), GraphError> {
+        if self.has_edge(u, v) {
+            return match self.mode {
+                GraphMode::Permissive => Ok(()),
+                GraphMode::Strict => Err(GraphError::DuplicateEdge { u, v }),
+            };
+        }
+
+        self.adj_list_mut()[u].push(v);
+        self.adj_list_mut()[v].push(u);
+        self.num_edges += 1;
+        self.clear_cache();
+
+        Ok(())
+    }
+
+    /// Adds every edge of `edges`, all of them or none: they're all checked before adding any.
+    /// Edges already in the graph, or repeated, are ignored as in [`AdjList::try_add_edge`].
+    ///
+    /// Returns [`GraphError::VertexOutOfRange`] if an endpoint isn't a vertex,
+    /// [`GraphError::SelfLoop`] if an edge joins a vertex to itself, or, in
+    /// [`GraphMode::Strict`], [`GraphError::DuplicateEdge`] if an edge is already in the graph or
+    /// repeated.
+    pub fn add_edges_from_edge_list(&mut self, edges: &[(usize, usize)]) -> Result<(), GraphError> {
+        let n = self.num_vertices;
+        let mut seen = HashSet::new();
+
+        for (u, v) in edges.iter().copied() {
+            if let Some(vertex) = [u, v].into_iter().find(|vertex| *vertex >= n) {
+                return Err(GraphError::VertexOutOfRange { vertex, n });
+            }
+
+            if u == v {
+                return Err(GraphError::SelfLoop { vertex: u });
+            }
+
+            if self.mode == GraphMode::Strict
+                && (self.has_edge(u, v) || !seen.insert(edge_key(u, v)))
+            {
+                return Err(GraphError::DuplicateEdge { u, v });
+            }
+        }
+
+        self.add_edges_from_edge_list_unchecked(edges);
+
+        Ok(())
+    }
+
+    /// Same as [`AdjList::add_edges_from_edge_list`], but without checking the edges, which the
+    /// caller guarantees to join two different vertices. Edges already in the graph are ignored,
+    /// even in [`GraphMode::Strict`]. Unlike adding them one by one, the cached values are
+    /// cleared once.
+    pub fn add_edges_from_edge_list_unchecked(&mut self, edges: &[(usize, usize)]) {
+        for (u, v) in edges.iter().copied() {
+            if !self.adj_list[u].contains(&v) {
+                self.adj_list[u].push(v);
+                self.adj_list[v].push(u);
+                self.num_edges += 1;
+            }
+        }
+
+        self.clear_cache();
+    }
+
+    /// Adds an edge between `u` and `v` with the given `weight`. Edges added otherwise have a
+    /// weight of 1.
+    pub fn add_weighted_edge(&mut self, u: usize, v: usize, weight: f64) {
+        self.add_edge(u, v);
+
+        if weight != 1.0 {
+            self.weights.insert(edge_key(u, v), weight);
+        }
+    }
+
+    /// Weight of the edge between `u` and `v`, or `None` if they aren't adjacent.
+    pub fn edge_weight(&self, u: usize, v: usize) -> Option<f64> {
+        if self.adj_list[u].contains(&v) {
+            Some(*self.weights.get(&edge_key(u, v)).unwrap_or(&1.0))
+        } else {
+            None
+        }
+    }
+
+    /// Checks whether some edge has a weight other than the default of 1.
+    pub fn is_weighted(&self) -> bool {
+        !self.weights.is_empty()
+    }
+
+    pub fn remove_edge(&mut self, u: usize, v: usize) {
+        if let Some(index) = self.adj_list[u].iter().position(|x| *x == v) {
+            self.adj_list[u].swap_remove(index);
+            self.num_edges -= 1;
+            self.clear_cache();
+        }
+        if let Some(index) = self.adj_list[v].iter().position(|x| *x == u) {
+            self.adj_list[v].swap_remove(index);
+        }
+        self.weights.remove(&edge_key(u, v));
+    }
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+    use crate::{
+        graph::generators::{cycle_graph, path_graph},
+        input,
+    };
+
+    /// Sorts the neighborhoods of `graph`, so graphs built in different orders can be compared.
+    fn sorted_adj_list(graph: &AdjList) -> Vec<Vec<usize>> {
+        let mut adj_list = graph.adj_list().to_vec();
+
+        for neighbors in adj_list.iter_mut() {
+            neighbors.sort();
+        }
+
+        adj_list
+    }
+
+    #[test]
+    fn test_get_degree_in_list() {
+        if let Ok(Some(graph)) = input::read_graph_from_file("data/myc/myciel3.col") {
+            let list = vec![0, 1, 2];
+            let num_vertices = graph.num_vertices();
+            let degree = graph.get_degree_in_list(1, &list);
+
+            assert_eq!(degree, 2);
+
+            let degree = graph.get_degree_in_list(num_vertices + 1, &list);
+            assert_eq!(degree, 0);
+
+            // Both agree on sorted lists
+            let list: Vec<usize> = (0..num_vertices).step_by(2).collect();
+            for vertex in 0..num_vertices + 1 {
+                assert_eq!(
+                    graph.get_degree_in_sorted_list(vertex, &list),
+                    graph.get_degree_in_list(vertex, &list)
+                );
+            }
+        } else {
+            panic!("The file containing the test graph is missing")
+        }
+    }
+
+    #[test]
+    fn test_neighbors_union_and_intersection() {
+        let graph = AdjList::complete(4);
+
+        assert_eq!(graph.neighbors_union(&[0, 1]), HashSet::from([2, 3]));
+        assert_eq!(graph.neighbors_intersection(&[0, 1]), HashSet::from([2, 3]));
+
+        let graph = path_graph(5);
+
+        assert_eq!(graph.neighbors_union(&[0, 4]), HashSet::from([1, 3]));
+        assert_eq!(graph.neighbors_intersection(&[0, 2]), HashSet::from([1]));
+        assert!(graph.neighbors_intersection(&[0, 4]).is_empty());
+        assert!(graph.neighbors_union(&[]).is_empty());
+        assert!(graph.neighbors_intersection(&[]).is_empty());
+    }
+
+    #[test]
+    fn test_build_dynamically() {
+        let mut graph = AdjList::new(0);
+
+        for i in 0..5 {
+            assert_eq!(graph.add_vertex(), i);
+        }
+
+        for i in 0..5 {
+            graph.add_edge(i, (i + 1) % 5);
+        }
+
+        let cycle = cycle_graph(5);
+
+        assert_eq!(graph.num_vertices(), cycle.num_vertices());
+        assert_eq!(sorted_adj_list(&graph), sorted_adj_list(&cycle));
+    }
+
+    #[test]
+    fn test_remove_edge() {
+        let mut graph = cycle_graph(5);
+        graph.remove_edge(4, 0);
+
+        assert_eq!(
+            sorted_adj_list(&graph),
+            vec![vec![1], vec![0, 2], vec![1, 3], vec![2, 4], vec![3]]
+        );
+    }
+
+    #[test]
+    fn test_remove_vertex() {
+        // Removing a vertex from a cycle yields a path with the indexes shifted
+        let mut graph = cycle_graph(5);
+        graph.remove_vertex(2);
+
+        assert_eq!(graph.num_vertices(), 4);
+        assert_eq!(
+            sorted_adj_list(&graph),
+            vec![vec![1, 3], vec![0], vec![3], vec![0, 2]]
+        );
+    }
+
+    #[test]
+    fn test_complement() {
+        let complement = cycle_graph(5).complement();
+
+        // The complement of C_5 is also a 5-cycle
+        assert_eq!(complement.edge_count(), 5);
+        assert!(complement
+            .adj_list()
+            .iter()
+            .all(|neighbors| neighbors.len() == 2));
+        assert_eq!(sorted_adj_list(&complement)[0], vec![2, 3]);
+
+        assert_eq!(AdjList::complete(4).complement().edge_count(), 0);
+        assert_eq!(AdjList::new(4).complement().edge_count(), 6);
+    }
+
+    #[test]
+    fn test_line_graph() {
+        // The line graph of a cycle is the same cycle, and that of a star is a complete graph
+        let line_graph = cycle_graph(5).line_graph();
+
+        assert_eq!(line_graph.num_vertices(), 5);
+        assert_eq!(line_graph.edge_count(), 5);
+        assert!(line_graph
+            .adj_list()
+            .iter()
+            .all(|neighbors| neighbors.len() == 2));
+
+        let mut star = AdjList::new(5);
+        for leaf in 1..5 {
+            star.add_edge(0, leaf);
+        }
+
+        assert_eq!(
+            sorted_adj_list(&star.line_graph()),
+            sorted_adj_list(&AdjList::complete(4))
+        );
+
+        // The path 2---0---1 has the edges (0, 1) and (0, 2), which share the vertex 0
+        let mut path = AdjList::new(3);
+        path.add_edge(1, 0);
+        path.add_edge(0, 2);
+
+        assert_eq!(sorted_adj_list(&path.line_graph()), vec![vec![1], vec![0]]);
+        assert_eq!(AdjList::new(3).line_graph().num_vertices(), 0);
+    }
+
+    #[test]
+    fn test_union() {
+        let cycle = cycle_graph(5);
+        let union = AdjList::union(&cycle, &cycle.complement()).unwrap();
+
+        assert_eq!(
+            sorted_adj_list(&union),
+            sorted_adj_list(&AdjList::complete(5))
+        );
+
+        assert_eq!(
+            AdjList::union(&cycle, &AdjList::new(4)).unwrap_err(),
+            GraphError::VertexCountMismatch { left: 5, right: 4 }
+        );
+    }
+
+    #[test]
+    fn test_intersection() {
+        let cycle = cycle_graph(4);
+        let intersection = AdjList::intersection(&AdjList::complete(4), &cycle).unwrap();
+
+        assert_eq!(sorted_adj_list(&intersection), sorted_adj_list(&cycle));
+        assert_eq!(
+            AdjList::intersection(&cycle, &cycle.complement())
+                .unwrap()
+                .num_edges(),
+            0
+        );
+    }
+
+    #[test]
+    fn test_symmetric_difference() {
+        let cycle = cycle_graph(4);
+        let difference = AdjList::symmetric_difference(&AdjList::complete(4), &cycle).unwrap();
+
+        // Only the diagonals of the square are left
+        assert_eq!(
+            sorted_adj_list(&difference),
+            vec![vec![2], vec![3], vec![0], vec![1]]
+        );
+        assert_eq!(
+            AdjList::symmetric_difference(&cycle, &cycle)
+                .unwrap()
+                .num_edges(),
+            0
+        );
+    }
+
+    #[test]
+    fn test_is_valid_coloring() {
+        // A triangle 0---1---2 with a pendant vertex 3 on 2
+        let graph = AdjList::complete_minus(4, &[(0, 3), (1, 3)]);
+
+        assert!(graph.is_valid_coloring(&[1, 2, 3, 1]));
+        assert!(!graph.is_valid_coloring(&[1, 2, 2, 1]));
+        // Malformed colorings
+        assert!(!graph.is_valid_coloring(&[1, 2, 3]));
+        assert!(!graph.is_valid_coloring(&[1, 2, 3, 0]));
+        assert!(AdjList::new(0).is_valid_coloring(&[]));
+    }
+
+    #[test]
+    fn test_conflict_subgraph() {
+        let graph = AdjList::complete(4);
+        let conflicts = graph.conflict_subgraph(&[1, 1, 1, 1]);
+
+        assert_eq!(conflicts.num_vertices(), 4);
+        assert_eq!(conflicts.num_edges(), 6);
+        assert!(conflicts.is_valid_coloring(&[1, 2, 3, 4]));
+
+        // A valid 2-coloring of C_4 has no conflicts
+        let graph = AdjList::complete_minus(4, &[(0, 2), (1, 3)]);
+        let conflicts = graph.conflict_subgraph(&[1, 2, 1, 2]);
+
+        assert_eq!(conflicts.num_vertices(), 4);
+        assert_eq!(conflicts.num_edges(), 0);
+
+        let conflicts = graph.conflict_subgraph(&[1, 1, 2, 2]);
+
+        assert_eq!(conflicts.edge_list_as_slice().len(), 2);
+        assert!(conflicts.has_edge(0, 1));
+        assert!(conflicts.has_edge(2, 3));
+    }
+
+    #[test]
+    fn test_is_valid_class_list() {
+        let graph = AdjList::complete_minus(4, &[(0, 3), (1, 3)]);
+
+        assert!(graph.is_valid_class_list(&[vec![0, 3], vec![1], vec![2]]));
+        assert!(graph.is_valid_class_list(&[vec![0, 3], vec![], vec![1], vec![2]]));
+        assert!(!graph.is_valid_class_list(&[vec![0, 3], vec![1, 2]]));
+        // Missing, repeated and unknown vertices
+        assert!(!graph.is_valid_class_list(&[vec![0, 3], vec![1]]));
+        assert!(!graph.is_valid_class_list(&[vec![0, 3], vec![1], vec![2, 0]]));
+        assert!(!graph.is_valid_class_list(&[vec![0, 3], vec![1], vec![2], vec![4]]));
+    }
+
+    #[test]
+    fn test_is_clique_cover_valid() {
+        // A triangle 0---1---2 and an edge 3---4
+        let graph = AdjList::complete_minus(5, &[(0, 3), (0, 4), (1, 3), (1, 4), (2, 3), (2, 4)]);
+
+        assert!(graph.is_clique_cover_valid(&[vec![0, 1, 2], vec![3, 4]]));
+        assert!(graph.is_clique_cover_valid(&[vec![0], vec![1, 2], vec![4], vec![3]]));
+        // Not a clique
+        assert!(!graph.is_clique_cover_valid(&[vec![0, 1, 2, 3], vec![4]]));
+        // Vertex 4 is missing, and then repeated
+        assert!(!graph.is_clique_cover_valid(&[vec![0, 1, 2], vec![3]]));
+        assert!(!graph.is_clique_cover_valid(&[vec![0, 1, 2], vec![3, 4], vec![4]]));
+    }
+
+    #[test]
+    fn test_distances() {
+        // A path 0---1---2---3---4
+        let mut graph = AdjList::new(5);
+        for i in 0..4 {
+            graph.add_edge(i, i + 1);
+        }
+
+        assert_eq!(graph.neighbors_at_distance(0, 2), vec![2]);
+        assert_eq!(graph.neighbors_at_distance(2, 1), vec![1, 3]);
+        assert_eq!(graph.neighbors_at_distance(2, 0), vec![2]);
+        assert!(graph.neighbors_at_distance(2, 3).is_empty());
+        assert_eq!(graph.eccentricity(0), Some(4));
+        assert_eq!(graph.eccentricity(2), Some(2));
+        assert_eq!(graph.diameter(), Some(4));
+
+        assert_eq!(AdjList::complete(5).diameter(), Some(1));
+        assert_eq!(cycle_graph(6).diameter(), Some(3));
+
+        // An isolated vertex disconnects the graph
+        graph.add_vertex();
+
+        assert_eq!(graph.eccentricity(0), None);
+        assert_eq!(graph.diameter(), None);
+    }
+
+    #[test]
+    fn test_regularity() {
+        assert!(AdjList::complete(5).is_regular(4));
+        assert!(cycle_graph(6).is_regular(2));
+        assert!(!path_graph(3).is_regular(1));
+        assert!(cycle_graph(6).is_vertex_transitive_approx());
+        assert!(!path_graph(3).is_vertex_transitive_approx());
+        assert!(AdjList::new(0).is_vertex_transitive_approx());
+
+        // Degrees from 1 to 3 take two doublings
+        let mut graph = path_graph(4);
+        graph.add_edge(1, 3);
+        let regular = graph.regularize();
+
+        assert_eq!(regular.num_vertices(), 16);
+        assert!(regular.is_regular(3));
+        assert_eq!(
+            sorted_adj_list(&regular.induced_subgraph(&[0, 1, 2, 3])),
+            sorted_adj_list(&graph)
+        );
+
+        assert!(cycle_graph(5).regularize().is_regular(2));
+        assert_eq!(cycle_graph(5).regularize().num_vertices(), 5);
+        assert_eq!(AdjList::new(3).regularize().num_vertices(), 3);
+    }
+
+    #[test]
+    fn test_girth() {
+        assert_eq!(cycle_graph(5).girth(), Some(5));
+        assert_eq!(AdjList::complete(4).girth(), Some(3));
+        assert_eq!(path_graph(5).girth(), None);
+        assert_eq!(AdjList::new(0).girth(), None);
+
+        // A square with a pendant triangle 3---4---5, reached through the edge 0---3
+        let mut graph = cycle_graph(4);
+        let first = graph.add_vertex();
+        let second = graph.add_vertex();
+        graph.add_edge(3, first);
+        graph.add_edge(3, second);
+        graph.add_edge(first, second);
+
+        assert_eq!(graph.girth(), Some(3));
+        assert!(!graph.is_triangle_free());
+        assert!(cycle_graph(4).is_triangle_free());
+        assert!(path_graph(3).is_triangle_free());
+
+        // The Mycielski graphs are triangle-free, with girth 4
+        if let Ok(Some(graph)) = input::read_graph_from_file("data/myc/myciel4.col") {
+            assert_eq!(graph.girth(), Some(4));
+            assert!(graph.is_triangle_free());
+        } else {
+            panic!("The file containing the test graph is missing")
+        }
+    }
+
+    #[test]
+    fn test_shortest_path() {
+        let mut graph = path_graph(5);
+
+        assert_eq!(graph.shortest_path(0, 4), Some(vec![0, 1, 2, 3, 4]));
+        assert_eq!(graph.shortest_path(3, 1), Some(vec![3, 2, 1]));
+        assert_eq!(graph.shortest_path(2, 2), Some(vec![2]));
+        assert_eq!(graph.shortest_path_length(0, 4), Some(4));
+
+        // An isolated vertex can't be reached
+        graph.add_vertex();
+
+        assert_eq!(graph.shortest_path(0, 5), None);
+        assert_eq!(graph.shortest_path_length(5, 0), None);
+
+        // The path around the cycle has two edges instead of four
+        assert_eq!(cycle_graph(6).shortest_path(0, 4), Some(vec![0, 5, 4]));
+    }
+
+    #[test]
+    fn test_all_pairs_shortest_paths() {
+        let mut graph = path_graph(3);
+        graph.add_vertex();
+        let distances = graph.all_pairs_shortest_paths();
+
+        assert_eq!(distances[0], vec![Some(0), Some(1), Some(2), None]);
+        assert_eq!(distances[1], vec![Some(1), Some(0), Some(1), None]);
+        assert_eq!(distances[3], vec![None, None, None, Some(0)]);
+    }
+
+    #[test]
+    fn test_num_edges() {
+        assert_eq!(AdjList::complete(5).num_edges(), 10);
+        assert_eq!(path_graph(4).num_edges(), 3);
+
+        for graph in [cycle_graph(7), AdjList::new(3)] {
+            assert_eq!(graph.num_edges(), graph.edge_count());
+        }
+
+        if let Ok(Some(graph)) = input::read_graph_from_file("data/myc/myciel4.col") {
+            assert_eq!(graph.num_edges(), graph.edge_count());
+        } else {
+            panic!("The file containing the test graph is missing")
+        }
+
+        // Self-loops are ignored
+        let mut graph = path_graph(4);
+        graph.add_edge(1, 1);
+
+        assert_eq!(graph.num_edges(), 3);
+    }
+
+    #[test]
+    fn test_from_adjacency_matrix() {
+        let matrix = vec![
+            vec![false, true, true],
+            vec![true, true, false],
+            vec![true, false, false],
+        ];
+
+        if let Ok(graph) = AdjList::from_adjacency_matrix(matrix) {
+            // The diagonal entry isn't a self-loop
+            assert_eq!(graph.num_edges(), 2);
+            assert_eq!(sorted_adj_list(&graph), vec![vec![1, 2], vec![0], vec![0]]);
+        } else {
+            panic!("The matrix should be valid")
+        }
+
+        let asymmetric = vec![vec![false, true], vec![false, false]];
+
+        assert_eq!(
+            AdjList::from_adjacency_matrix(asymmetric).unwrap_err(),
+            GraphError::Asymmetric { i: 0, j: 1 }
+        );
+
+        let not_square = vec![vec![false, true], vec![true]];
+
+        assert_eq!(
+            AdjList::from_adjacency_matrix(not_square).unwrap_err(),
+            GraphError::NotSquare {
+                row: 1,
+                len: 1,
+                n: 2
+            }
+        );
+    }
+
+    #[test]
+    fn test_from_adjacency_matrix_with_weights() {
+        let matrix = vec![
+            vec![None, Some(1.0), Some(2.0)],
+            vec![Some(1.0), None, Some(0.5)],
+            vec![Some(2.0), Some(0.5), None],
+        ];
+
+        if let Ok(graph) = AdjList::from_adjacency_matrix_with_weights(matrix) {
+            assert_eq!(graph.num_edges(), 3);
+            assert_eq!(graph.edge_weight(0, 1), Some(1.0));
+            assert_eq!(graph.edge_weight(0, 2), Some(2.0));
+            assert_eq!(graph.edge_weight(2, 1), Some(0.5));
+            assert!(graph.is_weighted());
+        } else {
+            panic!("The matrix should be valid")
+        }
+
+        // Weights of 1 are the default ones
+        let graph = AdjList::from_adjacency_matrix_with_weights(vec![
+            vec![None, Some(1.0)],
+            vec![Some(1.0), None],
+        ])
+        .unwrap();
+
+        assert!(!graph.is_weighted());
+        assert!(!AdjList::complete(3).is_weighted());
+
+        let asymmetric = vec![vec![None, Some(1.0)], vec![Some(2.0), None]];
+
+        assert_eq!(
+            AdjList::from_adjacency_matrix_with_weights(asymmetric).unwrap_err(),
+            GraphError::Asymmetric { i: 0, j: 1 }
+        );
+    }
+
+    #[test]
+    fn test_from_adjacency_list() {
+        let adj: Vec<Vec<usize>> = (0..5)
+            .map(|u| (0..5).filter(|v| *v != u).collect())
+            .collect();
+
+        if let Ok(graph) = AdjList::from_adjacency_list(5, adj) {
+            let complete = AdjList::complete(5);
+
+            assert_eq!(graph.num_edges(), complete.num_edges());
+            assert_eq!(sorted_adj_list(&graph), sorted_adj_list(&complete));
+        } else {
+            panic!("The adjacency lists should be valid")
+        }
+
+        // Repeated neighbors are a single edge
+        let graph = AdjList::from_adjacency_list(2, vec![vec![1, 1], vec![0]]).unwrap();
+
+        assert_eq!(graph.num_edges(), 1);
+
+        assert_eq!(
+            AdjList::from_adjacency_list(2, vec![vec![1], vec![]]).unwrap_err(),
+            GraphError::Asymmetric { i: 0, j: 1 }
+        );
+        assert_eq!(
+            AdjList::from_adjacency_list(2, vec![vec![0], vec![]]).unwrap_err(),
+            GraphError::SelfLoop { vertex: 0 }
+        );
+        assert_eq!(
+            AdjList::from_adjacency_list(2, vec![vec![2], vec![]]).unwrap_err(),
+            GraphError::VertexOutOfRange { vertex: 2, n: 2 }
+        );
+        assert_eq!(
+            AdjList::from_adjacency_list(3, vec![vec![], vec![]]).unwrap_err(),
+            GraphError::VertexCountMismatch { left: 3, right: 2 }
+        );
+    }
+
+    #[test]
+    fn test_induced_subgraph() {
+        let mut graph = cycle_graph(5);
+        graph.remove_edge(0, 1);
+        graph.add_weighted_edge(0, 1, 2.5);
+        let subgraph = graph.induced_subgraph(&[4, 0, 1]);
+
+        assert_eq!(subgraph.num_vertices(), 3);
+        assert_eq!(subgraph.edge_count(), 2);
+        assert_eq!(subgraph.edge_weight(1, 2), Some(2.5));
+        assert_eq!(subgraph.edge_weight(0, 1), Some(1.0));
+        assert_eq!(subgraph.edge_weight(0, 2), None);
+    }
+
+    #[test]
+    fn test_k_core_decomposition() {
+        // Every vertex of a path is in its 1-core, including the endpoints
+        assert_eq!(path_graph(5).k_core_decomposition(), vec![1; 5]);
+        assert_eq!(AdjList::complete(4).k_core_decomposition(), vec![3; 4]);
+        assert_eq!(AdjList::new(2).k_core_decomposition(), vec![0; 2]);
+
+        // A triangle 0---1---2 with a pendant vertex 3 on 2
+        let graph = AdjList::complete_minus(4, &[(0, 3), (1, 3)]);
+
+        assert_eq!(graph.k_core_decomposition(), vec![2, 2, 2, 1]);
+
+        let core = graph.k_core(2);
+
+        assert_eq!(core.num_vertices(), 3);
+        assert_eq!(core.edge_count(), 3);
+        assert_eq!(graph.k_core(3).num_vertices(), 0);
+    }
+
+    #[test]
+    fn test_degeneracy_ordering() {
+        // The pendant vertex is removed first, so it comes last
+        let graph = AdjList::complete_minus(4, &[(0, 3), (1, 3)]);
+        let order = graph.degeneracy_ordering();
+
+        assert_eq!(order.last(), Some(&3));
+
+        for graph in [graph, path_graph(6), AdjList::complete(5), cycle_graph(7)] {
+            let order = graph.degeneracy_ordering();
+            let degeneracy = graph.k_core_decomposition().into_iter().max().unwrap();
+            let mut sorted = order.clone();
+            sorted.sort();
+
+            assert_eq!(sorted, (0..graph.num_vertices()).collect::<Vec<_>>());
+
+            for (i, vertex) in order.iter().enumerate() {
+                let earlier = graph.adj_list()[*vertex]
+                    .iter()
+                    .filter(|u| order[..i].contains(u))
+                    .count();
+
+                assert!(earlier <= degeneracy);
+            }
+        }
+    }
+
+    #[test]
+    fn test_edge_count() {
+        let mut graph = cycle_graph(5);
+
+        assert_eq!(graph.edge_count(), 5);
+
+        graph.add_edge(0, 2);
+
+        assert_eq!(graph.edge_count(), 6);
+
+        graph.remove_edge(0, 2);
+        graph.remove_vertex(0);
+
+        assert_eq!(graph.edge_count(), 3);
+        assert_eq!(AdjList::complete(5).edge_count(), 10);
+
+        if let Ok(Some(graph)) = input::read_graph_from_file("data/myc/myciel3.col") {
+            assert_eq!(graph.edge_count(), 20);
+        } else {
+            panic!("The file containing the test graph is missing")
+        }
+    }
+
+    #[test]
+    fn test_max_degree_cache() {
+        let mut graph = cycle_graph(5);
+
+        assert!(graph.max_degree.get().is_none());
+        assert_eq!(graph.max_degree(), 2);
+
+        // The value is cached after the first call
+        assert_eq!(graph.max_degree.get(), Some(&2));
+
+        // Changing the structure invalidates it
+        graph.add_edge(0, 2);
+
+        assert!(graph.max_degree.get().is_none());
+        assert_eq!(graph.max_degree(), 3);
+    }
+
+    #[test]
+    fn test_get_degree() {
+        let mut graph = path_graph(4);
+
+        assert_eq!(graph.get_degree(0), 1);
+        assert_eq!(graph.degree_sequence(), &[2, 2, 1, 1]);
+
+        graph.add_edge(0, 3);
+
+        assert_eq!(graph.get_degree(0), 2);
+        assert_eq!(graph.degree_sequence(), &[2, 2, 2, 2]);
+
+        graph.remove_edge(1, 2);
+
+        assert_eq!(graph.get_degree(1), 1);
+        assert_eq!(graph.degree_sequence(), &[2, 2, 1, 1]);
+    }
+
+    #[test]
+    fn test_reset_cache() {
+        let mut graph = cycle_graph(5);
+        graph.adj_list_mut()[0].push(2);
+        graph.adj_list_mut()[2].push(0);
+
+        assert_eq!(graph.edge_count(), 5);
+
+        graph.reset_cache();
+
+        assert_eq!(graph.edge_count(), 6);
+        assert_eq!(graph.max_degree(), 3);
+    }
+
+    #[test]
+    fn test_edge_weight() {
+        let mut graph = AdjList::new(4);
+        graph.add_edge(0, 1);
+        graph.add_weighted_edge(1, 2, 2.5);
+        graph.add_weighted_edge(2, 3, 4.0);
+
+        assert_eq!(graph.edge_weight(0, 1), Some(1.0));
+        assert_eq!(graph.edge_weight(2, 1), Some(2.5));
+        assert_eq!(graph.edge_weight(0, 2), None);
+
+        graph.remove_edge(1, 2);
+
+        assert_eq!(graph.edge_weight(1, 2), None);
+
+        // Weights follow the shifted indices
+        graph.remove_vertex(0);
+
+        assert_eq!(graph.edge_weight(1, 2), Some(4.0));
+
+        // Merging 2 into 0 moves the edge 1---2, along with its weight
+        let graph = graph.contract_edge(0, 2);
+
+        assert_eq!(graph.edge_weight(0, 1), Some(4.0));
+    }
+
+    #[test]
+    fn test_complete_minus() {
+        let graph = AdjList::complete_minus(5, &[]);
+
+        assert_eq!(graph.edge_count(), 10);
+        assert_eq!(sorted_adj_list(&graph), AdjList::complete(5).adj_list());
+
+        let graph = AdjList::complete_minus(5, &[(0, 1), (1, 2)]);
+
+        assert_eq!(graph.edge_count(), 8);
+        assert!(!graph.adj_list()[1].contains(&0));
+        assert!(!graph.adj_list()[2].contains(&1));
+    }
+
+    #[test]
+    fn test_empty_graph() {
+        let graph = AdjList::empty_graph(5);
+
+        assert_eq!(graph.num_vertices(), 5);
+        assert_eq!(graph.edge_count(), 0);
+        assert!(graph.is_bipartite());
+    }
+
+    #[test]
+    fn test_is_bipartite() {
+        assert!(cycle_graph(6).is_bipartite());
+        assert!(!cycle_graph(5).is_bipartite());
+        assert!(!AdjList::complete(3).is_bipartite());
+        assert!(AdjList::complete_minus(4, &[(0, 1), (2, 3)]).is_bipartite());
+        assert_eq!(
+            path_graph(4).bipartition(),
+            Some(vec![false, true, false, true])
+        );
+        assert_eq!(cycle_graph(5).bipartition(), None);
+    }
+
+    #[test]
+    fn test_chromatic_index_bounds() {
+        use crate::graph::generators::complete_bipartite_graph;
+
+        assert_eq!(cycle_graph(5).chromatic_index_bounds(), (2, 3));
+        assert_eq!(AdjList::complete(5).chromatic_index_bounds(), (4, 5));
+
+        assert_eq!(cycle_graph(6).is_class_one_graph(), Some(true));
+        assert_eq!(
+            complete_bipartite_graph(2, 3).is_class_one_graph(),
+            Some(true)
+        );
+        assert_eq!(AdjList::new(3).is_class_one_graph(), Some(true));
+        assert_eq!(cycle_graph(5).is_class_one_graph(), Some(false));
+        assert_eq!(AdjList::complete(4).is_class_one_graph(), Some(true));
+        assert_eq!(AdjList::complete(5).is_class_one_graph(), Some(false));
+
+        // The Petersen graph is class 2, but neither bipartite nor overfull
+        let mut petersen = AdjList::new(10);
+        for i in 0..5 {
+            petersen.add_edge(i, (i + 1) % 5);
+            petersen.add_edge(i, 5 + i);
+            petersen.add_edge(5 + i, 5 + (i + 2) % 5);
+        }
+
+        assert_eq!(petersen.chromatic_index_bounds(), (3, 4));
+        assert_eq!(petersen.is_class_one_graph(), None);
+    }
+
+    #[test]
+    fn test_contract_edge() {
+        let graph = AdjList::complete(4).contract_edge(1, 3);
+
+        assert_eq!(graph.num_vertices(), 3);
+        assert_eq!(graph.edge_count(), 3);
+        assert_eq!(sorted_adj_list(&graph), AdjList::complete(3).adj_list());
+
+        // A path 0---1---2---3: merging 0 and 2 leaves 0 adjacent to 1 and 2 (the old 3)
+        let mut graph = AdjList::new(4);
+        graph.add_edge(0, 1);
+        graph.add_edge(1, 2);
+        graph.add_edge(2, 3);
+        let graph = graph.contract_edge(0, 2);
+
+        assert_eq!(sorted_adj_list(&graph), vec![vec![1, 2], vec![0], vec![0]]);
+    }
+
+    #[test]
+    fn test_vertex_contraction_sequence() {
+        let sequence = path_graph(5).vertex_contraction_sequence(&[0, 1, 2, 3, 4]);
+
+        assert_eq!(sequence.len(), 5);
+        assert_eq!(sequence[0], path_graph(5));
+        for (i, graph) in sequence.iter().enumerate() {
+            // Merging the first vertex of a path into the second leaves a shorter path
+            assert_eq!(graph.num_vertices(), 5 - i);
+            assert_eq!(sorted_adj_list(graph), path_graph(5 - i).adj_list());
+        }
+
+        // Merging the even vertices of C_6, never adjacent, leaves K_{1,3}, with 1 as vertex 0
+        let sequence = cycle_graph(6).vertex_contraction_sequence(&[0, 2, 4]);
+
+        assert_eq!(sequence.len(), 3);
+        assert_eq!(sequence[2].num_vertices(), 4);
+        assert_eq!(sequence[2].edge_count(), 3);
+        assert_eq!(sequence[2].is_complete_bipartite(), Some((3, 1)));
+
+        assert!(cycle_graph(6).vertex_contraction_sequence(&[]).is_empty());
+        assert_eq!(cycle_graph(6).vertex_contraction_sequence(&[3]).len(), 1);
+    }
+
+    #[test]
+    fn test_deletion_contraction_primitives() {
+        let triangle = AdjList::complete(3);
+
+        // Deleting an edge of K_3 leaves the path 0---2---1
+        let deleted = triangle.delete_edge(0, 1);
+
+        assert_eq!(deleted.num_vertices(), 3);
+        assert_eq!(deleted.edge_count(), 2);
+        assert_eq!(
+            sorted_adj_list(&deleted),
+            vec![vec![2], vec![2], vec![0, 1]]
+        );
+
+        let contracted = triangle.contract_edge(0, 1);
+
+        assert_eq!(
+            sorted_adj_list(&contracted),
+            AdjList::complete(2).adj_list()
+        );
+
+        let deleted = cycle_graph(5).delete_vertex(2);
+
+        assert_eq!(
+            sorted_adj_list(&deleted),
+            vec![vec![1, 3], vec![0], vec![3], vec![0, 2]]
+        );
+
+        // The original graph is left untouched
+        assert_eq!(triangle.edge_count(), 3);
+    }
+
+    #[test]
+    fn test_subgraph_density() {
+        let graph = AdjList::complete(5);
+
+        assert_eq!(graph.subgraph_density(&[0, 2, 4]), 1.0);
+        assert_eq!(graph.subgraph_density(&[3]), 0.0);
+
+        // A path 0---1---2---3
+        let mut graph = AdjList::new(4);
+        graph.add_edge(0, 1);
+        graph.add_edge(1, 2);
+        graph.add_edge(2, 3);
+
+        assert_eq!(graph.subgraph_density(&[0, 2]), 0.0);
+        assert_eq!(graph.subgraph_density(&[0, 1, 2, 3]), 0.5);
+    }
+
+    #[test]
+    fn test_independence_number_approx() {
+        assert_eq!(AdjList::complete(5).independence_number_approx(10, 0), 1);
+        assert_eq!(AdjList::new(4).independence_number_approx(1, 0), 4);
+        assert_eq!(AdjList::new(4).independence_number_approx(0, 0), 0);
+
+        // A path 0---1---2---3---4, whose largest independent set is {0, 2, 4}
+        let mut graph = AdjList::new(5);
+        for i in 0..4 {
+            graph.add_edge(i, i + 1);
+        }
+
+        assert_eq!(graph.independence_number_approx(50, 42), 3);
+    }
+
+    #[test]
+    fn test_min_vertex_cover_approx() {
+        let is_cover = |graph: &AdjList, cover: &[usize]| {
+            (0..graph.num_vertices()).all(|u| {
+                cover.contains(&u) || graph.adj_list()[u].iter().all(|v| cover.contains(v))
+            })
+        };
+
+        let triangle = AdjList::complete(3);
+        let cover = triangle.min_vertex_cover_approx();
+
+        assert_eq!(cover.len(), 2);
+        assert!(is_cover(&triangle, &cover));
+        assert_eq!(triangle.independence_number_from_cover(), 1);
+
+        // The matching of P_4 takes all its vertices, but {1, 2} covers it
+        let path = path_graph(4);
+        let cover = path.min_vertex_cover_approx();
+
+        assert_eq!(cover.len(), 2);
+        assert!(is_cover(&path, &cover));
+        assert_eq!(path.independence_number_from_cover(), 2);
+
+        assert!(AdjList::new(3).min_vertex_cover_approx().is_empty());
+
+        if let Ok(Some(graph)) = input::read_graph_from_file("data/myc/myciel4.col") {
+            assert!(is_cover(&graph, &graph.min_vertex_cover_approx()));
+        } else {
+            panic!("The file containing the test graph is missing")
+        }
+    }
+
+    #[test]
+    fn test_graph_products() {
+        let k2 = AdjList::complete(2);
+        let square = AdjList::cartesian_product(&k2, &k2);
+
+        // The 4-cycle 0---1---3---2---0
+        assert_eq!(
+            sorted_adj_list(&square),
+            vec![vec![1, 2], vec![0, 3], vec![0, 3], vec![1, 2]]
+        );
+
+        let k3 = AdjList::complete(3);
+        let tensor = AdjList::tensor_product(&k3, &k3);
+
+        // |E| = 2 |E(a)| |E(b)|
+        assert_eq!(tensor.num_vertices(), 9);
+        assert_eq!(tensor.edge_count(), 18);
+        assert!(tensor.has_edge(0, 4));
+        assert!(!tensor.has_edge(0, 1));
+
+        // Products of graphs of different sizes, with |E| = |V(a)| |E(b)| + |V(b)| |E(a)| for the
+        // Cartesian one, and the sum of both for the strong one
+        let (a, b) = (path_graph(4), cycle_graph(5));
+        let cartesian = AdjList::cartesian_product(&a, &b);
+        let strong = AdjList::strong_product(&a, &b);
+
+        assert_eq!(cartesian.num_vertices(), 20);
+        assert_eq!(cartesian.edge_count(), 4 * 5 + 5 * 3);
+        assert_eq!(AdjList::tensor_product(&a, &b).edge_count(), 2 * 3 * 5);
+        assert_eq!(strong.edge_count(), 35 + 30);
+
+        // The strong product of complete graphs is complete
+        assert_eq!(AdjList::strong_product(&k2, &k3).edge_count(), 15);
+    }
+
+    #[test]
+    fn test_duplicate_edges() {
+        let mut graph = AdjList::new(3);
+        graph.add_edge(0, 1);
+        graph.add_edge(1, 0);
+
+        assert_eq!(graph.edge_count(), 1);
+        assert!(graph.has_edge(1, 0));
+        assert!(!graph.has_edge(1, 2));
+        assert_eq!(graph.try_add_edge(0, 1), Ok(()));
+
+        let mut graph = AdjList::new(3).with_mode(GraphMode::Strict);
+
+        assert_eq!(graph.try_add_edge(0, 1), Ok(()));
+        assert_eq!(
+            graph.try_add_edge(0, 1),
+            Err(GraphError::DuplicateEdge { u: 0, v: 1 })
+        );
+        assert_eq!(graph.edge_count(), 1);
+        assert_eq!(graph.adj_list()[0], vec![1]);
+    }
+
+    #[test]
+    fn test_add_edges_from_edge_list() {
+        let edges = [(0, 1), (0, 2), (0, 3), (1, 2), (1, 3), (2, 3)];
+        let mut graph = AdjList::new(4);
+
+        // The cached degrees are cleared along with the others
+        assert_eq!(graph.max_degree(), 0);
+        assert_eq!(graph.add_edges_from_edge_list(&edges), Ok(()));
+        assert_eq!(graph.edge_count(), 6);
+        assert_eq!(graph.max_degree(), 3);
+        assert_eq!(graph, AdjList::complete(4));
+
+        // Nothing is added when any edge is invalid
+        let mut graph = AdjList::new(4);
+
+        assert_eq!(
+            graph.add_edges_from_edge_list(&[(0, 1), (2, 2)]),
+            Err(GraphError::SelfLoop { vertex: 2 })
+        );
+        assert_eq!(
+            graph.add_edges_from_edge_list(&[(0, 1), (3, 4)]),
+            Err(GraphError::VertexOutOfRange { vertex: 4, n: 4 })
+        );
+        assert_eq!(graph.edge_count(), 0);
+
+        // Repeated edges are only rejected in strict mode
+        assert_eq!(graph.add_edges_from_edge_list(&[(0, 1), (1, 0)]), Ok(()));
+        assert_eq!(graph.edge_count(), 1);
+
+        let mut graph = AdjList::new(4).with_mode(GraphMode::Strict);
+
+        assert_eq!(
+            graph.add_edges_from_edge_list(&[(0, 1), (2, 3), (1, 0)]),
+            Err(GraphError::DuplicateEdge { u: 1, v: 0 })
+        );
+        assert_eq!(graph.edge_count(), 0);
+
+        let mut graph = AdjList::new(4);
+        graph.add_edges_from_edge_list_unchecked(&edges);
+
+        assert_eq!(graph, AdjList::complete(4));
+    }
+
+    #[test]
+    fn test_fingerprint() {
+        // The same graph with edges added in another order
+        let mut a = AdjList::new(3);
+        a.add_edge(0, 1);
+        a.add_edge(1, 2);
+        let mut b = AdjList::new(3);
+        b.add_edge(2, 1);
+        b.add_edge(1, 0);
+
+        assert_eq!(a.fingerprint(), b.fingerprint());
+        assert_ne!(a.fingerprint(), AdjList::new(3).fingerprint());
+        assert_ne!(AdjList::new(3).fingerprint(), AdjList::new(4).fingerprint());
+        assert_ne!(
+            AdjList::complete(5).fingerprint(),
+            cycle_graph(5).fingerprint()
+        );
+        assert_eq!(cycle_graph(5).fingerprint(), cycle_graph(5).fingerprint());
+    }
+
+    #[test]
+    fn test_structural_equality() {
+        let mut a = AdjList::new(3);
+        a.add_edge(0, 1);
+        a.add_edge(1, 2);
+        let mut b = AdjList::new(3);
+        b.add_weighted_edge(2, 1, 5.0);
+        b.add_edge(1, 0);
+
+        // Weights are ignored
+        assert!(a.structural_equality(&b));
+        assert_eq!(a, b);
+        assert_ne!(AdjList::complete(5), cycle_graph(5));
+        assert_ne!(AdjList::new(3), AdjList::new(4));
+        assert_eq!(AdjList::complete(3), cycle_graph(3));
+    }
+
+    #[test]
+    fn test_edge_list() {
+        let graph = AdjList::complete(4);
+        let edges = graph.edge_list();
+
+        assert_eq!(edges.len(), 6);
+        assert!(edges.iter().all(|(u, v)| u < v));
+        assert_eq!(graph.edge_list_as_slice(), edges.as_slice());
+
+        let mut graph = path_graph(3);
+
+        assert_eq!(graph.edge_list(), vec![(0, 1), (1, 2)]);
+
+        // The cached list is cleared by the changes
+        graph.add_edge(2, 0);
+
+        assert_eq!(graph.edge_list(), vec![(0, 1), (0, 2), (1, 2)]);
+
+        graph.remove_edge(0, 1);
+
+        assert_eq!(graph.edge_list_as_slice(), &[(0, 2), (1, 2)]);
+    }
+
+    #[test]
+    fn test_find_automorphism_orbits() {
+        assert_eq!(
+            AdjList::complete(5).find_automorphism_orbits(),
+            vec![vec![0, 1, 2, 3, 4]]
+        );
+
+        let path = path_graph(4);
+
+        assert_eq!(
+            path.find_automorphism_orbits(),
+            vec![vec![0, 3], vec![1, 2]]
+        );
+        assert_eq!(path.orbit_of(2), vec![1, 2]);
+
+        // K_3 with a pendant vertex: only the two vertices of degree 2 can be swapped
+        let mut graph = AdjList::new(4);
+        graph.add_edge(0, 1);
+        graph.add_edge(1, 2);
+        graph.add_edge(0, 2);
+        graph.add_edge(2, 3);
+
+        assert_eq!(
+            graph.find_automorphism_orbits(),
+            vec![vec![0, 1], vec![2], vec![3]]
+        );
+
+        // Larger graphs are split by color refinement
+        assert_eq!(cycle_graph(10).find_automorphism_orbits().len(), 1);
+        assert_eq!(
+            path_graph(10).find_automorphism_orbits(),
+            vec![vec![0, 9], vec![1, 8], vec![2, 7], vec![3, 6], vec![4, 5]]
+        );
+
+        if let Ok(Some(graph)) = input::read_graph_from_file("data/myc/myciel3.col") {
+            let orbits = graph.find_automorphism_orbits();
+
+            assert_eq!(orbits.iter().map(Vec::len).sum::<usize>(), 11);
+            assert!(orbits.iter().all(|orbit| {
+                orbit
+                    .iter()
+                    .all(|v| graph.get_degree(*v) == graph.get_degree(orbit[0]))
+            }));
+        } else {
+            panic!("The file containing the test graph is missing")
+        }
+    }
+
+    #[test]
+    fn test_find_all_cliques_up_to_size() {
+        // Every triangle of K_5, and none of its larger cliques
+        let triangles = AdjList::complete(5).find_all_cliques_up_to_size(3);
+
+        assert_eq!(triangles.len(), 10);
+        assert!(triangles.iter().all(|clique| clique.len() == 3));
+        assert_eq!(triangles[0], vec![0, 1, 2]);
+        assert_eq!(
+            AdjList::complete(5).find_all_cliques_up_to_size(7),
+            vec![vec![0, 1, 2, 3, 4]]
+        );
+
+        // K_3 with a pendant vertex: the edge 2---3 is maximal
+        let graph = AdjList::complete_minus(4, &[(0, 3), (1, 3)]);
+
+        assert_eq!(
+            graph.find_all_cliques_up_to_size(3),
+            vec![vec![0, 1, 2], vec![2, 3]]
+        );
+        assert_eq!(
+            graph.find_all_cliques_up_to_size(2),
+            vec![vec![0, 1], vec![0, 2], vec![1, 2], vec![2, 3]]
+        );
+        assert_eq!(
+            AdjList::new(2).find_all_cliques_up_to_size(3),
+            vec![vec![0], vec![1]]
+        );
+        assert!(graph.find_all_cliques_up_to_size(0).is_empty());
+
+        if let Ok(Some(graph)) = input::read_graph_from_file("data/myc/myciel3.col") {
+            // Triangle-free, so the maximal cliques are the edges
+            let cliques = graph.find_all_cliques_up_to_size(4);
+            let mut maximum = graph.greedy_clique();
+            maximum.sort();
+
+            assert_eq!(cliques.len(), graph.num_edges());
+            assert!(cliques.contains(&maximum));
+        } else {
+            panic!("The file containing the test graph is missing")
+        }
+    }
+
+    #[test]
+    fn test_greedy_clique() {
+        let graph = AdjList::complete(5);
+
+        assert_eq!(graph.clique_lower_bound(), 5);
+
+        // K_3 with a pendant vertex
+        let mut graph = AdjList::new(4);
+        graph.add_edge(0, 1);
+        graph.add_edge(1, 2);
+        graph.add_edge(0, 2);
+        graph.add_edge(2, 3);
+
+        let mut clique = graph.greedy_clique();
+        clique.sort();
+
+        assert_eq!(clique, vec![0, 1, 2]);
+
+        // The Mycielski graphs are triangle free
+        if let Ok(Some(graph)) = input::read_graph_from_file("data/myc/myciel4.col") {
+            assert_eq!(graph.clique_lower_bound(), 2);
+        } else {
+            panic!("The file containing the test graph is missing")
+        }
+
+        assert_eq!(AdjList::new(3).clique_lower_bound(), 1);
+        assert_eq!(AdjList::new(0).clique_lower_bound(), 0);
     }
 }
@@ -0,0 +1,65 @@
+use super::adj_list::AdjList;
+
+/// Bandwidth of `graph` under `ordering`, where `ordering[i]` is the vertex at position `i`: the
+/// largest distance between the positions of two adjacent vertices.
+pub fn bandwidth(graph: &AdjList, ordering: &[usize]) -> usize {
+    let mut position = vec![0; graph.num_vertices()];
+    for (i, vertex) in ordering.iter().enumerate() {
+        position[*vertex] = i;
+    }
+
+    (0..graph.num_vertices())
+        .flat_map(|u| {
+            let position = &position;
+            graph.adj_list()[u]
+                .iter()
+                .map(move |v| position[u].abs_diff(position[*v]))
+        })
+        .max()
+        .unwrap_or(0)
+}
+
+/// Approximates the bandwidth of `graph` with the [`AdjList::cuthill_mckee_ordering`], returning
+/// the bandwidth and the ordering achieving it.
+pub fn bandwidth_approx(graph: &AdjList) -> (usize, Vec<usize>) {
+    let ordering = graph.cuthill_mckee_ordering();
+
+    (bandwidth(graph, &ordering), ordering)
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+    use crate::graph::generators::cycle_graph;
+
+    #[test]
+    fn test_bandwidth() {
+        let graph = cycle_graph(4);
+
+        assert_eq!(bandwidth(&graph, &[0, 1, 2, 3]), 3);
+        assert_eq!(bandwidth(&graph, &[0, 1, 3, 2]), 2);
+        assert_eq!(bandwidth(&AdjList::new(3), &[0, 1, 2]), 0);
+    }
+
+    #[test]
+    fn test_bandwidth_approx() {
+        // A path 0---1---2---3---4
+        let mut graph = AdjList::new(5);
+        for i in 0..4 {
+            graph.add_edge(i, i + 1);
+        }
+
+        let (bandwidth, ordering) = bandwidth_approx(&graph);
+
+        assert!(ordering == vec![0, 1, 2, 3, 4] || ordering == vec![4, 3, 2, 1, 0]);
+        assert_eq!(bandwidth, 1);
+
+        // Every vertex is ordered, including the isolated ones
+        let (bandwidth, mut ordering) =
+            bandwidth_approx(&AdjList::complete_minus(4, &[(0, 3), (1, 3), (2, 3)]));
+        ordering.sort();
+
+        assert_eq!(ordering, vec![0, 1, 2, 3]);
+        assert_eq!(bandwidth, 2);
+    }
+}
@@ -0,0 +1,347 @@
+use super::adj_list::AdjList;
+use std::collections::{HashSet, VecDeque};
+
+/// The clique tree of a chordal graph: its maximal cliques, joined so that the cliques holding
+/// any given vertex form a subtree (the running intersection property).
+///
+/// The tree is a forest if the graph is disconnected, as cliques of different components aren't
+/// joined.
+#[derive(Debug, Clone, PartialEq, Eq)]
+pub struct CliqueTree {
+    /// The maximal cliques, each sorted.
+    pub cliques: Vec<Vec<usize>>,
+    /// Edges between the indices of the cliques, each sharing at least one vertex.
+    pub tree: Vec<(usize, usize)>,
+}
+
+impl CliqueTree {
+    /// Colors `graph`, the graph of the clique tree, with as many colors as its largest clique,
+    /// which is optimal.
+    ///
+    /// The cliques are visited in breadth-first order from the root of each tree. By the
+    /// running intersection property, the colored vertices of a clique are those it shares with
+    /// its parent, so the others can take the colors missing from the clique.
+    pub fn color_optimally(&self, graph: &AdjList) -> Vec<Vec<usize>> {
+        let num_colors = self.cliques.iter().map(Vec::len).max().unwrap_or(0);
+        let mut adjacent_cliques = vec![Vec::new(); self.cliques.len()];
+        for (a, b) in self.tree.iter() {
+            adjacent_cliques[*a].push(*b);
+            adjacent_cliques[*b].push(*a);
+        }
+
+        let mut coloring = vec![0; graph.num_vertices()];
+        let mut visited = vec![false; self.cliques.len()];
+
+        for root in 0..self.cliques.len() {
+            if visited[root] {
+                continue;
+            }
+
+            visited[root] = true;
+            let mut queue = VecDeque::from([root]);
+
+            while let Some(clique) = queue.pop_front() {
+                let used: HashSet<usize> =
+                    self.cliques[clique].iter().map(|v| coloring[*v]).collect();
+                let mut free_colors = (1..=num_colors).filter(|color| !used.contains(color));
+
+                for vertex in self.cliques[clique].iter() {
+                    if coloring[*vertex] == 0 {
+                        // The clique has at most `num_colors` vertices, so we can unwrap
+                        coloring[*vertex] = free_colors.next().unwrap();
+                    }
+                }
+
+                for neighbor in adjacent_cliques[clique].iter() {
+                    if !visited[*neighbor] {
+                        visited[*neighbor] = true;
+                        queue.push_back(*neighbor);
+                    }
+                }
+            }
+        }
+
+        let mut class_list = vec![Vec::new(); num_colors];
+        for (vertex, color) in coloring.iter().enumerate() {
+            class_list[color - 1].push(vertex);
+        }
+
+        class_list
+    }
+}
+
+/// Checks whether `elimination_order` is a perfect elimination ordering of `graph`, i.e., whether
+/// the neighbors of every vertex that come after it in the order form a clique, in `O(n + m)`
+/// time.
+///
+/// For each vertex `v`, only the first of its later neighbors, `u`, is checked: the other later
+/// neighbors of `v` must be adjacent to `u`, and the cliques of later vertices follow by
+/// induction (Tarjan and Yannakakis).
+fn is_perfect_elimination_ordering(graph: &AdjList, elimination_order: &[usize]) -> bool {
+    let n = graph.num_vertices();
+    let mut position = vec![0; n];
+    for (i, vertex) in elimination_order.iter().enumerate() {
+        position[*vertex] = i;
+    }
+
+    // Vertices that must be adjacent to each vertex
+    let mut required: Vec<Vec<usize>> = vec![Vec::new(); n];
+
+    for vertex in elimination_order.iter() {
+        let later_neighbors = graph.adj_list()[*vertex]
+            .iter()
+            .filter(|u| position[**u] > position[*vertex]);
+
+        if let Some(first) = later_neighbors.clone().min_by_key(|u| position[**u]) {
+            required[*first].extend(later_neighbors.filter(|u| *u != first));
+        }
+    }
+
+    let mut is_neighbor = vec![false; n];
+
+    for (vertex, required) in required.iter().enumerate() {
+        for u in graph.adj_list()[vertex].iter() {
+            is_neighbor[*u] = true;
+        }
+
+        if required.iter().any(|u| !is_neighbor[*u]) {
+            return false;
+        }
+
+        for u in graph.adj_list()[vertex].iter() {
+            is_neighbor[*u] = false;
+        }
+    }
+
+    true
+}
+
+impl AdjList {
+    /// Orders the vertices with a Maximum Cardinality Search: each vertex is the one with the
+    /// most neighbors already in the order (the smallest index on ties), in `O(n + m)` time.
+    ///
+    /// The reverse of the order is a perfect elimination ordering if the graph is chordal.
+    pub fn maximum_cardinality_search(&self) -> Vec<usize> {
+        let n = self.num_vertices();
+        let mut weight = vec![0; n];
+        let mut visited = vec![false; n];
+        // Vertices by weight, with repetitions: a vertex is only valid in the bucket of its
+        // current weight
+        let mut buckets: Vec<Vec<usize>> = vec![Vec::new(); n.max(1)];
+        buckets[0] = (0..n).rev().collect();
+        let mut max_weight = 0;
+        let mut order = Vec::with_capacity(n);
+
+        while order.len() < n {
+            let Some(vertex) = buckets[max_weight].pop() else {
+                max_weight -= 1;
+                continue;
+            };
+
+            if visited[vertex] || weight[vertex] != max_weight {
+                continue;
+            }
+
+            visited[vertex] = true;
+            order.push(vertex);
+
+            for u in self.adj_list()[vertex].iter() {
+                if !visited[*u] {
+                    weight[*u] += 1;
+                    buckets[weight[*u]].push(*u);
+                    max_weight = max_weight.max(weight[*u]);
+                }
+            }
+        }
+
+        order
+    }
+
+    /// Finds a perfect elimination ordering of the graph: an order of the vertices where the
+    /// neighbors of each vertex that come after it form a clique. Returns `None` if there is none,
+    /// i.e., if the graph isn't chordal.
+    pub fn perfect_elimination_ordering(&self) -> Option<Vec<usize>> {
+        let mut order = self.maximum_cardinality_search();
+        order.reverse();
+
+        is_perfect_elimination_ordering(self, &order).then_some(order)
+    }
+
+    /// Builds the [`CliqueTree`] of the graph, or returns `None` if it isn't chordal.
+    ///
+    /// The maximal cliques are found among the sets made of each vertex and its later neighbors
+    /// in the perfect elimination ordering, and joined by a maximum spanning tree of the graph
+    /// whose edges between cliques weigh the number of vertices they share.
+    pub fn clique_tree(&self) -> Option<CliqueTree> {
+        let order = self.perfect_elimination_ordering()?;
+        let n = self.num_vertices();
+        let mut position = vec![0; n];
+        for (i, vertex) in order.iter().enumerate() {
+            position[*vertex] = i;
+        }
+
+        let candidates: Vec<HashSet<usize>> = (0..n)
+            .map(|vertex| {
+                std::iter::once(vertex)
+                    .chain(
+                        self.adj_list()[vertex]
+                            .iter()
+                            .copied()
+                            .filter(|u| position[*u] > position[vertex]),
+                    )
+                    .collect()
+            })
+            .collect();
+
+        // The first vertex of any maximal clique in the order spans it with its later neighbors,
+        // so a candidate that isn't maximal is within the candidate of an earlier neighbor
+        let cliques: Vec<Vec<usize>> = order
+            .iter()
+            .filter(|vertex| {
+                !self.adj_list()[**vertex].iter().any(|u| {
+                    position[*u] < position[**vertex]
+                        && candidates[*u].len() > candidates[**vertex].len()
+                        && candidates[**vertex].is_subset(&candidates[*u])
+                })
+            })
+            .map(|vertex| {
+                let mut clique: Vec<usize> = candidates[*vertex].iter().copied().collect();
+                clique.sort();
+                clique
+            })
+            .collect();
+
+        // Prim's algorithm, starting a new tree when no clique shares a vertex with the current one
+        let k = cliques.len();
+        let shared = |a: usize, b: usize| {
+            cliques[a]
+                .iter()
+                .filter(|v| cliques[b].binary_search(v).is_ok())
+                .count()
+        };
+        let mut in_tree = vec![false; k];
+        // Largest intersection with the tree and the clique of the tree it is with
+        let mut best: Vec<(usize, Option<usize>)> = vec![(0, None); k];
+        let mut tree = Vec::with_capacity(k.saturating_sub(1));
+
+        for _ in 0..k {
+            // Some clique is still out of the tree, so we can unwrap
+            let next = (0..k)
+                .filter(|c| !in_tree[*c])
+                .max_by_key(|c| (best[*c].0, std::cmp::Reverse(*c)))
+                .unwrap();
+            in_tree[next] = true;
+
+            if let Some(parent) = best[next].1 {
+                tree.push((parent, next));
+            }
+
+            for c in (0..k).filter(|c| !in_tree[*c]) {
+                let weight = shared(next, c);
+
+                if weight > best[c].0 {
+                    best[c] = (weight, Some(next));
+                }
+            }
+        }
+
+        Some(CliqueTree { cliques, tree })
+    }
+
+    /// Checks whether the graph is chordal, i.e., whether every cycle of length at least 4 has a
+    /// chord, in `O(n + m)` time.
+    pub fn is_chordal(&self) -> bool {
+        self.perfect_elimination_ordering().is_some()
+    }
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+    use crate::graph::generators::{cycle_graph, path_graph};
+
+    #[test]
+    fn test_is_chordal() {
+        assert!(path_graph(6).is_chordal());
+        assert!(cycle_graph(3).is_chordal());
+        assert!(!cycle_graph(4).is_chordal());
+        assert!(!cycle_graph(7).is_chordal());
+        assert!(AdjList::complete(5).is_chordal());
+        assert!(AdjList::new(0).is_chordal());
+
+        // A square with a chord is chordal, while two squares sharing an edge aren't
+        let mut graph = cycle_graph(4);
+        graph.add_edge(0, 2);
+
+        assert!(graph.is_chordal());
+
+        let mut graph = cycle_graph(6);
+        graph.add_edge(0, 3);
+
+        assert!(!graph.is_chordal());
+    }
+
+    #[test]
+    fn test_clique_tree() {
+        let graph = path_graph(5);
+        let clique_tree = graph.clique_tree().unwrap();
+
+        assert_eq!(clique_tree.cliques.len(), 4);
+        assert!(clique_tree.cliques.iter().all(|clique| clique.len() == 2));
+        assert_eq!(clique_tree.tree.len(), 3);
+
+        let class_list = clique_tree.color_optimally(&graph);
+
+        assert_eq!(class_list.len(), 2);
+        assert!(graph.is_valid_class_list(&class_list));
+
+        let graph = AdjList::complete(4);
+        let clique_tree = graph.clique_tree().unwrap();
+
+        assert_eq!(clique_tree.cliques, vec![vec![0, 1, 2, 3]]);
+        assert!(clique_tree.tree.is_empty());
+        assert_eq!(clique_tree.color_optimally(&graph).len(), 4);
+
+        assert_eq!(cycle_graph(4).clique_tree(), None);
+
+        // Two triangles sharing the edge 1---2, a pendant 3---4 and an isolated vertex 5
+        let mut graph = AdjList::new(6);
+        for (u, v) in [(0, 1), (0, 2), (1, 2), (1, 3), (2, 3), (3, 4)] {
+            graph.add_edge(u, v);
+        }
+
+        let clique_tree = graph.clique_tree().unwrap();
+        let mut cliques = clique_tree.cliques.clone();
+        cliques.sort();
+
+        assert_eq!(
+            cliques,
+            vec![vec![0, 1, 2], vec![1, 2, 3], vec![3, 4], vec![5]]
+        );
+        // The isolated vertex is in a tree of its own
+        assert_eq!(clique_tree.tree.len(), 2);
+
+        let class_list = clique_tree.color_optimally(&graph);
+
+        assert_eq!(class_list.len(), 3);
+        assert!(graph.is_valid_class_list(&class_list));
+    }
+
+    #[test]
+    fn test_perfect_elimination_ordering() {
+        // Two triangles sharing the edge 1---2, and a pendant vertex 4 attached to 3
+        let mut graph = AdjList::new(5);
+        for (u, v) in [(0, 1), (0, 2), (1, 2), (1, 3), (2, 3), (3, 4)] {
+            graph.add_edge(u, v);
+        }
+
+        let order = graph.perfect_elimination_ordering().unwrap();
+        let mut sorted = order.clone();
+        sorted.sort();
+
+        assert_eq!(sorted, vec![0, 1, 2, 3, 4]);
+        assert!(is_perfect_elimination_ordering(&graph, &order));
+        assert!(!is_perfect_elimination_ordering(&graph, &[1, 0, 2, 3, 4]));
+        assert_eq!(cycle_graph(5).perfect_elimination_ordering(), None);
+    }
+}
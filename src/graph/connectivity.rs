@@ -0,0 +1,239 @@
+use super::adj_list::AdjList;
+use std::collections::VecDeque;
+
+/// Directed network with integer capacities, stored as arcs paired with their reverse arcs: arc
+/// `2i + 1` is the reverse of arc `2i`.
+struct FlowNetwork {
+    // Arcs leaving each node, as indices into heads and capacities
+    arcs: Vec<Vec<usize>>,
+    heads: Vec<usize>,
+    capacities: Vec<usize>,
+}
+
+impl FlowNetwork {
+    fn new(num_nodes: usize) -> Self {
+        FlowNetwork {
+            arcs: vec![Vec::new(); num_nodes],
+            heads: Vec::new(),
+            capacities: Vec::new(),
+        }
+    }
+
+    fn add_arc(&mut self, from: usize, to: usize, capacity: usize) {
+        self.arcs[from].push(self.heads.len());
+        self.heads.push(to);
+        self.capacities.push(capacity);
+
+        self.arcs[to].push(self.heads.len());
+        self.heads.push(from);
+        self.capacities.push(0);
+    }
+
+    /// Maximum flow from `source` to `sink`, capped at `limit`, by the Edmonds-Karp algorithm:
+    /// augmenting along shortest paths, found by breadth-first search, until there is none. The
+    /// capacities are left as the residual ones.
+    fn max_flow(&mut self, source: usize, sink: usize, limit: usize) -> usize {
+        let mut flow = 0;
+
+        while flow < limit {
+            // Arc through which each node was reached
+            let mut reached_by: Vec<Option<usize>> = vec![None; self.arcs.len()];
+            let mut queue = VecDeque::from([source]);
+
+            while let Some(node) = queue.pop_front() {
+                if node == sink {
+                    break;
+                }
+
+                for arc in self.arcs[node].iter().copied() {
+                    let head = self.heads[arc];
+
+                    if self.capacities[arc] > 0 && head != source && reached_by[head].is_none() {
+                        reached_by[head] = Some(arc);
+                        queue.push_back(head);
+                    }
+                }
+            }
+
+            if reached_by[sink].is_none() {
+                break;
+            }
+
+            let mut path = Vec::new();
+            let mut node = sink;
+            while let Some(arc) = reached_by[node] {
+                path.push(arc);
+                // The tail of an arc is the head of its reverse
+                node = self.heads[arc ^ 1];
+            }
+
+            // The path has at least one arc, so we can unwrap
+            let amount = path
+                .iter()
+                .map(|arc| self.capacities[*arc])
+                .min()
+                .unwrap()
+                .min(limit - flow);
+
+            for arc in path {
+                self.capacities[arc] -= amount;
+                self.capacities[arc ^ 1] += amount;
+            }
+
+            flow += amount;
+        }
+
+        flow
+    }
+}
+
+impl AdjList {
+    /// Maximum number of internally disjoint paths between the non-adjacent vertices `u` and
+    /// `v`, capped at `limit`, which by Menger's theorem is the fewest vertices whose removal
+    /// separates them.
+    ///
+    /// Each vertex `x` is split into the nodes `2x`, where its arcs arrive, and `2x + 1`, where
+    /// they leave, joined by an arc of capacity 1, so that each vertex is used by one path.
+    fn local_vertex_connectivity(&self, u: usize, v: usize, limit: usize) -> usize {
+        let n = self.num_vertices();
+        let mut network = FlowNetwork::new(2 * n);
+
+        for x in 0..n {
+            network.add_arc(2 * x, 2 * x + 1, 1);
+        }
+
+        for (x, y) in self.edge_list_as_slice().iter().copied() {
+            network.add_arc(2 * x + 1, 2 * y, n);
+            network.add_arc(2 * y + 1, 2 * x, n);
+        }
+
+        network.max_flow(2 * u + 1, 2 * v, limit)
+    }
+
+    /// Vertex connectivity `κ(G)`, the fewest vertices whose removal disconnects the graph or
+    /// leaves a single vertex, so that `κ(K_n) = n - 1` and disconnected graphs have `κ = 0`.
+    ///
+    /// Takes the minimum of the maximum flows between the non-adjacent pairs of vertices (see
+    /// Menger's theorem), each found by the Edmonds-Karp algorithm and stopped once it reaches the
+    /// minimum so far.
+    pub fn vertex_connectivity(&self) -> usize {
+        let n = self.num_vertices();
+        let mut connectivity = n.saturating_sub(1);
+
+        for u in 0..n {
+            for v in u + 1..n {
+                if connectivity == 0 {
+                    return 0;
+                }
+
+                if !self.adj_list()[u].contains(&v) {
+                    connectivity = self.local_vertex_connectivity(u, v, connectivity);
+                }
+            }
+        }
+
+        connectivity
+    }
+
+    /// Edge connectivity `λ(G)`, the fewest edges whose removal disconnects the graph, 0 for
+    /// graphs with less than two vertices.
+    ///
+    /// Every cut separates vertex 0 from some other vertex, so it is the minimum of the maximum
+    /// flows from vertex 0, where each edge is a pair of opposite arcs of capacity 1.
+    pub fn edge_connectivity(&self) -> usize {
+        let n = self.num_vertices();
+
+        if n < 2 {
+            return 0;
+        }
+
+        // The edges of a vertex of minimum degree form a cut, and n >= 2, so we can unwrap
+        let mut connectivity = *self.degree_sequence().last().unwrap();
+
+        for v in 1..n {
+            if connectivity == 0 {
+                break;
+            }
+
+            let mut network = FlowNetwork::new(n);
+            for (x, y) in self.edge_list_as_slice().iter().copied() {
+                network.add_arc(x, y, 1);
+                network.add_arc(y, x, 1);
+            }
+
+            connectivity = network.max_flow(0, v, connectivity);
+        }
+
+        connectivity
+    }
+
+    /// Checks whether the graph is `k`-connected, i.e., whether it has more than `k` vertices and
+    /// stays connected after removing any `k - 1` of them.
+    pub fn is_k_connected(&self, k: usize) -> bool {
+        self.num_vertices() > k && self.vertex_connectivity() >= k
+    }
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+    use crate::{
+        graph::generators::{cycle_graph, path_graph},
+        input,
+    };
+
+    #[test]
+    fn test_vertex_connectivity() {
+        for n in 1..7 {
+            assert_eq!(AdjList::complete(n).vertex_connectivity(), n - 1);
+        }
+
+        for n in 4..9 {
+            assert_eq!(cycle_graph(n).vertex_connectivity(), 2);
+            assert_eq!(path_graph(n).vertex_connectivity(), 1);
+        }
+
+        // Two disjoint edges
+        let mut graph = AdjList::new(4);
+        graph.add_edge(0, 1);
+        graph.add_edge(2, 3);
+
+        assert_eq!(graph.vertex_connectivity(), 0);
+        assert_eq!(AdjList::new(0).vertex_connectivity(), 0);
+
+        // Two triangles sharing the vertex 2
+        let mut graph = AdjList::new(5);
+        for (u, v) in [(0, 1), (0, 2), (1, 2), (2, 3), (2, 4), (3, 4)] {
+            graph.add_edge(u, v);
+        }
+
+        assert_eq!(graph.vertex_connectivity(), 1);
+        assert_eq!(graph.edge_connectivity(), 2);
+
+        if let Ok(Some(graph)) = input::read_graph_from_file("data/myc/myciel3.col") {
+            // The Grötzsch graph is 3-connected, with minimum degree 3
+            assert_eq!(graph.vertex_connectivity(), 3);
+        } else {
+            panic!("The file containing the test graph is missing")
+        }
+    }
+
+    #[test]
+    fn test_edge_connectivity() {
+        assert_eq!(AdjList::complete(5).edge_connectivity(), 4);
+        assert_eq!(cycle_graph(6).edge_connectivity(), 2);
+        assert_eq!(path_graph(6).edge_connectivity(), 1);
+        assert_eq!(AdjList::new(3).edge_connectivity(), 0);
+        assert_eq!(AdjList::new(1).edge_connectivity(), 0);
+    }
+
+    #[test]
+    fn test_is_k_connected() {
+        assert!(cycle_graph(5).is_k_connected(2));
+        assert!(!cycle_graph(5).is_k_connected(3));
+        assert!(AdjList::complete(4).is_k_connected(3));
+        assert!(!AdjList::complete(4).is_k_connected(4));
+        assert!(path_graph(3).is_k_connected(1));
+        assert!(!AdjList::new(0).is_k_connected(0));
+    }
+}
@@ -0,0 +1,247 @@
+use super::adj_list::AdjList;
+use rand::rngs::StdRng;
+use rand::{Rng, SeedableRng};
+use std::collections::VecDeque;
+
+/// A graph whose edges are oriented, kept as its underlying undirected graph along with the
+/// direction of each edge.
+#[derive(Debug, Clone)]
+pub struct DirectedGraph {
+    graph: AdjList,
+    // direction[i][j] tells whether the edge between i and j goes from i to j
+    direction: Vec<Vec<bool>>,
+}
+
+impl DirectedGraph {
+    /// Builds the directed graph on `n` vertices with the given `arcs`, each `(u, v)` going from
+    /// `u` to `v`. An arc opposite to a previous one replaces it.
+    pub fn from_arcs(n: usize, arcs: &[(usize, usize)]) -> Self {
+        let mut graph = DirectedGraph {
+            graph: AdjList::new(n),
+            direction: vec![vec![false; n]; n],
+        };
+
+        for (u, v) in arcs.iter().copied() {
+            graph.graph.add_edge(u, v);
+            graph.direction[u][v] = true;
+            graph.direction[v][u] = false;
+        }
+
+        graph
+    }
+
+    /// Builds a random tournament on `n` vertices, an orientation of `K_n` where each edge points
+    /// either way with the same probability. The same `seed` gives the same tournament.
+    pub fn tournament(n: usize, seed: u64) -> Self {
+        let mut rng = StdRng::seed_from_u64(seed);
+        let arcs: Vec<(usize, usize)> = (0..n)
+            .flat_map(|u| (u + 1..n).map(move |v| (u, v)))
+            .map(|(u, v)| if rng.gen_bool(0.5) { (u, v) } else { (v, u) })
+            .collect();
+
+        DirectedGraph::from_arcs(n, &arcs)
+    }
+
+    /// Builds the transitive tournament on `n` vertices, where each edge points from the smaller
+    /// vertex to the larger one.
+    pub fn transitive_tournament(n: usize) -> Self {
+        let arcs: Vec<(usize, usize)> = (0..n)
+            .flat_map(|u| (u + 1..n).map(move |v| (u, v)))
+            .collect();
+
+        DirectedGraph::from_arcs(n, &arcs)
+    }
+
+    pub fn num_vertices(&self) -> usize {
+        self.graph.num_vertices()
+    }
+
+    pub fn num_arcs(&self) -> usize {
+        self.graph.num_edges()
+    }
+
+    /// Checks whether there is an arc from `u` to `v`.
+    pub fn has_arc(&self, u: usize, v: usize) -> bool {
+        self.direction[u][v]
+    }
+
+    /// Number of arcs leaving each vertex, the score sequence in the case of tournaments.
+    pub fn out_degrees(&self) -> Vec<usize> {
+        self.direction
+            .iter()
+            .map(|row| row.iter().filter(|out| **out).count())
+            .collect()
+    }
+
+    /// Vertices reached by the arcs leaving `vertex`.
+    fn out_neighbors(&self, vertex: usize) -> impl Iterator<Item = &usize> {
+        self.graph.adj_list()[vertex]
+            .iter()
+            .filter(move |u| self.direction[vertex][**u])
+    }
+
+    /// Orders the vertices so that every arc goes from an earlier vertex to a later one, with
+    /// Kahn's algorithm: vertices without arcs left entering them are taken in breadth-first
+    /// order, the smallest index first. Returns `None` if there is a cycle, as then there is no
+    /// such order.
+    pub fn topological_sort(&self) -> Option<Vec<usize>> {
+        let n = self.num_vertices();
+        let mut in_degree = vec![0; n];
+        for u in 0..n {
+            for v in self.out_neighbors(u) {
+                in_degree[*v] += 1;
+            }
+        }
+
+        let mut queue: VecDeque<usize> = (0..n).filter(|v| in_degree[*v] == 0).collect();
+        let mut order = Vec::with_capacity(n);
+
+        while let Some(u) = queue.pop_front() {
+            order.push(u);
+
+            for v in self.out_neighbors(u) {
+                in_degree[*v] -= 1;
+
+                if in_degree[*v] == 0 {
+                    queue.push_back(*v);
+                }
+            }
+        }
+
+        (order.len() == n).then_some(order)
+    }
+
+    /// Minimum number of levels of an acyclic graph, so that every arc goes from a level to a
+    /// later one, the number of vertices of its longest path. Returns `None` if there is a cycle.
+    ///
+    /// Giving each level a color colors the underlying undirected graph, where the levels are the
+    /// rounds of a parallel execution of the dependencies, so this is an upper bound for its
+    /// chromatic number (Gallai-Roy). It is exact for paths, but not in general.
+    pub fn chromatic_number_dag(&self) -> Option<usize> {
+        let order = self.topological_sort()?;
+        // Number of vertices of the longest path ending at each vertex
+        let mut level = vec![1; self.num_vertices()];
+
+        for u in order {
+            for v in self.out_neighbors(u) {
+                level[*v] = level[*v].max(level[u] + 1);
+            }
+        }
+
+        Some(level.into_iter().max().unwrap_or(0))
+    }
+
+    /// The graph with the arcs as undirected edges, which is `K_n` for tournaments, so it is what
+    /// gets colored.
+    pub fn underlying_undirected(&self) -> AdjList {
+        self.graph.clone()
+    }
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+
+    fn sorted_adj_list(graph: &AdjList) -> Vec<Vec<usize>> {
+        let mut adj_list = graph.adj_list().to_vec();
+        adj_list.iter_mut().for_each(|neighbors| neighbors.sort());
+        adj_list
+    }
+
+    #[test]
+    fn test_tournament() {
+        for n in 0..8 {
+            let tournament = DirectedGraph::tournament(n, n as u64);
+
+            assert_eq!(tournament.num_arcs(), n * n.saturating_sub(1) / 2);
+            assert_eq!(
+                tournament.out_degrees().iter().sum::<usize>(),
+                tournament.num_arcs()
+            );
+
+            // Each pair is joined by exactly one arc
+            for u in 0..n {
+                for v in u + 1..n {
+                    assert!(tournament.has_arc(u, v) != tournament.has_arc(v, u));
+                }
+            }
+        }
+
+        let underlying = DirectedGraph::tournament(5, 0).underlying_undirected();
+
+        assert_eq!(
+            sorted_adj_list(&underlying),
+            sorted_adj_list(&AdjList::complete(5))
+        );
+
+        // The same seed gives the same tournament
+        let a = DirectedGraph::tournament(10, 42);
+        let b = DirectedGraph::tournament(10, 42);
+
+        assert_eq!(a.direction, b.direction);
+    }
+
+    #[test]
+    fn test_transitive_tournament() {
+        let tournament = DirectedGraph::transitive_tournament(5);
+
+        assert_eq!(tournament.num_arcs(), 10);
+        assert!(tournament.has_arc(1, 3));
+        assert!(!tournament.has_arc(3, 1));
+        assert_eq!(tournament.out_degrees(), vec![4, 3, 2, 1, 0]);
+    }
+
+    #[test]
+    fn test_topological_sort() {
+        let graph = DirectedGraph::from_arcs(4, &[(2, 0), (0, 3), (2, 1), (1, 3)]);
+        let order = graph.topological_sort().unwrap();
+
+        assert_eq!(order, vec![2, 0, 1, 3]);
+
+        assert_eq!(
+            DirectedGraph::transitive_tournament(5).topological_sort(),
+            Some((0..5).collect())
+        );
+
+        let cycle = DirectedGraph::from_arcs(3, &[(0, 1), (1, 2), (2, 0)]);
+
+        assert_eq!(cycle.topological_sort(), None);
+        assert_eq!(cycle.chromatic_number_dag(), None);
+        assert_eq!(
+            DirectedGraph::from_arcs(0, &[]).topological_sort(),
+            Some(vec![])
+        );
+    }
+
+    #[test]
+    fn test_chromatic_number_dag() {
+        // A chain 0 -> 1 -> 2 -> 3
+        let chain = DirectedGraph::from_arcs(4, &[(0, 1), (1, 2), (2, 3)]);
+
+        assert_eq!(chain.chromatic_number_dag(), Some(4));
+
+        // A forest of two stars with arcs from their centers
+        let forest = DirectedGraph::from_arcs(6, &[(0, 1), (0, 2), (3, 4), (3, 5)]);
+
+        assert_eq!(forest.chromatic_number_dag(), Some(2));
+        assert_eq!(
+            DirectedGraph::from_arcs(3, &[]).chromatic_number_dag(),
+            Some(1)
+        );
+        assert_eq!(
+            DirectedGraph::from_arcs(0, &[]).chromatic_number_dag(),
+            Some(0)
+        );
+    }
+
+    #[test]
+    fn test_from_arcs() {
+        let graph = DirectedGraph::from_arcs(3, &[(0, 1), (2, 1), (1, 0)]);
+
+        assert_eq!(graph.num_vertices(), 3);
+        assert_eq!(graph.num_arcs(), 2);
+        assert!(graph.has_arc(1, 0));
+        assert!(!graph.has_arc(0, 1));
+        assert_eq!(graph.out_degrees(), vec![0, 1, 1]);
+    }
+}
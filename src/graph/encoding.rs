@@ -0,0 +1,156 @@
+use super::adj_list::AdjList;
+use std::error::Error;
+use std::fmt;
+
+/// Length of the vertex count that starts an encoded graph.
+const HEADER_LENGTH: usize = 4;
+
+/// Errors raised by [`AdjList::decode`].
+#[derive(Debug, Clone, PartialEq, Eq)]
+pub enum DecodeError {
+    /// The bytes end before the vertex count.
+    MissingHeader,
+    /// The bytes don't hold the `expected` number of bytes for the vertex count, but `found`.
+    WrongLength { expected: usize, found: usize },
+}
+
+impl fmt::Display for DecodeError {
+    fn fmt(&self, f: &mut fmt::Formatter<'_>) -> fmt::Result {
+        match self {
+            DecodeError::MissingHeader => write!(f, "the vertex count is missing"),
+            DecodeError::WrongLength { expected, found } => write!(
+                f,
+                "expected {expected} bytes for the vertex count, found {found}"
+            ),
+        }
+    }
+}
+
+impl Error for DecodeError {}
+
+/// Number of bytes of the encoding of a graph on `n` vertices.
+fn encoded_length(n: usize) -> usize {
+    HEADER_LENGTH + (n * n.saturating_sub(1) / 2).div_ceil(8)
+}
+
+impl AdjList {
+    /// Encodes the graph in a compact binary form: the number of vertices as a little-endian
+    /// 32-bit integer, followed by the upper triangle of the adjacency matrix packed in
+    /// `n(n - 1) / 8` bytes (rounded up). The pairs `(u, v)`, with `u < v`, come in lexicographic
+    /// order, the bit of the `k`th pair being bit `7 - k % 8` of byte `k / 8` (most significant
+    /// first), and the unused bits of the last byte are 0.
+    ///
+    /// This isn't the binary DIMACS format, which has a text preamble and pads every row (see
+    /// [`crate::input::read_graph_from_binary_dimacs`]). Edge weights are left out.
+    ///
+    /// Panics if the graph has more than `u32::MAX` vertices.
+    pub fn encode(&self) -> Vec<u8> {
+        let n = self.num_vertices();
+        let header = u32::try_from(n).expect("The graph has too many vertices to be encoded");
+        let mut bytes = vec![0; encoded_length(n)];
+        bytes[..HEADER_LENGTH].copy_from_slice(&header.to_le_bytes());
+        let matrix = &mut bytes[HEADER_LENGTH..];
+
+        for (u, v) in self.edge_list_as_slice().iter() {
+            let (u, v) = if u < v { (*u, *v) } else { (*v, *u) };
+            // Pairs starting with the vertices before u, then the pairs (u, w) with w < v
+            let k = u * (2 * n - u - 1) / 2 + (v - u - 1);
+            matrix[k / 8] |= 0x80 >> (k % 8);
+        }
+
+        bytes
+    }
+
+    /// Decodes a graph encoded by [`AdjList::encode`].
+    ///
+    /// Returns [`DecodeError::MissingHeader`] if there are less than 4 bytes, and
+    /// [`DecodeError::WrongLength`] if the rest doesn't match the vertex count.
+    pub fn decode(bytes: &[u8]) -> Result<AdjList, DecodeError> {
+        let Some(header) = bytes.get(..HEADER_LENGTH) else {
+            return Err(DecodeError::MissingHeader);
+        };
+        // The header has 4 bytes, so we can unwrap
+        let n = u32::from_le_bytes(header.try_into().unwrap()) as usize;
+        let expected = encoded_length(n);
+
+        if bytes.len() != expected {
+            return Err(DecodeError::WrongLength {
+                expected,
+                found: bytes.len(),
+            });
+        }
+
+        let matrix = &bytes[HEADER_LENGTH..];
+        let mut graph = AdjList::new(n);
+        let mut k = 0;
+
+        for u in 0..n {
+            for v in u + 1..n {
+                if matrix[k / 8] & (0x80 >> (k % 8)) != 0 {
+                    graph.add_edge(u, v);
+                }
+                k += 1;
+            }
+        }
+
+        Ok(graph)
+    }
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+    use crate::input;
+    use std::fs;
+
+    #[test]
+    fn test_encode_decode() {
+        let path = "data/myc/myciel4.col";
+
+        if let Ok(Some(graph)) = input::read_graph_from_file(path) {
+            let bytes = graph.encode();
+
+            // 23 vertices have 253 pairs, which take 32 bytes
+            assert_eq!(bytes.len(), 4 + 32);
+            assert_eq!(&bytes[..4], &[23, 0, 0, 0]);
+            assert!(bytes.len() * 10 < fs::metadata(path).unwrap().len() as usize);
+            assert_eq!(AdjList::decode(&bytes), Ok(graph));
+        } else {
+            panic!("The file containing the test graph is missing")
+        }
+
+        // The pairs of K_3 minus 1---2: (0, 1), (0, 2) and (1, 2)
+        let graph = AdjList::complete_minus(3, &[(1, 2)]);
+        let bytes = graph.encode();
+
+        assert_eq!(bytes, vec![3, 0, 0, 0, 0b1100_0000]);
+        assert_eq!(AdjList::decode(&bytes), Ok(graph));
+
+        for n in [0, 1] {
+            assert_eq!(AdjList::new(n).encode().len(), 4);
+            assert_eq!(
+                AdjList::decode(&AdjList::new(n).encode()),
+                Ok(AdjList::new(n))
+            );
+        }
+    }
+
+    #[test]
+    fn test_decode_errors() {
+        assert_eq!(AdjList::decode(&[3, 0]), Err(DecodeError::MissingHeader));
+        assert_eq!(
+            AdjList::decode(&[3, 0, 0, 0]),
+            Err(DecodeError::WrongLength {
+                expected: 5,
+                found: 4
+            })
+        );
+        assert_eq!(
+            AdjList::decode(&[1, 0, 0, 0, 0]),
+            Err(DecodeError::WrongLength {
+                expected: 4,
+                found: 5
+            })
+        );
+    }
+}
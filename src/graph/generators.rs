@@ -0,0 +1,243 @@
+use super::adj_list::AdjList;
+use rand::rngs::StdRng;
+use rand::{Rng, SeedableRng};
+
+/// Builds the cycle graph `C_n`, where each vertex `i` is adjacent to `i + 1` (modulo `n`).
+pub fn cycle_graph(n: usize) -> AdjList {
+    let mut graph = AdjList::new(n);
+
+    for i in 0..n {
+        graph.add_edge(i, (i + 1) % n);
+    }
+
+    graph
+}
+
+/// Builds the path graph `P_n`, where each vertex `i` is adjacent to `i + 1`.
+pub fn path_graph(n: usize) -> AdjList {
+    let mut graph = AdjList::new(n);
+
+    for i in 1..n {
+        graph.add_edge(i - 1, i);
+    }
+
+    graph
+}
+
+/// Builds the complete bipartite graph `K_{m,n}`, where the vertices `0..m` are adjacent to the
+/// vertices `m..m + n`.
+pub fn complete_bipartite_graph(m: usize, n: usize) -> AdjList {
+    let mut graph = AdjList::new(m + n);
+
+    for u in 0..m {
+        for v in m..m + n {
+            graph.add_edge(u, v);
+        }
+    }
+
+    graph
+}
+
+/// Builds the interval graph of the closed `intervals`, where each vertex `i` is adjacent to the
+/// vertices whose intervals intersect `intervals[i]`.
+pub fn interval_graph(intervals: &[(f64, f64)]) -> AdjList {
+    let mut graph = AdjList::new(intervals.len());
+
+    for (u, (a, b)) in intervals.iter().enumerate() {
+        for (v, (c, d)) in intervals.iter().enumerate().skip(u + 1) {
+            if a <= d && c <= b {
+                graph.add_edge(u, v);
+            }
+        }
+    }
+
+    graph
+}
+
+/// Builds the Mycielski graph `M_k`, a triangle-free graph with chromatic number `k`.
+///
+/// Starts from `K_2` and applies the Mycielski construction `k - 2` times: the graph on `n`
+/// vertices gets a shadow `n + i` of each vertex `i`, adjacent to the neighbors of `i`, and an
+/// apex `2n` adjacent to every shadow. The vertices are numbered as in the DIMACS `myciel` files,
+/// so `M_k` is `myciel<k - 1>.col`. `M_1` is a single vertex and `M_0` the empty graph.
+pub fn mycielski_graph(k: usize) -> AdjList {
+    if k < 2 {
+        return AdjList::new(k);
+    }
+
+    let mut graph = AdjList::complete(2);
+
+    for _ in 2..k {
+        let n = graph.num_vertices();
+        let mut next = AdjList::new(2 * n + 1);
+
+        for (u, v) in graph.edge_list_as_slice().iter() {
+            next.add_edge(*u, *v);
+            next.add_edge(n + u, *v);
+            next.add_edge(*u, n + v);
+        }
+        for shadow in n..2 * n {
+            next.add_edge(shadow, 2 * n);
+        }
+
+        graph = next;
+    }
+
+    graph
+}
+
+/// Builds a random planar graph on `n` vertices, with a generator seeded with `seed`.
+///
+/// Starts from a triangle and places each new vertex inside a random triangular face, joined to
+/// its 3 corners (a random Apollonian network). The result is a maximal planar graph with
+/// `3n - 6` edges when `n >= 3`.
+pub fn random_planar_graph(n: usize, seed: u64) -> AdjList {
+    let mut rng = StdRng::seed_from_u64(seed);
+    let mut graph = AdjList::new(n);
+
+    for v in 1..n.min(3) {
+        for u in 0..v {
+            graph.add_edge(u, v);
+        }
+    }
+
+    // The inner and outer faces of the triangle
+    let mut faces = vec![[0, 1, 2], [0, 1, 2]];
+
+    for v in 3..n {
+        // The outer face is never split, so the graph stays connected to the first triangle
+        let [a, b, c] = faces.swap_remove(rng.gen_range(1..faces.len()));
+
+        graph.add_edge(a, v);
+        graph.add_edge(b, v);
+        graph.add_edge(c, v);
+        faces.extend([[a, b, v], [a, c, v], [b, c, v]]);
+    }
+
+    graph
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+    use crate::{
+        algorithms::{greedy::dsatur, sat_coloring::is_k_colorable},
+        input,
+    };
+
+    #[test]
+    fn test_cycle_graph() {
+        let graph = cycle_graph(5);
+
+        assert_eq!(graph.num_vertices(), 5);
+        assert!(graph
+            .adj_list()
+            .iter()
+            .all(|neighbors| neighbors.len() == 2));
+        assert_eq!(graph.adj_list()[0], vec![1, 4]);
+    }
+
+    #[test]
+    fn test_path_graph() {
+        let graph = path_graph(5);
+
+        assert_eq!(graph.num_vertices(), 5);
+        assert_eq!(graph.edge_count(), 4);
+        assert_eq!(graph.adj_list()[0], vec![1]);
+        assert_eq!(graph.adj_list()[2], vec![1, 3]);
+        assert_eq!(path_graph(0).num_vertices(), 0);
+    }
+
+    #[test]
+    fn test_complete_bipartite_graph() {
+        let graph = complete_bipartite_graph(3, 4);
+
+        assert_eq!(graph.num_vertices(), 7);
+        assert_eq!(graph.edge_count(), 12);
+        assert_eq!(graph.adj_list()[0], vec![3, 4, 5, 6]);
+        assert_eq!(graph.adj_list()[6], vec![0, 1, 2]);
+        assert_eq!(graph.is_complete_bipartite(), Some((3, 4)));
+        assert!(graph.is_bipartite());
+        assert_eq!(dsatur(&graph).0, 2);
+
+        assert_eq!(
+            complete_bipartite_graph(1, 1).is_complete_bipartite(),
+            Some((1, 1))
+        );
+        assert_eq!(complete_bipartite_graph(0, 4).edge_count(), 0);
+        assert_eq!(
+            complete_bipartite_graph(4, 0).is_complete_bipartite(),
+            Some((4, 0))
+        );
+        // C_4 is K_{2,2}, but the longer even cycles miss edges
+        assert_eq!(cycle_graph(4).is_complete_bipartite(), Some((2, 2)));
+        assert_eq!(cycle_graph(6).is_complete_bipartite(), None);
+        assert_eq!(cycle_graph(5).is_complete_bipartite(), None);
+
+        // An isolated vertex makes a side miss an edge
+        let mut graph = AdjList::new(4);
+        graph.add_edge(0, 1);
+        graph.add_edge(0, 2);
+
+        assert_eq!(graph.is_complete_bipartite(), None);
+    }
+
+    #[test]
+    fn test_interval_graph() {
+        let graph = interval_graph(&[(0.0, 2.0), (1.0, 3.0), (2.0, 4.0), (5.0, 6.0)]);
+
+        assert_eq!(graph.num_vertices(), 4);
+        assert_eq!(graph.edge_count(), 3);
+        assert!(graph.has_edge(0, 1) && graph.has_edge(0, 2) && graph.has_edge(1, 2));
+        assert!(graph.adj_list()[3].is_empty());
+    }
+
+    #[test]
+    fn test_mycielski_graph() {
+        // M_3 is the 5-cycle 0---1---2---4---3
+        let graph = mycielski_graph(3);
+
+        assert_eq!(graph.edge_count(), 5);
+        assert_eq!(graph.girth(), Some(5));
+        assert!(graph
+            .adj_list()
+            .iter()
+            .all(|neighbors| neighbors.len() == 2));
+
+        // The Grötzsch graph, which needs 4 colors
+        let graph = mycielski_graph(4);
+
+        assert_eq!(graph.num_vertices(), 11);
+        assert!(graph.is_triangle_free());
+        assert!(!is_k_colorable(&graph, 3));
+        assert!(is_k_colorable(&graph, 4));
+
+        assert_eq!(mycielski_graph(5).num_vertices(), 23);
+        assert_eq!(mycielski_graph(2).edge_count(), 1);
+        assert_eq!(mycielski_graph(1).num_vertices(), 1);
+        assert_eq!(mycielski_graph(0).num_vertices(), 0);
+    }
+
+    #[test]
+    fn test_mycielski_graph_matches_files() {
+        for k in 4..=7 {
+            let path = format!("data/myc/myciel{}.col", k - 1);
+
+            if let Ok(Some(graph)) = input::read_graph_from_file(&path) {
+                assert_eq!(mycielski_graph(k).edge_list(), graph.edge_list());
+            } else {
+                panic!("The file containing the test graph is missing")
+            }
+        }
+    }
+
+    #[test]
+    fn test_random_planar_graph() {
+        let graph = random_planar_graph(20, 42);
+
+        assert_eq!(graph.num_vertices(), 20);
+        assert_eq!(graph.edge_count(), 3 * 20 - 6);
+        assert_eq!(random_planar_graph(2, 42).edge_count(), 1);
+        assert_eq!(random_planar_graph(0, 42).num_vertices(), 0);
+    }
+}
@@ -0,0 +1,186 @@
+use super::adj_list::AdjList;
+use std::collections::HashSet;
+
+/// A call of the Bron-Kerbosch algorithm in progress: the independent set `r` grows with the
+/// vertices of `p`, while those of `x` were already tried.
+#[derive(Debug, Clone)]
+struct Frame {
+    r: Vec<usize>,
+    p: Vec<usize>,
+    x: Vec<usize>,
+    /// Vertices of `p` still to branch on, those that aren't independent from the pivot.
+    branches: Vec<usize>,
+}
+
+/// Lazy iterator over the maximal independent sets of a graph, see
+/// [`AdjList::maximal_independent_sets`].
+#[derive(Debug, Clone)]
+pub struct MaximalIndependentSets {
+    neighbors: Vec<HashSet<usize>>,
+    stack: Vec<Frame>,
+    // The only set of the graph without vertices, which has no frame to come from
+    empty_set: Option<Vec<usize>>,
+}
+
+impl MaximalIndependentSets {
+    fn new(graph: &AdjList) -> Self {
+        let n = graph.num_vertices();
+        let neighbors = graph
+            .adj_list()
+            .iter()
+            .map(|list| list.iter().copied().collect())
+            .collect();
+        let mut iterator = MaximalIndependentSets {
+            neighbors,
+            stack: Vec::new(),
+            empty_set: (n == 0).then(Vec::new),
+        };
+
+        if n > 0 {
+            iterator.push_frame(Vec::new(), (0..n).collect(), Vec::new());
+        }
+
+        iterator
+    }
+
+    /// Checks whether `u` and `v` are distinct and not adjacent, i.e., adjacent in the complement.
+    fn independent(&self, u: usize, v: usize) -> bool {
+        u != v && !self.neighbors[u].contains(&v)
+    }
+
+    /// Pushes the call for `r`, `p` and `x`, branching only on the vertices that aren't
+    /// independent from the pivot, the vertex of `p` or `x` independent from the most vertices
+    /// of `p` (Tomita et al.).
+    fn push_frame(&mut self, r: Vec<usize>, p: Vec<usize>, x: Vec<usize>) {
+        // `p` isn't empty, so we can unwrap
+        let pivot = p
+            .iter()
+            .chain(x.iter())
+            .copied()
+            .max_by_key(|u| p.iter().filter(|v| self.independent(*u, **v)).count())
+            .unwrap();
+        let branches = p
+            .iter()
+            .copied()
+            .filter(|v| !self.independent(pivot, *v))
+            .collect();
+
+        self.stack.push(Frame { r, p, x, branches });
+    }
+}
+
+impl Iterator for MaximalIndependentSets {
+    type Item = Vec<usize>;
+
+    fn next(&mut self) -> Option<Self::Item> {
+        if let Some(set) = self.empty_set.take() {
+            return Some(set);
+        }
+
+        loop {
+            let frame = self.stack.last_mut()?;
+            let Some(vertex) = frame.branches.pop() else {
+                self.stack.pop();
+                continue;
+            };
+
+            frame.p.retain(|v| *v != vertex);
+            frame.x.push(vertex);
+
+            let mut r = frame.r.clone();
+            r.push(vertex);
+            let (p, x) = (frame.p.clone(), frame.x.clone());
+            let p: Vec<usize> = p
+                .into_iter()
+                .filter(|v| self.independent(vertex, *v))
+                .collect();
+            let x: Vec<usize> = x
+                .into_iter()
+                .filter(|v| self.independent(vertex, *v))
+                .collect();
+
+            if p.is_empty() {
+                // The set is maximal only if no vertex already tried could extend it
+                if x.is_empty() {
+                    r.sort();
+                    return Some(r);
+                }
+            } else {
+                self.push_frame(r, p, x);
+            }
+        }
+    }
+}
+
+impl AdjList {
+    /// Enumerates the maximal independent sets of the graph, one at a time and without
+    /// repetitions, each sorted. Uses the Bron-Kerbosch algorithm with pivoting on the cliques
+    /// of the complement, without building it.
+    pub fn maximal_independent_sets(&self) -> MaximalIndependentSets {
+        MaximalIndependentSets::new(self)
+    }
+
+    /// Counts the maximal independent sets of the graph by enumerating them, which takes
+    /// exponential time.
+    pub fn num_maximal_independent_sets(&self) -> usize {
+        self.maximal_independent_sets().count()
+    }
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+    use crate::{
+        graph::generators::{cycle_graph, path_graph},
+        input,
+    };
+
+    #[test]
+    fn test_maximal_independent_sets() {
+        let mut sets: Vec<Vec<usize>> = cycle_graph(4).maximal_independent_sets().collect();
+        sets.sort();
+
+        assert_eq!(sets, vec![vec![0, 2], vec![1, 3]]);
+
+        let mut sets: Vec<Vec<usize>> = path_graph(3).maximal_independent_sets().collect();
+        sets.sort();
+
+        assert_eq!(sets, vec![vec![0, 2], vec![1]]);
+        assert_eq!(AdjList::complete(4).num_maximal_independent_sets(), 4);
+        assert_eq!(cycle_graph(5).num_maximal_independent_sets(), 5);
+        assert_eq!(AdjList::new(3).num_maximal_independent_sets(), 1);
+        assert_eq!(
+            AdjList::new(0)
+                .maximal_independent_sets()
+                .collect::<Vec<_>>(),
+            vec![Vec::<usize>::new()]
+        );
+    }
+
+    #[test]
+    fn test_maximal_independent_sets_brute_force() {
+        if let Ok(Some(graph)) = input::read_graph_from_file("data/myc/myciel3.col") {
+            let n = graph.num_vertices();
+            let is_independent = |set: &[usize]| {
+                set.iter()
+                    .all(|u| set.iter().all(|v| !graph.adj_list()[*u].contains(v)))
+            };
+            let expected = (0..1usize << n)
+                .map(|mask| (0..n).filter(|v| mask & (1 << v) != 0).collect::<Vec<_>>())
+                .filter(|set| {
+                    is_independent(set)
+                        && (0..n).all(|v| {
+                            set.contains(&v) || !is_independent(&[set.clone(), vec![v]].concat())
+                        })
+                })
+                .count();
+            let sets: HashSet<Vec<usize>> = graph.maximal_independent_sets().collect();
+
+            assert_eq!(sets.len(), expected);
+            assert_eq!(graph.num_maximal_independent_sets(), expected);
+            assert!(sets.iter().all(|set| is_independent(set)));
+        } else {
+            panic!("The file containing the test graph is missing")
+        }
+    }
+}
@@ -0,0 +1,110 @@
+use super::adj_list::AdjList;
+use std::collections::VecDeque;
+
+/// Labels the connected components of the graph without `vertex` and its neighbors, in
+/// `O(n + m)` time. The removed vertices get no label.
+fn components_without_neighborhood(graph: &AdjList, vertex: usize) -> Vec<Option<usize>> {
+    let n = graph.num_vertices();
+    let mut removed = vec![false; n];
+    removed[vertex] = true;
+    for u in graph.adj_list()[vertex].iter() {
+        removed[*u] = true;
+    }
+
+    let mut component = vec![None; n];
+    let mut num_components = 0;
+
+    for root in 0..n {
+        if removed[root] || component[root].is_some() {
+            continue;
+        }
+
+        component[root] = Some(num_components);
+        let mut queue = VecDeque::from([root]);
+
+        while let Some(u) = queue.pop_front() {
+            for w in graph.adj_list()[u].iter() {
+                if !removed[*w] && component[*w].is_none() {
+                    component[*w] = Some(num_components);
+                    queue.push_back(*w);
+                }
+            }
+        }
+
+        num_components += 1;
+    }
+
+    component
+}
+
+impl AdjList {
+    /// Checks whether the graph has an asteroidal triple: three pairwise non-adjacent vertices
+    /// such that any two of them are joined by a path avoiding the neighbors of the third, in
+    /// `O(n(n + m) + n³)` time.
+    pub fn has_asteroidal_triple(&self) -> bool {
+        let n = self.num_vertices();
+        let components: Vec<Vec<Option<usize>>> = (0..n)
+            .map(|vertex| components_without_neighborhood(self, vertex))
+            .collect();
+        // Two vertices outside the neighborhood of a third are joined avoiding it if they're in
+        // the same component
+        let joined_avoiding = |a: usize, b: usize, c: usize| components[c][a] == components[c][b];
+
+        (0..n).any(|a| {
+            (a + 1..n).filter(|b| !self.has_edge(a, *b)).any(|b| {
+                (b + 1..n)
+                    .filter(|c| !self.has_edge(a, *c) && !self.has_edge(b, *c))
+                    .any(|c| {
+                        joined_avoiding(b, c, a)
+                            && joined_avoiding(a, c, b)
+                            && joined_avoiding(a, b, c)
+                    })
+            })
+        })
+    }
+
+    /// Checks whether the graph is an interval graph, the intersection graph of intervals of the
+    /// real line.
+    ///
+    /// Interval graphs are the chordal graphs without asteroidal triples (Lekkerkerker and
+    /// Boland), which is what is checked. Equivalently, the maximal cliques can be ordered so
+    /// that the ones holding each vertex are consecutive.
+    pub fn is_interval_graph(&self) -> bool {
+        self.is_chordal() && !self.has_asteroidal_triple()
+    }
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+    use crate::graph::generators::{cycle_graph, interval_graph, path_graph};
+
+    #[test]
+    fn test_is_interval_graph() {
+        assert!(path_graph(5).is_interval_graph());
+        assert!(AdjList::complete(4).is_interval_graph());
+        assert!(AdjList::new(0).is_interval_graph());
+        assert!(!cycle_graph(4).is_interval_graph());
+        assert!(!cycle_graph(5).is_interval_graph());
+
+        // A claw with subdivided edges is chordal, but its leaves form an asteroidal triple
+        let mut graph = AdjList::new(7);
+        for (u, v) in [(0, 1), (1, 2), (0, 3), (3, 4), (0, 5), (5, 6)] {
+            graph.add_edge(u, v);
+        }
+
+        assert!(graph.is_chordal());
+        assert!(graph.has_asteroidal_triple());
+        assert!(!graph.is_interval_graph());
+
+        // Without one of the subdivisions, it becomes a caterpillar
+        graph.remove_edge(5, 6);
+
+        assert!(graph.is_interval_graph());
+
+        // Two intervals overlapping a third, and disjoint from each other
+        assert!(
+            interval_graph(&[(0.0, 2.0), (1.0, 4.0), (3.0, 5.0), (6.0, 7.0)]).is_interval_graph()
+        );
+    }
+}
@@ -0,0 +1,339 @@
+use super::adj_list::AdjList;
+use std::collections::VecDeque;
+
+/// The edges `(u, v)`, with `u < v`, of the matching described by the mate of each vertex,
+/// sorted.
+fn matching_edges(mate: &[Option<usize>]) -> Vec<(usize, usize)> {
+    mate.iter()
+        .enumerate()
+        .filter_map(|(u, v)| v.filter(|v| u < *v).map(|v| (u, v)))
+        .collect()
+}
+
+/// State of the Hopcroft-Karp algorithm, with `left` holding the side of each vertex.
+struct HopcroftKarp<'a> {
+    graph: &'a AdjList,
+    left: Vec<bool>,
+    mate: Vec<Option<usize>>,
+    // Layer of each left vertex in the last BFS, usize::MAX if unreached
+    layer: Vec<usize>,
+}
+
+impl HopcroftKarp<'_> {
+    /// Layers the left vertices by the length of the shortest alternating paths from the free
+    /// ones, returning whether any of them reaches a free right vertex.
+    fn bfs(&mut self) -> bool {
+        let mut queue = VecDeque::new();
+        for u in 0..self.graph.num_vertices() {
+            if self.left[u] && self.mate[u].is_none() {
+                self.layer[u] = 0;
+                queue.push_back(u);
+            } else {
+                self.layer[u] = usize::MAX;
+            }
+        }
+
+        let mut found = false;
+        while let Some(u) = queue.pop_front() {
+            for v in self.graph.adj_list()[u].iter() {
+                match self.mate[*v] {
+                    None => found = true,
+                    Some(w) if self.layer[w] == usize::MAX => {
+                        self.layer[w] = self.layer[u] + 1;
+                        queue.push_back(w);
+                    }
+                    _ => {}
+                }
+            }
+        }
+
+        found
+    }
+
+    /// Augments the matching along a shortest alternating path from the left vertex `u`, going
+    /// down the layers, returning whether there was one.
+    fn dfs(&mut self, u: usize) -> bool {
+        for i in 0..self.graph.get_degree(u) {
+            let v = self.graph.adj_list()[u][i];
+            let augments = match self.mate[v] {
+                None => true,
+                Some(w) => self.layer[w] == self.layer[u] + 1 && self.dfs(w),
+            };
+
+            if augments {
+                self.mate[u] = Some(v);
+                self.mate[v] = Some(u);
+                return true;
+            }
+        }
+
+        // No path goes through u in this phase
+        self.layer[u] = usize::MAX;
+
+        false
+    }
+}
+
+/// State of the search for an augmenting path of Edmonds' blossom algorithm.
+struct BlossomSearch<'a> {
+    graph: &'a AdjList,
+    mate: Vec<Option<usize>>,
+    // Vertex that reached each odd vertex of the alternating tree
+    parent: Vec<Option<usize>>,
+    // Base of the contracted blossom holding each vertex
+    base: Vec<usize>,
+    // Even vertices of the alternating tree
+    used: Vec<bool>,
+    queue: VecDeque<usize>,
+}
+
+impl BlossomSearch<'_> {
+    /// Lowest common ancestor of the bases of `a` and `b` in the alternating tree.
+    fn lowest_common_ancestor(&self, mut a: usize, mut b: usize) -> usize {
+        let mut on_path = vec![false; self.graph.num_vertices()];
+
+        loop {
+            a = self.base[a];
+            on_path[a] = true;
+            match self.mate[a] {
+                // Only the root is an even vertex without a mate, and it has no parent
+                None => break,
+                Some(mate) => a = self.parent[mate].unwrap(),
+            }
+        }
+
+        loop {
+            b = self.base[b];
+            if on_path[b] {
+                return b;
+            }
+            // b isn't the root, so it has a mate with a parent
+            b = self.parent[self.mate[b].unwrap()].unwrap();
+        }
+    }
+
+    /// Marks the blossoms on the path from `vertex` up to the base `blossom_base`, pointing the
+    /// odd vertices along it back towards `child`, so that the blossom can be crossed both ways.
+    fn mark_path(
+        &mut self,
+        in_blossom: &mut [bool],
+        mut vertex: usize,
+        blossom_base: usize,
+        mut child: usize,
+    ) {
+        while self.base[vertex] != blossom_base {
+            // vertex is below the base, so it has a mate with a parent
+            let mate = self.mate[vertex].unwrap();
+            in_blossom[self.base[vertex]] = true;
+            in_blossom[self.base[mate]] = true;
+            self.parent[vertex] = Some(child);
+            child = mate;
+            vertex = self.parent[mate].unwrap();
+        }
+    }
+
+    /// Contracts the blossom closed by the edge between the even vertices `u` and `v`.
+    fn contract(&mut self, u: usize, v: usize) {
+        let blossom_base = self.lowest_common_ancestor(u, v);
+        let mut in_blossom = vec![false; self.graph.num_vertices()];
+        self.mark_path(&mut in_blossom, u, blossom_base, v);
+        self.mark_path(&mut in_blossom, v, blossom_base, u);
+
+        for vertex in 0..self.graph.num_vertices() {
+            if in_blossom[self.base[vertex]] {
+                self.base[vertex] = blossom_base;
+                if !self.used[vertex] {
+                    self.used[vertex] = true;
+                    self.queue.push_back(vertex);
+                }
+            }
+        }
+    }
+
+    /// Grows an alternating tree from the free vertex `root`, returning the free vertex that ends
+    /// an augmenting path from it, if any.
+    fn find_augmenting_path(&mut self, root: usize) -> Option<usize> {
+        let n = self.graph.num_vertices();
+        self.parent = vec![None; n];
+        self.base = (0..n).collect();
+        self.used = vec![false; n];
+        self.used[root] = true;
+        self.queue = VecDeque::from([root]);
+
+        while let Some(u) = self.queue.pop_front() {
+            for i in 0..self.graph.get_degree(u) {
+                let v = self.graph.adj_list()[u][i];
+                if self.base[u] == self.base[v] || self.mate[u] == Some(v) {
+                    continue;
+                }
+
+                if v == root || self.mate[v].is_some_and(|mate| self.parent[mate].is_some()) {
+                    // v is even too, so the edge closes an odd cycle
+                    self.contract(u, v);
+                } else if self.parent[v].is_none() {
+                    self.parent[v] = Some(u);
+                    match self.mate[v] {
+                        None => return Some(v),
+                        Some(mate) => {
+                            self.used[mate] = true;
+                            self.queue.push_back(mate);
+                        }
+                    }
+                }
+            }
+        }
+
+        None
+    }
+
+    /// Flips the matching along the augmenting path ending at `last`.
+    fn augment(&mut self, last: usize) {
+        let mut vertex = Some(last);
+
+        while let Some(v) = vertex {
+            // Every odd vertex on the path has a parent
+            let parent = self.parent[v].unwrap();
+            vertex = self.mate[parent];
+            self.mate[v] = Some(parent);
+            self.mate[parent] = Some(v);
+        }
+    }
+}
+
+impl AdjList {
+    /// Finds a maximum matching of a bipartite graph with the Hopcroft-Karp algorithm, in
+    /// `O(m√n)` time, as edges `(u, v)` with `u < v`, sorted. `None` if the graph isn't
+    /// bipartite.
+    pub fn maximum_matching_bipartite(&self) -> Option<Vec<(usize, usize)>> {
+        let n = self.num_vertices();
+        let mut search = HopcroftKarp {
+            graph: self,
+            left: self.bipartition()?,
+            mate: vec![None; n],
+            layer: vec![usize::MAX; n],
+        };
+
+        while search.bfs() {
+            for u in 0..n {
+                if search.left[u] && search.mate[u].is_none() {
+                    search.dfs(u);
+                }
+            }
+        }
+
+        Some(matching_edges(&search.mate))
+    }
+
+    /// Finds a maximum matching with Edmonds' blossom algorithm, in `O(n³)` time, as edges
+    /// `(u, v)` with `u < v`, sorted.
+    ///
+    /// No color class of an edge coloring holds more edges than a maximum matching, so at least
+    /// `m` divided by its size colors (rounded up) are needed to color the edges.
+    pub fn maximum_matching(&self) -> Vec<(usize, usize)> {
+        let mut search = BlossomSearch {
+            graph: self,
+            mate: vec![None; self.num_vertices()],
+            parent: Vec::new(),
+            base: Vec::new(),
+            used: Vec::new(),
+            queue: VecDeque::new(),
+        };
+
+        for root in 0..self.num_vertices() {
+            if search.mate[root].is_none() {
+                if let Some(last) = search.find_augmenting_path(root) {
+                    search.augment(last);
+                }
+            }
+        }
+
+        matching_edges(&search.mate)
+    }
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+    use crate::graph::generators::{complete_bipartite_graph, cycle_graph, path_graph};
+    use crate::input;
+
+    fn is_matching(graph: &AdjList, matching: &[(usize, usize)]) -> bool {
+        let mut matched = vec![false; graph.num_vertices()];
+
+        matching.iter().all(|(u, v)| {
+            let disjoint = !matched[*u] && !matched[*v];
+            matched[*u] = true;
+            matched[*v] = true;
+
+            disjoint && graph.has_edge(*u, *v)
+        })
+    }
+
+    #[test]
+    fn test_maximum_matching_bipartite() {
+        for (graph, size) in [
+            (complete_bipartite_graph(3, 3), 3),
+            (complete_bipartite_graph(2, 5), 2),
+            (path_graph(5), 2),
+            (path_graph(6), 3),
+            (cycle_graph(8), 4),
+            (AdjList::new(3), 0),
+        ] {
+            // Some to unwrap, since the graphs are bipartite
+            let matching = graph.maximum_matching_bipartite().unwrap();
+
+            assert_eq!(matching.len(), size);
+            assert!(is_matching(&graph, &matching));
+            assert_eq!(graph.maximum_matching().len(), size);
+        }
+
+        assert_eq!(AdjList::complete(4).maximum_matching_bipartite(), None);
+        assert_eq!(cycle_graph(5).maximum_matching_bipartite(), None);
+    }
+
+    #[test]
+    fn test_maximum_matching() {
+        for (graph, size) in [
+            (AdjList::complete(4), 2),
+            (AdjList::complete(5), 2),
+            (cycle_graph(5), 2),
+            (cycle_graph(7), 3),
+            (path_graph(5), 2),
+        ] {
+            let matching = graph.maximum_matching();
+
+            assert_eq!(matching.len(), size);
+            assert!(is_matching(&graph, &matching));
+        }
+
+        // Two triangles joined by a path of length 2 through 6: augmenting the matching from 6
+        // has to go through a blossom
+        let mut graph = AdjList::new(7);
+        for (u, v) in [
+            (0, 1),
+            (1, 2),
+            (0, 2),
+            (3, 4),
+            (4, 5),
+            (3, 5),
+            (2, 6),
+            (6, 3),
+        ] {
+            graph.add_edge(u, v);
+        }
+        let matching = graph.maximum_matching();
+
+        assert_eq!(matching.len(), 3);
+        assert!(is_matching(&graph, &matching));
+
+        if let Ok(Some(graph)) = input::read_graph_from_file("data/myc/myciel3.col") {
+            // The Grötzsch graph has a near-perfect matching of its 11 vertices
+            let matching = graph.maximum_matching();
+
+            assert_eq!(matching.len(), 5);
+            assert!(is_matching(&graph, &matching));
+        } else {
+            panic!("The file containing the test graph is missing")
+        }
+    }
+}
@@ -1 +1,18 @@
 pub mod adj_list;
+pub mod bandwidth;
+pub mod chordal;
+pub mod connectivity;
+pub mod directed;
+pub mod encoding;
+pub mod generators;
+pub mod independent_sets;
+pub mod interval;
+pub mod matching;
+pub mod perfect;
+pub mod planarity;
+pub mod preprocessing;
+pub mod random;
+pub mod residual;
+pub mod sparse;
+pub mod spectrum;
+pub mod statistics;
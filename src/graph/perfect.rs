@@ -0,0 +1,295 @@
+use super::adj_list::AdjList;
+use std::collections::VecDeque;
+
+/// Longest odd hole looked for by [`AdjList::is_perfect_approx`], which bounds the search to
+/// `O(n^MAX_HOLE_LENGTH)` time.
+pub const MAX_HOLE_LENGTH: usize = 7;
+
+/// Extends the induced path `path` of `graph` into a hole, a chordless cycle, of `length`
+/// vertices. To find each hole once, the first vertex of the path is the smallest of the hole.
+fn extend_hole(
+    graph: &AdjList,
+    path: &mut Vec<usize>,
+    is_adjacent: &[Vec<bool>],
+    length: usize,
+) -> bool {
+    // The path has at least one vertex, so we can unwrap
+    let first = path[0];
+    let last = *path.last().unwrap();
+
+    for vertex in graph.adj_list()[last].iter().copied() {
+        if vertex <= first || path.contains(&vertex) {
+            continue;
+        }
+
+        let is_last = path.len() + 1 == length;
+
+        // Besides the last vertex of the path, the new vertex may only be adjacent to the first
+        // one, and only if it closes the hole
+        if path.len() > 1 {
+            let closes = is_adjacent[vertex][first];
+            let has_chord = path[1..path.len() - 1]
+                .iter()
+                .any(|u| is_adjacent[vertex][*u]);
+
+            if has_chord || closes != is_last {
+                continue;
+            }
+        }
+
+        if is_last {
+            return true;
+        }
+
+        path.push(vertex);
+
+        if extend_hole(graph, path, is_adjacent, length) {
+            return true;
+        }
+
+        path.pop();
+    }
+
+    false
+}
+
+/// Checks whether `graph` has an odd hole with at least 5 and at most [`MAX_HOLE_LENGTH`]
+/// vertices.
+fn has_short_odd_hole(graph: &AdjList) -> bool {
+    let n = graph.num_vertices();
+    let mut is_adjacent = vec![vec![false; n]; n];
+    for (u, neighbors) in graph.adj_list().iter().enumerate() {
+        for v in neighbors.iter() {
+            is_adjacent[u][*v] = true;
+        }
+    }
+
+    (5..=MAX_HOLE_LENGTH).step_by(2).any(|length| {
+        (0..n).any(|start| extend_hole(graph, &mut vec![start], &is_adjacent, length))
+    })
+}
+
+/// Shortest path from `from` to `to` that avoids the `blocked` vertices, found with a BFS, if
+/// there is one. A shortest path has no chords, since any chord would shorten it.
+fn shortest_path_avoiding(
+    graph: &AdjList,
+    from: usize,
+    to: usize,
+    blocked: &[bool],
+) -> Option<Vec<usize>> {
+    let mut parent = vec![None; graph.num_vertices()];
+    let mut reached = vec![false; graph.num_vertices()];
+    let mut queue = VecDeque::from([from]);
+    reached[from] = true;
+
+    while let Some(vertex) = queue.pop_front() {
+        if vertex == to {
+            let mut path = vec![to];
+            while let Some(previous) = parent[*path.last().unwrap()] {
+                path.push(previous);
+            }
+            path.reverse();
+
+            return Some(path);
+        }
+
+        for neighbor in graph.adj_list()[vertex].iter() {
+            if !reached[*neighbor] && !blocked[*neighbor] {
+                reached[*neighbor] = true;
+                parent[*neighbor] = Some(vertex);
+                queue.push_back(*neighbor);
+            }
+        }
+    }
+
+    None
+}
+
+impl AdjList {
+    /// Decides whether the graph is perfect, i.e., whether every induced subgraph has chromatic
+    /// number equal to its clique number, when that is cheap to tell.
+    ///
+    /// By the strong perfect graph theorem, a graph is perfect iff it has no odd hole or odd
+    /// antihole (the complement of an odd hole) with at least 5 vertices. Returns:
+    ///
+    /// - `Some(true)` if the graph or its complement is bipartite or chordal, which are perfect
+    /// - `Some(false)` if the graph or its complement has an odd hole with at most
+    ///   [`MAX_HOLE_LENGTH`] vertices
+    /// - `None` otherwise, as longer odd holes aren't looked for
+    pub fn is_perfect_approx(&self) -> Option<bool> {
+        let complement = self.complement();
+
+        if self.is_bipartite()
+            || complement.is_bipartite()
+            || self.is_chordal()
+            || complement.is_chordal()
+        {
+            return Some(true);
+        }
+
+        if has_short_odd_hole(self) || has_short_odd_hole(&complement) {
+            return Some(false);
+        }
+
+        None
+    }
+    /// Finds an induced path, one where only consecutive vertices are adjacent, from `start` to
+    /// `end`, in `O(n + m)` time. `None` if `end` can't be reached.
+    ///
+    /// A shortest path, found by a BFS, is always induced.
+    pub fn induced_path(&self, start: usize, end: usize) -> Option<Vec<usize>> {
+        shortest_path_avoiding(self, start, end, &vec![false; self.num_vertices()])
+    }
+
+    /// Finds a shortest hole, a chordless cycle of at least 4 vertices, as its vertices in order
+    /// around it, in `O(nΔ²(n + m))` time. `None` if the graph has no hole, i.e., if it's chordal.
+    ///
+    /// Every hole goes through some vertex `a` between two non-adjacent neighbors `b` and `c`,
+    /// and the rest of it is a path from `b` to `c` avoiding the other neighbors of `a`. The
+    /// shortest such path closes a hole with `a`, so trying every `a`, `b` and `c` finds a
+    /// shortest hole.
+    pub fn find_shortest_induced_hole(&self) -> Option<Vec<usize>> {
+        let mut shortest: Option<Vec<usize>> = None;
+
+        for a in 0..self.num_vertices() {
+            let neighbors = &self.adj_list()[a];
+            let mut blocked = vec![false; self.num_vertices()];
+            blocked[a] = true;
+            for neighbor in neighbors.iter() {
+                blocked[*neighbor] = true;
+            }
+
+            for (i, b) in neighbors.iter().enumerate() {
+                for c in neighbors[i + 1..]
+                    .iter()
+                    .filter(|c| !self.has_edge(*b, **c))
+                {
+                    blocked[*c] = false;
+                    let path = shortest_path_avoiding(self, *b, *c, &blocked);
+                    blocked[*c] = true;
+
+                    if let Some(path) = path.filter(|path| {
+                        shortest
+                            .as_ref()
+                            .is_none_or(|hole| path.len() + 1 < hole.len())
+                    }) {
+                        shortest = Some([vec![a], path].concat());
+                    }
+                }
+            }
+        }
+
+        shortest
+    }
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+    use crate::{
+        graph::generators::{cycle_graph, path_graph},
+        input,
+    };
+
+    #[test]
+    fn test_is_perfect_approx() {
+        assert_eq!(AdjList::complete(5).is_perfect_approx(), Some(true));
+        assert_eq!(cycle_graph(6).is_perfect_approx(), Some(true));
+        assert_eq!(path_graph(7).is_perfect_approx(), Some(true));
+        assert_eq!(AdjList::new(0).is_perfect_approx(), Some(true));
+
+        // A chordal graph that isn't bipartite, nor is its complement: two triangles sharing the
+        // edge 1---2, with a path 3---4---5 hanging from 3
+        let mut graph = AdjList::new(6);
+        for (u, v) in [(0, 1), (0, 2), (1, 2), (1, 3), (2, 3), (3, 4), (4, 5)] {
+            graph.add_edge(u, v);
+        }
+
+        assert_eq!(graph.is_perfect_approx(), Some(true));
+
+        // Odd holes and antiholes
+        assert_eq!(cycle_graph(5).is_perfect_approx(), Some(false));
+        assert_eq!(cycle_graph(7).complement().is_perfect_approx(), Some(false));
+
+        if let Ok(Some(graph)) = input::read_graph_from_file("data/myc/myciel3.col") {
+            assert_eq!(graph.is_perfect_approx(), Some(false));
+        } else {
+            panic!("The file containing the test graph is missing")
+        }
+
+        // The only odd hole of C_9 is too long to be found
+        assert_eq!(cycle_graph(9).is_perfect_approx(), None);
+    }
+
+    #[test]
+    fn test_has_short_odd_hole() {
+        // A 5-cycle with a chord has no hole of length 5, only a triangle and a 4-hole
+        let mut graph = cycle_graph(5);
+        graph.add_edge(0, 2);
+
+        assert!(!has_short_odd_hole(&graph));
+        assert!(has_short_odd_hole(&cycle_graph(7)));
+        assert!(!has_short_odd_hole(&cycle_graph(6)));
+    }
+
+    /// Checks whether `hole` is a chordless cycle of `graph` with at least 4 vertices.
+    fn is_hole(graph: &AdjList, hole: &[usize]) -> bool {
+        let n = hole.len();
+
+        n >= 4
+            && (0..n).all(|i| {
+                (i + 1..n).all(|j| {
+                    graph.has_edge(hole[i], hole[j]) == (j == i + 1 || (i == 0 && j == n - 1))
+                })
+            })
+    }
+
+    #[test]
+    fn test_induced_path() {
+        let mut graph = cycle_graph(6);
+        graph.add_edge(0, 2);
+        let path = graph.induced_path(1, 4).unwrap();
+
+        assert_eq!(path.len(), 4);
+        assert_eq!((path[0], path[3]), (1, 4));
+        for i in 0..path.len() {
+            for j in i + 1..path.len() {
+                assert_eq!(graph.has_edge(path[i], path[j]), j == i + 1);
+            }
+        }
+
+        assert_eq!(graph.induced_path(3, 3), Some(vec![3]));
+        assert_eq!(AdjList::new(2).induced_path(0, 1), None);
+    }
+
+    #[test]
+    fn test_find_shortest_induced_hole() {
+        assert_eq!(AdjList::complete(5).find_shortest_induced_hole(), None);
+        assert_eq!(path_graph(6).find_shortest_induced_hole(), None);
+        assert_eq!(cycle_graph(3).find_shortest_induced_hole(), None);
+
+        let hole = cycle_graph(5).find_shortest_induced_hole().unwrap();
+
+        assert_eq!(hole.len(), 5);
+        assert!(is_hole(&cycle_graph(5), &hole));
+
+        // The chord 0---3 splits C_6 into two 4-holes
+        let mut graph = cycle_graph(6);
+        graph.add_edge(0, 3);
+        let hole = graph.find_shortest_induced_hole().unwrap();
+
+        assert_eq!(hole.len(), 4);
+        assert!(is_hole(&graph, &hole));
+
+        if let Ok(Some(graph)) = input::read_graph_from_file("data/myc/myciel3.col") {
+            // The Grötzsch graph is triangle-free, and has 4-holes
+            assert!(!graph.is_chordal());
+            let hole = graph.find_shortest_induced_hole().unwrap();
+
+            assert_eq!(hole.len(), 4);
+            assert!(is_hole(&graph, &hole));
+        } else {
+            panic!("The file containing the test graph is missing")
+        }
+    }
+}
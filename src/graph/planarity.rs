@@ -0,0 +1,459 @@
+use super::adj_list::AdjList;
+use std::collections::HashSet;
+
+/// Interval of return edges, given by its lowest and highest edges. Both are `None` if it's empty.
+#[derive(Debug, Clone, Copy, Default, PartialEq)]
+struct Interval {
+    low: Option<usize>,
+    high: Option<usize>,
+}
+
+impl Interval {
+    fn is_empty(&self) -> bool {
+        self.low.is_none() && self.high.is_none()
+    }
+}
+
+/// Return edges that must be embedded on opposite sides.
+#[derive(Debug, Clone, Copy, Default, PartialEq)]
+struct ConflictPair {
+    left: Interval,
+    right: Interval,
+}
+
+impl ConflictPair {
+    fn swap(&mut self) {
+        std::mem::swap(&mut self.left, &mut self.right);
+    }
+}
+
+/// State of the Left-Right planarity test, with the graph oriented by a DFS. Edges are identified
+/// by their index in the orientation.
+struct LeftRight {
+    height: Vec<Option<usize>>,
+    parent_edge: Vec<Option<usize>>,
+    source: Vec<usize>,
+    target: Vec<usize>,
+    lowpt: Vec<usize>,
+    lowpt2: Vec<usize>,
+    nesting_depth: Vec<usize>,
+    // Outgoing edges of each vertex, sorted by nesting depth
+    ordered_adjs: Vec<Vec<usize>>,
+    lowpt_edge: Vec<Option<usize>>,
+    reference: Vec<Option<usize>>,
+    stack_bottom: Vec<usize>,
+    stack: Vec<ConflictPair>,
+}
+
+impl LeftRight {
+    fn new(num_vertices: usize) -> Self {
+        LeftRight {
+            height: vec![None; num_vertices],
+            parent_edge: vec![None; num_vertices],
+            source: Vec::new(),
+            target: Vec::new(),
+            lowpt: Vec::new(),
+            lowpt2: Vec::new(),
+            nesting_depth: Vec::new(),
+            ordered_adjs: vec![Vec::new(); num_vertices],
+            lowpt_edge: Vec::new(),
+            reference: Vec::new(),
+            stack_bottom: Vec::new(),
+            stack: Vec::new(),
+        }
+    }
+
+    /// Orients the edges reachable from `root` with an (iterative) DFS, computing the lowpoints
+    /// and nesting depths of the edges.
+    fn orient(&mut self, graph: &AdjList, root: usize, oriented: &mut HashSet<(usize, usize)>) {
+        let adj_list = graph.adj_list();
+        // Next neighbor to handle for each vertex, and the tree edge being explored, if any
+        let mut next = vec![0; graph.num_vertices()];
+        let mut pending: Vec<Option<usize>> = vec![None; graph.num_vertices()];
+        let mut dfs_stack = vec![root];
+
+        while let Some(v) = dfs_stack.pop() {
+            let parent = self.parent_edge[v];
+
+            while next[v] < adj_list[v].len() {
+                let w = adj_list[v][next[v]];
+
+                let vw = match pending[v].take() {
+                    // Back from the DFS of w
+                    Some(vw) => vw,
+                    None => {
+                        if !oriented.insert((v.min(w), v.max(w))) {
+                            next[v] += 1;
+                            continue;
+                        }
+
+                        let vw = self.target.len();
+                        // The height of v is set before it is visited, so we can unwrap
+                        let height = self.height[v].unwrap();
+                        self.source.push(v);
+                        self.target.push(w);
+                        self.lowpt.push(height);
+                        self.lowpt2.push(height);
+                        self.nesting_depth.push(0);
+                        self.ordered_adjs[v].push(vw);
+
+                        match self.height[w] {
+                            None => {
+                                self.parent_edge[w] = Some(vw);
+                                self.height[w] = Some(height + 1);
+                                pending[v] = Some(vw);
+                                dfs_stack.push(v);
+                                dfs_stack.push(w);
+                                break;
+                            }
+                            Some(height) => self.lowpt[vw] = height,
+                        }
+
+                        vw
+                    }
+                };
+
+                let height = self.height[v].unwrap();
+
+                self.nesting_depth[vw] = 2 * self.lowpt[vw];
+                // Chordal edge
+                if self.lowpt2[vw] < height {
+                    self.nesting_depth[vw] += 1;
+                }
+
+                if let Some(e) = parent {
+                    if self.lowpt[vw] < self.lowpt[e] {
+                        self.lowpt2[e] = self.lowpt[e].min(self.lowpt2[vw]);
+                        self.lowpt[e] = self.lowpt[vw];
+                    } else if self.lowpt[vw] > self.lowpt[e] {
+                        self.lowpt2[e] = self.lowpt2[e].min(self.lowpt[vw]);
+                    } else {
+                        self.lowpt2[e] = self.lowpt2[e].min(self.lowpt2[vw]);
+                    }
+                }
+
+                next[v] += 1;
+            }
+        }
+    }
+
+    /// Whether the return edges of `interval` conflict with the edge `b`.
+    fn conflicting(&self, interval: &Interval, b: usize) -> bool {
+        interval
+            .high
+            .is_some_and(|high| self.lowpt[high] > self.lowpt[b])
+    }
+
+    /// Lowest return point of the edges of `pair`.
+    fn lowest(&self, pair: &ConflictPair) -> usize {
+        match (pair.left.low, pair.right.low) {
+            (Some(left), Some(right)) => self.lowpt[left].min(self.lowpt[right]),
+            (Some(low), None) | (None, Some(low)) => self.lowpt[low],
+            // Conflict pairs on the stack aren't empty
+            (None, None) => unreachable!(),
+        }
+    }
+
+    /// Tests the edges reachable from `root`, in the order of their nesting depth.
+    fn test(&mut self, root: usize) -> bool {
+        let num_vertices = self.height.len();
+        // Next edge to handle for each vertex, and whether the DFS of that edge is over
+        let mut next = vec![0; num_vertices];
+        let mut returning = vec![false; num_vertices];
+        let mut dfs_stack = vec![root];
+
+        while let Some(v) = dfs_stack.pop() {
+            let parent = self.parent_edge[v];
+            let mut descended = false;
+
+            while next[v] < self.ordered_adjs[v].len() {
+                let ei = self.ordered_adjs[v][next[v]];
+                let w = self.target[ei];
+
+                if returning[v] {
+                    returning[v] = false;
+                } else {
+                    self.stack_bottom[ei] = self.stack.len();
+
+                    if self.parent_edge[w] == Some(ei) {
+                        returning[v] = true;
+                        dfs_stack.push(v);
+                        dfs_stack.push(w);
+                        descended = true;
+                        break;
+                    }
+
+                    self.lowpt_edge[ei] = Some(ei);
+                    self.stack.push(ConflictPair {
+                        left: Interval::default(),
+                        right: Interval {
+                            low: Some(ei),
+                            high: Some(ei),
+                        },
+                    });
+                }
+
+                // The height of v is set by the orientation, so we can unwrap
+                if self.lowpt[ei] < self.height[v].unwrap() {
+                    // The edge has a return edge, so v has a parent edge
+                    let e = parent.unwrap();
+
+                    if next[v] == 0 {
+                        self.lowpt_edge[e] = self.lowpt_edge[ei];
+                    } else if !self.add_constraints(ei, e) {
+                        return false;
+                    }
+                }
+
+                next[v] += 1;
+            }
+
+            if !descended {
+                if let Some(e) = parent {
+                    self.remove_back_edges(e);
+                }
+            }
+        }
+
+        true
+    }
+
+    /// Merges the return edges of `ei` with the conflicting ones of its siblings, the edges of
+    /// the parent `e` handled before it. Returns `false` if they can't be split into two sides.
+    fn add_constraints(&mut self, ei: usize, e: usize) -> bool {
+        let mut pair = ConflictPair::default();
+
+        // Merges the return edges of ei into the right interval
+        loop {
+            // There are return edges of ei on the stack, so we can unwrap
+            let mut q = self.stack.pop().unwrap();
+
+            if !q.left.is_empty() {
+                q.swap();
+            }
+            if !q.left.is_empty() {
+                return false;
+            }
+
+            // The right interval isn't empty, so we can unwrap
+            let q_low = q.right.low.unwrap();
+
+            if self.lowpt[q_low] > self.lowpt[e] {
+                if pair.right.is_empty() {
+                    pair.right = q.right;
+                } else if let Some(low) = pair.right.low {
+                    self.reference[low] = q.right.high;
+                }
+                pair.right.low = q.right.low;
+            } else {
+                self.reference[q_low] = self.lowpt_edge[e];
+            }
+
+            if self.stack.len() == self.stack_bottom[ei] {
+                break;
+            }
+        }
+
+        // Merges the conflicting return edges of the siblings into the left interval
+        while let Some(top) = self.stack.last() {
+            if !self.conflicting(&top.left, ei) && !self.conflicting(&top.right, ei) {
+                break;
+            }
+
+            let mut q = self.stack.pop().unwrap();
+
+            if self.conflicting(&q.right, ei) {
+                q.swap();
+            }
+            if self.conflicting(&q.right, ei) {
+                return false;
+            }
+
+            if let Some(low) = pair.right.low {
+                self.reference[low] = q.right.high;
+            }
+            if q.right.low.is_some() {
+                pair.right.low = q.right.low;
+            }
+
+            if pair.left.is_empty() {
+                pair.left = q.left;
+            } else if let Some(low) = pair.left.low {
+                self.reference[low] = q.left.high;
+            }
+            pair.left.low = q.left.low;
+        }
+
+        if !pair.left.is_empty() || !pair.right.is_empty() {
+            self.stack.push(pair);
+        }
+
+        true
+    }
+
+    /// Trims the back edges ending at the source `u` of the tree edge `e`, whose DFS is over.
+    fn remove_back_edges(&mut self, e: usize) {
+        let u = self.source[e];
+        // The height of every oriented vertex is set, so we can unwrap
+        let u_height = self.height[u].unwrap();
+        let is_to_u = |edge: usize, lr: &LeftRight| lr.target[edge] == u;
+
+        while self
+            .stack
+            .last()
+            .is_some_and(|top| self.lowest(top) == u_height)
+        {
+            self.stack.pop();
+        }
+
+        if let Some(mut pair) = self.stack.pop() {
+            while let Some(high) = pair.left.high.filter(|high| is_to_u(*high, self)) {
+                pair.left.high = self.reference[high];
+            }
+            if pair.left.high.is_none() {
+                if let Some(low) = pair.left.low.take() {
+                    self.reference[low] = pair.right.low;
+                }
+            }
+
+            while let Some(high) = pair.right.high.filter(|high| is_to_u(*high, self)) {
+                pair.right.high = self.reference[high];
+            }
+            if pair.right.high.is_none() {
+                if let Some(low) = pair.right.low.take() {
+                    self.reference[low] = pair.left.low;
+                }
+            }
+
+            self.stack.push(pair);
+        }
+
+        // The reference of e is a highest return edge
+        if self.lowpt[e] < u_height {
+            if let Some(top) = self.stack.last() {
+                let (left, right) = (top.left.high, top.right.high);
+
+                self.reference[e] = match (left, right) {
+                    (Some(l), Some(r)) if self.lowpt[l] > self.lowpt[r] => left,
+                    (Some(_), None) => left,
+                    _ => right,
+                };
+            }
+        }
+    }
+}
+
+impl AdjList {
+    /// Checks whether the graph can be drawn on the plane without crossing edges, with the
+    /// Left-Right planarity test of de Fraysseix and Ossona de Mendez, in `O(n + m)` time.
+    pub fn is_planar(&self) -> bool {
+        let n = self.num_vertices();
+
+        // Euler's formula bounds the edges of planar graphs
+        if n > 2 && self.edge_count() > 3 * n - 6 {
+            return false;
+        }
+
+        let mut state = LeftRight::new(n);
+        let mut oriented = HashSet::new();
+        let mut roots = Vec::new();
+
+        for v in 0..n {
+            if state.height[v].is_none() {
+                state.height[v] = Some(0);
+                roots.push(v);
+                state.orient(self, v, &mut oriented);
+            }
+        }
+
+        let num_edges = state.target.len();
+        state.lowpt_edge = vec![None; num_edges];
+        state.reference = vec![None; num_edges];
+        state.stack_bottom = vec![0; num_edges];
+
+        for adjs in state.ordered_adjs.iter_mut() {
+            adjs.sort_by_key(|e| state.nesting_depth[*e]);
+        }
+
+        roots.into_iter().all(|root| state.test(root))
+    }
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+    use crate::{
+        graph::generators::{cycle_graph, path_graph, random_planar_graph},
+        input,
+    };
+
+    #[test]
+    fn test_is_planar_complete() {
+        for n in 0..5 {
+            assert!(AdjList::complete(n).is_planar());
+        }
+
+        assert!(!AdjList::complete(5).is_planar());
+        assert!(AdjList::complete_minus(5, &[(0, 1)]).is_planar());
+    }
+
+    #[test]
+    fn test_is_planar_sparse() {
+        // K_3,3 has 9 edges, below the 3n - 6 bound, but isn't planar
+        let mut graph = AdjList::new(6);
+        for u in 0..3 {
+            for v in 3..6 {
+                graph.add_edge(u, v);
+            }
+        }
+
+        assert!(!graph.is_planar());
+
+        // Removing any edge makes it planar
+        graph.remove_edge(0, 3);
+
+        assert!(graph.is_planar());
+        assert!(path_graph(10).is_planar());
+        assert!(cycle_graph(10).is_planar());
+
+        // A disconnected graph is planar iff each component is
+        let mut graph = AdjList::new(8);
+        for (u, v) in [(0, 1), (1, 2), (2, 0), (3, 4)] {
+            graph.add_edge(u, v);
+        }
+
+        assert!(graph.is_planar());
+    }
+
+    #[test]
+    fn test_is_planar_random() {
+        for seed in 0..20 {
+            let mut graph = random_planar_graph(30, seed);
+
+            assert!(graph.is_planar());
+
+            // Any edge added to a maximal planar graph breaks the planarity
+            if let Some(v) = (1..30).find(|v| !graph.adj_list()[0].contains(v)) {
+                graph.add_edge(0, v);
+
+                assert!(!graph.is_planar());
+            }
+        }
+    }
+
+    #[test]
+    fn test_is_planar_instances() {
+        // The Grötzsch graph (myciel3) and the Petersen graph aren't planar
+        if let Ok(Some(graph)) = input::read_graph_from_file("data/myc/myciel3.col") {
+            assert!(!graph.is_planar());
+        } else {
+            panic!("The file containing the test graph is missing")
+        }
+
+        if let Ok(Some(graph)) = input::read_graph_from_matrix_market("data/mtx/petersen.mtx") {
+            assert!(!graph.is_planar());
+        } else {
+            panic!("The file containing the test graph is missing")
+        }
+    }
+}
@@ -0,0 +1,390 @@
+use super::adj_list::AdjList;
+
+impl AdjList {
+    /// Lists the pairs of non-adjacent vertices `(u, v)`, with `u < v`, such that the neighbors of
+    /// one of them are all neighbors of the other, in lexicographic order.
+    ///
+    /// Merging such a pair (see [`AdjList::contract_edge`]) preserves the chromatic number: the
+    /// merged vertex keeps the larger neighborhood, and the other vertex can take its color. An
+    /// edge can never be merged this way, since its endpoints need different colors.
+    pub fn contractable_edge_pairs(&self) -> Vec<(usize, usize)> {
+        let contains = |a: usize, b: usize| self.adj_list()[b].iter().all(|w| self.has_edge(a, *w));
+        let mut pairs = Vec::new();
+
+        for u in 0..self.num_vertices() {
+            for v in u + 1..self.num_vertices() {
+                if !self.has_edge(u, v) && (contains(u, v) || contains(v, u)) {
+                    pairs.push((u, v));
+                }
+            }
+        }
+
+        pairs
+    }
+}
+
+/// Reduces `graph` by merging the first of its [`AdjList::contractable_edge_pairs`], such as
+/// twins (non-adjacent vertices with the same neighbors), up to `max_contractions` times.
+///
+/// Such pairs can always share a color, so the chromatic number is preserved: a coloring of the
+/// reduced graph extends to the original one by giving each merged vertex the color of the
+/// vertex it was merged into.
+///
+/// Returns the reduced graph and the merged pairs `(u, v)`, in order. Each pair refers to the
+/// indices of the graph at the time of its contraction (see [`AdjList::contract_edge`]).
+pub fn preprocess_with_contraction(
+    graph: &AdjList,
+    max_contractions: usize,
+) -> (AdjList, Vec<(usize, usize)>) {
+    let mut reduced = graph.clone();
+    let mut contracted = Vec::new();
+
+    while contracted.len() < max_contractions {
+        let Some((u, v)) = reduced.contractable_edge_pairs().first().copied() else {
+            break;
+        };
+
+        reduced = reduced.contract_edge(u, v);
+        contracted.push((u, v));
+    }
+
+    (reduced, contracted)
+}
+
+/// Reduces `graph` to its `k`-core (see [`AdjList::k_core`]). The removed vertices can be
+/// colored afterwards, for instance greedily in the reverse order of their removal.
+///
+/// Returns the core and the mapping from its vertices to the vertices of `graph`.
+pub fn preprocess_via_core(graph: &AdjList, k: usize) -> (AdjList, Vec<usize>) {
+    let vertices = graph.k_core_vertices(k);
+
+    (graph.induced_subgraph(&vertices), vertices)
+}
+
+/// Finds a vertex, among the `alive` ones, that is dominated by a non-adjacent one (i.e., whose
+/// neighbors among the alive vertices are all adjacent to the other), along with it.
+fn find_dominated_vertex(graph: &AdjList, alive: &[bool]) -> Option<(usize, usize)> {
+    let n = graph.num_vertices();
+
+    (0..n).filter(|v| alive[*v]).find_map(|v| {
+        (0..n)
+            .filter(|u| alive[*u] && *u != v && !graph.has_edge(*u, v))
+            .find(|u| {
+                graph.adj_list()[v]
+                    .iter()
+                    .filter(|w| alive[**w])
+                    .all(|w| graph.has_edge(*w, *u))
+            })
+            .map(|u| (v, u))
+    })
+}
+
+/// Reduces `graph` by removing, while there is one, a vertex `v` whose neighbors are all adjacent
+/// to a vertex `u` not adjacent to `v`.
+///
+/// `v` can always take the color of `u`, so the chromatic number is preserved: a coloring of the
+/// reduced graph extends to the original one by going through the removals backwards, giving
+/// each `v` the color of its `u`.
+///
+/// Returns the reduced graph, induced by the remaining vertices in increasing order, and the
+/// removed pairs `(v, u)`, in order, as vertices of `graph`.
+pub fn reduce_dominated_vertices(graph: &AdjList) -> (AdjList, Vec<(usize, usize)>) {
+    let mut alive = vec![true; graph.num_vertices()];
+    let mut removed = Vec::new();
+
+    while let Some((v, u)) = find_dominated_vertex(graph, &alive) {
+        alive[v] = false;
+        removed.push((v, u));
+    }
+
+    let remaining: Vec<usize> = (0..graph.num_vertices()).filter(|v| alive[*v]).collect();
+
+    (graph.induced_subgraph(&remaining), removed)
+}
+
+/// Reduces `graph` by removing, while there is one, a vertex of a greedy clique (see
+/// [`AdjList::greedy_clique`]) with fewer than `k` neighbors left.
+///
+/// If `k` is at most the chromatic number, as the size of any clique is, this preserves it: a
+/// coloring of the reduced graph extends to the original one by going through the removals
+/// backwards, giving each vertex the smallest color unused by its neighbors, which is at most
+/// `k`.
+///
+/// Returns the reduced graph, induced by the remaining vertices in increasing order, and the
+/// removed vertices, in order, as vertices of `graph`.
+pub fn reduce_clique_vertices(graph: &AdjList, k: usize) -> (AdjList, Vec<usize>) {
+    let mut alive = vec![true; graph.num_vertices()];
+    let mut removed = Vec::new();
+    let mut clique = graph.greedy_clique();
+
+    while let Some(i) = clique
+        .iter()
+        .position(|v| graph.adj_list()[*v].iter().filter(|w| alive[**w]).count() < k)
+    {
+        let vertex = clique.swap_remove(i);
+        alive[vertex] = false;
+        removed.push(vertex);
+    }
+
+    let remaining: Vec<usize> = (0..graph.num_vertices()).filter(|v| alive[*v]).collect();
+
+    (graph.induced_subgraph(&remaining), removed)
+}
+
+/// A vertex removed by [`preprocess`], as a vertex of the original graph.
+#[derive(Debug, Clone, Copy, PartialEq, Eq)]
+enum Reduction {
+    /// Takes the color of the second vertex (see [`reduce_dominated_vertices`]).
+    Dominated(usize, usize),
+    /// Takes the smallest color unused by its neighbors (see [`reduce_clique_vertices`]).
+    LowDegree(usize),
+}
+
+/// The reductions applied by [`preprocess`], which turn a coloring of the reduced graph into one
+/// of the original graph.
+#[derive(Debug, Clone)]
+pub struct Preprocessor {
+    graph: AdjList,
+    // The vertex of the original graph behind each vertex of the reduced one
+    vertices: Vec<usize>,
+    reductions: Vec<Reduction>,
+}
+
+impl Preprocessor {
+    /// Number of vertices removed by the reductions.
+    pub fn num_removed(&self) -> usize {
+        self.reductions.len()
+    }
+
+    /// Extends the coloring `partial` of the reduced graph (colors from 1 onwards) to the
+    /// original graph, undoing the reductions backwards. If `partial` is valid, so is the
+    /// result, which uses at most `max(χ, colors of partial)` colors.
+    pub fn extend_coloring(&self, partial: Vec<usize>) -> Vec<usize> {
+        let mut coloring = vec![0; self.graph.num_vertices()];
+        for (vertex, color) in self.vertices.iter().zip(partial) {
+            coloring[*vertex] = color;
+        }
+
+        for reduction in self.reductions.iter().rev() {
+            match *reduction {
+                Reduction::Dominated(v, u) => coloring[v] = coloring[u],
+                Reduction::LowDegree(v) => {
+                    // The neighbors removed earlier are still uncolored, with color 0
+                    let used: Vec<usize> = self.graph.adj_list()[v]
+                        .iter()
+                        .map(|u| coloring[*u])
+                        .collect();
+
+                    // There are finitely many neighbors, so we can unwrap
+                    coloring[v] = (1..).find(|color| !used.contains(color)).unwrap();
+                }
+            }
+        }
+
+        coloring
+    }
+}
+
+/// Reduces `graph` with [`reduce_dominated_vertices`] and [`reduce_clique_vertices`], with `k`
+/// the size of a greedy clique of what is left, until neither removes a vertex.
+///
+/// Returns the reduced graph and the [`Preprocessor`] that extends its colorings to `graph`.
+pub fn preprocess(graph: &AdjList) -> (AdjList, Preprocessor) {
+    let mut reduced = graph.clone();
+    let mut preprocessor = Preprocessor {
+        graph: graph.clone(),
+        vertices: (0..graph.num_vertices()).collect(),
+        reductions: Vec::new(),
+    };
+    // Keeps the vertices of the original graph behind the vertices of the reduced one left
+    let remap = |vertices: &Vec<usize>, removed: &[usize]| -> Vec<usize> {
+        vertices
+            .iter()
+            .enumerate()
+            .filter(|(i, _)| !removed.contains(i))
+            .map(|(_, vertex)| *vertex)
+            .collect()
+    };
+
+    loop {
+        let (dominated_reduced, dominated) = reduce_dominated_vertices(&reduced);
+        for (v, u) in dominated.iter() {
+            preprocessor.reductions.push(Reduction::Dominated(
+                preprocessor.vertices[*v],
+                preprocessor.vertices[*u],
+            ));
+        }
+        let removed: Vec<usize> = dominated.iter().map(|(v, _)| *v).collect();
+        preprocessor.vertices = remap(&preprocessor.vertices, &removed);
+
+        let k = dominated_reduced.clique_lower_bound();
+        let (clique_reduced, low_degree) = reduce_clique_vertices(&dominated_reduced, k);
+        for v in low_degree.iter() {
+            preprocessor
+                .reductions
+                .push(Reduction::LowDegree(preprocessor.vertices[*v]));
+        }
+        preprocessor.vertices = remap(&preprocessor.vertices, &low_degree);
+
+        reduced = clique_reduced;
+
+        if dominated.is_empty() && low_degree.is_empty() {
+            break;
+        }
+    }
+
+    (reduced, preprocessor)
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+    use crate::{
+        algorithms::{count_colors, greedy::dsatur},
+        graph::generators::{complete_bipartite_graph, cycle_graph, path_graph},
+        input,
+    };
+
+    #[test]
+    fn test_preprocess_with_contraction() {
+        // In C_4, the opposite vertices are twins
+        let (reduced, contracted) = preprocess_with_contraction(&cycle_graph(4), 10);
+
+        assert_eq!(contracted, vec![(0, 2), (1, 2)]);
+        assert_eq!(reduced.num_vertices(), 2);
+        assert_eq!(reduced.edge_count(), 1);
+
+        let (reduced, contracted) = preprocess_with_contraction(&cycle_graph(4), 1);
+
+        assert_eq!(contracted, vec![(0, 2)]);
+        assert_eq!(reduced.num_vertices(), 3);
+
+        // Adjacent vertices are never merged, even with the same closed neighborhood
+        let (reduced, contracted) = preprocess_with_contraction(&AdjList::complete(4), 10);
+
+        assert!(contracted.is_empty());
+        assert_eq!(reduced.edge_count(), 6);
+    }
+
+    #[test]
+    fn test_contractable_edge_pairs() {
+        // Every pair of K_4 is adjacent, and no neighborhood of C_5 holds another
+        assert!(AdjList::complete(4).contractable_edge_pairs().is_empty());
+        assert!(cycle_graph(5).contractable_edge_pairs().is_empty());
+        assert_eq!(
+            cycle_graph(4).contractable_edge_pairs(),
+            vec![(0, 2), (1, 3)]
+        );
+
+        // The neighbor of the end 0 of a path is a neighbor of 2
+        assert_eq!(
+            path_graph(4).contractable_edge_pairs(),
+            vec![(0, 2), (1, 3)]
+        );
+        assert_eq!(
+            path_graph(5).contractable_edge_pairs(),
+            vec![(0, 2), (2, 4)]
+        );
+
+        // Merging the pairs keeps the chromatic number of K_{2,3}
+        let graph = complete_bipartite_graph(2, 3);
+
+        assert_eq!(graph.contractable_edge_pairs().len(), 1 + 3);
+
+        let (reduced, contracted) = preprocess_with_contraction(&graph, 10);
+
+        assert_eq!(contracted.len(), 3);
+        assert_eq!(reduced.is_complete_bipartite(), Some((1, 1)));
+    }
+
+    #[test]
+    fn test_preprocess_via_core() {
+        // A triangle 0---1---2 with a pendant vertex 3 on 1
+        let graph = AdjList::complete_minus(4, &[(0, 3), (2, 3)]);
+        let (core, mapping) = preprocess_via_core(&graph, 2);
+
+        assert_eq!(mapping, vec![0, 1, 2]);
+        assert_eq!(core.edge_count(), 3);
+
+        if let Ok(Some(graph)) = input::read_graph_from_file("data/myc/myciel4.col") {
+            let (core, mapping) = preprocess_via_core(&graph, 1);
+
+            assert_eq!(core.num_vertices(), graph.num_vertices());
+            assert_eq!(mapping, (0..graph.num_vertices()).collect::<Vec<_>>());
+        } else {
+            panic!("The file containing the test graph is missing")
+        }
+    }
+
+    #[test]
+    fn test_reduce_dominated_vertices() {
+        // In P_4, 0 is dominated by 2, and then 1 by 3
+        let (reduced, removed) = reduce_dominated_vertices(&path_graph(4));
+
+        assert_eq!(removed, vec![(0, 2), (1, 3)]);
+        assert_eq!(reduced.num_vertices(), 2);
+        assert_eq!(reduced.edge_count(), 1);
+
+        // No vertex of an odd cycle longer than 3 is dominated
+        let (reduced, removed) = reduce_dominated_vertices(&cycle_graph(5));
+
+        assert!(removed.is_empty());
+        assert_eq!(reduced, cycle_graph(5));
+    }
+
+    #[test]
+    fn test_reduce_clique_vertices() {
+        // A triangle 0---1---2 with a pendant vertex 3 on 1: 0 and 2 have 2 neighbors each, and 1
+        // has only 2 left once they're gone
+        let graph = AdjList::complete_minus(4, &[(0, 3), (2, 3)]);
+        let (reduced, removed) = reduce_clique_vertices(&graph, 3);
+
+        assert_eq!(removed.len(), 3);
+        assert_eq!(reduced.num_vertices(), 1);
+
+        let (reduced, removed) = reduce_clique_vertices(&graph, 2);
+
+        assert!(removed.is_empty());
+        assert_eq!(reduced, graph);
+    }
+
+    #[test]
+    fn test_preprocess() {
+        // A split graph: the clique {0, 1, 2, 3} and the independent set {4, 5, 6}
+        let mut graph = AdjList::complete(7);
+        for (u, v) in [
+            (4, 5),
+            (4, 6),
+            (5, 6),
+            (0, 4),
+            (1, 4),
+            (0, 5),
+            (2, 6),
+            (3, 6),
+            (1, 6),
+        ] {
+            graph.remove_edge(u, v);
+        }
+        let (reduced, preprocessor) = preprocess(&graph);
+
+        assert_eq!(reduced.num_vertices(), 0);
+        assert_eq!(preprocessor.num_removed(), 7);
+
+        let coloring = preprocessor.extend_coloring(Vec::new());
+
+        assert!(graph.is_valid_coloring(&coloring));
+        assert_eq!(count_colors(&coloring), 4);
+
+        if let Ok(Some(graph)) = input::read_graph_from_file("data/myc/myciel4.col") {
+            let (reduced, preprocessor) = preprocess(&graph);
+            let (num_colors, partial) = dsatur(&reduced);
+            let coloring = preprocessor.extend_coloring(partial);
+
+            assert_eq!(reduced.num_vertices() + preprocessor.num_removed(), 23);
+            assert!(graph.is_valid_coloring(&coloring));
+            assert!(count_colors(&coloring) <= num_colors.max(5));
+        } else {
+            panic!("The file containing the test graph is missing")
+        }
+    }
+}
@@ -0,0 +1,152 @@
+use super::adj_list::AdjList;
+use rand::rngs::StdRng;
+use rand::seq::{index::sample, SliceRandom};
+use rand::SeedableRng;
+use std::error::Error;
+use std::fmt;
+
+/// Errors raised by the random graph generators.
+#[derive(Debug, Clone, PartialEq, Eq)]
+pub enum GeneratorError {
+    /// More edges were requested than the `max` possible ones.
+    TooManyEdges { m: usize, max: usize },
+}
+
+impl fmt::Display for GeneratorError {
+    fn fmt(&self, f: &mut fmt::Formatter<'_>) -> fmt::Result {
+        match self {
+            GeneratorError::TooManyEdges { m, max } => {
+                write!(
+                    f,
+                    "{m} edges were requested, but at most {max} are possible"
+                )
+            }
+        }
+    }
+}
+
+impl Error for GeneratorError {}
+
+/// Pair `(u, v)`, with `u < v`, at position `index` in the lexicographic order of the pairs of
+/// distinct vertices among `n`.
+fn pair_at(mut index: usize, n: usize) -> (usize, usize) {
+    let mut u = 0;
+
+    while index >= n - 1 - u {
+        index -= n - 1 - u;
+        u += 1;
+    }
+
+    (u, u + 1 + index)
+}
+
+/// Builds a graph on `n` vertices with exactly `m` edges, chosen uniformly at random among the
+/// pairs of distinct vertices (the `G(n, m)` model), with a generator seeded with `seed`.
+///
+/// Returns [`GeneratorError::TooManyEdges`] if `m` exceeds `n (n - 1) / 2`.
+pub fn random_graph(n: usize, m: usize, seed: u64) -> Result<AdjList, GeneratorError> {
+    let max = n * n.saturating_sub(1) / 2;
+
+    if m > max {
+        return Err(GeneratorError::TooManyEdges { m, max });
+    }
+
+    let mut rng = StdRng::seed_from_u64(seed);
+    let mut graph = AdjList::new(n);
+
+    for index in sample(&mut rng, max, m) {
+        let (u, v) = pair_at(index, n);
+        graph.add_edge(u, v);
+    }
+
+    Ok(graph)
+}
+
+/// Builds a graph on `n` vertices with a known `k`-coloring, the usual way to generate
+/// benchmark instances with a bounded chromatic number.
+///
+/// The vertices are randomly split into `k` independent sets of sizes differing by at most one,
+/// and `extra_edges` random edges are added between different sets (or all of them, if there are
+/// fewer pairs). Uses a generator seeded with `seed`.
+pub fn random_k_colorable(n: usize, k: usize, extra_edges: usize, seed: u64) -> AdjList {
+    let mut rng = StdRng::seed_from_u64(seed);
+    let mut vertices: Vec<usize> = (0..n).collect();
+    vertices.shuffle(&mut rng);
+
+    let mut class = vec![0; n];
+    for (i, vertex) in vertices.iter().enumerate() {
+        class[*vertex] = i % k.max(1);
+    }
+
+    let pairs: Vec<(usize, usize)> = (0..n)
+        .flat_map(|u| (u + 1..n).map(move |v| (u, v)))
+        .filter(|(u, v)| class[*u] != class[*v])
+        .collect();
+
+    let mut graph = AdjList::new(n);
+
+    for index in sample(&mut rng, pairs.len(), extra_edges.min(pairs.len())) {
+        let (u, v) = pairs[index];
+        graph.add_edge(u, v);
+    }
+
+    graph
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+    use crate::algorithms::grasp::grasp_wrapper;
+
+    #[test]
+    fn test_pair_at() {
+        let pairs: Vec<(usize, usize)> = (0..6).map(|index| pair_at(index, 4)).collect();
+
+        assert_eq!(pairs, vec![(0, 1), (0, 2), (0, 3), (1, 2), (1, 3), (2, 3)]);
+    }
+
+    #[test]
+    fn test_random_graph() {
+        let graph = random_graph(20, 50, 42).unwrap();
+
+        assert_eq!(graph.num_vertices(), 20);
+        assert_eq!(graph.num_edges(), 50);
+        // There are no repeated edges
+        assert!(graph.adj_list().iter().all(|neighbors| {
+            let mut neighbors = neighbors.clone();
+            neighbors.sort();
+            neighbors.windows(2).all(|pair| pair[0] != pair[1])
+        }));
+
+        // The same seed gives the same graph
+        assert_eq!(
+            random_graph(20, 50, 42).unwrap().adj_list(),
+            graph.adj_list()
+        );
+
+        assert_eq!(random_graph(5, 10, 0).unwrap().num_edges(), 10);
+        assert_eq!(
+            random_graph(5, 11, 0).unwrap_err(),
+            GeneratorError::TooManyEdges { m: 11, max: 10 }
+        );
+    }
+
+    #[test]
+    fn test_random_k_colorable() {
+        assert_eq!(random_k_colorable(30, 4, 0, 42).num_edges(), 0);
+
+        let graph = random_k_colorable(30, 4, 200, 42);
+
+        assert_eq!(graph.num_edges(), 200);
+
+        let (num_colors, coloring) = grasp_wrapper(&graph, 10, 10, 3).unwrap();
+
+        assert!(graph.is_valid_coloring(&coloring));
+        assert!(num_colors <= 4);
+
+        // Asking for too many edges gives the complete 4-partite graph
+        let graph = random_k_colorable(8, 4, 100, 42);
+
+        assert_eq!(graph.num_edges(), 8 * 7 / 2 - 4);
+    }
+}
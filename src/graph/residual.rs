@@ -0,0 +1,140 @@
+use super::adj_list::AdjList;
+
+/// The subgraph induced by a shrinking set of vertices of a graph, keeping the degrees of its
+/// vertices and its number of edges up to date as vertices are removed, so that neither has to be
+/// counted from scratch.
+#[derive(Debug, Clone)]
+pub struct ResidualGraph<'a> {
+    graph: &'a AdjList,
+    in_subgraph: Vec<bool>,
+    // Number of neighbors of each vertex in the subgraph
+    degrees: Vec<usize>,
+    num_edges: usize,
+}
+
+impl<'a> ResidualGraph<'a> {
+    /// Starts with the subgraph of `graph` induced by `vertex_set`, in `O(n + m)` time.
+    pub fn new(graph: &'a AdjList, vertex_set: &[usize]) -> Self {
+        let mut in_subgraph = vec![false; graph.num_vertices()];
+        for vertex in vertex_set.iter() {
+            in_subgraph[*vertex] = true;
+        }
+
+        let degrees: Vec<usize> = graph
+            .adj_list()
+            .iter()
+            .map(|neighbors| neighbors.iter().filter(|u| in_subgraph[**u]).count())
+            .collect();
+        let num_edges = (0..graph.num_vertices())
+            .filter(|vertex| in_subgraph[*vertex])
+            .map(|vertex| degrees[vertex])
+            .sum::<usize>()
+            / 2;
+
+        ResidualGraph {
+            graph,
+            in_subgraph,
+            degrees,
+            num_edges,
+        }
+    }
+
+    /// The whole graph.
+    pub fn graph(&self) -> &'a AdjList {
+        self.graph
+    }
+
+    pub fn contains(&self, vertex: usize) -> bool {
+        self.in_subgraph[vertex]
+    }
+
+    /// Degree of `vertex` towards the vertices of the subgraph.
+    pub fn degree(&self, vertex: usize) -> usize {
+        self.degrees[vertex]
+    }
+
+    pub fn num_edges(&self) -> usize {
+        self.num_edges
+    }
+
+    /// Number of edges left if `vertices` were removed, without removing them, in `O(n)` time
+    /// plus the sum of their degrees. Vertices outside the subgraph are ignored.
+    pub fn num_edges_without(&self, vertices: &[usize]) -> usize {
+        let mut removed = vec![false; self.graph.num_vertices()];
+        let mut num_removed_edges = 0;
+
+        for vertex in vertices.iter() {
+            if !self.in_subgraph[*vertex] || removed[*vertex] {
+                continue;
+            }
+            removed[*vertex] = true;
+
+            // The edges towards the vertices already removed were counted with them
+            num_removed_edges += self.degrees[*vertex]
+                - self.graph.adj_list()[*vertex]
+                    .iter()
+                    .filter(|u| removed[**u])
+                    .count();
+        }
+
+        self.num_edges - num_removed_edges
+    }
+
+    /// Removes `vertices` from the subgraph, in `O(Σ deg(v))` time over them. Vertices outside
+    /// the subgraph are ignored.
+    pub fn remove_vertices(&mut self, vertices: &[usize]) {
+        for vertex in vertices.iter() {
+            if !self.in_subgraph[*vertex] {
+                continue;
+            }
+
+            self.in_subgraph[*vertex] = false;
+            self.num_edges -= self.degrees[*vertex];
+
+            for u in self.graph.adj_list()[*vertex].iter() {
+                self.degrees[*u] -= 1;
+            }
+        }
+    }
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+    use crate::input;
+
+    #[test]
+    fn test_residual_graph() {
+        if let Ok(Some(graph)) = input::read_graph_from_file("data/myc/myciel3.col") {
+            let all: Vec<usize> = (0..graph.num_vertices()).collect();
+            let mut residual = ResidualGraph::new(&graph, &all);
+
+            assert_eq!(residual.num_edges(), graph.num_edges());
+            assert_eq!(residual.degree(10), graph.get_degree(10));
+            assert_eq!(residual.num_edges_without(&[]), graph.num_edges());
+
+            let mut remaining = all.clone();
+            for removed in [vec![0, 6, 8], vec![1, 2, 1, 0], vec![10]] {
+                remaining.retain(|vertex| !removed.contains(vertex));
+                let expected = graph.induced_subgraph(&remaining);
+
+                assert_eq!(residual.num_edges_without(&removed), expected.num_edges());
+
+                residual.remove_vertices(&removed);
+
+                assert_eq!(residual.num_edges(), expected.num_edges());
+                assert!(removed.iter().all(|vertex| !residual.contains(*vertex)));
+                for (i, vertex) in remaining.iter().enumerate() {
+                    assert!(residual.contains(*vertex));
+                    assert_eq!(residual.degree(*vertex), expected.get_degree(i));
+                }
+            }
+
+            let residual = ResidualGraph::new(&graph, &[0, 6, 8, 10]);
+
+            assert_eq!(residual.num_edges(), 4);
+        } else {
+            panic!("The file containing the test graph is missing")
+        }
+    }
+}
@@ -0,0 +1,157 @@
+use super::adj_list::AdjList;
+use std::collections::HashSet;
+
+/// Read-only adjacency queries shared by the graph representations.
+pub trait SparseAdjacency {
+    /// Checks whether `u` and `v` are adjacent.
+    fn has_edge(&self, u: usize, v: usize) -> bool;
+
+    fn neighbors(&self, v: usize) -> &[usize];
+
+    fn degree(&self, v: usize) -> usize {
+        self.neighbors(v).len()
+    }
+}
+
+impl SparseAdjacency for AdjList {
+    fn has_edge(&self, u: usize, v: usize) -> bool {
+        AdjList::has_edge(self, u, v)
+    }
+
+    fn neighbors(&self, v: usize) -> &[usize] {
+        &self.adj_list()[v]
+    }
+}
+
+/// A graph for sparse instances, kept as its adjacency lists along with the set of its edges,
+/// so that adjacency is checked in constant time instead of scanning a list.
+///
+/// Edge weights aren't kept.
+#[derive(Debug, Clone, PartialEq, Eq)]
+pub struct SparseGraph {
+    adj_list: Vec<Vec<usize>>,
+    // Edges (u, v) with u < v
+    edges: HashSet<(usize, usize)>,
+}
+
+impl SparseGraph {
+    pub fn num_vertices(&self) -> usize {
+        self.adj_list.len()
+    }
+
+    pub fn num_edges(&self) -> usize {
+        self.edges.len()
+    }
+
+    /// Converts back to an [`AdjList`] with the same edges.
+    pub fn into_dense(self) -> AdjList {
+        let mut graph = AdjList::new(self.num_vertices());
+
+        for (u, neighbors) in self.adj_list.iter().enumerate() {
+            for v in neighbors.iter().filter(|v| u < **v) {
+                graph.add_edge(u, *v);
+            }
+        }
+
+        graph
+    }
+}
+
+impl SparseAdjacency for SparseGraph {
+    fn has_edge(&self, u: usize, v: usize) -> bool {
+        self.edges.contains(&(u.min(v), u.max(v)))
+    }
+
+    fn neighbors(&self, v: usize) -> &[usize] {
+        &self.adj_list[v]
+    }
+}
+
+impl AdjList {
+    /// Checks whether the density of the graph (see [`AdjList::density`]) is below `threshold`.
+    pub fn is_sparse(&self, threshold: f64) -> bool {
+        self.density() < threshold
+    }
+
+    /// Converts the graph to a [`SparseGraph`], dropping the edge weights.
+    pub fn into_sparse(self) -> SparseGraph {
+        SparseGraph {
+            edges: self.edge_list_as_slice().iter().copied().collect(),
+            adj_list: self.adj_list().to_vec(),
+        }
+    }
+}
+
+/// Counts the edges between the vertices of `list`, checking each pair for adjacency, which
+/// takes `O(|list|^2)` time with the constant time lookups of a [`SparseGraph`].
+pub fn sparse_count_remaining_edges(list: &[usize], adj: &dyn SparseAdjacency) -> usize {
+    list.iter()
+        .enumerate()
+        .map(|(i, u)| {
+            list[i + 1..]
+                .iter()
+                .filter(|v| adj.has_edge(*u, **v))
+                .count()
+        })
+        .sum()
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+    use crate::input;
+
+    fn sorted_adj_list(graph: &AdjList) -> Vec<Vec<usize>> {
+        let mut adj_list = graph.adj_list().to_vec();
+        adj_list.iter_mut().for_each(|neighbors| neighbors.sort());
+        adj_list
+    }
+
+    #[test]
+    fn test_sparse_round_trip() {
+        if let Ok(Some(graph)) = input::read_graph_from_file("data/myc/myciel3.col") {
+            // 20 of the 55 possible edges
+            assert!(graph.is_sparse(0.5));
+            assert!(!graph.is_sparse(0.3));
+
+            let sparse = graph.clone().into_sparse();
+
+            assert_eq!(sparse.num_vertices(), graph.num_vertices());
+            assert_eq!(sparse.num_edges(), graph.num_edges());
+
+            for u in 0..graph.num_vertices() {
+                assert_eq!(sparse.degree(u), graph.get_degree(u));
+
+                for v in 0..graph.num_vertices() {
+                    assert_eq!(
+                        SparseAdjacency::has_edge(&sparse, u, v),
+                        graph.has_edge(u, v)
+                    );
+                }
+            }
+
+            let dense = sparse.into_dense();
+
+            assert_eq!(dense.num_edges(), graph.num_edges());
+            assert_eq!(sorted_adj_list(&dense), sorted_adj_list(&graph));
+        } else {
+            panic!("The file containing the test graph is missing")
+        }
+    }
+
+    #[test]
+    fn test_sparse_count_remaining_edges() {
+        if let Ok(Some(graph)) = input::read_graph_from_file("data/myc/myciel3.col") {
+            let sparse = graph.clone().into_sparse();
+
+            for list in [vec![0, 1, 2], vec![0, 6, 8, 10], vec![], (0..11).collect()] {
+                let expected = graph.induced_subgraph(&list).num_edges();
+
+                assert_eq!(sparse_count_remaining_edges(&list, &sparse), expected);
+                assert_eq!(sparse_count_remaining_edges(&list, &graph), expected);
+            }
+        } else {
+            panic!("The file containing the test graph is missing")
+        }
+    }
+}
@@ -0,0 +1,219 @@
+use super::adj_list::AdjList;
+
+/// Maximum number of sweeps of [`symmetric_eigenvalues`].
+const MAX_JACOBI_SWEEPS: usize = 100;
+
+/// Off-diagonal Frobenius norm, relative to the norm of the whole matrix, under which
+/// [`symmetric_eigenvalues`] stops.
+const JACOBI_TOLERANCE: f64 = 1e-14;
+
+/// Frobenius norm of the off-diagonal part of the `n x n` matrix stored row by row in `matrix`.
+fn off_diagonal_norm(matrix: &[f64], n: usize) -> f64 {
+    matrix
+        .chunks(n)
+        .enumerate()
+        .map(|(u, row)| {
+            row.iter()
+                .enumerate()
+                .filter(|&(v, _)| v != u)
+                .map(|(_, a)| a * a)
+                .sum::<f64>()
+        })
+        .sum::<f64>()
+        .sqrt()
+}
+
+/// Eigenvalues of the symmetric `n x n` matrix stored row by row in `matrix`, in decreasing order,
+/// along with an error bound that every one of them is within, found by the cyclic Jacobi method.
+///
+/// The error bound adds the off-diagonal norm left when the rotations stop, which limits how far
+/// the diagonal is from the spectrum, to a generous bound on the rounding of the rotations, each
+/// of which is backward stable.
+fn symmetric_eigenvalues(mut matrix: Vec<f64>, n: usize) -> (Vec<f64>, f64) {
+    let norm = matrix.iter().map(|a| a * a).sum::<f64>().sqrt();
+    let mut sweeps = 0;
+
+    while sweeps < MAX_JACOBI_SWEEPS && off_diagonal_norm(&matrix, n) > JACOBI_TOLERANCE * norm {
+        sweeps += 1;
+
+        for p in 0..n {
+            for q in p + 1..n {
+                let apq = matrix[p * n + q];
+
+                if apq == 0.0 {
+                    continue;
+                }
+
+                // Rotation in the plane of `p` and `q` that zeroes the entry `(p, q)`
+                let theta = (matrix[q * n + q] - matrix[p * n + p]) / (2.0 * apq);
+                let t = theta.signum() / (theta.abs() + (theta * theta + 1.0).sqrt());
+                let c = 1.0 / (t * t + 1.0).sqrt();
+                let s = t * c;
+
+                for k in 0..n {
+                    let (akp, akq) = (matrix[k * n + p], matrix[k * n + q]);
+                    matrix[k * n + p] = c * akp - s * akq;
+                    matrix[k * n + q] = s * akp + c * akq;
+                }
+
+                for k in 0..n {
+                    let (apk, aqk) = (matrix[p * n + k], matrix[q * n + k]);
+                    matrix[p * n + k] = c * apk - s * aqk;
+                    matrix[q * n + k] = s * apk + c * aqk;
+                }
+            }
+        }
+    }
+
+    let rounding = 16.0 * ((sweeps + 1) * n * n) as f64 * f64::EPSILON * norm;
+    let error = off_diagonal_norm(&matrix, n) + rounding;
+
+    let mut eigenvalues: Vec<f64> = (0..n).map(|u| matrix[u * n + u]).collect();
+    eigenvalues.sort_by(|a, b| b.total_cmp(a));
+
+    (eigenvalues, error)
+}
+
+impl AdjList {
+    /// Adjacency matrix of the graph, stored row by row.
+    fn adjacency_matrix(&self) -> Vec<f64> {
+        let n = self.num_vertices();
+        let mut matrix = vec![0.0; n * n];
+
+        for u in 0..n {
+            for v in self.adj_list()[u].iter() {
+                matrix[u * n + v] = 1.0;
+            }
+        }
+
+        matrix
+    }
+
+    /// Eigenvalues of the adjacency matrix in decreasing order, along with an error bound that
+    /// every one of them is within (see [`symmetric_eigenvalues`]).
+    fn adjacency_eigenvalues(&self) -> (Vec<f64>, f64) {
+        symmetric_eigenvalues(self.adjacency_matrix(), self.num_vertices())
+    }
+
+    /// Largest and smallest eigenvalues of the adjacency matrix, in that order, or `(0, 0)` for
+    /// the empty graph.
+    pub fn extreme_eigenvalues(&self) -> (f64, f64) {
+        let (eigenvalues, _) = self.adjacency_eigenvalues();
+
+        match (eigenvalues.first(), eigenvalues.last()) {
+            (Some(&largest), Some(&smallest)) => (largest, smallest),
+            _ => (0.0, 0.0),
+        }
+    }
+
+    /// Hoffman's lower bound for the chromatic number, `1 + λ_max / |λ_min|`, where `λ_max` and
+    /// `λ_min` are the largest and smallest eigenvalues of the adjacency matrix (see
+    /// [`AdjList::extreme_eigenvalues`]). Equals 1 for graphs without edges and 0 for the empty
+    /// graph.
+    ///
+    /// The eigenvalues are only known up to the error bound of the eigensolver, so `λ_max` is
+    /// lowered and `|λ_min|` raised by it, which keeps the result a proven lower bound at the cost
+    /// of a tiny gap, and bipartite graphs never come out above 2.
+    pub fn spectral_lower_bound(&self) -> f64 {
+        if self.num_vertices() == 0 {
+            return 0.0;
+        }
+
+        if self.max_degree() == 0 {
+            return 1.0;
+        }
+
+        let (eigenvalues, error) = self.adjacency_eigenvalues();
+        let max_eigenvalue = eigenvalues[0] - error;
+        let min_eigenvalue = eigenvalues[eigenvalues.len() - 1].abs() + error;
+
+        // Guards against the rounding of the division as well
+        (1.0 + max_eigenvalue.max(0.0) / min_eigenvalue) * (1.0 - 4.0 * f64::EPSILON)
+    }
+
+    /// Difference `λ_1 - λ_2` between the two largest eigenvalues of the adjacency matrix, which
+    /// is 0 for disconnected graphs and for graphs with less than two vertices.
+    pub fn spectral_gap(&self) -> f64 {
+        if self.num_vertices() < 2 {
+            return 0.0;
+        }
+
+        let (eigenvalues, _) = self.adjacency_eigenvalues();
+
+        eigenvalues[0] - eigenvalues[1]
+    }
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+    use crate::algorithms::chromatic_polynomial::chromatic_number_exact_via_polynomial;
+    use crate::graph::generators::{cycle_graph, path_graph};
+    use crate::graph::random::random_graph;
+
+    #[test]
+    fn test_spectral_lower_bound() {
+        for n in 2..7 {
+            let (max_eigenvalue, min_eigenvalue) = AdjList::complete(n).extreme_eigenvalues();
+
+            assert!((max_eigenvalue - (n - 1) as f64).abs() < 1e-6);
+            assert!((min_eigenvalue + 1.0).abs() < 1e-6);
+            assert!((AdjList::complete(n).spectral_lower_bound() - n as f64).abs() < 1e-6);
+        }
+
+        assert!((cycle_graph(6).spectral_lower_bound() - 2.0).abs() < 1e-6);
+        assert_eq!(AdjList::new(3).spectral_lower_bound(), 1.0);
+        assert_eq!(AdjList::new(0).spectral_lower_bound(), 0.0);
+
+        // Bipartite graphs have symmetric spectra, so the bound can't go above 2
+        assert!(path_graph(50).spectral_lower_bound() <= 2.0);
+        assert!(cycle_graph(60).spectral_lower_bound() <= 2.0);
+    }
+
+    #[test]
+    fn test_spectral_lower_bound_below_chromatic_number() {
+        // Graphs whose smallest eigenvector a fixed start vector used to miss
+        for (n, m, seed) in [(5, 6, 26), (5, 6, 202), (6, 13, 299)] {
+            let graph = random_graph(n, m, seed).unwrap();
+            let chromatic_number = chromatic_number_exact_via_polynomial(&graph).unwrap();
+
+            assert!(graph.spectral_lower_bound() <= chromatic_number as f64);
+        }
+
+        for seed in 0..200 {
+            let n = 2 + (seed % 7) as usize;
+            let m = (seed as usize * 7) % (n * (n - 1) / 2 + 1);
+            let graph = random_graph(n, m, seed).unwrap();
+            let chromatic_number = chromatic_number_exact_via_polynomial(&graph).unwrap();
+
+            assert!(graph.spectral_lower_bound() <= chromatic_number as f64);
+        }
+    }
+
+    #[test]
+    fn test_spectral_gap() {
+        // The eigenvalues of K_n are n - 1 and -1, those of C_6 are 2, 1, 1, -1, -1 and -2 and
+        // those of the Petersen graph are 3, 1 and -2
+        assert!((AdjList::complete(5).spectral_gap() - 5.0).abs() < 1e-6);
+        assert!((cycle_graph(6).spectral_gap() - 1.0).abs() < 1e-6);
+
+        let mut petersen = AdjList::new(10);
+        for i in 0..5 {
+            petersen.add_edge(i, (i + 1) % 5);
+            petersen.add_edge(i, 5 + i);
+            petersen.add_edge(5 + i, 5 + (i + 2) % 5);
+        }
+
+        assert!((petersen.spectral_gap() - 2.0).abs() < 1e-6);
+
+        // Two disjoint triangles share their largest eigenvalue
+        let mut two_triangles = AdjList::new(6);
+        for i in 0..3 {
+            two_triangles.add_edge(i, (i + 1) % 3);
+            two_triangles.add_edge(3 + i, 3 + (i + 1) % 3);
+        }
+
+        assert!(two_triangles.spectral_gap() < 1e-6);
+        assert_eq!(AdjList::new(1).spectral_gap(), 0.0);
+    }
+}
@@ -0,0 +1,266 @@
+use super::adj_list::AdjList;
+use std::collections::VecDeque;
+use std::fmt;
+
+/// Structural properties of a graph, computed together by [`AdjList::statistics`].
+#[derive(Debug, Clone, PartialEq)]
+pub struct GraphStatistics {
+    pub num_vertices: usize,
+    pub num_edges: usize,
+    /// Ratio between the edges and the edges of a complete graph on the same vertices, 0 when
+    /// there are less than two vertices.
+    pub density: f64,
+    pub max_degree: usize,
+    pub min_degree: usize,
+    pub average_degree: f64,
+    pub num_components: usize,
+    pub is_bipartite: bool,
+    /// Size of the clique found by [`AdjList::greedy_clique`].
+    pub clique_lower_bound: usize,
+    /// Largest coreness of [`AdjList::k_core_decomposition`].
+    pub degeneracy: usize,
+}
+
+/// Header matching the rows of [`GraphStatistics::to_csv_row`].
+pub const CSV_HEADER: &str = "graph,num_vertices,num_edges,density,max_degree,min_degree,\
+average_degree,num_components,is_bipartite,clique_lower_bound,degeneracy";
+
+/// Number of connected components of `graph`, found by breadth-first search.
+fn count_components(graph: &AdjList) -> usize {
+    let mut visited = vec![false; graph.num_vertices()];
+    let mut num_components = 0;
+
+    for start in 0..graph.num_vertices() {
+        if visited[start] {
+            continue;
+        }
+
+        num_components += 1;
+        visited[start] = true;
+        let mut queue = VecDeque::from([start]);
+
+        while let Some(vertex) = queue.pop_front() {
+            for neighbor in graph.adj_list()[vertex].iter() {
+                if !visited[*neighbor] {
+                    visited[*neighbor] = true;
+                    queue.push_back(*neighbor);
+                }
+            }
+        }
+    }
+
+    num_components
+}
+
+impl AdjList {
+    /// Ratio between the edges and the edges of a complete graph on the same vertices, 0 when
+    /// there are less than two vertices.
+    pub fn density(&self) -> f64 {
+        let n = self.num_vertices();
+
+        if n < 2 {
+            0.0
+        } else {
+            self.edge_count() as f64 / (n * (n - 1) / 2) as f64
+        }
+    }
+
+    /// Fraction of the pairs of neighbors of `vertex` that are adjacent, 0 when it has less than
+    /// two neighbors.
+    pub fn clustering_coefficient(&self, vertex: usize) -> f64 {
+        let neighbors = &self.adj_list()[vertex];
+        let degree = neighbors.len();
+
+        if degree < 2 {
+            return 0.0;
+        }
+
+        let num_links: usize = neighbors
+            .iter()
+            .enumerate()
+            .map(|(i, u)| {
+                neighbors[i + 1..]
+                    .iter()
+                    .filter(|v| self.has_edge(*u, **v))
+                    .count()
+            })
+            .sum();
+
+        num_links as f64 / (degree * (degree - 1) / 2) as f64
+    }
+
+    /// Average of the [`AdjList::clustering_coefficient`] of the vertices, 0 for the empty graph.
+    ///
+    /// Dense neighborhoods hold large cliques, so highly clustered graphs tend to need many
+    /// colors. In a uniform random graph, the average is close to the density.
+    pub fn average_clustering_coefficient(&self) -> f64 {
+        let n = self.num_vertices();
+
+        if n == 0 {
+            return 0.0;
+        }
+
+        (0..n)
+            .map(|vertex| self.clustering_coefficient(vertex))
+            .sum::<f64>()
+            / n as f64
+    }
+
+    /// Computes the [`GraphStatistics`] of the graph.
+    pub fn statistics(&self) -> GraphStatistics {
+        let n = self.num_vertices();
+        let num_edges = self.edge_count();
+        let average_degree = if n == 0 {
+            0.0
+        } else {
+            2.0 * num_edges as f64 / n as f64
+        };
+
+        GraphStatistics {
+            num_vertices: n,
+            num_edges,
+            density: self.density(),
+            max_degree: self.max_degree(),
+            min_degree: self.degree_sequence().last().copied().unwrap_or(0),
+            average_degree,
+            num_components: count_components(self),
+            is_bipartite: self.is_bipartite(),
+            clique_lower_bound: self.clique_lower_bound(),
+            degeneracy: self.k_core_decomposition().into_iter().max().unwrap_or(0),
+        }
+    }
+}
+
+impl GraphStatistics {
+    /// Formats the statistics as a CSV row starting with `graph_name`, in the order of
+    /// [`CSV_HEADER`].
+    pub fn to_csv_row(&self, graph_name: &str) -> String {
+        format!(
+            "{graph_name},{},{},{:.6},{},{},{:.6},{},{},{},{}",
+            self.num_vertices,
+            self.num_edges,
+            self.density,
+            self.max_degree,
+            self.min_degree,
+            self.average_degree,
+            self.num_components,
+            self.is_bipartite,
+            self.clique_lower_bound,
+            self.degeneracy
+        )
+    }
+}
+
+impl fmt::Display for GraphStatistics {
+    fn fmt(&self, f: &mut fmt::Formatter<'_>) -> fmt::Result {
+        writeln!(f, "Vertices: {}", self.num_vertices)?;
+        writeln!(f, "Edges: {}", self.num_edges)?;
+        writeln!(f, "Density: {:.4}", self.density)?;
+        writeln!(
+            f,
+            "Degree: min {}, max {}, average {:.2}",
+            self.min_degree, self.max_degree, self.average_degree
+        )?;
+        writeln!(f, "Connected components: {}", self.num_components)?;
+        writeln!(
+            f,
+            "Bipartite: {}",
+            if self.is_bipartite { "yes" } else { "no" }
+        )?;
+        writeln!(f, "Clique lower bound: {}", self.clique_lower_bound)?;
+        writeln!(f, "Degeneracy: {}", self.degeneracy)
+    }
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+    use crate::{
+        graph::{
+            generators::{cycle_graph, path_graph},
+            random::random_graph,
+        },
+        input,
+    };
+
+    #[test]
+    fn test_statistics() {
+        if let Ok(Some(graph)) = input::read_graph_from_file("data/myc/myciel3.col") {
+            let statistics = graph.statistics();
+
+            assert_eq!(statistics.num_vertices, 11);
+            assert_eq!(statistics.num_edges, 20);
+            assert!(!statistics.is_bipartite);
+            assert_eq!(statistics.num_components, 1);
+            assert_eq!(statistics.clique_lower_bound, 2);
+            assert_eq!(statistics.min_degree, 3);
+            assert_eq!(statistics.max_degree, 5);
+            assert!((statistics.average_degree - 40.0 / 11.0).abs() < 1e-9);
+            assert!((statistics.density - 20.0 / 55.0).abs() < 1e-9);
+
+            let summary = statistics.to_string();
+
+            assert!(summary.contains("Vertices: 11\n"));
+            assert!(summary.contains("Bipartite: no\n"));
+        } else {
+            panic!("The file containing the test graph is missing")
+        }
+
+        assert_eq!(cycle_graph(5).statistics().num_components, 1);
+
+        // Two disjoint cycles, each 2-degenerate
+        let mut two_cycles = AdjList::new(8);
+        for i in 0..4 {
+            two_cycles.add_edge(i, (i + 1) % 4);
+            two_cycles.add_edge(4 + i, 4 + (i + 1) % 4);
+        }
+
+        let statistics = two_cycles.statistics();
+
+        assert_eq!(statistics.num_components, 2);
+        assert!(statistics.is_bipartite);
+        assert_eq!(statistics.degeneracy, 2);
+
+        let empty = AdjList::new(0).statistics();
+
+        assert_eq!(empty.density, 0.0);
+        assert_eq!(empty.num_components, 0);
+    }
+
+    #[test]
+    fn test_clustering_coefficient() {
+        let graph = AdjList::complete(5);
+
+        assert!((0..5).all(|vertex| graph.clustering_coefficient(vertex) == 1.0));
+        assert_eq!(graph.average_clustering_coefficient(), 1.0);
+
+        // Trees have no triangles
+        let graph = path_graph(6);
+
+        assert!((0..6).all(|vertex| graph.clustering_coefficient(vertex) == 0.0));
+        assert_eq!(graph.average_clustering_coefficient(), 0.0);
+
+        // A triangle 0---1---2 with a pendant vertex 3 on 0
+        let graph = AdjList::complete_minus(4, &[(1, 3), (2, 3)]);
+
+        assert!((graph.clustering_coefficient(0) - 1.0 / 3.0).abs() < 1e-9);
+        assert_eq!(graph.clustering_coefficient(1), 1.0);
+        assert_eq!(graph.clustering_coefficient(3), 0.0);
+        assert!((graph.average_clustering_coefficient() - 7.0 / 12.0).abs() < 1e-9);
+
+        // Each pair of neighbors is an edge with probability close to the density
+        let graph = random_graph(200, 1990, 0).unwrap();
+
+        assert!((graph.average_clustering_coefficient() - graph.density()).abs() < 0.02);
+        assert_eq!(AdjList::new(0).average_clustering_coefficient(), 0.0);
+    }
+
+    #[test]
+    fn test_to_csv_row() {
+        let statistics = AdjList::complete(3).statistics();
+        let row = statistics.to_csv_row("K_3");
+
+        assert_eq!(row, "K_3,3,3,1.000000,2,2,2.000000,1,false,3,2");
+        assert_eq!(row.split(',').count(), CSV_HEADER.split(',').count());
+    }
+}
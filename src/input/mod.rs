@@ -1,40 +1,599 @@
 use crate::graph::adj_list::AdjList;
 use std::error::Error;
-use std::fs::File;
-use std::io::{BufRead, BufReader};
+use std::fmt;
+use std::fs::{self, File};
+use std::io::{self, BufRead, BufReader, BufWriter, Write};
 
-pub fn read_graph_from_file(filename: &str) -> Result<Option<AdjList>, Box<dyn Error>> {
+/// Errors raised while reading a graph or coloring file.
+#[derive(Debug)]
+pub enum InputError {
+    IoError(io::Error),
+    /// The 1-indexed `line` couldn't be parsed.
+    ParseError {
+        line: usize,
+        message: String,
+    },
+    /// The edge `(u, v)`, as written in the file, references a vertex outside of `1..=n`.
+    InvalidEdge {
+        u: usize,
+        v: usize,
+        n: usize,
+    },
+    /// The coloring read doesn't color each vertex exactly once. Holds the reason.
+    InvalidColoring(String),
+}
+
+impl fmt::Display for InputError {
+    fn fmt(&self, f: &mut fmt::Formatter<'_>) -> fmt::Result {
+        match self {
+            InputError::IoError(err) => write!(f, "failed to read the file: {err}"),
+            InputError::ParseError { line, message } => write!(f, "line {line}: {message}"),
+            InputError::InvalidEdge { u, v, n } => write!(
+                f,
+                "the edge ({u}, {v}) is out of bounds for a graph with {n} vertices"
+            ),
+            InputError::InvalidColoring(reason) => write!(f, "invalid coloring: {reason}"),
+        }
+    }
+}
+
+impl Error for InputError {
+    fn source(&self) -> Option<&(dyn Error + 'static)> {
+        match self {
+            InputError::IoError(err) => Some(err),
+            _ => None,
+        }
+    }
+}
+
+impl From<io::Error> for InputError {
+    fn from(err: io::Error) -> Self {
+        InputError::IoError(err)
+    }
+}
+
+/// Parses `token`, found at the 1-indexed `line`, as a number.
+fn parse_number(token: &str, line: usize) -> Result<usize, InputError> {
+    token.parse().map_err(|_| InputError::ParseError {
+        line,
+        message: format!("expected a number, found `{token}`"),
+    })
+}
+
+/// Same as [`parse_number`], for a token given as bytes, which are only decoded for the error.
+fn parse_number_bytes(token: &[u8], line: usize) -> Result<usize, InputError> {
+    let invalid = || InputError::ParseError {
+        line,
+        message: format!(
+            "expected a number, found `{}`",
+            String::from_utf8_lossy(token)
+        ),
+    };
+
+    token.iter().try_fold(0usize, |number, byte| {
+        if !byte.is_ascii_digit() {
+            return Err(invalid());
+        }
+
+        number
+            .checked_mul(10)
+            .and_then(|number| number.checked_add((byte - b'0') as usize))
+            .ok_or_else(invalid)
+    })
+}
+
+/// Converts the edge `(u, v)` given with 1-indexed vertices to 0-indexed ones, checking that
+/// both are in `graph`.
+fn to_vertices(graph: &AdjList, u: usize, v: usize) -> Result<(usize, usize), InputError> {
+    let n = graph.num_vertices();
+
+    if u == 0 || v == 0 || u > n || v > n {
+        return Err(InputError::InvalidEdge { u, v, n });
+    }
+
+    Ok((u - 1, v - 1))
+}
+
+/// Reads a graph in the DIMACS format, made of a `p edge <vertices> <edges>` line followed by
+/// `e <u> <v>` lines with 1-indexed vertices. Self-loops and other lines are ignored.
+pub fn read_graph_from_file(filename: &str) -> Result<Option<AdjList>, InputError> {
+    let file = File::open(filename)?;
+
+    parse_dimacs(BufReader::new(file))
+}
+
+/// Same as [`read_graph_from_file`], reading the file through a buffer of `buffer_size` bytes.
+pub fn read_graph_from_file_buffered(
+    filename: &str,
+    buffer_size: usize,
+) -> Result<Option<AdjList>, InputError> {
+    let file = File::open(filename)?;
+
+    parse_dimacs(BufReader::with_capacity(buffer_size, file))
+}
+
+/// Parses a graph in the DIMACS format (see [`read_graph_from_file`]) from `reader`.
+///
+/// Returns `None` if there is no `p` line.
+pub fn parse_dimacs(mut reader: impl BufRead) -> Result<Option<AdjList>, InputError> {
+    let mut graph: Option<AdjList> = None;
+    // Reused for every line, and scanned as bytes, to avoid allocating per line
+    let mut line: Vec<u8> = Vec::new();
+    let mut number = 0;
+
+    while reader.read_until(b'\n', &mut line)? > 0 {
+        number += 1;
+
+        let mut tokens = line
+            .split(|byte| byte.is_ascii_whitespace())
+            .filter(|token| !token.is_empty());
+
+        match tokens.next() {
+            Some(b"p") => {
+                if let Some(num_vertices) = tokens.nth(1) {
+                    let num_vertices = parse_number_bytes(num_vertices, number)?;
+                    graph = Some(AdjList::new(num_vertices));
+                }
+            }
+            Some(b"e") => {
+                if let (Some(from), Some(to)) = (tokens.next(), tokens.next()) {
+                    if let Some(graph) = graph.as_mut() {
+                        let from = parse_number_bytes(from, number)?;
+                        let to = parse_number_bytes(to, number)?;
+                        let (from, to) = to_vertices(graph, from, to)?;
+
+                        if from != to {
+                            graph.add_edge(from, to);
+                        }
+                    }
+                }
+            }
+            _ => {}
+        }
+
+        line.clear();
+    }
+
+    Ok(graph)
+}
+
+impl AdjList {
+    /// Parses a graph in the DIMACS format (see [`read_graph_from_file`]) from the contents `s`.
+    pub fn from_dimacs_string(s: &str) -> Result<Option<AdjList>, InputError> {
+        parse_dimacs(s.as_bytes())
+    }
+}
+
+/// Reads a symmetric Matrix Market (`.mtx`) coordinate file as an undirected graph.
+///
+/// Each `row col [value]` entry becomes an edge, ignoring the value column, self-loops and
+/// repeated entries. Returns an error if the matrix isn't square or if an index exceeds its size.
+pub fn read_graph_from_matrix_market(filename: &str) -> Result<Option<AdjList>, InputError> {
     let file = File::open(filename)?;
     let reader = BufReader::new(file);
     let mut graph: Option<AdjList> = None;
 
-    for line in reader.lines().flatten() {
+    for (i, line) in reader.lines().enumerate() {
+        let line = line?;
         let splits: Vec<&str> = line.split_whitespace().collect();
 
-        if splits.is_empty() {
+        // Skips the header, comments and empty lines
+        if splits.is_empty() || splits[0].starts_with('%') {
             continue;
         }
 
-        match splits[0] {
-            "p" => {
-                if let Some(num_vertices) = splits.get(2) {
-                    let num_vertices: usize = num_vertices.parse()?;
-                    graph = Some(AdjList::new(num_vertices));
+        match graph.as_mut() {
+            None => {
+                if let (Some(rows), Some(cols)) = (splits.first(), splits.get(1)) {
+                    let rows = parse_number(rows, i + 1)?;
+                    let cols = parse_number(cols, i + 1)?;
+
+                    if rows != cols {
+                        return Err(InputError::ParseError {
+                            line: i + 1,
+                            message: format!("the matrix must be square, found {rows}x{cols}"),
+                        });
+                    }
+
+                    graph = Some(AdjList::new(rows));
                 }
             }
-            "e" => {
-                if let (Some(from), Some(to)) = (splits.get(1), splits.get(2)) {
-                    if let Some(graph) = graph.as_mut() {
-                        let from = from.parse::<usize>()? - 1;
-                        let to = to.parse::<usize>()? - 1;
-                        graph.adj_list_mut()[from].push(to);
-                        graph.adj_list_mut()[to].push(from);
+            Some(graph) => {
+                if let (Some(row), Some(col)) = (splits.first(), splits.get(1)) {
+                    let row = parse_number(row, i + 1)?;
+                    let col = parse_number(col, i + 1)?;
+                    let (u, v) = to_vertices(graph, row, col)?;
+
+                    if u != v && !graph.adj_list()[u].contains(&v) {
+                        graph.add_edge(u, v);
                     }
                 }
             }
-            _ => {}
         }
     }
 
     Ok(graph)
 }
+
+/// Reads a graph in the binary DIMACS format (`.col.b`).
+///
+/// The file starts with a line holding the length of a text preamble, which includes the
+/// `p edge <vertices> <edges>` line. It is followed by the lower triangle of the adjacency matrix:
+/// row `i` takes `i / 8 + 1` bytes, and the bit of column `j <= i` is bit `7 - j % 8` of its
+/// `j / 8`th byte (most significant first).
+pub fn read_graph_from_binary_dimacs(filename: &str) -> Result<Option<AdjList>, InputError> {
+    let bytes = fs::read(filename)?;
+    let truncated = || {
+        InputError::IoError(io::Error::new(
+            io::ErrorKind::UnexpectedEof,
+            "the file is truncated",
+        ))
+    };
+
+    let Some(header_end) = bytes.iter().position(|byte| *byte == b'\n') else {
+        return Err(truncated());
+    };
+    let header = String::from_utf8_lossy(&bytes[..header_end]);
+    let preamble_length = parse_number(header.trim(), 1)?;
+    let preamble_end = header_end + 1 + preamble_length;
+
+    if bytes.len() < preamble_end {
+        return Err(truncated());
+    }
+
+    let preamble = String::from_utf8_lossy(&bytes[header_end + 1..preamble_end]);
+    let mut num_vertices = None;
+
+    for (i, line) in preamble.lines().enumerate() {
+        let splits: Vec<&str> = line.split_whitespace().collect();
+
+        if let (Some(&"p"), Some(vertices)) = (splits.first(), splits.get(2)) {
+            // The preamble starts on the second line of the file
+            num_vertices = Some(parse_number(vertices, i + 2)?);
+        }
+    }
+
+    let Some(num_vertices) = num_vertices else {
+        return Ok(None);
+    };
+
+    let mut graph = AdjList::new(num_vertices);
+    let mut offset = preamble_end;
+
+    for u in 0..num_vertices {
+        let row_length = u / 8 + 1;
+        let Some(row) = bytes.get(offset..offset + row_length) else {
+            return Err(truncated());
+        };
+
+        for v in (0..u).filter(|v| row[v / 8] & (0x80 >> (v % 8)) != 0) {
+            graph.add_edge(u, v);
+        }
+
+        offset += row_length;
+    }
+
+    Ok(Some(graph))
+}
+
+/// Reads a coloring of `n` vertices made of `v <vertex> <color>` lines, with 1-indexed vertices
+/// and colors. Other lines are ignored.
+///
+/// Returns [`InputError::InvalidColoring`] unless every vertex is colored exactly once.
+pub fn read_coloring_from_file(filename: &str, n: usize) -> Result<Vec<usize>, InputError> {
+    let file = File::open(filename)?;
+    let reader = BufReader::new(file);
+    let mut coloring = vec![0; n];
+
+    for (i, line) in reader.lines().enumerate() {
+        let line = line?;
+        let splits: Vec<&str> = line.split_whitespace().collect();
+
+        if splits.first() != Some(&"v") {
+            continue;
+        }
+
+        let (Some(vertex), Some(color)) = (splits.get(1), splits.get(2)) else {
+            return Err(InputError::ParseError {
+                line: i + 1,
+                message: "expected a vertex and its color".to_string(),
+            });
+        };
+        let vertex = parse_number(vertex, i + 1)?;
+        let color = parse_number(color, i + 1)?;
+
+        if vertex == 0 || vertex > n {
+            return Err(InputError::InvalidColoring(format!(
+                "vertex {vertex} is out of bounds for a graph with {n} vertices"
+            )));
+        }
+
+        if color == 0 {
+            return Err(InputError::InvalidColoring(format!(
+                "vertex {vertex} has the color 0, but colors start from 1"
+            )));
+        }
+
+        if coloring[vertex - 1] != 0 {
+            return Err(InputError::InvalidColoring(format!(
+                "vertex {vertex} is colored more than once"
+            )));
+        }
+
+        coloring[vertex - 1] = color;
+    }
+
+    let num_colored = coloring.iter().filter(|color| **color != 0).count();
+
+    if num_colored != n {
+        return Err(InputError::InvalidColoring(format!(
+            "expected {n} colored vertices, found {num_colored}"
+        )));
+    }
+
+    Ok(coloring)
+}
+
+/// Writes `coloring` as `v <vertex> <color>` lines, with 1-indexed vertices, so that it can be
+/// read by [`read_coloring_from_file`].
+pub fn write_coloring_to_file(coloring: &[usize], filename: &str) -> Result<(), io::Error> {
+    let mut writer = BufWriter::new(File::create(filename)?);
+
+    for (vertex, color) in coloring.iter().enumerate() {
+        writeln!(writer, "v {} {color}", vertex + 1)?;
+    }
+
+    writer.flush()
+}
+
+/// Writes `graph` in the DIMACS format, so that it can be read by [`read_graph_from_file`].
+pub fn write_graph_to_file(graph: &AdjList, filename: &str) -> Result<(), io::Error> {
+    let mut writer = BufWriter::new(File::create(filename)?);
+    let edges = graph.edge_list_as_slice();
+
+    writeln!(writer, "p edge {} {}", graph.num_vertices(), edges.len())?;
+    for (u, v) in edges.iter() {
+        writeln!(writer, "e {} {}", u + 1, v + 1)?;
+    }
+
+    writer.flush()
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+    use crate::graph::random::random_graph;
+    use std::env;
+    use std::fs;
+
+    #[test]
+    fn test_read_graph_from_matrix_market() {
+        if let Ok(Some(graph)) = read_graph_from_matrix_market("data/mtx/petersen.mtx") {
+            assert_eq!(graph.num_vertices(), 10);
+            assert_eq!(graph.edge_count(), 15);
+            assert!(graph
+                .adj_list()
+                .iter()
+                .all(|neighbors| neighbors.len() == 3));
+        } else {
+            panic!("The file containing the test graph is missing")
+        }
+    }
+
+    #[test]
+    fn test_read_graph_from_matrix_market_errors() {
+        let non_square = env::temp_dir().join("gcp_heuristics_non_square.mtx");
+        fs::write(
+            &non_square,
+            "%%MatrixMarket matrix coordinate pattern general\n3 4 1\n1 2\n",
+        )
+        .unwrap();
+
+        assert!(matches!(
+            read_graph_from_matrix_market(non_square.to_str().unwrap()),
+            Err(InputError::ParseError { line: 2, .. })
+        ));
+
+        let out_of_bounds = env::temp_dir().join("gcp_heuristics_out_of_bounds.mtx");
+        fs::write(
+            &out_of_bounds,
+            "%%MatrixMarket matrix coordinate pattern symmetric\n3 3 1\n4 1\n",
+        )
+        .unwrap();
+
+        assert!(matches!(
+            read_graph_from_matrix_market(out_of_bounds.to_str().unwrap()),
+            Err(InputError::InvalidEdge { u: 4, v: 1, n: 3 })
+        ));
+    }
+
+    #[test]
+    fn test_read_graph_from_file() {
+        let path = env::temp_dir().join("gcp_heuristics_triangle.col");
+        fs::write(&path, "c A triangle\np edge 3 3\ne 1 2\ne 2 3\ne 1 3\n").unwrap();
+
+        if let Ok(Some(graph)) = read_graph_from_file(path.to_str().unwrap()) {
+            assert_eq!(graph.num_vertices(), 3);
+            assert_eq!(graph.edge_count(), 3);
+        } else {
+            panic!("The triangle should be read")
+        }
+
+        assert!(matches!(
+            read_graph_from_file("data/missing.col"),
+            Err(InputError::IoError(_))
+        ));
+    }
+
+    #[test]
+    fn test_from_dimacs_string() {
+        let petersen = "c The Petersen graph
+p edge 10 15
+e 1 2
+e 2 3
+e 3 4
+e 4 5
+e 5 1
+e 1 6
+e 2 7
+e 3 8
+e 4 9
+e 5 10
+e 6 8
+e 8 10
+e 10 7
+e 7 9
+e 9 6
+";
+
+        if let Ok(Some(graph)) = AdjList::from_dimacs_string(petersen) {
+            assert_eq!(graph.num_vertices(), 10);
+            assert_eq!(graph.edge_count(), 15);
+            assert!((0..10).all(|v| graph.get_degree(v) == 3));
+        } else {
+            panic!("The Petersen graph should be parsed")
+        }
+
+        assert!(matches!(
+            AdjList::from_dimacs_string("c No graph\n"),
+            Ok(None)
+        ));
+        assert!(matches!(
+            AdjList::from_dimacs_string("p edge 2 1\ne 1 y\n"),
+            Err(InputError::ParseError { line: 2, .. })
+        ));
+
+        // Self-loops are dropped, as the graph has no room for them
+        if let Ok(Some(graph)) = AdjList::from_dimacs_string("p edge 3 2\ne 2 2\ne 1 2\n") {
+            assert_eq!(graph.num_edges(), 1);
+            assert_eq!(graph.adj_list()[1], vec![0]);
+        } else {
+            panic!("The graph with a self-loop should be parsed")
+        }
+    }
+
+    #[test]
+    fn test_write_graph_round_trip() {
+        let graph = random_graph(200, 1500, 3).unwrap();
+        let path = env::temp_dir().join("gcp_heuristics_round_trip.col");
+        let path = path.to_str().unwrap();
+        write_graph_to_file(&graph, path).unwrap();
+
+        for read in [
+            read_graph_from_file(path),
+            read_graph_from_file_buffered(path, 16),
+            read_graph_from_file_buffered(path, 1 << 20),
+        ] {
+            if let Ok(Some(read)) = read {
+                assert_eq!(read.num_vertices(), 200);
+                assert_eq!(read.edge_list(), graph.edge_list());
+            } else {
+                panic!("The written graph should be read")
+            }
+        }
+
+        if let Ok(Some(graph)) = read_graph_from_file("data/myc/myciel4.col") {
+            write_graph_to_file(&graph, path).unwrap();
+
+            if let Ok(Some(read)) = read_graph_from_file(path) {
+                assert_eq!(read.edge_list(), graph.edge_list());
+            } else {
+                panic!("The written graph should be read")
+            }
+        } else {
+            panic!("The file containing the test graph is missing")
+        }
+    }
+
+    #[test]
+    fn test_read_graph_from_file_errors() {
+        let malformed = env::temp_dir().join("gcp_heuristics_malformed.col");
+        fs::write(&malformed, "c A malformed edge\np edge 3 2\ne 1 2\ne 2 x\n").unwrap();
+
+        match read_graph_from_file(malformed.to_str().unwrap()) {
+            Err(InputError::ParseError { line, .. }) => assert_eq!(line, 4),
+            other => panic!("Expected a parse error, found {other:?}"),
+        }
+
+        let invalid_edge = env::temp_dir().join("gcp_heuristics_invalid_edge.col");
+        fs::write(&invalid_edge, "p edge 10 2\ne 1 2\ne 3 999\n").unwrap();
+
+        assert!(matches!(
+            read_graph_from_file(invalid_edge.to_str().unwrap()),
+            Err(InputError::InvalidEdge {
+                u: 3,
+                v: 999,
+                n: 10
+            })
+        ));
+    }
+
+    #[test]
+    fn test_read_graph_from_binary_dimacs() {
+        // A triangle on the first 3 vertices, and vertex 8 adjacent to 0 and 7 (2 bytes per row)
+        let preamble = b"c A binary graph\np edge 9 5\n";
+        let mut bytes = format!("{}\n", preamble.len()).into_bytes();
+        bytes.extend_from_slice(preamble);
+        bytes.extend_from_slice(&[0, 0x80, 0xC0, 0, 0, 0, 0, 0, 0x81, 0]);
+
+        let path = env::temp_dir().join("gcp_heuristics_binary.col.b");
+        fs::write(&path, &bytes).unwrap();
+
+        if let Ok(Some(graph)) = read_graph_from_binary_dimacs(path.to_str().unwrap()) {
+            assert_eq!(graph.num_vertices(), 9);
+            assert_eq!(graph.edge_count(), 5);
+            assert!(graph.adj_list()[2].contains(&0) && graph.adj_list()[2].contains(&1));
+            assert!(graph.adj_list()[8].contains(&0) && graph.adj_list()[8].contains(&7));
+        } else {
+            panic!("The binary graph should be read")
+        }
+
+        let truncated = env::temp_dir().join("gcp_heuristics_truncated.col.b");
+        fs::write(&truncated, &bytes[..bytes.len() - 1]).unwrap();
+
+        assert!(matches!(
+            read_graph_from_binary_dimacs(truncated.to_str().unwrap()),
+            Err(InputError::IoError(_))
+        ));
+    }
+
+    #[test]
+    fn test_coloring_file_round_trip() {
+        let path = env::temp_dir().join("gcp_heuristics_coloring.sol");
+        let coloring = vec![1, 2, 1, 3, 2];
+
+        write_coloring_to_file(&coloring, path.to_str().unwrap()).unwrap();
+
+        assert_eq!(
+            read_coloring_from_file(path.to_str().unwrap(), 5).unwrap(),
+            coloring
+        );
+    }
+
+    #[test]
+    fn test_read_coloring_from_file_errors() {
+        let path = env::temp_dir().join("gcp_heuristics_short_coloring.sol");
+        write_coloring_to_file(&[1, 2, 1, 2], path.to_str().unwrap()).unwrap();
+
+        assert!(matches!(
+            read_coloring_from_file(path.to_str().unwrap(), 5),
+            Err(InputError::InvalidColoring(_))
+        ));
+
+        let zero = env::temp_dir().join("gcp_heuristics_zero_coloring.sol");
+        fs::write(&zero, "v 1 1\nv 2 0\n").unwrap();
+
+        assert!(matches!(
+            read_coloring_from_file(zero.to_str().unwrap(), 2),
+            Err(InputError::InvalidColoring(_))
+        ));
+
+        let malformed = env::temp_dir().join("gcp_heuristics_malformed_coloring.sol");
+        fs::write(&malformed, "c A solution\nv 1 1\nv 2\n").unwrap();
+
+        assert!(matches!(
+            read_coloring_from_file(malformed.to_str().unwrap(), 2),
+            Err(InputError::ParseError { line: 3, .. })
+        ));
+    }
+}
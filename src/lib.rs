@@ -0,0 +1,8 @@
+//! Heuristics for the Graph Coloring Problem (GCP).
+
+pub mod algorithms;
+pub mod benchmark;
+pub mod graph;
+pub mod input;
+pub mod output;
+pub mod tuning;
@@ -1,14 +1,21 @@
-mod algorithms;
 mod args;
-mod graph;
-mod input;
 
-use algorithms::{genetic::genetic, grasp::grasp_wrapper, grasp_pr::grasp_path_relinking};
 use args::Algorithm;
 use args::Args;
 use clap::Parser;
+use gcp_heuristics::algorithms::{
+    genetic::genetic,
+    grasp::{grasp_with_config, GraspConfig},
+    grasp_pr::grasp_path_relinking,
+    greedy::{dsatur, welsh_powell},
+    partition::coloring_to_partition,
+    rlf::rlf,
+    tabu::tabu_search,
+    vns::variable_neighborhood_search,
+};
+use gcp_heuristics::input;
 use std::process;
-use std::time::Instant;
+use std::time::{Duration, Instant};
 
 fn main() {
     let Args {
@@ -18,14 +25,26 @@ fn main() {
         grasp_iterations,
         color_iterations,
         color_list_size,
+        time_budget,
         generations,
         population_size,
         offspring_size,
         mutation_probaility,
         population_ratio,
+        k_max,
+        vns_iterations,
+        tabu_iterations,
     } = Args::parse();
 
-    if let Ok(Some(graph)) = input::read_graph_from_file(path.as_str()) {
+    let graph = if path.ends_with(".mtx") {
+        input::read_graph_from_matrix_market(path.as_str())
+    } else if path.ends_with(".col.b") {
+        input::read_graph_from_binary_dimacs(path.as_str())
+    } else {
+        input::read_graph_from_file(path.as_str())
+    };
+
+    if let Ok(Some(graph)) = graph {
         let start = Instant::now();
 
         let (num_colors, coloring) = match algorithm {
@@ -37,19 +56,45 @@ fn main() {
                 mutation_probaility.unwrap_or(0.01),
                 population_ratio.unwrap_or(0.2),
             ),
-            Algorithm::Grasp => grasp_wrapper(
+            Algorithm::Grasp => {
+                let mut config = GraspConfig::new(
+                    grasp_iterations.unwrap_or(25),
+                    color_iterations.unwrap_or(25),
+                    color_list_size.unwrap_or(3),
+                );
+
+                if let Some(time_budget) = time_budget {
+                    config = config.time_budget(Duration::from_millis(time_budget));
+                }
+
+                match grasp_with_config(&graph, &config) {
+                    Ok(solution) => solution,
+                    Err(err) => {
+                        eprintln!("Failed to run GRASP: {err}");
+                        process::exit(1);
+                    }
+                }
+            }
+            Algorithm::GraspPR => grasp_path_relinking(&graph, pr_solutions.unwrap_or(10)),
+            Algorithm::Vns => variable_neighborhood_search(
                 &graph,
-                grasp_iterations.unwrap_or(25),
-                color_iterations.unwrap_or(25),
-                color_list_size.unwrap_or(3),
+                k_max.unwrap_or(3),
+                vns_iterations.unwrap_or(200),
             ),
-            Algorithm::GraspPR => grasp_path_relinking(&graph, pr_solutions.unwrap_or(10)),
+            Algorithm::WelshPowell => welsh_powell(&graph),
+            Algorithm::Dsatur => dsatur(&graph),
+            Algorithm::Tabu => tabu_search(&graph, tabu_iterations.unwrap_or(10000)),
+            Algorithm::Rlf => rlf(&graph),
         };
 
         let duration = start.elapsed().as_millis();
+        let partition = coloring_to_partition(&coloring);
 
         println!("Number of colors used: {:?}", num_colors);
         println!("Color assignment: {:?}", coloring);
+        println!("Class sizes: {:?}", partition.class_sizes());
+        println!("Imbalance: {:.4}", partition.imbalance());
+        println!("Entropy: {:.4}", partition.entropy());
         println!("Duration: {:?}", duration);
     } else {
         eprintln!("Failed to open the specified instance: {path}");
@@ -0,0 +1,169 @@
+use crate::graph::adj_list::AdjList;
+use std::error::Error;
+use std::fmt::{self, Write as _};
+
+/// Largest graph rendered by [`render_coloring`] with a line per vertex and per edge.
+const MAX_LISTED_VERTICES: usize = 10;
+
+/// Largest graph rendered by [`render_coloring`], as an adjacency matrix.
+const MAX_RENDERED_VERTICES: usize = 20;
+
+/// Errors raised by the ASCII renderers.
+#[derive(Debug, Clone, PartialEq, Eq)]
+pub enum RenderError {
+    /// The graph has `n` vertices, more than the `max` that can be rendered.
+    TooManyVertices { n: usize, max: usize },
+}
+
+impl fmt::Display for RenderError {
+    fn fmt(&self, f: &mut fmt::Formatter<'_>) -> fmt::Result {
+        match self {
+            RenderError::TooManyVertices { n, max } => {
+                write!(
+                    f,
+                    "the graph has {n} vertices, but at most {max} can be rendered"
+                )
+            }
+        }
+    }
+}
+
+impl Error for RenderError {}
+
+/// Label of a color of a coloring, `[-]` for uncolored vertices.
+fn color_label(color: usize) -> String {
+    match color {
+        0 => "[-]".to_string(),
+        color => format!("[{color}]"),
+    }
+}
+
+/// Renders `coloring`, the color of each vertex of `graph` (0 if uncolored), as ASCII text.
+///
+/// Graphs with up to 10 vertices get a line per vertex with its color, followed by a line per
+/// edge. Graphs with up to 20 vertices get their adjacency matrix instead, each row labeled with
+/// the color of its vertex, the edges marked with `#` and the forbidden ones with `!`. Returns
+/// [`RenderError::TooManyVertices`] for larger graphs.
+pub fn render_coloring(graph: &AdjList, coloring: &[usize]) -> Result<String, RenderError> {
+    let n = graph.num_vertices();
+    let forbidden = |u: usize, v: usize| coloring[u] != 0 && coloring[u] == coloring[v];
+    let mut text = String::new();
+
+    // Writing to a String can't fail, so we can unwrap
+    if n <= MAX_LISTED_VERTICES {
+        writeln!(text, "Vertices:").unwrap();
+        for (vertex, color) in coloring.iter().enumerate() {
+            writeln!(text, "  {vertex} {}", color_label(*color)).unwrap();
+        }
+
+        writeln!(text, "Edges:").unwrap();
+        for (u, v) in graph.edge_list_as_slice().iter() {
+            let note = if forbidden(*u, *v) {
+                " (forbidden)"
+            } else {
+                ""
+            };
+            writeln!(text, "  {u}---{v}{note}").unwrap();
+        }
+    } else if n <= MAX_RENDERED_VERTICES {
+        let labels: Vec<String> = coloring.iter().map(|color| color_label(*color)).collect();
+        // The labels are left aligned after the vertex, and the columns are 3 characters wide
+        let label_width = labels.iter().map(String::len).max().unwrap_or(0);
+
+        write!(text, "{:width$}", "", width = label_width + 3).unwrap();
+        for vertex in 0..n {
+            write!(text, "{vertex:>3}").unwrap();
+        }
+        writeln!(text).unwrap();
+
+        for (u, label) in labels.iter().enumerate() {
+            write!(text, "{u:>2} {label:<label_width$}").unwrap();
+            for v in 0..n {
+                let cell = if !graph.has_edge(u, v) {
+                    '.'
+                } else if forbidden(u, v) {
+                    '!'
+                } else {
+                    '#'
+                };
+                write!(text, "{cell:>3}").unwrap();
+            }
+            writeln!(text).unwrap();
+        }
+    } else {
+        return Err(RenderError::TooManyVertices {
+            n,
+            max: MAX_RENDERED_VERTICES,
+        });
+    }
+
+    Ok(text)
+}
+
+/// Renders `class_list` as ASCII text, with a `Color <i>: <vertices>` line per class. Colors are
+/// 1-indexed.
+pub fn render_class_list(class_list: &[Vec<usize>]) -> String {
+    let mut text = String::new();
+
+    for (i, class) in class_list.iter().enumerate() {
+        let vertices: Vec<String> = class.iter().map(usize::to_string).collect();
+        // Writing to a String can't fail, so we can unwrap
+        writeln!(text, "Color {}: {}", i + 1, vertices.join(" ")).unwrap();
+    }
+
+    text
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+    use crate::graph::generators::path_graph;
+    use std::collections::HashSet;
+
+    #[test]
+    fn test_render_coloring() {
+        let graph = AdjList::complete(4);
+        let text = render_coloring(&graph, &[1, 2, 3, 4]).unwrap();
+        let labels: HashSet<&str> = ["[1]", "[2]", "[3]", "[4]"]
+            .into_iter()
+            .filter(|label| text.contains(label))
+            .collect();
+
+        assert_eq!(labels.len(), 4);
+        assert_eq!(text.lines().filter(|line| line.contains("---")).count(), 6);
+        assert!(text.starts_with("Vertices:\n  0 [1]\n"));
+        assert!(text.contains("  0---1\n"));
+        assert!(!text.contains("forbidden"));
+
+        let text = render_coloring(&graph, &[1, 1, 2, 0]).unwrap();
+
+        assert!(text.contains("  0---1 (forbidden)\n"));
+        assert!(text.contains("  3 [-]\n"));
+
+        // The path on 12 vertices colored with 2 colors, where 11---10 is forbidden
+        let graph = path_graph(12);
+        let mut coloring: Vec<usize> = (0..12).map(|vertex| vertex % 2 + 1).collect();
+        coloring[11] = 1;
+        let text = render_coloring(&graph, &coloring).unwrap();
+        let lines: Vec<&str> = text.lines().collect();
+
+        assert_eq!(lines.len(), 13);
+        assert_eq!(lines[1], " 0 [1]  .  #  .  .  .  .  .  .  .  .  .  .");
+        assert_eq!(lines[12], "11 [1]  .  .  .  .  .  .  .  .  .  .  !  .");
+        assert_eq!(text.matches('#').count(), 2 * 10);
+
+        assert_eq!(
+            render_coloring(&path_graph(21), &[1; 21]),
+            Err(RenderError::TooManyVertices { n: 21, max: 20 })
+        );
+    }
+
+    #[test]
+    fn test_render_class_list() {
+        assert_eq!(
+            render_class_list(&[vec![0, 2], vec![1], vec![]]),
+            "Color 1: 0 2\nColor 2: 1\nColor 3: \n"
+        );
+        assert_eq!(render_class_list(&[]), "");
+    }
+}
@@ -0,0 +1,554 @@
+use crate::algorithms::grasp::{GraspConfig, GraspStats};
+use crate::graph::adj_list::AdjList;
+use std::error::Error;
+use std::fmt::Write as _;
+use std::fs;
+use std::time::Duration;
+
+/// A complete experiment, archived as JSON by [`ExperimentRecord::save`]: the graph, the GRASP
+/// configuration, the solution found and the statistics of the execution.
+///
+/// Only the numeric and boolean parameters of the configuration are archived. The vertex scorer,
+/// the iteration callback and the initial solution are left out, so they are unset in a loaded
+/// record.
+#[derive(Debug, Clone)]
+pub struct ExperimentRecord {
+    pub graph_name: String,
+    pub graph: AdjList,
+    pub config: GraspConfig,
+    pub solution: Vec<Vec<usize>>,
+    pub stats: GraspStats,
+    pub runtime_ms: u64,
+}
+
+/// A parsed JSON value. Numbers keep their text, so that integers are read without going
+/// through `f64`.
+#[derive(Debug, Clone, PartialEq)]
+enum Json {
+    Null,
+    Bool(bool),
+    Number(String),
+    String(String),
+    Array(Vec<Json>),
+    Object(Vec<(String, Json)>),
+}
+
+/// Recursive descent parser over the bytes of a JSON document.
+struct Parser<'a> {
+    bytes: &'a [u8],
+    position: usize,
+}
+
+impl<'a> Parser<'a> {
+    fn new(text: &'a str) -> Self {
+        Parser {
+            bytes: text.as_bytes(),
+            position: 0,
+        }
+    }
+
+    fn skip_whitespace(&mut self) {
+        while self
+            .bytes
+            .get(self.position)
+            .is_some_and(|byte| byte.is_ascii_whitespace())
+        {
+            self.position += 1;
+        }
+    }
+
+    fn peek(&mut self) -> Option<u8> {
+        self.skip_whitespace();
+        self.bytes.get(self.position).copied()
+    }
+
+    fn expect(&mut self, expected: u8) -> Result<(), Box<dyn Error>> {
+        match self.peek() {
+            Some(byte) if byte == expected => {
+                self.position += 1;
+                Ok(())
+            }
+            _ => Err(format!("Expected '{}' at byte {}", expected as char, self.position).into()),
+        }
+    }
+
+    fn parse_literal(&mut self, literal: &str, value: Json) -> Result<Json, Box<dyn Error>> {
+        if self.bytes[self.position..].starts_with(literal.as_bytes()) {
+            self.position += literal.len();
+            Ok(value)
+        } else {
+            Err(format!("Unexpected token at byte {}", self.position).into())
+        }
+    }
+
+    fn parse_value(&mut self) -> Result<Json, Box<dyn Error>> {
+        match self.peek() {
+            Some(b'n') => self.parse_literal("null", Json::Null),
+            Some(b't') => self.parse_literal("true", Json::Bool(true)),
+            Some(b'f') => self.parse_literal("false", Json::Bool(false)),
+            Some(b'"') => Ok(Json::String(self.parse_string()?)),
+            Some(b'[') => {
+                self.position += 1;
+                let mut values = Vec::new();
+
+                if self.peek() == Some(b']') {
+                    self.position += 1;
+                    return Ok(Json::Array(values));
+                }
+
+                loop {
+                    values.push(self.parse_value()?);
+
+                    if self.peek() == Some(b',') {
+                        self.position += 1;
+                    } else {
+                        self.expect(b']')?;
+                        return Ok(Json::Array(values));
+                    }
+                }
+            }
+            Some(b'{') => {
+                self.position += 1;
+                let mut members = Vec::new();
+
+                if self.peek() == Some(b'}') {
+                    self.position += 1;
+                    return Ok(Json::Object(members));
+                }
+
+                loop {
+                    self.skip_whitespace();
+                    let key = self.parse_string()?;
+                    self.expect(b':')?;
+                    members.push((key, self.parse_value()?));
+
+                    if self.peek() == Some(b',') {
+                        self.position += 1;
+                    } else {
+                        self.expect(b'}')?;
+                        return Ok(Json::Object(members));
+                    }
+                }
+            }
+            Some(byte) if byte == b'-' || byte.is_ascii_digit() => {
+                let start = self.position;
+                while self.bytes.get(self.position).is_some_and(|byte| {
+                    byte.is_ascii_digit() || matches!(byte, b'-' | b'+' | b'.' | b'e' | b'E')
+                }) {
+                    self.position += 1;
+                }
+
+                // The bytes are ASCII, so we can unwrap
+                let text = std::str::from_utf8(&self.bytes[start..self.position]).unwrap();
+                Ok(Json::Number(text.to_string()))
+            }
+            _ => Err(format!("Unexpected token at byte {}", self.position).into()),
+        }
+    }
+
+    fn parse_string(&mut self) -> Result<String, Box<dyn Error>> {
+        self.expect(b'"')?;
+        let mut bytes = Vec::new();
+
+        loop {
+            let Some(byte) = self.bytes.get(self.position).copied() else {
+                return Err("Unterminated string".into());
+            };
+            self.position += 1;
+
+            match byte {
+                b'"' => return Ok(String::from_utf8(bytes)?),
+                b'\\' => {
+                    let escaped = match self.bytes.get(self.position) {
+                        Some(b'n') => '\n',
+                        Some(b't') => '\t',
+                        Some(b'r') => '\r',
+                        Some(b'b') => '\u{8}',
+                        Some(b'f') => '\u{c}',
+                        Some(b'u') => {
+                            let digits = self
+                                .bytes
+                                .get(self.position + 1..self.position + 5)
+                                .ok_or("Truncated unicode escape")?;
+                            let code = u32::from_str_radix(std::str::from_utf8(digits)?, 16)?;
+                            self.position += 4;
+                            char::from_u32(code).ok_or("Invalid unicode escape")?
+                        }
+                        Some(other) => *other as char,
+                        None => return Err("Unterminated string".into()),
+                    };
+                    self.position += 1;
+                    bytes.extend_from_slice(escaped.encode_utf8(&mut [0; 4]).as_bytes());
+                }
+                _ => bytes.push(byte),
+            }
+        }
+    }
+}
+
+impl Json {
+    fn parse(text: &str) -> Result<Json, Box<dyn Error>> {
+        let mut parser = Parser::new(text);
+        let value = parser.parse_value()?;
+
+        if parser.peek().is_some() {
+            return Err(format!("Trailing characters at byte {}", parser.position).into());
+        }
+
+        Ok(value)
+    }
+
+    fn get(&self, key: &str) -> Result<&Json, Box<dyn Error>> {
+        match self {
+            Json::Object(members) => members
+                .iter()
+                .find(|(name, _)| name == key)
+                .map(|(_, value)| value)
+                .ok_or_else(|| format!("Missing field \"{key}\"").into()),
+            _ => Err(format!("Expected an object with the field \"{key}\"").into()),
+        }
+    }
+
+    fn as_number<T: std::str::FromStr>(&self) -> Result<T, Box<dyn Error>>
+    where
+        T::Err: Error + 'static,
+    {
+        match self {
+            Json::Number(text) => Ok(text.parse()?),
+            _ => Err("Expected a number".into()),
+        }
+    }
+
+    /// The number, or `None` if the value is `null`.
+    fn as_optional_number<T: std::str::FromStr>(&self) -> Result<Option<T>, Box<dyn Error>>
+    where
+        T::Err: Error + 'static,
+    {
+        match self {
+            Json::Null => Ok(None),
+            _ => self.as_number().map(Some),
+        }
+    }
+
+    fn as_bool(&self) -> Result<bool, Box<dyn Error>> {
+        match self {
+            Json::Bool(value) => Ok(*value),
+            _ => Err("Expected a boolean".into()),
+        }
+    }
+
+    fn as_array(&self) -> Result<&[Json], Box<dyn Error>> {
+        match self {
+            Json::Array(values) => Ok(values),
+            _ => Err("Expected an array".into()),
+        }
+    }
+
+    fn as_usize_array(&self) -> Result<Vec<usize>, Box<dyn Error>> {
+        self.as_array()?.iter().map(Json::as_number).collect()
+    }
+}
+
+/// Writes `text` as a JSON string, escaping the quotes, backslashes and control characters.
+fn write_json_string(out: &mut String, text: &str) {
+    out.push('"');
+
+    for c in text.chars() {
+        match c {
+            '"' => out.push_str("\\\""),
+            '\\' => out.push_str("\\\\"),
+            '\n' => out.push_str("\\n"),
+            c if c.is_control() => {
+                let _ = write!(out, "\\u{:04x}", c as u32);
+            }
+            c => out.push(c),
+        }
+    }
+
+    out.push('"');
+}
+
+/// Formats a list of numbers as a JSON array.
+fn json_array(values: impl IntoIterator<Item = impl ToString>) -> String {
+    let values: Vec<String> = values.into_iter().map(|value| value.to_string()).collect();
+
+    format!("[{}]", values.join(","))
+}
+
+/// Formats an optional value as JSON, `null` for `None`.
+fn json_optional(value: Option<impl ToString>) -> String {
+    value.map_or("null".to_string(), |value| value.to_string())
+}
+
+impl ExperimentRecord {
+    /// Formats the record as a JSON document.
+    ///
+    /// Edges are written as `[u, v]`, followed by their weight if it isn't 1, and the time budget
+    /// in seconds.
+    pub fn to_json(&self) -> String {
+        let config = &self.config;
+        let mut out = String::new();
+
+        out.push_str("{\n  \"graph_name\": ");
+        write_json_string(&mut out, &self.graph_name);
+
+        let edges: Vec<String> = self
+            .graph
+            .edge_list_as_slice()
+            .iter()
+            .map(|(u, v)| match self.graph.edge_weight(*u, *v) {
+                Some(weight) if weight != 1.0 => format!("[{u},{v},{weight}]"),
+                _ => format!("[{u},{v}]"),
+            })
+            .collect();
+        let _ = write!(
+            out,
+            ",\n  \"graph\": {{\"num_vertices\": {}, \"edges\": [{}]}}",
+            self.graph.num_vertices(),
+            edges.join(",")
+        );
+
+        let _ = write!(
+            out,
+            ",\n  \"config\": {{\"grasp_iterations\": {}, \"color_iterations\": {}, \
+             \"color_list_size\": {}, \"elite_size\": {}, \"diversity_threshold\": {}, \
+             \"time_budget_s\": {}, \"weighted_objective\": {}, \"upper_bound_pruning\": {}, \
+             \"color_upper_bound\": {}, \"merge_group_size\": {}, \"plateau_moves\": {}, \
+             \"improve_min_reduction\": {}, \"merge_oracle_threshold\": {}, \"exact_threshold\": {}, \"adaptive_csize\": {}, \
+             \"seed\": {}}}",
+            config.grasp_iterations,
+            config.color_iterations,
+            config.color_list_size,
+            config.elite_size,
+            config.diversity_threshold,
+            json_optional(config.time_budget.map(|budget| budget.as_secs_f64())),
+            config.weighted_objective,
+            config.upper_bound_pruning,
+            json_optional(config.color_upper_bound),
+            config.merge_group_size,
+            config.plateau_moves,
+            config.improve_min_reduction,
+            config.merge_oracle_threshold,
+            config.exact_threshold,
+            json_optional(
+                config
+                    .adaptive_csize
+                    .map(|(min, max)| format!("[{min}, {max}]"))
+            ),
+            json_optional(config.seed)
+        );
+
+        let classes: Vec<String> = self.solution.iter().map(json_array).collect();
+        let _ = write!(out, ",\n  \"solution\": [{}]", classes.join(","));
+
+        let stats = &self.stats;
+        let _ = write!(
+            out,
+            ",\n  \"stats\": {{\"best_colors\": {}, \"num_perturbations\": {}, \"num_merges\": {}, \
+             \"num_improve_phases\": {}}}",
+            json_array(&stats.best_colors),
+            stats.num_perturbations,
+            stats.num_merges,
+            stats.num_improve_phases
+        );
+
+        let _ = write!(out, ",\n  \"runtime_ms\": {}\n}}\n", self.runtime_ms);
+
+        out
+    }
+
+    /// Reads a record from a JSON document written by [`ExperimentRecord::to_json`].
+    pub fn from_json(text: &str) -> Result<ExperimentRecord, Box<dyn Error>> {
+        let json = Json::parse(text)?;
+
+        let graph_name = match json.get("graph_name")? {
+            Json::String(name) => name.clone(),
+            _ => return Err("Expected the graph name to be a string".into()),
+        };
+
+        let graph_json = json.get("graph")?;
+        let num_vertices: usize = graph_json.get("num_vertices")?.as_number()?;
+        let mut graph = AdjList::new(num_vertices);
+        for edge in graph_json.get("edges")?.as_array()? {
+            let edge = edge.as_array()?;
+            let (u, v): (usize, usize) = match edge {
+                [u, v] | [u, v, _] => (u.as_number()?, v.as_number()?),
+                _ => return Err("Expected edges with 2 or 3 fields".into()),
+            };
+
+            if u >= num_vertices || v >= num_vertices {
+                return Err(format!("The edge {u}---{v} is out of bounds").into());
+            }
+
+            match edge {
+                [_, _, weight] => graph.add_weighted_edge(u, v, weight.as_number()?),
+                _ => graph.add_edge(u, v),
+            }
+        }
+
+        let config_json = json.get("config")?;
+        let mut config = GraspConfig::new(
+            config_json.get("grasp_iterations")?.as_number()?,
+            config_json.get("color_iterations")?.as_number()?,
+            config_json.get("color_list_size")?.as_number()?,
+        )
+        .elite_size(config_json.get("elite_size")?.as_number()?)
+        .diversity_threshold(config_json.get("diversity_threshold")?.as_number()?)
+        .use_weighted_objective(config_json.get("weighted_objective")?.as_bool()?)
+        .upper_bound_pruning(config_json.get("upper_bound_pruning")?.as_bool()?)
+        .merge_group_size(config_json.get("merge_group_size")?.as_number()?)
+        .plateau_moves(config_json.get("plateau_moves")?.as_number()?)
+        .improve_min_reduction(config_json.get("improve_min_reduction")?.as_number()?)
+        .merge_oracle_threshold(config_json.get("merge_oracle_threshold")?.as_number()?)
+        .exact_threshold(config_json.get("exact_threshold")?.as_number()?);
+        if let Some(seconds) = config_json.get("time_budget_s")?.as_optional_number()? {
+            config = config.time_budget(Duration::from_secs_f64(seconds));
+        }
+        if let Some(k) = config_json.get("color_upper_bound")?.as_optional_number()? {
+            config = config.color_upper_bound(k);
+        }
+        match config_json.get("adaptive_csize")? {
+            Json::Null => {}
+            sizes => match sizes.as_usize_array()?.as_slice() {
+                [min, max] => config = config.adaptive_csize(*min, *max),
+                _ => return Err("Expected adaptive_csize to have 2 fields".into()),
+            },
+        }
+        if let Some(seed) = config_json.get("seed")?.as_optional_number()? {
+            config = config.seed(seed);
+        }
+
+        let solution = json
+            .get("solution")?
+            .as_array()?
+            .iter()
+            .map(Json::as_usize_array)
+            .collect::<Result<_, _>>()?;
+
+        let stats_json = json.get("stats")?;
+        let stats = GraspStats {
+            best_colors: stats_json.get("best_colors")?.as_usize_array()?,
+            num_perturbations: stats_json.get("num_perturbations")?.as_number()?,
+            num_merges: stats_json.get("num_merges")?.as_number()?,
+            num_improve_phases: stats_json.get("num_improve_phases")?.as_number()?,
+        };
+
+        Ok(ExperimentRecord {
+            graph_name,
+            graph,
+            config,
+            solution,
+            stats,
+            runtime_ms: json.get("runtime_ms")?.as_number()?,
+        })
+    }
+
+    /// Writes the record to a JSON file at `path`.
+    pub fn save(&self, path: &str) -> Result<(), Box<dyn Error>> {
+        fs::write(path, self.to_json())?;
+
+        Ok(())
+    }
+
+    /// Reads a record from a JSON file at `path`, written by [`ExperimentRecord::save`].
+    pub fn load(path: &str) -> Result<ExperimentRecord, Box<dyn Error>> {
+        ExperimentRecord::from_json(&fs::read_to_string(path)?)
+    }
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+    use crate::{
+        algorithms::{get_class_list_from_coloring, grasp::grasp_with_stats},
+        input,
+    };
+    use std::env;
+    use std::time::Instant;
+
+    #[test]
+    fn test_experiment_record_round_trip() {
+        if let Ok(Some(graph)) = input::read_graph_from_file("data/myc/myciel3.col") {
+            let config = GraspConfig::new(4, 4, 3)
+                .seed(u64::MAX)
+                .diversity_threshold(0.25)
+                .adaptive_csize(2, 3)
+                .time_budget(Duration::from_millis(1500));
+            let start = Instant::now();
+            let ((_, coloring), stats) = grasp_with_stats(&graph, &config).unwrap();
+            let record = ExperimentRecord {
+                graph_name: "myciel3 \"Grötzsch\"".to_string(),
+                graph,
+                config,
+                solution: get_class_list_from_coloring(&coloring),
+                stats,
+                runtime_ms: start.elapsed().as_millis() as u64,
+            };
+
+            let path = env::temp_dir().join("gcp_heuristics_experiment.json");
+            let path = path.to_str().unwrap();
+            record.save(path).unwrap();
+            let loaded = ExperimentRecord::load(path).unwrap();
+
+            assert!(loaded.graph.is_valid_class_list(&loaded.solution));
+            assert_eq!(loaded.graph_name, record.graph_name);
+            assert_eq!(loaded.graph.edge_list(), record.graph.edge_list());
+            assert_eq!(loaded.solution, record.solution);
+            assert_eq!(loaded.stats, record.stats);
+            assert_eq!(loaded.runtime_ms, record.runtime_ms);
+            assert_eq!(
+                format!("{:?}", loaded.config),
+                format!("{:?}", record.config)
+            );
+        } else {
+            panic!("The file containing the test graph is missing")
+        }
+    }
+
+    #[test]
+    fn test_experiment_record_weights_and_errors() {
+        let mut graph = AdjList::new(3);
+        graph.add_weighted_edge(0, 1, 2.5);
+        graph.add_edge(1, 2);
+        let record = ExperimentRecord {
+            graph_name: String::new(),
+            graph,
+            config: GraspConfig::default(),
+            solution: vec![vec![0, 2], vec![1]],
+            stats: GraspStats::default(),
+            runtime_ms: 0,
+        };
+
+        let loaded = ExperimentRecord::from_json(&record.to_json()).unwrap();
+
+        assert_eq!(loaded.graph.edge_weight(0, 1), Some(2.5));
+        assert_eq!(loaded.graph.edge_weight(1, 2), Some(1.0));
+
+        assert!(ExperimentRecord::from_json("{}").is_err());
+        assert!(
+            ExperimentRecord::from_json(&record.to_json().replace("\"edges\": [", "[")).is_err()
+        );
+        assert!(ExperimentRecord::from_json(&record.to_json().replace("[0,1,", "[0,7,")).is_err());
+    }
+
+    #[test]
+    fn test_parse_json() {
+        assert_eq!(
+            Json::parse(r#" {"a": [1, -2.5e3, true, null], "b": "x\"é"} "#).unwrap(),
+            Json::Object(vec![
+                (
+                    "a".to_string(),
+                    Json::Array(vec![
+                        Json::Number("1".to_string()),
+                        Json::Number("-2.5e3".to_string()),
+                        Json::Bool(true),
+                        Json::Null,
+                    ])
+                ),
+                ("b".to_string(), Json::String("x\"é".to_string())),
+            ])
+        );
+        assert!(Json::parse("[1, 2").is_err());
+        assert!(Json::parse("[1] 2").is_err());
+    }
+}
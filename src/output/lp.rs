@@ -0,0 +1,143 @@
+use crate::graph::adj_list::AdjList;
+use std::fs::File;
+use std::io::{self, BufWriter, Write};
+
+/// Number of terms written per line in the sums of [`write_lp_model`].
+const TERMS_PER_LINE: usize = 8;
+
+/// Writes `terms` joined by `+` signs, [`TERMS_PER_LINE`] per line.
+fn write_sum(writer: &mut impl Write, terms: &[String]) -> Result<(), io::Error> {
+    for (i, chunk) in terms.chunks(TERMS_PER_LINE).enumerate() {
+        let separator = if i == 0 { " " } else { "\n   + " };
+        write!(writer, "{separator}{}", chunk.join(" + "))?;
+    }
+
+    Ok(())
+}
+
+/// Writes `coloring` as a warm start for the model of [`write_lp_model`], with one
+/// `x_<vertex>_<color> = <value>` line per assignment variable, 1 if the vertex holds the color
+/// and 0 otherwise. Vertices are 1-indexed, and the colors go up to the largest one used.
+pub fn write_lp_warm_start(coloring: &[usize], path: &str) -> Result<(), io::Error> {
+    let mut writer = BufWriter::new(File::create(path)?);
+    let num_colors = coloring.iter().copied().max().unwrap_or(0);
+
+    for (vertex, color) in coloring.iter().enumerate() {
+        for c in 1..=num_colors {
+            writeln!(writer, "x_{}_{c} = {}", vertex + 1, u8::from(c == *color))?;
+        }
+    }
+
+    writer.flush()
+}
+
+/// Writes the assignment formulation of the coloring of `graph` with at most `k` colors in the
+/// CPLEX LP format, minimizing the number of used colors.
+///
+/// The binary variable `x_<vertex>_<color>` tells whether the vertex holds the color, and
+/// `y_<color>` whether the color is used. Each vertex gets exactly one color, and the endpoints
+/// of an edge can't share a used color. Vertices are 1-indexed.
+pub fn write_lp_model(graph: &AdjList, k: usize, path: &str) -> Result<(), io::Error> {
+    let mut writer = BufWriter::new(File::create(path)?);
+    let num_vertices = graph.num_vertices();
+
+    writeln!(writer, "Minimize")?;
+    write!(writer, " colors:")?;
+    write_sum(
+        &mut writer,
+        &(1..=k).map(|c| format!("y_{c}")).collect::<Vec<_>>(),
+    )?;
+    writeln!(writer)?;
+
+    writeln!(writer, "Subject To")?;
+    for v in 1..=num_vertices {
+        write!(writer, " assign_{v}:")?;
+        write_sum(
+            &mut writer,
+            &(1..=k).map(|c| format!("x_{v}_{c}")).collect::<Vec<_>>(),
+        )?;
+        writeln!(writer, " = 1")?;
+    }
+
+    for (u, v) in graph.edge_list_as_slice().iter() {
+        for c in 1..=k {
+            writeln!(
+                writer,
+                " edge_{}_{}_{c}: x_{}_{c} + x_{}_{c} - y_{c} <= 0",
+                u + 1,
+                v + 1,
+                u + 1,
+                v + 1
+            )?;
+        }
+    }
+
+    writeln!(writer, "Binary")?;
+    for v in 1..=num_vertices {
+        for c in 1..=k {
+            writeln!(writer, " x_{v}_{c}")?;
+        }
+    }
+    for c in 1..=k {
+        writeln!(writer, " y_{c}")?;
+    }
+
+    writeln!(writer, "End")?;
+
+    writer.flush()
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+    use crate::{algorithms::grasp::grasp_wrapper, input};
+    use std::env;
+    use std::fs;
+
+    #[test]
+    fn test_write_lp_warm_start() {
+        if let Ok(Some(graph)) = input::read_graph_from_file("data/myc/myciel3.col") {
+            let (num_colors, coloring) = grasp_wrapper(&graph, 5, 5, 3).unwrap();
+            let path = env::temp_dir().join("gcp_heuristics_warm_start.mst");
+            let path = path.to_str().unwrap();
+
+            write_lp_warm_start(&coloring, path).unwrap();
+            let content = fs::read_to_string(path).unwrap();
+
+            assert_eq!(content.lines().count(), 11 * num_colors);
+            assert_eq!(
+                content.lines().filter(|line| line.ends_with("= 1")).count(),
+                11
+            );
+            assert!(content.contains(&format!("x_1_{} = 1", coloring[0])));
+        } else {
+            panic!("The file containing the test graph is missing")
+        }
+    }
+
+    #[test]
+    fn test_write_lp_model() {
+        // A triangle with 3 colors
+        let graph = AdjList::complete(3);
+        let path = env::temp_dir().join("gcp_heuristics_model.lp");
+        let path = path.to_str().unwrap();
+
+        write_lp_model(&graph, 3, path).unwrap();
+        let content = fs::read_to_string(path).unwrap();
+        let count = |prefix: &str| {
+            content
+                .lines()
+                .filter(|line| line.trim_start().starts_with(prefix))
+                .count()
+        };
+
+        assert!(content.starts_with("Minimize\n colors: y_1 + y_2 + y_3\n"));
+        assert!(content.contains(" assign_1: x_1_1 + x_1_2 + x_1_3 = 1\n"));
+        assert!(content.contains(" edge_1_2_3: x_1_3 + x_2_3 - y_3 <= 0\n"));
+        assert_eq!(count("assign_"), 3);
+        // One constraint per edge and color
+        assert_eq!(count("edge_"), 9);
+        assert_eq!(count("x_"), 9);
+        assert!(content.ends_with("End\n"));
+    }
+}
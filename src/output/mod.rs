@@ -0,0 +1,6 @@
+//! Writers for exporting graphs and colorings to other tools, rendering them as text, and
+//! archiving experiments.
+
+pub mod ascii;
+pub mod experiment;
+pub mod lp;
@@ -0,0 +1,263 @@
+use crate::algorithms::grasp::{grasp_with_config, GraspConfig};
+use crate::algorithms::ColoringError;
+use crate::benchmark::write_table;
+use crate::graph::adj_list::AdjList;
+use std::fmt;
+use std::time::{Duration, Instant};
+
+/// Values of the main GRASP parameters to try, every combination of them being benchmarked.
+#[derive(Debug, Clone, Default)]
+pub struct ParameterGrid {
+    pub grasp_iterations: Vec<i32>,
+    pub color_iterations: Vec<i32>,
+    pub color_list_sizes: Vec<usize>,
+}
+
+impl ParameterGrid {
+    /// Every combination of the values, varying the color list size fastest.
+    fn combinations(&self) -> Vec<(i32, i32, usize)> {
+        let mut combinations = Vec::new();
+
+        for grasp_iterations in self.grasp_iterations.iter() {
+            for color_iterations in self.color_iterations.iter() {
+                for color_list_size in self.color_list_sizes.iter() {
+                    combinations.push((*grasp_iterations, *color_iterations, *color_list_size));
+                }
+            }
+        }
+
+        combinations
+    }
+}
+
+/// Averages of the trials of a combination of parameters of a [`ParameterGrid`].
+#[derive(Debug, Clone)]
+pub struct ParameterTuningEntry {
+    pub grasp_iterations: i32,
+    pub color_iterations: i32,
+    pub color_list_size: usize,
+    pub average_colors: f64,
+    pub average_elapsed: Duration,
+}
+
+impl ParameterTuningEntry {
+    /// The GRASP configuration with the parameters of the entry.
+    pub fn config(&self) -> GraspConfig {
+        GraspConfig::new(
+            self.grasp_iterations,
+            self.color_iterations,
+            self.color_list_size,
+        )
+    }
+}
+
+/// Results of [`benchmark_grasp_parameters`], one entry per combination of parameters, in the
+/// order of the grid.
+#[derive(Debug, Clone, Default)]
+pub struct ParameterTuningReport {
+    pub entries: Vec<ParameterTuningEntry>,
+}
+
+impl ParameterTuningReport {
+    /// The configuration of the entry with the fewest colors on average, the first one among
+    /// ties.
+    ///
+    /// Panics if the report is empty.
+    pub fn best_config(&self) -> GraspConfig {
+        self.entries
+            .iter()
+            .reduce(|best, entry| {
+                if entry.average_colors < best.average_colors {
+                    entry
+                } else {
+                    best
+                }
+            })
+            .expect("The report has no entries")
+            .config()
+    }
+
+    /// Prints the report to stdout as an ASCII table.
+    pub fn print_table(&self) {
+        print!("{self}");
+    }
+}
+
+impl fmt::Display for ParameterTuningReport {
+    fn fmt(&self, f: &mut fmt::Formatter<'_>) -> fmt::Result {
+        let rows: Vec<Vec<String>> = self
+            .entries
+            .iter()
+            .map(|entry| {
+                vec![
+                    entry.grasp_iterations.to_string(),
+                    entry.color_iterations.to_string(),
+                    entry.color_list_size.to_string(),
+                    format!("{:.2}", entry.average_colors),
+                    format!("{:.3}", entry.average_elapsed.as_secs_f64() * 1000.0),
+                ]
+            })
+            .collect();
+
+        write_table(
+            f,
+            &[
+                "GRASP iterations",
+                "Color iterations",
+                "List size",
+                "Avg colors",
+                "Avg time (ms)",
+            ],
+            &rows,
+        )
+    }
+}
+
+/// Runs GRASP `trials` times on `graph` with every combination of parameters of `grid`,
+/// averaging the number of colors and the running time of each. Trial `i` is seeded with
+/// `seed + i`, so every combination sees the same seeds.
+///
+/// Returns [`ColoringError::InvalidConfig`] if the grid or `trials` is empty, or if a
+/// combination is invalid.
+pub fn benchmark_grasp_parameters(
+    graph: &AdjList,
+    grid: &ParameterGrid,
+    trials: usize,
+    seed: u64,
+) -> Result<ParameterTuningReport, ColoringError> {
+    let combinations = grid.combinations();
+
+    if combinations.is_empty() {
+        return Err(ColoringError::InvalidConfig(
+            "the parameter grid has no combinations".to_string(),
+        ));
+    }
+    if trials == 0 {
+        return Err(ColoringError::InvalidConfig(
+            "at least one trial per combination is needed".to_string(),
+        ));
+    }
+
+    let mut report = ParameterTuningReport::default();
+
+    for (grasp_iterations, color_iterations, color_list_size) in combinations {
+        let mut total_colors = 0;
+        let mut total_elapsed = Duration::ZERO;
+
+        for trial in 0..trials {
+            let config = GraspConfig::new(grasp_iterations, color_iterations, color_list_size)
+                .seed(seed.wrapping_add(trial as u64));
+
+            let start = Instant::now();
+            let (num_colors, _) = grasp_with_config(graph, &config)?;
+            total_elapsed += start.elapsed();
+            total_colors += num_colors;
+        }
+
+        report.entries.push(ParameterTuningEntry {
+            grasp_iterations,
+            color_iterations,
+            color_list_size,
+            average_colors: total_colors as f64 / trials as f64,
+            average_elapsed: total_elapsed / trials as u32,
+        });
+    }
+
+    Ok(report)
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+    use crate::input;
+
+    #[test]
+    fn test_benchmark_grasp_parameters() {
+        if let Ok(Some(graph)) = input::read_graph_from_file("data/myc/myciel4.col") {
+            let grid = ParameterGrid {
+                grasp_iterations: vec![1, 3],
+                color_iterations: vec![1, 5],
+                color_list_sizes: vec![1, 3],
+            };
+            let report = benchmark_grasp_parameters(&graph, &grid, 5, 0).unwrap();
+
+            assert_eq!(report.entries.len(), 8);
+            assert_eq!(
+                (
+                    report.entries[1].grasp_iterations,
+                    report.entries[1].color_iterations,
+                    report.entries[1].color_list_size
+                ),
+                (1, 1, 3)
+            );
+            // myciel4 has chromatic number 5
+            assert!(report
+                .entries
+                .iter()
+                .all(|entry| entry.average_colors >= 5.0));
+
+            let best = report.best_config();
+            let best_colors = report
+                .entries
+                .iter()
+                .map(|entry| entry.average_colors)
+                .fold(f64::INFINITY, f64::min);
+            let best_entry = report
+                .entries
+                .iter()
+                .find(|entry| entry.average_colors == best_colors)
+                .unwrap();
+
+            assert_eq!(
+                (
+                    best.grasp_iterations,
+                    best.color_iterations,
+                    best.color_list_size
+                ),
+                (
+                    best_entry.grasp_iterations,
+                    best_entry.color_iterations,
+                    best_entry.color_list_size
+                )
+            );
+
+            let (_, coloring) = grasp_with_config(&graph, &best).unwrap();
+
+            assert!(graph.is_valid_coloring(&coloring));
+
+            // A row per entry, plus the header and 3 separators
+            assert_eq!(report.to_string().lines().count(), 12);
+        } else {
+            panic!("The file containing the test graph is missing")
+        }
+    }
+
+    #[test]
+    fn test_benchmark_grasp_parameters_empty() {
+        let graph = AdjList::complete(3);
+        let grid = ParameterGrid {
+            grasp_iterations: vec![1],
+            color_iterations: vec![1],
+            color_list_sizes: Vec::new(),
+        };
+
+        assert!(matches!(
+            benchmark_grasp_parameters(&graph, &grid, 5, 0),
+            Err(ColoringError::InvalidConfig(_))
+        ));
+
+        let grid = ParameterGrid {
+            color_list_sizes: vec![1],
+            ..grid
+        };
+
+        assert!(benchmark_grasp_parameters(&graph, &grid, 0, 0).is_err());
+        assert_eq!(
+            benchmark_grasp_parameters(&graph, &grid, 1, 0)
+                .unwrap()
+                .entries[0]
+                .average_colors,
+            3.0
+        );
+    }
+}
@@ -0,0 +1,73 @@
+use gcp_heuristics::algorithms::{
+    grasp::{grasp_with_config, GraspConfig},
+    greedy::{dsatur, welsh_powell},
+    rlf::rlf,
+    Solution,
+};
+use gcp_heuristics::graph::adj_list::AdjList;
+use gcp_heuristics::input;
+use std::fs;
+
+/// Reads every instance of `data/myc`, sorted by path.
+fn myciel_graphs() -> Vec<(String, AdjList)> {
+    let mut paths: Vec<String> = fs::read_dir("data/myc")
+        .expect("The directory containing the test graphs is missing")
+        .map(|entry| entry.unwrap().path().to_string_lossy().into_owned())
+        .filter(|path| path.ends_with(".col"))
+        .collect();
+    paths.sort();
+
+    paths
+        .into_iter()
+        .map(|path| match input::read_graph_from_file(&path) {
+            Ok(Some(graph)) => (path, graph),
+            _ => panic!("Failed to read the test graph {path}"),
+        })
+        .collect()
+}
+
+fn check_solution(path: &str, algorithm: &str, graph: &AdjList, (num_colors, coloring): &Solution) {
+    assert!(
+        graph.is_valid_coloring(coloring),
+        "{algorithm} returned an invalid coloring of {path}"
+    );
+    assert!(
+        (graph.clique_lower_bound()..=graph.num_vertices()).contains(num_colors),
+        "{algorithm} used {num_colors} colors on {path}"
+    );
+}
+
+#[test]
+fn test_algorithm_comparison() {
+    let graphs = myciel_graphs();
+
+    assert!(!graphs.is_empty());
+
+    for (path, graph) in graphs.iter() {
+        let grasp_solution = grasp_with_config(graph, &GraspConfig::new(2, 2, 3)).unwrap();
+        let dsatur_solution = dsatur(graph);
+        let welsh_powell_solution = welsh_powell(graph);
+        let rlf_solution = rlf(graph);
+
+        check_solution(path, "GRASP", graph, &grasp_solution);
+        check_solution(path, "DSatur", graph, &dsatur_solution);
+        check_solution(path, "Welsh-Powell", graph, &welsh_powell_solution);
+        check_solution(path, "RLF", graph, &rlf_solution);
+
+        // The greedy heuristics shouldn't be far behind GRASP
+        let limit = 1.5 * grasp_solution.0 as f64;
+
+        assert!(
+            dsatur_solution.0 as f64 <= limit,
+            "DSatur used {} colors on {path}, GRASP {}",
+            dsatur_solution.0,
+            grasp_solution.0
+        );
+        assert!(
+            rlf_solution.0 as f64 <= limit,
+            "RLF used {} colors on {path}, GRASP {}",
+            rlf_solution.0,
+            grasp_solution.0
+        );
+    }
+}